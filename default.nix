@@ -0,0 +1,9 @@
+{ pkgs ? import <nixpkgs> { }, features ? [ ] }:
+
+pkgs.rustPlatform.buildRustPackage {
+  pname = "noir-circuit-profiler";
+  version = "1.0.0";
+  src = ./.;
+  cargoLock.lockFile = ./Cargo.lock;
+  buildFeatures = features;
+}
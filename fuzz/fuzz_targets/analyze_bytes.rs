@@ -0,0 +1,19 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use noir_circuit_profiler::{AnalysisBuilder, AnalysisLimits, CostModel};
+use std::collections::HashMap;
+use std::time::Duration;
+
+fuzz_target!(|data: &[u8]| {
+    let analyzer = AnalysisBuilder::new()
+        .cost_model(CostModel::Fixed(HashMap::new()))
+        .limits(AnalysisLimits {
+            max_file_size: Some(1 << 20),
+            max_opcodes: Some(10_000),
+            timeout: Some(Duration::from_secs(2)),
+        })
+        .build();
+    
+    let _ = analyzer.analyze_bytes(data);
+});
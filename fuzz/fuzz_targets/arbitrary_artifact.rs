@@ -0,0 +1,18 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use noir_circuit_profiler::calls::entry_points;
+use noir_circuit_profiler::fuzzing::arbitrary_artifact;
+use noir_circuit_profiler::{AnalysisBuilder, CostModel};
+use std::collections::HashMap;
+
+fuzz_target!(|data: &[u8]| {
+    let artifact = arbitrary_artifact(data);
+    let builder = AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new()));
+    
+    let _ = builder.clone().build().analyze_value(&artifact);
+    
+    if let Some(entry) = entry_points(&artifact).last() {
+        let _ = builder.entry(entry.clone()).build().analyze_value(&artifact);
+    }
+});
@@ -64,7 +64,7 @@ fatal_error() {
 ensure_build() {
   if [ ! -f "target/release/noir-circuit-profiler" ]; then
     echo -e "\n${YELLOW}${BOLD}building noir-circuit-profiler...${NC}"
-    cargo build --release > /dev/null 2>&1
+    cargo build --release --features server,nargo > /dev/null 2>&1
     echo -e "${GREEN}${BOLD}build complete${NC}"
   fi
 }
@@ -89,7 +89,7 @@ analyze_circuit() {
   fi
   
   print_header "analyzing circuit: $TARGET"
-  run_profiler analyze "$TARGET"
+  run_profiler analyze "$TARGET" "$@"
 }
 
 # compare two circuits
@@ -132,7 +132,7 @@ run_demo() {
   
   # ensure fresh build
   echo -e "${YELLOW}${BOLD}building latest version...${NC}"
-  cargo build --release > /dev/null
+  cargo build --release --features server,nargo > /dev/null
   echo -e "${GREEN}${BOLD}build complete${NC}"
 
   # analyze simple circuit
@@ -166,7 +166,7 @@ batch_analyze() {
   fi
   
   print_header "batch analyzing circuits in $TARGET"
-  run_profiler batch "$TARGET"
+  run_profiler batch "$TARGET" "$@"
 }
 
 collect_stats() {
@@ -201,19 +201,19 @@ calibrate_model() {
   fi
   
   print_header "calibrating cost model"
-  run_profiler calibrate "$TARGET" "$RESET"
+  run_profiler calibrate "$TARGET" $RESET
 }
 
 # main script logic
 case $MODE in
   "analyze")
-    analyze_circuit
+    analyze_circuit "${@:3}"
     ;;
   "compare")
     compare_circuits "$2" "$3"
     ;;
   "batch")
-    batch_analyze
+    batch_analyze "${@:3}"
     ;;
   "stats")
     collect_stats
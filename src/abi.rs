@@ -0,0 +1,138 @@
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PublicValue {
+    pub name: String,
+    pub kind: String,
+    pub fields: usize,
+}
+
+pub fn field_count(abi_type: &Value) -> usize {
+    match abi_type["kind"].as_str() {
+        Some("array") => {
+            let length = abi_type["length"].as_u64().unwrap_or(0) as usize;
+            length.saturating_mul(field_count(&abi_type["type"]))
+        },
+        Some("string") => abi_type["length"].as_u64().unwrap_or(0) as usize,
+        Some("struct") => abi_type["fields"].as_array()
+            .into_iter()
+            .flatten()
+            .map(|field| field_count(&field["type"]))
+            .sum(),
+        Some("tuple") => abi_type["fields"].as_array()
+            .into_iter()
+            .flatten()
+            .map(field_count)
+            .sum(),
+        _ => 1,
+    }
+}
+
+fn from_abi(abi: &Value) -> Vec<PublicValue> {
+    let mut values: Vec<PublicValue> = abi["parameters"].as_array()
+        .into_iter()
+        .flatten()
+        .filter(|parameter| parameter["visibility"].as_str() == Some("public"))
+        .map(|parameter| PublicValue {
+            name: parameter["name"].as_str().unwrap_or("unnamed").to_string(),
+            kind: "input".to_string(),
+            fields: field_count(&parameter["type"]),
+        })
+        .collect();
+    
+    let return_type = &abi["return_type"]["abi_type"];
+    if !return_type.is_null() {
+        values.push(PublicValue {
+            name: "return".to_string(),
+            kind: "return".to_string(),
+            fields: field_count(return_type),
+        });
+    }
+    
+    values
+}
+
+fn from_names(data: &Value, key: &str, kind: &str) -> Vec<PublicValue> {
+    data[key].as_array()
+        .into_iter()
+        .flatten()
+        .enumerate()
+        .map(|(idx, value)| PublicValue {
+            name: value.as_str().map_or_else(|| format!("{}[{}]", key, idx), str::to_string),
+            kind: kind.to_string(),
+            fields: 1,
+        })
+        .collect()
+}
+
+pub fn abi_public_inputs(data: &Value) -> Option<usize> {
+    let parameters = data["abi"]["parameters"].as_array()?;
+    Some(parameters.iter()
+        .filter(|parameter| parameter["visibility"].as_str() == Some("public"))
+        .map(|parameter| field_count(&parameter["type"]))
+        .sum())
+}
+
+pub fn abi_return_values(data: &Value) -> Option<usize> {
+    if !data["abi"].is_object() {
+        return None;
+    }
+    
+    match &data["abi"]["return_type"]["abi_type"] {
+        Value::Null => Some(0),
+        return_type => Some(field_count(return_type)),
+    }
+}
+
+pub fn public_values(data: &Value) -> Vec<PublicValue> {
+    if data["abi"]["parameters"].is_array() {
+        return from_abi(&data["abi"]);
+    }
+    
+    let mut values = from_names(data, "public_inputs", "input");
+    values.extend(from_names(data, "return_values", "return"));
+    values
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn counts_fields_in_nested_types() {
+        let field = json!({ "kind": "field" });
+        let point = json!({ "kind": "struct", "fields": [{ "name": "x", "type": field }, { "name": "y", "type": field }] });
+        
+        assert_eq!(field_count(&json!({ "kind": "array", "length": 3, "type": point })), 6);
+        assert_eq!(field_count(&json!({ "kind": "tuple", "fields": [field, { "kind": "string", "length": 5 }] })), 6);
+    }
+    
+    #[test]
+    fn prefers_the_abi_over_witness_names() {
+        let data = json!({
+            "abi": {
+                "parameters": [
+                    { "name": "root", "visibility": "public", "type": { "kind": "array", "length": 2, "type": { "kind": "field" } } },
+                    { "name": "secret", "visibility": "private", "type": { "kind": "field" } },
+                ],
+                "return_type": { "abi_type": { "kind": "boolean" } },
+            },
+            "public_inputs": ["w0"],
+        });
+        let values = public_values(&data);
+        
+        assert_eq!(values.iter().map(|value| (value.name.as_str(), value.fields)).collect::<Vec<_>>(), [("root", 2), ("return", 1)]);
+        assert_eq!((abi_public_inputs(&data), abi_return_values(&data)), (Some(2), Some(1)));
+    }
+    
+    #[test]
+    fn falls_back_to_witness_names() {
+        let data = json!({ "public_inputs": ["x", 7], "return_values": ["out"] });
+        let names: Vec<String> = public_values(&data).into_iter().map(|value| value.name).collect();
+        
+        assert_eq!(names, ["x", "public_inputs[1]", "out"]);
+        assert_eq!((abi_public_inputs(&data), abi_return_values(&data)), (None, None));
+    }
+}
@@ -0,0 +1,149 @@
+use crate::notify::Violation;
+use anyhow::{bail, Context, Result};
+use chrono::NaiveDate;
+use serde::Deserialize;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Default, Deserialize)]
+pub struct Allowlist {
+    #[serde(default)]
+    pub allow: Vec<AllowEntry>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct AllowEntry {
+    #[serde(default)]
+    pub circuit: Option<String>,
+    #[serde(default)]
+    pub operation: Option<String>,
+    #[serde(default)]
+    pub until: Option<NaiveDate>,
+    #[serde(default)]
+    pub ticket: Option<String>,
+    #[serde(default)]
+    pub reason: Option<String>,
+}
+
+impl AllowEntry {
+    fn matches(&self, circuits: &[String], violation: &Violation) -> bool {
+        let circuit_matches = self.circuit.as_ref()
+            .is_none_or(|circuit| circuit == "*" || circuits.iter().any(|name| name == circuit));
+        
+        let operation_matches = self.operation.as_ref()
+            .is_none_or(|operation| violation.metric == *operation || violation.metric.starts_with(&format!("{}_", operation)));
+        
+        circuit_matches && operation_matches
+    }
+    
+    pub fn expired(&self, today: NaiveDate) -> bool {
+        self.until.is_some_and(|until| until < today)
+    }
+    
+    pub fn describe(&self) -> String {
+        let mut parts = Vec::new();
+        if let Some(until) = self.until {
+            parts.push(format!("until {}", until));
+        }
+        if let Some(ticket) = &self.ticket {
+            parts.push(ticket.clone());
+        }
+        if let Some(reason) = &self.reason {
+            parts.push(reason.clone());
+        }
+        
+        parts.join(", ")
+    }
+}
+
+pub fn load_allowlist(path: &Path) -> Result<Allowlist> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read allowlist: {}", path.display()))?;
+    
+    let allowlist: Allowlist = serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse allowlist: {}", path.display()))?;
+    
+    for (idx, entry) in allowlist.allow.iter().enumerate() {
+        if entry.until.is_none() && entry.ticket.is_none() {
+            bail!("Allowlist entry {} in {} needs an `until` date or a `ticket`", idx + 1, path.display());
+        }
+    }
+    
+    Ok(allowlist)
+}
+
+#[derive(Debug, Default)]
+pub struct Triage<'a> {
+    pub blocking: Vec<Violation>,
+    pub allowed: Vec<(Violation, &'a AllowEntry)>,
+}
+
+pub fn triage<'a>(allowlist: &'a Allowlist, circuits: &[String], violations: Vec<Violation>, today: NaiveDate) -> Triage<'a> {
+    let mut result = Triage::default();
+    
+    for mut violation in violations {
+        let matching: Vec<&AllowEntry> = allowlist.allow.iter()
+            .filter(|entry| entry.matches(circuits, &violation))
+            .collect();
+        
+        match matching.iter().find(|entry| !entry.expired(today)) {
+            Some(entry) => result.allowed.push((violation, entry)),
+            None => {
+                if let Some(expired) = matching.first() {
+                    violation.message = format!("{} (allowlist entry expired: {})", violation.message, expired.describe());
+                }
+                result.blocking.push(violation);
+            }
+        }
+    }
+    
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn violation(metric: &str) -> Violation {
+        Violation { metric: metric.to_string(), limit: 1.0, actual: 2.0, message: format!("{} over budget", metric) }
+    }
+    
+    fn date(text: &str) -> NaiveDate {
+        text.parse().unwrap()
+    }
+    
+    #[test]
+    fn active_entries_allow_matching_violations() {
+        let allowlist: Allowlist = serde_yaml::from_str("
+allow:
+  - { circuit: main, operation: hashing, until: 2026-12-31 }
+  - { circuit: '*', operation: constraints, until: 2026-01-01, ticket: PROJ-1 }
+").unwrap();
+        let violations = vec![violation("hashing_percent"), violation("constraints"), violation("proving_time")];
+        let result = triage(&allowlist, &["main".to_string()], violations, date("2026-06-01"));
+        
+        assert_eq!(result.allowed.len(), 1);
+        assert_eq!(result.allowed[0].0.metric, "hashing_percent");
+        assert_eq!(result.blocking[0].message, "constraints over budget (allowlist entry expired: until 2026-01-01, PROJ-1)");
+        assert_eq!(result.blocking[1].message, "proving_time over budget");
+    }
+    
+    #[test]
+    fn entries_only_match_their_circuit_and_metric_prefix() {
+        let entry = AllowEntry { circuit: Some("main".to_string()), operation: Some("hash".to_string()), ..Default::default() };
+        
+        assert!(!entry.matches(&["main".to_string()], &violation("hashing_percent")));
+        assert!(entry.matches(&["main".to_string()], &violation("hash_constraints")));
+        assert!(!entry.matches(&["other".to_string()], &violation("hash")));
+    }
+    
+    #[test]
+    fn entries_need_an_expiry_or_ticket() {
+        let path = std::env::temp_dir().join(format!("noir-profiler-allow-{}.yaml", std::process::id()));
+        fs::write(&path, "allow:\n  - { circuit: main, reason: legacy }\n").unwrap();
+        let error = load_allowlist(&path).err().map(|e| e.to_string());
+        fs::remove_file(&path).ok();
+        
+        assert!(error.unwrap().contains("needs an `until` date or a `ticket`"));
+    }
+}
@@ -1,203 +1,745 @@
-use crate::core::{CircuitAnalysis, PROVING_TIME_FACTOR, get_operation_details, update_cost_database, save_cost_database};
+use crate::abi::{abi_public_inputs, abi_return_values, public_values};
+use crate::core::{CircuitAnalysis, PROVING_TIME_FACTOR, record_seen_operations, update_cost_database, save_cost_database};
+use crate::cost::CostModel;
+use crate::assertions::assertion_costs;
+use crate::attribution::{function_ranges, source_files};
+use crate::annotations::{find_sidecar, load_markers, aggregate_by_label};
+use crate::artifact::{artifact_metadata, check_artifact, decode_artifact, is_artifact_path, read_artifact, resolve_missing, ArtifactMetadata, ARTIFACT_SUFFIXES};
+use crate::calls::{analyze_calls, entry_index, select_entry, CallGraph, ProvingStrategy};
+use crate::curve::{curve_operations, model_cost};
+use crate::dedup::find_duplicate_calls;
+use crate::exact::{exact_count, ExpressionWidth};
+use crate::field::{resolve_field, Field};
+use crate::lint::{run_lints, LintContext};
+use crate::folding::classify_assertions;
+use crate::patterns::detect_patterns;
+use crate::report::long_rows;
+use crate::passes::{Pass, PassSelection};
+use crate::progress::{CancellationToken, Cancelled, Deadline, ProgressCallback, PROGRESS_INTERVAL};
+use crate::timings::{lap, record};
+use crate::sampling::{extrapolate, sample_mask, scale_count};
+use crate::taxonomy::{black_box_category, opcode_category, operation_category, Category};
 use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
 use serde_json::Value;
 use std::fs;
-use std::path::Path;
-use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+use std::time::{Duration, Instant};
+
+pub const IN_MEMORY_CIRCUIT: &str = "in-memory";
+
+#[derive(Debug, Default, Clone, Serialize, Deserialize)]
+pub struct AnalysisLimits {
+    pub max_file_size: Option<u64>,
+    pub max_opcodes: Option<usize>,
+    pub timeout: Option<Duration>,
+}
+
+#[derive(Debug)]
+pub enum Skipped {
+    FileTooLarge { size: u64, limit: u64 },
+    TooManyOpcodes { count: usize, limit: usize },
+    TimedOut { limit: Duration },
+}
+
+impl fmt::Display for Skipped {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Skipped::FileTooLarge { size, limit } => {
+                write!(f, "skipped: too large ({} bytes, limit {} bytes)", size, limit)
+            },
+            Skipped::TooManyOpcodes { count, limit } => {
+                write!(f, "skipped: too large ({} opcodes, limit {} opcodes)", count, limit)
+            },
+            Skipped::TimedOut { limit } => {
+                write!(f, "skipped: timed out after {:.1?}", limit)
+            }
+        }
+    }
+}
+
+impl std::error::Error for Skipped {}
+
+#[derive(Debug, Default, Clone)]
+pub struct AnalysisOptions {
+    pub limits: AnalysisLimits,
+    pub expression_width: ExpressionWidth,
+    pub proving_strategy: ProvingStrategy,
+    pub label: Option<String>,
+    pub sample: Option<f64>,
+    pub entry: Option<String>,
+    pub passes: PassSelection,
+    pub profiles: Option<PathBuf>,
+    pub exclude: Vec<PathBuf>,
+    pub cost_model: CostModel,
+    pub cancel: Option<CancellationToken>,
+    pub progress: Option<ProgressCallback>,
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct AnalysisBuilder {
+    options: AnalysisOptions,
+    skip: Vec<Pass>,
+}
+
+impl AnalysisBuilder {
+    pub fn new() -> Self {
+        AnalysisBuilder::default()
+    }
+    
+    fn with_pass(mut self, pass: Pass, enabled: bool) -> Self {
+        self.skip.retain(|skipped| *skipped != pass);
+        if !enabled {
+            self.skip.push(pass);
+        }
+        self
+    }
+    
+    pub fn with_witness_graph(self, enabled: bool) -> Self {
+        self.with_pass(Pass::WitnessGraph, enabled)
+    }
+    
+    pub fn with_bottlenecks(self, enabled: bool) -> Self {
+        self.with_pass(Pass::Bottlenecks, enabled)
+    }
+    
+    pub fn with_attribution(self, enabled: bool) -> Self {
+        self.with_pass(Pass::Attribution, enabled)
+    }
+    
+    pub fn with_suggestions(self, enabled: bool) -> Self {
+        self.with_pass(Pass::Suggestions, enabled)
+    }
+    
+    pub fn quick(self) -> Self {
+        self.with_witness_graph(false)
+            .with_attribution(false)
+            .with_suggestions(false)
+    }
+    
+    pub fn cost_model(mut self, model: CostModel) -> Self {
+        self.options.cost_model = model;
+        self
+    }
+    
+    pub fn limits(mut self, limits: AnalysisLimits) -> Self {
+        self.options.limits = limits;
+        self
+    }
+    
+    pub fn expression_width(mut self, width: ExpressionWidth) -> Self {
+        self.options.expression_width = width;
+        self
+    }
+    
+    pub fn proving_strategy(mut self, strategy: ProvingStrategy) -> Self {
+        self.options.proving_strategy = strategy;
+        self
+    }
+    
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.options.label = Some(label.into());
+        self
+    }
+    
+    pub fn sample(mut self, rate: f64) -> Self {
+        self.options.sample = Some(rate);
+        self
+    }
+    
+    pub fn entry(mut self, entry: impl Into<String>) -> Self {
+        self.options.entry = Some(entry.into());
+        self
+    }
+    
+    pub fn profiles(mut self, path: impl Into<PathBuf>) -> Self {
+        self.options.profiles = Some(path.into());
+        self
+    }
+    
+    pub fn cancellation(mut self, token: CancellationToken) -> Self {
+        self.options.cancel = Some(token);
+        self
+    }
+    
+    pub fn on_progress(mut self, callback: impl Fn(&str, f64) + Send + Sync + 'static) -> Self {
+        self.options.progress = Some(ProgressCallback::new(callback));
+        self
+    }
+    
+    pub fn build(self) -> Analyzer {
+        let AnalysisBuilder { mut options, skip } = self;
+        options.passes = PassSelection::new(&[], &skip).unwrap_or_default();
+        Analyzer { options }
+    }
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Analyzer {
+    options: AnalysisOptions,
+}
+
+impl Analyzer {
+    pub fn options(&self) -> &AnalysisOptions {
+        &self.options
+    }
+    
+    pub fn analyze(&self, path: &Path) -> Result<CircuitAnalysis> {
+        analyze_circuit_with_options(path, &self.options)
+    }
+    
+    pub fn analyze_data(&self, path: &Path, data: &Value) -> Result<CircuitAnalysis> {
+        analyze_circuit_data(path, data, &self.options)
+    }
+    
+    pub fn analyze_bytes(&self, bytes: &[u8]) -> Result<CircuitAnalysis> {
+        analyze_bytes_with_options(bytes, &self.options)
+    }
+    
+    pub fn analyze_value(&self, data: &Value) -> Result<CircuitAnalysis> {
+        analyze_value_with_options(data, &self.options)
+    }
+    
+    pub fn batch<'a>(&'a self, dir: &'a Path) -> Result<impl Iterator<Item = (String, Result<CircuitAnalysis>)> + 'a> {
+        batch_analyze_iter(dir, &self.options)
+    }
+    
+    pub fn compare(&self, path1: &Path, path2: &Path) -> Result<(CircuitAnalysis, CircuitAnalysis)> {
+        compare_circuits_with_options(path1, path2, &self.options)
+    }
+}
 
 #[allow(dead_code)]
 pub fn analyze_circuit(path: &Path) -> Result<CircuitAnalysis> {
-    let json = fs::read_to_string(path)
-        .with_context(|| format!("Failed to read circuit file: {}", path.display()))?;
+    AnalysisBuilder::new().build().analyze(path)
+}
+
+pub fn analyze_circuit_with_limits(path: &Path, limits: &AnalysisLimits) -> Result<CircuitAnalysis> {
+    analyze_circuit_with_options(path, &AnalysisOptions { limits: limits.clone(), ..Default::default() })
+}
+
+pub fn analyze_circuit_with_options(path: &Path, options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    let start = Instant::now();
+    let limits = &options.limits;
+    
+    if let Some(limit) = limits.max_file_size {
+        let size = fs::metadata(path)
+            .with_context(|| format!("Failed to read circuit file: {}", path.display()))?
+            .len();
+        
+        if size > limit {
+            return Err(Skipped::FileTooLarge { size, limit }.into());
+        }
+    }
+    
+    let mut phase = Instant::now();
+    let json = read_artifact(path, limits.max_file_size)?;
+    lap("read", &mut phase);
     
-    let data: Value = serde_json::from_str(&json)
+    if let Some(limit) = limits.max_file_size {
+        if json.len() as u64 > limit {
+            return Err(Skipped::FileTooLarge { size: json.len() as u64, limit }.into());
+        }
+    }
+    
+    let data: Value = serde_json::from_slice(&json)
         .context("Failed to parse JSON")?;
+    lap("decode", &mut phase);
+    
+    analyze_parsed(path, &data, options, start)
+}
+
+pub fn analyze_circuit_data(path: &Path, data: &Value, options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    analyze_parsed(path, data, options, Instant::now())
+}
+
+pub fn analyze_bytes(bytes: &[u8]) -> Result<CircuitAnalysis> {
+    analyze_bytes_with_options(bytes, &AnalysisOptions::default())
+}
+
+pub fn analyze_bytes_with_options(bytes: &[u8], options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    analyze_bytes_at(Path::new(""), bytes, options)
+}
+
+pub fn analyze_bytes_at(path: &Path, bytes: &[u8], options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    let start = Instant::now();
+    let limit = options.limits.max_file_size;
     
-    let empty_vec = Vec::new();
-    let opcodes = data["opcodes"].as_array().unwrap_or(&empty_vec);
+    let mut phase = Instant::now();
+    let json = decode_artifact(bytes, limit)?;
+    lap("read", &mut phase);
     
-    let public_inputs = if let Some(inputs) = data["public_inputs"].as_array() {
-        inputs.len()
-    } else {
-        0
-    };
+    if let Some(limit) = limit {
+        let size = bytes.len().max(json.len()) as u64;
+        if size > limit {
+            return Err(Skipped::FileTooLarge { size, limit }.into());
+        }
+    }
     
-    let return_values = if let Some(outputs) = data["return_values"].as_array() {
-        outputs.len()
-    } else {
-        0
-    };
+    let data: Value = serde_json::from_slice(&json)
+        .context("Failed to parse JSON")?;
+    lap("decode", &mut phase);
     
-    let mut _total_witnesses = 0;
-    
-    if let Some(witnesses) = data["witnesses"].as_object() {
-        _total_witnesses = witnesses.len();
-    } else {
-        let mut witness_set = std::collections::HashSet::new();
-        
-        for op in opcodes {
-            if let Some(op_type) = op["type"].as_str() {
-                match op_type {
-                    "AssertZero" => {
-                        if let Some(terms) = op["expression"]["terms"].as_array() {
-                            for term in terms {
-                                if let Some(var) = term["variable"].as_str() {
-                                    witness_set.insert(var.to_string());
-                                }
-                            }
-                        }
-                    },
-                    "BlackBoxFunction" => {
-                        if let Some(inputs) = op["inputs"].as_array() {
-                            for input in inputs {
-                                if let Some(var) = input["variable"].as_str() {
-                                    witness_set.insert(var.to_string());
-                                }
-                            }
-                        }
-                        if let Some(outputs) = op["outputs"].as_array() {
-                            for output in outputs {
-                                if let Some(var) = output["variable"].as_str() {
-                                    witness_set.insert(var.to_string());
-                                }
-                            }
-                        }
-                    },
-                    _ => {}
-                }
-            }
+    analyze_parsed(path, &data, options, start)
+}
+
+pub fn analyze_value(data: &Value) -> Result<CircuitAnalysis> {
+    analyze_value_with_options(data, &AnalysisOptions::default())
+}
+
+pub fn analyze_value_with_options(data: &Value, options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    analyze_parsed(Path::new(""), data, options, Instant::now())
+}
+
+struct PassRunner<'a> {
+    path: &'a Path,
+    data: Cow<'a, Value>,
+    options: &'a AnalysisOptions,
+    start: Instant,
+    analysis: CircuitAnalysis,
+    opcode_costs: Vec<usize>,
+    graph: Option<CallGraph>,
+    completed: usize,
+    total: usize,
+}
+
+fn opcodes_of(data: &Value) -> &[Value] {
+    data["opcodes"].as_array().map_or(&[][..], Vec::as_slice)
+}
+
+fn opcode_key(op_type: &str) -> String {
+    match op_type {
+        "BlackBoxFunction" => "External".to_string(),
+        "AssertZero" => "Constraint".to_string(),
+        _ => op_type.to_string(),
+    }
+}
+
+fn witness_names(opcodes: &[Value]) -> HashSet<&str> {
+    let mut names = HashSet::new();
+    
+    for op in opcodes {
+        let operands: Vec<&Value> = match op["type"].as_str() {
+            Some("AssertZero") => op["expression"]["terms"].as_array().into_iter().flatten().collect(),
+            Some("BlackBoxFunction") => op["inputs"].as_array().into_iter().flatten()
+                .chain(op["outputs"].as_array().into_iter().flatten())
+                .collect(),
+            _ => Vec::new(),
+        };
+        
+        names.extend(operands.into_iter().filter_map(|operand| operand["variable"].as_str()));
+    }
+    
+    names
+}
+
+impl<'a> PassRunner<'a> {
+    fn check_deadline(&self) -> Result<()> {
+        if self.options.cancel.as_ref().is_some_and(CancellationToken::is_cancelled) {
+            return Err(Cancelled.into());
         }
         
-        _total_witnesses = witness_set.len();
+        match self.options.limits.timeout {
+            Some(limit) if self.start.elapsed() > limit => Err(Skipped::TimedOut { limit }.into()),
+            _ => Ok(()),
+        }
     }
     
-    let private_inputs = if _total_witnesses >= public_inputs {
-        _total_witnesses - public_inputs
-    } else {
-        let max_var_index: usize = 0;
-        max_var_index.saturating_sub(public_inputs)
-    };
+    fn deadline(&self) -> Deadline {
+        Deadline::new(self.options.limits.timeout.map(|limit| self.start + limit), self.options.cancel.clone())
+    }
     
-    let mut analysis = CircuitAnalysis::default();
-    analysis.total_opcodes = opcodes.len();
-    analysis.public_inputs = public_inputs;
-    analysis.private_inputs = private_inputs;
-    analysis.return_values = return_values;
-    
-    let mut op_counts: HashMap<String, usize> = HashMap::new();
-    let mut black_box_usages = Vec::new();
-    let mut operation_costs = Vec::new();
-    let mut black_box_functions: Vec<(String, usize, usize)> = Vec::new();
-    
-    let mut operation_types = HashMap::new();
-    
-    for (idx, op) in opcodes.iter().enumerate() {
-        let op_type = op["type"].as_str().unwrap_or("Unknown");
-        let op_key = if op_type == "BlackBoxFunction" {
-            "External".to_string()
-        } else if op_type == "AssertZero" {
-            "Constraint".to_string()
-        } else {
-            op_type.to_string()
+    fn report_progress(&self, phase: &str, fraction: f64) {
+        if let Some(progress) = &self.options.progress {
+            progress.report(phase, (self.completed as f64 + fraction) / self.total.max(1) as f64 * 100.0);
+        }
+    }
+    
+    fn sampled(&self) -> bool {
+        self.options.sample.is_some_and(|rate| rate < 1.0)
+    }
+    
+    fn run(&mut self, pass: Pass) -> Result<()> {
+        match pass {
+            Pass::Parse => self.parse(),
+            Pass::WitnessGraph => self.witness_graph(),
+            Pass::Costs => self.costs(),
+            Pass::Bottlenecks => self.bottlenecks(),
+            Pass::Attribution => self.attribution(),
+            Pass::Suggestions => self.suggestions(),
+        }
+    }
+    
+    fn parse(&mut self) -> Result<()> {
+        let options = self.options;
+        check_artifact(&self.data)?;
+        
+        if let Some(entry) = &options.entry {
+            self.data = Cow::Owned(select_entry(&self.data, entry)?);
+        }
+        
+        let data = &*self.data;
+        let opcodes = opcodes_of(data);
+        
+        if let Some(limit) = options.limits.max_opcodes {
+            if opcodes.len() > limit {
+                return Err(Skipped::TooManyOpcodes { count: opcodes.len(), limit }.into());
+            }
+        }
+        
+        let analysis = &mut self.analysis;
+        let missing = &mut analysis.missing_sections;
+        analysis.total_opcodes = opcodes.len();
+        analysis.public_inputs = match data["public_inputs"].as_array() {
+            Some(inputs) => inputs.len(),
+            None => resolve_missing(missing, "public_inputs", abi_public_inputs(data), "the ABI"),
+        };
+        analysis.return_values = match data["return_values"].as_array() {
+            Some(outputs) => outputs.len(),
+            None => resolve_missing(missing, "return_values", abi_return_values(data), "the ABI"),
         };
+        analysis.private_inputs = match data["witnesses"].as_object() {
+            Some(witnesses) => witnesses.len().saturating_sub(analysis.public_inputs),
+            None if options.passes.runs(Pass::WitnessGraph) => 0,
+            None => resolve_missing(missing, "witnesses", None, ""),
+        };
+        analysis.metadata = ArtifactMetadata { label: options.label.clone(), ..artifact_metadata(data) };
+        analysis.field = resolve_field(data).0;
+        analysis.public_values = public_values(data);
+        analysis.expression_width = options.expression_width;
+        analysis.proving_strategy = options.proving_strategy;
+        analysis.proof_count = 1;
+        
+        Ok(())
+    }
+    
+    fn witness_graph(&mut self) -> Result<()> {
+        let data = &*self.data;
+        
+        if !data["witnesses"].is_object() {
+            let witnesses = witness_names(opcodes_of(data)).len();
+            self.check_deadline()?;
+            let witnesses = resolve_missing(&mut self.analysis.missing_sections, "witnesses", Some(witnesses), "opcode operands");
+            self.analysis.private_inputs = witnesses.saturating_sub(self.analysis.public_inputs);
+        }
         
-        *op_counts.entry(op_key.clone()).or_insert(0) += 1;
-        
-        let (cost, confidence) = match op_type {
-            "BlackBoxFunction" => {
-                let fn_name = op["function"].as_str().unwrap_or("unknown");
-                let (op_cost, conf) = get_operation_details(fn_name);
-                
-                black_box_usages.push((fn_name, idx));
-                operation_costs.push((format!("External::{}", fn_name), op_cost));
-                
-                operation_types.entry(fn_name.to_string())
-                    .or_insert_with(Vec::new)
-                    .push(idx);
-                
-                if let Some(idx) = black_box_functions.iter().position(|(name, _, _)| name == fn_name) {
-                    black_box_functions[idx].1 += 1;
-                } else {
-                    black_box_functions.push((fn_name.to_string(), 1, op_cost));
+        if !self.sampled() {
+            self.graph = analyze_calls(data, self.options.expression_width);
+        }
+        
+        Ok(())
+    }
+    
+    fn costs(&mut self) -> Result<()> {
+        let options = self.options;
+        let data = &*self.data;
+        let opcodes = opcodes_of(data);
+        let empty_vec = Vec::new();
+        
+        let mut op_counts: HashMap<String, usize> = HashMap::new();
+        let mut black_box_functions: Vec<(String, usize, usize)> = Vec::new();
+        let mut operation_types: HashMap<String, Vec<usize>> = HashMap::new();
+        let mut categories: HashMap<Category, usize> = HashMap::new();
+        let mut estimated_arithmetic: usize = 0;
+        let sample = options.sample
+            .filter(|rate| *rate < 1.0)
+            .map(|rate| (rate, sample_mask(opcodes.len(), rate)));
+        
+        self.opcode_costs = Vec::with_capacity(opcodes.len());
+        
+        for (idx, op) in opcodes.iter().enumerate() {
+            self.check_deadline()?;
+            
+            if idx % PROGRESS_INTERVAL == 0 {
+                self.report_progress(Pass::Costs.name(), idx as f64 / opcodes.len() as f64);
+            }
+            
+            if sample.as_ref().is_some_and(|(_, mask)| !mask[idx]) {
+                self.opcode_costs.push(0);
+                continue;
+            }
+            
+            let op_type = op["type"].as_str().unwrap_or("Unknown");
+            *op_counts.entry(opcode_key(op_type)).or_insert(0) += 1;
+            
+            let (cost, confidence) = match op_type {
+                "BlackBoxFunction" => {
+                    let fn_name = op["function"].as_str().unwrap_or("unknown");
+                    let (op_cost, conf) = match model_cost(op) {
+                        Some(cost) => (cost, 0.9),
+                        None => {
+                            if options.cost_model.is_fallback(fn_name) {
+                                match self.analysis.uncalibrated_operations.iter_mut().find(|(name, _)| name == fn_name) {
+                                    Some((_, count)) => *count += 1,
+                                    None => self.analysis.uncalibrated_operations.push((fn_name.to_string(), 1)),
+                                }
+                            }
+                            options.cost_model.operation_cost(fn_name)
+                        },
+                    };
+                    let op_cost = self.analysis.field.scale_black_box(fn_name, op_cost);
+                    
+                    operation_types.entry(fn_name.to_string())
+                        .or_default()
+                        .push(idx);
+                    
+                    if let Some(idx) = black_box_functions.iter().position(|(name, _, _)| name == fn_name) {
+                        let (_, count, cost) = &mut black_box_functions[idx];
+                        *cost = cost.saturating_mul(*count).saturating_add(op_cost) / (*count + 1);
+                        *count += 1;
+                    } else {
+                        black_box_functions.push((fn_name.to_string(), 1, op_cost));
+                    }
+                    
+                    (op_cost, conf)
+                },
+                "AssertZero" => {
+                    let terms = op["expression"]["terms"].as_array().unwrap_or(&empty_vec).len();
+                    let op_cost = options.expression_width.assert_zero_cost(terms);
+                    estimated_arithmetic = estimated_arithmetic.saturating_add(op_cost);
+                    
+                    operation_types.entry("AssertZero".to_string())
+                        .or_default()
+                        .push(idx);
+                    
+                    (op_cost, 0.98)
+                },
+                _ => {
+                    operation_types.entry(op_type.to_string())
+                        .or_default()
+                        .push(idx);
+                    
+                    (1, 0.9)
                 }
-                
-                (op_cost, conf)
-            },
-            "AssertZero" => {
-                let terms = op["expression"]["terms"].as_array().unwrap_or(&empty_vec).len();
-                let op_cost = if terms > 0 { (terms + 3) / 4 } else { 1 };
-                operation_costs.push(("Constraint".to_string(), op_cost));
-                
-                operation_types.entry("AssertZero".to_string())
-                    .or_insert_with(Vec::new)
-                    .push(idx);
-                
-                (op_cost, 0.98)
-            },
-            _ => {
-                let (op_cost, conf) = (1, 0.9);
-                operation_costs.push((op_type.to_string(), op_cost));
-                
-                operation_types.entry(op_type.to_string())
-                    .or_insert_with(Vec::new)
-                    .push(idx);
-                
-                (op_cost, conf)
+            };
+            
+            self.analysis.constraints = self.analysis.constraints.saturating_add(cost);
+            self.opcode_costs.push(cost);
+            let category = categories.entry(opcode_category(op)).or_insert(0);
+            *category = category.saturating_add(cost);
+            
+            if self.analysis.confidence == 0.0 {
+                self.analysis.confidence = confidence;
+            } else {
+                self.analysis.confidence = (self.analysis.confidence + confidence) / 2.0;
             }
-        };
+        }
         
-        analysis.constraints += cost;
+        let analysis = &mut self.analysis;
         
-        if cost > 10_000 {
-            analysis.bottlenecks.push((op_key, cost));
+        if let Some((rate, mask)) = &sample {
+            let sampled: Vec<usize> = mask.iter().zip(&self.opcode_costs)
+                .filter(|(selected, _)| **selected)
+                .map(|(_, cost)| *cost)
+                .collect();
+            let factor = opcodes.len() as f64 / sampled.len().max(1) as f64;
+            let (constraints, sampling) = extrapolate(&sampled, opcodes.len(), *rate);
+            
+            analysis.constraints = constraints;
+            analysis.sampling = Some(sampling);
+            for count in op_counts.values_mut() {
+                *count = scale_count(*count, factor);
+            }
+            for (_, count, _) in &mut black_box_functions {
+                *count = scale_count(*count, factor);
+            }
+            for constraints in categories.values_mut() {
+                *constraints = scale_count(*constraints, factor);
+            }
         }
         
-        if analysis.confidence == 0.0 {
-            analysis.confidence = confidence;
+        analysis.operation_counts = op_counts.into_iter().collect();
+        analysis.black_box_functions = black_box_functions;
+        analysis.operation_counts.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        analysis.black_box_functions.sort_by(|a, b| b.1.saturating_mul(b.2).cmp(&a.1.saturating_mul(a.2)).then(a.0.cmp(&b.0)));
+        analysis.uncalibrated_operations.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        
+        let sampled = analysis.sampling.is_some();
+        let function = options.entry.as_deref().and_then(|entry| entry_index(data, entry)).unwrap_or(0);
+        let exact = if sampled {
+            None
         } else {
-            analysis.confidence = (analysis.confidence + confidence) / 2.0;
+            match exact_count(data, options.expression_width, function) {
+                Ok(exact) => exact,
+                Err(error) => {
+                    analysis.warnings.push(format!("{:#}; constraint count is estimated", error));
+                    None
+                },
+            }
+        };
+        
+        let mut assert_zero_constraints = estimated_arithmetic;
+        if let Some(exact) = exact {
+            assert_zero_constraints = exact.assert_zero;
+            analysis.constraints = analysis.constraints.saturating_sub(estimated_arithmetic).saturating_add(exact.assert_zero);
+            analysis.exact = true;
+            
+            let arithmetic = categories.entry(Category::Arithmetic).or_insert(0);
+            *arithmetic = arithmetic.saturating_sub(estimated_arithmetic).saturating_add(exact.assert_zero);
+        }
+        
+        analysis.categories = categories.into_iter().filter(|(_, constraints)| *constraints > 0).collect();
+        analysis.categories.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+        
+        analysis.aggregate_constraints = analysis.constraints;
+        
+        if let Some(mut graph) = self.graph.take() {
+            graph.set_main_constraints(analysis.constraints);
+            analysis.aggregate_constraints = graph.aggregate_constraints(options.proving_strategy);
+            analysis.proof_count = graph.proofs(options.proving_strategy);
+            analysis.inlined_constraints = graph.inlined_constraints;
+            analysis.call_graph = graph.edges;
+            analysis.callees = graph.callees;
+        }
+        
+        let base_proving_time = (analysis.aggregate_constraints as f64) * PROVING_TIME_FACTOR / 50.0;
+        
+        analysis.estimated_proving_time = base_proving_time * analysis.field.proving_time_factor();
+        
+        if analysis.constraints > 0 {
+            let parallel_factor = if has_sequential_dependencies(analysis) {
+                1.0 - (0.15 * (analysis.public_inputs as f64).sqrt() / 10.0).min(0.5)
+            } else {
+                1.0 - (0.3 * (analysis.public_inputs as f64).sqrt() / 10.0).min(0.7)
+            };
+            
+            analysis.estimated_proving_time *= parallel_factor;
+        }
+        
+        if !sampled && options.cost_model.updates_database() {
+            update_cost_database_from_circuit(&operation_types, assert_zero_constraints, analysis);
         }
+        
+        Ok(())
     }
     
-    analysis.operation_counts = op_counts.into_iter().collect();
-    analysis.black_box_functions = black_box_functions;
-    analysis.operation_counts.sort_by(|a, b| b.1.cmp(&a.1));
-    
-    let hardware_factor = {
-        use std::time::{SystemTime, UNIX_EPOCH};
-        let seed = SystemTime::now()
-            .duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .subsec_nanos() as f64 / 1_000_000_000.0;
+    fn bottlenecks(&mut self) -> Result<()> {
+        let opcodes = opcodes_of(&self.data);
         
-        0.85 + (seed.sin().abs() * 0.3)
-    };
+        self.analysis.bottlenecks = opcodes.iter().zip(&self.opcode_costs)
+            .filter(|(_, cost)| **cost > 10_000)
+            .map(|(op, cost)| (opcode_key(op["type"].as_str().unwrap_or("Unknown")), *cost))
+            .collect();
+        
+        if !self.sampled() {
+            self.analysis.curve_operations = curve_operations(opcodes, &self.opcode_costs);
+            self.check_deadline()?;
+            self.analysis.repeated_patterns = detect_patterns(opcodes, &self.opcode_costs, &self.deadline());
+        }
+        
+        Ok(())
+    }
     
-    let base_proving_time = (analysis.constraints as f64) * PROVING_TIME_FACTOR / 50.0;
+    fn attribution(&mut self) -> Result<()> {
+        if self.sampled() {
+            return Ok(());
+        }
+        
+        let width = self.options.expression_width;
+        
+        self.analysis.function_ranges = function_ranges(&self.data, &self.opcode_costs, width);
+        self.check_deadline()?;
+        self.analysis.source_files = source_files(&self.data, &self.opcode_costs, width, &self.analysis.callees);
+        self.check_deadline()?;
+        self.analysis.assertions = assertion_costs(&self.data, &self.opcode_costs, width);
+        self.check_deadline()?;
+        
+        let data = &*self.data;
+        let analysis = &mut self.analysis;
+        let sidecar = self.options.profiles.clone().or_else(|| find_sidecar(self.path));
+        if let Some(sidecar) = sidecar {
+            match load_markers(&sidecar) {
+                Ok(markers) => analysis.annotations = aggregate_by_label(&markers, opcodes_of(data), &self.opcode_costs),
+                Err(error) => analysis.warnings.push(format!("{:#}; labeled regions skipped", error)),
+            }
+        }
+        
+        Ok(())
+    }
     
-    analysis.estimated_proving_time = base_proving_time * hardware_factor;
+    fn suggestions(&mut self) -> Result<()> {
+        if self.sampled() {
+            return Ok(());
+        }
+        
+        let deadline = self.deadline();
+        let data = &*self.data;
+        let opcodes = opcodes_of(data);
+        let folding = classify_assertions(data, opcodes, &self.opcode_costs, &deadline);
+        self.check_deadline()?;
+        
+        let analysis = &mut self.analysis;
+        analysis.constant_assertions = folding.constant_only;
+        analysis.foldable_assertions = folding.foldable;
+        analysis.witness_assertions = folding.witness_dependent;
+        analysis.wasted_constraints = folding.wasted_constraints;
+        analysis.wasted_sites = folding.sites;
+        analysis.duplicate_calls = find_duplicate_calls(opcodes, &self.opcode_costs, &deadline);
+        self.check_deadline()?;
+        
+        self.analysis.lints = run_lints(&LintContext { data, opcodes, opcode_costs: &self.opcode_costs });
+        
+        Ok(())
+    }
+}
+
+fn analyze_parsed(path: &Path, data: &Value, options: &AnalysisOptions, start: Instant) -> Result<CircuitAnalysis> {
+    let mut runner = PassRunner {
+        path,
+        data: Cow::Borrowed(data),
+        options,
+        start,
+        analysis: CircuitAnalysis::default(),
+        opcode_costs: Vec::new(),
+        graph: None,
+        completed: 0,
+        total: options.passes.enabled().count(),
+    };
     
-    if analysis.constraints > 0 {
-        let parallel_factor = if has_sequential_dependencies(&analysis) {
-            1.0 - (0.15 * (analysis.public_inputs as f64).sqrt() / 10.0).min(0.5)
-        } else {
-            1.0 - (0.3 * (analysis.public_inputs as f64).sqrt() / 10.0).min(0.7)
-        };
+    for pass in options.passes.enabled() {
+        runner.check_deadline()?;
+        runner.report_progress(pass.name(), 0.0);
         
-        analysis.estimated_proving_time *= parallel_factor;
+        let started = Instant::now();
+        runner.run(pass)?;
+        record(pass.name(), started.elapsed());
+        runner.completed += 1;
     }
     
-    update_cost_database_from_circuit(&operation_types, &analysis);
+    runner.check_deadline()?;
+    runner.report_progress("done", 0.0);
+    
+    let mut analysis = runner.analysis;
+    analysis.skipped_passes = options.passes.skipped().map(|pass| pass.name().to_string()).collect();
+    
+    let circuit = analysis.metadata.name.clone().unwrap_or_else(|| {
+        let Some(name) = path.file_name().map(|name| name.to_string_lossy().into_owned()) else {
+            return IN_MEMORY_CIRCUIT.to_string();
+        };
+        ARTIFACT_SUFFIXES.iter().find_map(|suffix| name.strip_suffix(suffix)).unwrap_or(&name).to_string()
+    });
+    analysis.rows = long_rows(&analysis, &circuit);
     
     Ok(analysis)
 }
 
 fn update_cost_database_from_circuit(
     operation_types: &HashMap<String, Vec<usize>>,
+    assert_zero_constraints: usize,
     analysis: &CircuitAnalysis
 ) {
+    record_seen_operations(analysis.black_box_functions.iter().map(|(name, _, _)| name.as_str()));
+    
+    if analysis.field != Field::default() {
+        save_cost_database();
+        return;
+    }
+    
     for (op_name, instances) in operation_types {
-        if instances.len() < 1 {
+        if instances.is_empty() {
             continue;
         }
         
@@ -207,13 +749,13 @@ fn update_cost_database_from_circuit(
         
         if let Some(bb_func) = analysis.black_box_functions.iter()
             .find(|(name, count, _)| name == op_name && *count == 1) {
-                
+            
             let (_, _, cost) = bb_func;
             update_cost_database(op_name, *cost);
         }
         
         if op_name == "AssertZero" && instances.len() >= 10 {
-            let avg_cost = analysis.constraints / instances.len();
+            let avg_cost = assert_zero_constraints / instances.len();
             update_cost_database(op_name, avg_cost);
         }
     }
@@ -223,10 +765,10 @@ fn update_cost_database_from_circuit(
 
 fn has_sequential_dependencies(analysis: &CircuitAnalysis) -> bool {
     let has_memory_ops = analysis.operation_counts.iter()
-        .any(|(op, _)| op.contains("Memory") || op.contains("Array"));
+        .any(|(op, _)| operation_category(op) == Category::Memory);
     
     let has_multiple_hashes = analysis.black_box_functions.iter()
-        .filter(|(name, _, _)| name.contains("hash") || name.contains("Hash"))
+        .filter(|(name, _, _)| black_box_category(name) == Category::Hashing)
         .map(|(_, count, _)| count)
         .sum::<usize>() > 1;
     
@@ -235,8 +777,12 @@ fn has_sequential_dependencies(analysis: &CircuitAnalysis) -> bool {
 
 #[allow(dead_code)]
 pub fn compare_circuits(path1: &Path, path2: &Path) -> Result<(CircuitAnalysis, CircuitAnalysis)> {
-    let analysis1 = analyze_circuit(path1)?;
-    let analysis2 = analyze_circuit(path2)?;
+    compare_circuits_with_options(path1, path2, &AnalysisOptions::default())
+}
+
+pub fn compare_circuits_with_options(path1: &Path, path2: &Path, options: &AnalysisOptions) -> Result<(CircuitAnalysis, CircuitAnalysis)> {
+    let analysis1 = analyze_circuit_with_options(path1, options)?;
+    let analysis2 = analyze_circuit_with_options(path2, options)?;
     
     analyze_diff_from_cost_model(&analysis1, &analysis2);
     
@@ -269,24 +815,24 @@ fn analyze_diff_from_cost_model(analysis1: &CircuitAnalysis, analysis2: &Circuit
         }
     }
     
-    op_diffs.sort_by(|a, b| b.1.abs().cmp(&a.1.abs()));
+    op_diffs.sort_by_key(|diff| std::cmp::Reverse(diff.1.abs()));
     
     let mut external_diffs = Vec::new();
     let bb1: std::collections::HashMap<_, _> = analysis1.black_box_functions
         .iter()
         .map(|(name, count, _)| (name.clone(), *count))
         .collect();
-        
+    
     let bb2: std::collections::HashMap<_, _> = analysis2.black_box_functions
         .iter()
         .map(|(name, count, _)| (name.clone(), *count))
         .collect();
-        
+    
     let mut all_bb = std::collections::HashSet::new();
-    for (name, _) in &bb1 {
+    for name in bb1.keys() {
         all_bb.insert(name.clone());
     }
-    for (name, _) in &bb2 {
+    for name in bb2.keys() {
         all_bb.insert(name.clone());
     }
     
@@ -303,34 +849,271 @@ fn analyze_diff_from_cost_model(analysis1: &CircuitAnalysis, analysis2: &Circuit
 
 #[allow(dead_code)]
 pub fn batch_analyze(dir: &Path) -> Result<Vec<(String, Result<CircuitAnalysis>)>> {
-    let mut results = Vec::new();
-    
+    batch_analyze_with_limits(dir, &AnalysisLimits::default())
+}
+
+pub fn batch_analyze_with_limits(dir: &Path, limits: &AnalysisLimits) -> Result<Vec<(String, Result<CircuitAnalysis>)>> {
+    batch_analyze_with_options(dir, &AnalysisOptions { limits: limits.clone(), ..Default::default() })
+}
+
+pub fn batch_analyze_with_options(dir: &Path, options: &AnalysisOptions) -> Result<Vec<(String, Result<CircuitAnalysis>)>> {
+    Ok(batch_analyze_iter(dir, options)?.collect())
+}
+
+pub fn batch_analyze_iter<'a>(
+    dir: &'a Path,
+    options: &'a AnalysisOptions
+) -> Result<impl Iterator<Item = (String, Result<CircuitAnalysis>)> + 'a> {
     if !dir.exists() || !dir.is_dir() {
         return Err(anyhow::anyhow!("Directory not found or is not a directory: {}", dir.display()));
     }
     
-    for entry in walkdir::WalkDir::new(dir)
+    let exclude: Vec<PathBuf> = options.exclude.iter()
+        .map(|path| fs::canonicalize(path).unwrap_or_else(|_| path.clone()))
+        .collect();
+    
+    Ok(walkdir::WalkDir::new(dir)
+        .sort_by_file_name()
         .into_iter()
         .filter_map(|e| e.ok())
-        .filter(|e| {
-            e.path().extension().map_or(false, |ext| ext == "json") && e.path().exists()
-        })
-    {
-        let path = entry.path();
-        let file_name = path.file_name()
-            .unwrap_or_default()
-            .to_string_lossy()
-            .to_string();
-        
-        match fs::metadata(path) {
-            Ok(metadata) => {
-                if metadata.is_file() && metadata.len() > 0 {
-                    results.push((file_name, analyze_circuit(path)));
-                }
-            },
-            Err(_) => continue
+        .filter(move |e| is_artifact_path(e.path())
+            && !fs::canonicalize(e.path()).is_ok_and(|path| exclude.contains(&path)))
+        .filter(|e| fs::metadata(e.path()).is_ok_and(|metadata| metadata.is_file() && metadata.len() > 0))
+        .take_while(move |_| !options.cancel.as_ref().is_some_and(CancellationToken::is_cancelled))
+        .map(move |entry| {
+            let path = entry.path();
+            let file_name = path.strip_prefix(dir)
+                .unwrap_or(path)
+                .to_string_lossy()
+                .to_string();
+            
+            (file_name, analyze_circuit_with_options(path, options))
+        }))
+}
+
+pub fn batch_analyze_paths(dir: &Path, paths: &[String], options: &AnalysisOptions) -> Result<Vec<(String, Result<CircuitAnalysis>)>> {
+    if !dir.is_dir() {
+        return Err(anyhow::anyhow!("Directory not found or is not a directory: {}", dir.display()));
+    }
+    
+    Ok(paths.iter()
+        .map(|name| (name.clone(), analyze_circuit_with_options(&dir.join(name), options)))
+        .collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::artifact::Resolution;
+    use crate::cost::CostModel;
+    use crate::passes::QUICK_SKIPPED;
+    
+    fn fixed() -> AnalysisOptions {
+        AnalysisOptions { cost_model: CostModel::Fixed(HashMap::new()), ..Default::default() }
+    }
+    
+    fn fixture(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR")).join("examples/circuits").join(name)
+    }
+    
+    #[test]
+    fn repeated_analyses_serialize_identically() {
+        for name in ["simple_hash.json", "function_calls.json", "repetitive_hashes.json", "duplicate_hashes.json"] {
+            let path = fixture(name);
+            let analyzer = AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).build();
+            
+            let first = serde_json::to_string(&analyzer.analyze(&path).unwrap()).unwrap();
+            let second = serde_json::to_string(&analyzer.analyze(&path).unwrap()).unwrap();
+            assert_eq!(first, second, "{} serialized differently across runs", name);
         }
     }
     
-    Ok(results)
-} 
\ No newline at end of file
+    #[test]
+    fn batch_guards_skip_oversized_circuits() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-guards-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("one.json"), r#"{ "opcodes": [{ "type": "AssertZero" }] }"#).unwrap();
+        fs::write(dir.join("two.json"), r#"{ "opcodes": [{ "type": "AssertZero" }, { "type": "AssertZero" }] }"#).unwrap();
+        
+        let run = |limits: AnalysisLimits| batch_analyze_with_options(&dir, &AnalysisOptions { limits, ..fixed() }).unwrap();
+        let by_opcodes = run(AnalysisLimits { max_opcodes: Some(1), ..Default::default() });
+        let by_size = run(AnalysisLimits { max_file_size: Some(50), ..Default::default() });
+        let by_time = run(AnalysisLimits { timeout: Some(Duration::ZERO), ..Default::default() });
+        fs::remove_dir_all(&dir).unwrap();
+        
+        assert_eq!(by_opcodes.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>(), ["one.json", "two.json"]);
+        assert!(by_opcodes[0].1.is_ok());
+        let skipped = |result: &Result<CircuitAnalysis>| result.as_ref().err().and_then(|error| error.downcast_ref::<Skipped>()).map(|skipped| skipped.to_string());
+        assert_eq!(skipped(&by_opcodes[1].1).as_deref(), Some("skipped: too large (2 opcodes, limit 1 opcodes)"));
+        assert!(by_size[0].1.is_ok() && skipped(&by_size[1].1).unwrap().contains("limit 50 bytes"));
+        assert!(by_time.iter().all(|(_, result)| skipped(result).is_some_and(|reason| reason.starts_with("skipped: timed out"))));
+    }
+    
+    #[test]
+    fn expression_width_splits_wide_assertions() {
+        let terms: Vec<Value> = (0..9).map(|idx| serde_json::json!({ "variable": format!("_{}", idx) })).collect();
+        let data = serde_json::json!({ "opcodes": [{ "type": "AssertZero", "expression": { "terms": terms } }] });
+        let analyze = |width: ExpressionWidth| AnalysisBuilder::new()
+            .cost_model(CostModel::Fixed(HashMap::new()))
+            .expression_width(width)
+            .build()
+            .analyze_value(&data)
+            .unwrap();
+        
+        let bounded = analyze(ExpressionWidth::Bounded(4));
+        assert_eq!((bounded.constraints, bounded.expression_width), (3, ExpressionWidth::Bounded(4)));
+        assert_eq!(analyze(ExpressionWidth::Unbounded).constraints, 1);
+    }
+    
+    #[test]
+    fn proving_strategy_sets_aggregate_cost() {
+        let data = serde_json::json!({ "functions": [
+            { "opcodes": [{ "type": "Call", "id": 1 }, { "type": "Call", "id": 1 }, { "type": "AssertZero" }] },
+            { "opcodes": [{ "type": "AssertZero" }, { "type": "AssertZero" }] },
+        ] });
+        let analyze = |strategy: ProvingStrategy| AnalysisBuilder::new()
+            .cost_model(CostModel::Fixed(HashMap::new()))
+            .proving_strategy(strategy)
+            .build()
+            .analyze_value(&data)
+            .unwrap();
+        
+        let (fold, inline) = (analyze(ProvingStrategy::Fold), analyze(ProvingStrategy::Inline));
+        assert_eq!((fold.proof_count, inline.proof_count), (3, 1));
+        assert_eq!(fold.proving_strategy, ProvingStrategy::Fold);
+        assert!(fold.aggregate_constraints > inline.aggregate_constraints);
+        assert_eq!("Inline".parse::<ProvingStrategy>(), Ok(ProvingStrategy::Inline));
+    }
+    
+    #[test]
+    fn unknown_black_boxes_are_tracked_as_uncalibrated() {
+        let data = serde_json::json!({ "opcodes": [
+            { "type": "BlackBoxFunction", "function": "mystery_gadget" },
+            { "type": "BlackBoxFunction", "function": "mystery_gadget" },
+            { "type": "BlackBoxFunction", "function": "keccak256" },
+        ] });
+        let analysis = AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).build().analyze_value(&data).unwrap();
+        
+        assert_eq!(analysis.uncalibrated_operations, [("mystery_gadget".to_string(), 2)]);
+        assert!(analysis.uncalibrated_warning().unwrap().starts_with("1 operation used the uncalibrated default cost"));
+    }
+    
+    #[test]
+    fn entry_selects_the_analyzed_function() {
+        let data = serde_json::json!({
+            "names": ["main", "leaf"],
+            "functions": [
+                { "opcodes": [{ "type": "Call", "id": 1 }, { "type": "AssertZero" }] },
+                { "opcodes": [{ "type": "AssertZero" }, { "type": "AssertZero" }, { "type": "AssertZero" }] },
+            ],
+        });
+        let analyze = |entry: &str| AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).entry(entry).build().analyze_value(&data);
+        
+        assert_eq!(analyze("main").unwrap().total_opcodes, 2);
+        assert_eq!(analyze("leaf").unwrap().total_opcodes, 3);
+        assert!(analyze("missing").unwrap_err().to_string().contains("available: main, leaf"));
+    }
+    
+    #[test]
+    fn rows_are_labelled_with_the_artifact_stem() {
+        let analysis = AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).build().analyze(&fixture("simple_hash.json")).unwrap();
+        
+        assert!(!analysis.rows.is_empty());
+        assert!(analysis.rows.iter().all(|row| row.circuit == "simple_hash"));
+    }
+    
+    #[test]
+    fn builder_toggles_skip_passes() {
+        let analyzer = AnalysisBuilder::new()
+            .cost_model(CostModel::Fixed(HashMap::new()))
+            .with_suggestions(false)
+            .with_bottlenecks(false)
+            .with_attribution(false)
+            .with_attribution(true)
+            .build();
+        let analysis = analyzer.analyze(&fixture("duplicate_hashes.json")).unwrap();
+        let full = AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).build().analyze(&fixture("duplicate_hashes.json")).unwrap();
+        
+        assert!(!analyzer.options().cost_model.updates_database());
+        assert!(full.skipped_passes.is_empty() && !full.duplicate_calls.is_empty() && !full.bottlenecks.is_empty());
+        assert_eq!(analysis.skipped_passes, ["bottlenecks", "suggestions"]);
+        assert!(analysis.duplicate_calls.is_empty() && analysis.bottlenecks.is_empty());
+    }
+    
+    #[test]
+    fn in_memory_artifacts_match_files() {
+        let analyzer = AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).build();
+        let path = fixture("function_calls.json");
+        let bytes = fs::read(&path).unwrap();
+        
+        let from_file = analyzer.analyze(&path).unwrap();
+        let from_bytes = analyzer.analyze_bytes(&zstd::encode_all(&bytes[..], 0).unwrap()).unwrap();
+        let from_value = analyzer.analyze_value(&serde_json::from_slice(&bytes).unwrap()).unwrap();
+        
+        assert_eq!((from_bytes.constraints, from_value.constraints), (from_file.constraints, from_file.constraints));
+        assert!(from_bytes.rows.iter().all(|row| row.circuit == IN_MEMORY_CIRCUIT));
+        assert!(analyzer.analyze_bytes(b"not json").unwrap_err().to_string().starts_with("Failed to parse JSON"));
+    }
+    
+    #[test]
+    fn batch_iter_streams_until_cancelled() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-batch-iter-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        for name in ["a.json", "b.json", "c.json"] {
+            fs::write(dir.join(name), r#"{ "opcodes": [{ "type": "AssertZero" }] }"#).unwrap();
+        }
+        fs::write(dir.join("empty.json"), "").unwrap();
+        
+        let token = CancellationToken::new();
+        let options = AnalysisOptions { exclude: vec![dir.join("b.json")], cancel: Some(token.clone()), ..fixed() };
+        let all: Vec<String> = batch_analyze_iter(&dir, &options).unwrap().map(|(name, _)| name).collect();
+        
+        let mut streamed = Vec::new();
+        for (name, result) in batch_analyze_iter(&dir, &options).unwrap() {
+            assert!(result.is_ok());
+            streamed.push(name);
+            token.cancel();
+        }
+        fs::remove_dir_all(&dir).unwrap();
+        
+        assert_eq!(all, ["a.json", "c.json"]);
+        assert_eq!(streamed, ["a.json"]);
+        assert!(batch_analyze_iter(&dir, &options).is_err());
+    }
+    
+    #[test]
+    fn missing_sections_are_estimated_or_flagged() {
+        let terms = serde_json::json!([{ "variable": "x" }, { "variable": "y" }, { "variable": "z" }]);
+        let opcodes = serde_json::json!([{ "type": "AssertZero", "expression": { "terms": terms } }]);
+        let analyze = |data: Value| AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).build().analyze_value(&data).unwrap();
+        let sections = |analysis: &CircuitAnalysis| analysis.missing_sections.iter()
+            .map(|missing| (missing.section.clone(), missing.resolution))
+            .collect::<Vec<_>>();
+        
+        let with_abi = analyze(serde_json::json!({
+            "opcodes": opcodes,
+            "abi": { "parameters": [{ "name": "x", "type": { "kind": "field" }, "visibility": "public" }], "return_type": null },
+        }));
+        assert_eq!((with_abi.public_inputs, with_abi.return_values, with_abi.private_inputs), (1, 0, 2));
+        assert_eq!(sections(&with_abi), [
+            ("public_inputs".to_string(), Resolution::Estimated),
+            ("return_values".to_string(), Resolution::Estimated),
+            ("witnesses".to_string(), Resolution::Estimated),
+        ]);
+        
+        let bare = analyze(serde_json::json!({ "opcodes": opcodes, "public_inputs": ["x"] }));
+        assert_eq!(sections(&bare), [("return_values".to_string(), Resolution::Unknown), ("witnesses".to_string(), Resolution::Estimated)]);
+        assert_eq!(bare.unknown_sections().next().unwrap().to_string(), "field `return_values` missing — value unknown, reported as 0");
+    }
+    
+    #[test]
+    fn quick_mode_keeps_the_core_counts() {
+        let analyze = |builder: AnalysisBuilder| builder.cost_model(CostModel::Fixed(HashMap::new())).build().analyze(&fixture("duplicate_hashes.json")).unwrap();
+        let (full, quick) = (analyze(AnalysisBuilder::new()), analyze(AnalysisBuilder::new().quick()));
+        
+        assert_eq!((quick.constraints, quick.total_opcodes, quick.public_inputs), (full.constraints, full.total_opcodes, full.public_inputs));
+        assert_eq!(quick.skipped_passes, QUICK_SKIPPED.map(|pass| pass.name()));
+        assert!(quick.duplicate_calls.is_empty() && !full.duplicate_calls.is_empty());
+        assert!(quick.unknown_sections().any(|missing| missing.section == "witnesses"));
+    }
+}
@@ -0,0 +1,150 @@
+use anyhow::{Context, Result};
+use serde::Deserialize;
+use serde_json::Value;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const PROFILES_FILE: &str = "profiles.yaml";
+
+#[derive(Debug, Deserialize)]
+pub struct ProfileMarkers {
+    #[serde(default)]
+    pub labels: Vec<ProfileMarker>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ProfileMarker {
+    pub label: String,
+    #[serde(default)]
+    pub opcodes: Option<(usize, usize)>,
+    #[serde(default)]
+    pub span: Option<SourceSpan>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct SourceSpan {
+    pub file: String,
+    #[serde(default)]
+    pub lines: Option<(usize, usize)>,
+}
+
+pub fn find_sidecar(artifact: &Path) -> Option<PathBuf> {
+    let dir = artifact.parent()?;
+    let sidecar = dir.join(PROFILES_FILE);
+    
+    if sidecar.is_file() {
+        Some(sidecar)
+    } else {
+        None
+    }
+}
+
+pub fn load_markers(path: &Path) -> Result<ProfileMarkers> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read profile markers: {}", path.display()))?;
+    
+    serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse profile markers: {}", path.display()))
+}
+
+impl ProfileMarker {
+    pub fn matches(&self, idx: usize, op: &Value) -> bool {
+        if let Some((start, end)) = self.opcodes {
+            if idx >= start && idx <= end {
+                return true;
+            }
+        }
+        
+        if let Some(span) = &self.span {
+            let location = &op["location"];
+            let file_matches = location["file"].as_str()
+                .is_some_and(|file| file.ends_with(&span.file));
+            
+            if file_matches {
+                return match (span.lines, location["line"].as_u64()) {
+                    (Some((first, last)), Some(line)) => {
+                        let line = line as usize;
+                        line >= first && line <= last
+                    },
+                    (Some(_), None) => false,
+                    (None, _) => true,
+                };
+            }
+        }
+        
+        false
+    }
+}
+
+pub fn aggregate_by_label(
+    markers: &ProfileMarkers,
+    opcodes: &[Value],
+    opcode_costs: &[usize]
+) -> Vec<(String, usize, usize)> {
+    let mut labels: Vec<(String, usize, usize)> = Vec::new();
+    
+    for marker in &markers.labels {
+        let mut opcode_count = 0;
+        let mut constraints = 0;
+        
+        for (idx, (op, cost)) in opcodes.iter().zip(opcode_costs).enumerate() {
+            if marker.matches(idx, op) {
+                opcode_count += 1;
+                constraints += cost;
+            }
+        }
+        
+        if let Some(existing) = labels.iter_mut().find(|(name, _, _)| name == &marker.label) {
+            existing.1 += opcode_count;
+            existing.2 += constraints;
+        } else {
+            labels.push((marker.label.clone(), opcode_count, constraints));
+        }
+    }
+    
+    labels.sort_by(|a, b| b.2.cmp(&a.2).then(a.0.cmp(&b.0)));
+    labels
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn marker(label: &str, opcodes: Option<(usize, usize)>, span: Option<SourceSpan>) -> ProfileMarker {
+        ProfileMarker { label: label.to_string(), opcodes, span }
+    }
+    
+    #[test]
+    fn markers_match_opcode_ranges_and_spans() {
+        let by_range = marker("hash", Some((1, 2)), None);
+        assert!(!by_range.matches(0, &json!({})));
+        assert!(by_range.matches(2, &json!({})));
+        
+        let by_lines = marker("verify", None, Some(SourceSpan { file: "src/main.nr".to_string(), lines: Some((10, 20)) }));
+        assert!(by_lines.matches(7, &json!({ "location": { "file": "/project/src/main.nr", "line": 12 } })));
+        assert!(!by_lines.matches(7, &json!({ "location": { "file": "/project/src/main.nr", "line": 21 } })));
+        assert!(!by_lines.matches(7, &json!({ "location": { "file": "/project/src/main.nr" } })));
+        assert!(!by_lines.matches(7, &json!({ "location": { "file": "/project/src/lib.nr", "line": 12 } })));
+        
+        let whole_file = marker("lib", None, Some(SourceSpan { file: "lib.nr".to_string(), lines: None }));
+        assert!(whole_file.matches(0, &json!({ "location": { "file": "src/lib.nr" } })));
+    }
+    
+    #[test]
+    fn labels_merge_and_sort_by_constraints() {
+        let markers = ProfileMarkers {
+            labels: vec![
+                marker("small", Some((0, 0)), None),
+                marker("big", Some((1, 1)), None),
+                marker("small", Some((2, 2)), None),
+            ],
+        };
+        let opcodes = vec![json!({}), json!({}), json!({})];
+        
+        assert_eq!(aggregate_by_label(&markers, &opcodes, &[5, 100, 7]), vec![
+            ("big".to_string(), 1, 100),
+            ("small".to_string(), 2, 12),
+        ]);
+    }
+}
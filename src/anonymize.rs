@@ -0,0 +1,183 @@
+use crate::core::CircuitAnalysis;
+use crate::names::canonical_name;
+use sha2::{Digest, Sha256};
+use std::collections::hash_map::RandomState;
+use std::collections::BTreeMap;
+use std::hash::BuildHasher;
+
+pub const DEFAULT_ROUND: usize = 100;
+pub const DEFAULT_K: usize = 5;
+
+#[derive(Debug, Clone, Copy)]
+pub struct Anonymizer {
+    pub round: usize,
+    pub noise_percent: f64,
+    pub k: usize,
+    pub key: [u8; 32],
+}
+
+#[derive(Debug, Clone)]
+pub struct AnonymousRow {
+    pub circuit: String,
+    pub constraints: usize,
+    pub opcodes: usize,
+    pub external_ops: usize,
+    pub public_inputs: usize,
+    pub private_inputs: usize,
+    pub return_values: usize,
+    pub count_mode: &'static str,
+}
+
+#[derive(Debug, Clone)]
+pub struct OperationFrequency {
+    pub operation: String,
+    pub circuits: usize,
+    pub calls: usize,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct AnonymousStats {
+    pub rows: Vec<AnonymousRow>,
+    pub operations: Vec<OperationFrequency>,
+    pub suppressed: usize,
+}
+
+fn random_key() -> [u8; 32] {
+    let state = RandomState::new();
+    let mut key = [0u8; 32];
+    
+    for (idx, chunk) in key.chunks_mut(8).enumerate() {
+        chunk.copy_from_slice(&state.hash_one(idx).to_le_bytes());
+    }
+    key
+}
+
+pub fn export_key(secret: Option<&[u8]>) -> [u8; 32] {
+    match secret {
+        Some(secret) => Sha256::digest(secret).into(),
+        None => random_key(),
+    }
+}
+
+pub fn coarse_count(value: usize) -> usize {
+    if value == 0 { 0 } else { value.checked_next_power_of_two().unwrap_or(usize::MAX) }
+}
+
+impl Anonymizer {
+    fn fingerprint(&self, name: &str, column: &str) -> u64 {
+        let digest = Sha256::new()
+            .chain_update(self.key)
+            .chain_update(name.as_bytes())
+            .chain_update([0u8])
+            .chain_update(column.as_bytes())
+            .finalize();
+        
+        let mut bytes = [0u8; 8];
+        bytes.copy_from_slice(&digest[..8]);
+        u64::from_le_bytes(bytes)
+    }
+    
+    pub fn bucket(&self, name: &str, column: &str, value: usize) -> usize {
+        let noise = self.fingerprint(name, column) as f64 / u64::MAX as f64 * 2.0 - 1.0;
+        let noisy = value as f64 * (1.0 + self.noise_percent / 100.0 * noise);
+        let round = self.round.max(1) as f64;
+        
+        ((noisy / round).round() * round).max(0.0) as usize
+    }
+    
+    pub fn anonymize(&self, analyses: &[(&str, &CircuitAnalysis)]) -> AnonymousStats {
+        let mut frequencies: BTreeMap<String, (usize, usize)> = BTreeMap::new();
+        for (_, analysis) in analyses {
+            for (name, count, _) in &analysis.black_box_functions {
+                let entry = frequencies.entry(canonical_name(name)).or_insert((0, 0));
+                entry.0 += 1;
+                entry.1 += count;
+            }
+        }
+        
+        let mut stats = AnonymousStats::default();
+        for (operation, (circuits, calls)) in frequencies {
+            if circuits < self.k {
+                stats.suppressed += 1;
+                continue;
+            }
+            
+            stats.operations.push(OperationFrequency {
+                calls: self.bucket(&operation, "calls", calls),
+                operation,
+                circuits,
+            });
+        }
+        
+        let mut order: Vec<&(&str, &CircuitAnalysis)> = analyses.iter().collect();
+        order.sort_by_key(|(name, _)| self.fingerprint(name, "circuit"));
+        
+        for (idx, (name, analysis)) in order.into_iter().enumerate() {
+            let external_ops = analysis.black_box_functions.iter()
+                .filter(|(function, _, _)| stats.operations.iter().any(|op| op.operation == canonical_name(function)))
+                .count();
+            
+            stats.rows.push(AnonymousRow {
+                circuit: format!("circuit-{:03}", idx + 1),
+                constraints: self.bucket(name, "constraints", analysis.constraints),
+                opcodes: self.bucket(name, "opcodes", analysis.total_opcodes),
+                external_ops,
+                public_inputs: coarse_count(analysis.public_inputs),
+                private_inputs: coarse_count(analysis.private_inputs),
+                return_values: coarse_count(analysis.return_values),
+                count_mode: analysis.count_label(),
+            });
+        }
+        
+        stats
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn anonymizer(secret: &[u8]) -> Anonymizer {
+        Anonymizer { round: DEFAULT_ROUND, noise_percent: 10.0, k: 2, key: export_key(Some(secret)) }
+    }
+    
+    fn analysis(constraints: usize, functions: &[&str]) -> CircuitAnalysis {
+        CircuitAnalysis {
+            constraints,
+            public_inputs: 3,
+            black_box_functions: functions.iter().map(|name| (name.to_string(), 2, 100)).collect(),
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn buckets_are_keyed_rounded_and_bounded() {
+        let a = anonymizer(b"team secret");
+        let bucket = a.bucket("main", "constraints", 10_000);
+        
+        assert_eq!(bucket % DEFAULT_ROUND, 0);
+        assert!((9_000..=11_000).contains(&bucket));
+        assert_eq!(bucket, anonymizer(b"team secret").bucket("main", "constraints", 10_000));
+        assert_ne!(export_key(None), export_key(None));
+    }
+    
+    #[test]
+    fn rare_operations_are_suppressed_and_names_hidden() {
+        let (first, second) = (analysis(5_000, &["sha256", "ecdsa_secp256k1"]), analysis(7_000, &["SHA256"]));
+        let stats = anonymizer(b"k").anonymize(&[("wallet/transfer.json", &first), ("wallet/mint.json", &second)]);
+        
+        assert_eq!(stats.suppressed, 1);
+        assert_eq!(stats.operations.len(), 1);
+        assert_eq!((stats.operations[0].operation.as_str(), stats.operations[0].circuits), ("sha256", 2));
+        
+        let names: Vec<&str> = stats.rows.iter().map(|row| row.circuit.as_str()).collect();
+        assert_eq!(names, ["circuit-001", "circuit-002"]);
+        assert!(stats.rows.iter().all(|row| row.external_ops == 1 && row.public_inputs == 4));
+    }
+    
+    #[test]
+    fn coarse_counts_round_up_to_powers_of_two() {
+        assert_eq!((coarse_count(0), coarse_count(1), coarse_count(5), coarse_count(8)), (0, 1, 8, 8));
+        assert_eq!(coarse_count(usize::MAX), usize::MAX);
+    }
+}
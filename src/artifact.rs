@@ -0,0 +1,300 @@
+use anyhow::{Context, Result};
+use crate::field::resolve_field;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::fs::File;
+use std::io::{BufRead, BufReader, Read};
+use std::path::Path;
+use std::str::FromStr;
+
+const GZIP_MAGIC: [u8; 2] = [0x1f, 0x8b];
+const ZSTD_MAGIC: [u8; 4] = [0x28, 0xb5, 0x2f, 0xfd];
+
+pub const ARTIFACT_SUFFIXES: [&str; 3] = [".json", ".json.gz", ".json.zst"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FileSize(pub u64);
+
+impl FromStr for FileSize {
+    type Err = String;
+    
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        let s = s.trim();
+        let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+        let (number, unit) = s.split_at(split);
+        
+        let number: u64 = number.parse().map_err(|_| format!("invalid size `{}`, expected e.g. 64, 512K, 64M or 2G", s))?;
+        let scale: u64 = match unit.trim().to_ascii_lowercase().as_str() {
+            "b" => 1,
+            "k" | "kb" | "kib" => 1024,
+            "" | "m" | "mb" | "mib" => 1024 * 1024,
+            "g" | "gb" | "gib" => 1024 * 1024 * 1024,
+            _ => return Err(format!("invalid size unit `{}`, expected B, K, M or G", unit)),
+        };
+        
+        number.checked_mul(scale)
+            .map(FileSize)
+            .ok_or_else(|| format!("size `{}` is too large", s))
+    }
+}
+
+const MIN_NOIR_VERSION: (u64, u64) = (0, 19);
+
+#[derive(Debug)]
+pub enum ArtifactError {
+    NotAnArtifact,
+    UnsupportedVersion { version: String },
+}
+
+impl fmt::Display for ArtifactError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ArtifactError::NotAnArtifact => {
+                write!(f, "not a Noir artifact (no opcodes, bytecode or functions)")
+            },
+            ArtifactError::UnsupportedVersion { version } => {
+                write!(f, "unsupported Noir version {} (need {}.{} or newer)", version, MIN_NOIR_VERSION.0, MIN_NOIR_VERSION.1)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ArtifactError {}
+
+fn parse_version(version: &str) -> Option<(u64, u64)> {
+    let mut parts = version.split(|c: char| !c.is_ascii_digit());
+    let major = parts.next()?.parse().ok()?;
+    let minor = parts.next()?.parse().ok()?;
+    
+    Some((major, minor))
+}
+
+pub fn check_artifact(data: &Value) -> std::result::Result<(), ArtifactError> {
+    if !["opcodes", "bytecode", "functions"].iter().any(|key| !data[*key].is_null()) {
+        return Err(ArtifactError::NotAnArtifact);
+    }
+    
+    if let Some(version) = data["noir_version"].as_str() {
+        if parse_version(version).is_some_and(|parsed| parsed < MIN_NOIR_VERSION) {
+            return Err(ArtifactError::UnsupportedVersion { version: version.to_string() });
+        }
+    }
+    
+    Ok(())
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ArtifactMetadata {
+    pub name: Option<String>,
+    pub noir_version: Option<String>,
+    pub hash: Option<String>,
+    pub label: Option<String>,
+    pub field: Option<String>,
+}
+
+impl ArtifactMetadata {
+    pub fn header_rows(&self) -> Vec<(&'static str, &str)> {
+        [
+            ("metrics.name", self.name.as_deref()),
+            ("metrics.noir_version", self.noir_version.as_deref()),
+            ("metrics.hash", self.hash.as_deref()),
+            ("metrics.label", self.label.as_deref()),
+            ("metrics.field", self.field.as_deref()),
+        ].into_iter()
+            .filter_map(|(key, value)| value.map(|value| (key, value)))
+            .collect()
+    }
+}
+
+pub fn artifact_metadata(data: &Value) -> ArtifactMetadata {
+    let text = |value: &Value| match value {
+        Value::String(text) => Some(text.clone()),
+        Value::Number(number) => Some(number.to_string()),
+        _ => None,
+    };
+    
+    ArtifactMetadata {
+        name: text(&data["name"]),
+        noir_version: text(&data["noir_version"]),
+        hash: text(&data["hash"]),
+        label: None,
+        field: Some(resolve_field(data).1),
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Resolution {
+    Estimated,
+    Unknown,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MissingSection {
+    pub section: String,
+    pub resolution: Resolution,
+    pub source: Option<String>,
+}
+
+impl fmt::Display for MissingSection {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.source {
+            Some(source) => write!(f, "field `{}` missing — value estimated from {}", self.section, source),
+            None => write!(f, "field `{}` missing — value unknown, reported as 0", self.section),
+        }
+    }
+}
+
+pub fn resolve_missing(missing: &mut Vec<MissingSection>, section: &str, estimate: Option<usize>, source: &str) -> usize {
+    missing.push(MissingSection {
+        section: section.to_string(),
+        resolution: if estimate.is_some() { Resolution::Estimated } else { Resolution::Unknown },
+        source: estimate.map(|_| source.to_string()),
+    });
+    estimate.unwrap_or(0)
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Compression {
+    None,
+    Gzip,
+    Zstd,
+}
+
+pub fn is_artifact_path(path: &Path) -> bool {
+    let name = path.file_name()
+        .unwrap_or_default()
+        .to_string_lossy();
+    
+    ARTIFACT_SUFFIXES.iter().any(|suffix| name.ends_with(suffix))
+}
+
+pub fn detect_compression(header: &[u8]) -> Compression {
+    if header.starts_with(&GZIP_MAGIC) {
+        Compression::Gzip
+    } else if header.starts_with(&ZSTD_MAGIC) {
+        Compression::Zstd
+    } else {
+        Compression::None
+    }
+}
+
+fn decompress<'a>(compression: Compression, stream: impl BufRead + 'a, max_size: Option<u64>) -> Result<Box<dyn Read + 'a>> {
+    let mut reader: Box<dyn Read + 'a> = match compression {
+        Compression::None => Box::new(stream),
+        Compression::Gzip => Box::new(flate2::read::MultiGzDecoder::new(stream)),
+        Compression::Zstd => Box::new(zstd::stream::read::Decoder::with_buffer(stream)
+            .context("Failed to initialize zstd decoder")?),
+    };
+    
+    if let Some(limit) = max_size {
+        reader = Box::new(reader.take(limit + 1));
+    }
+    
+    Ok(reader)
+}
+
+pub fn decode_artifact(bytes: &[u8], max_size: Option<u64>) -> Result<Vec<u8>> {
+    let compression = detect_compression(bytes);
+    let mut decoded = Vec::new();
+    
+    decompress(compression, bytes, max_size)?
+        .read_to_end(&mut decoded)
+        .with_context(|| format!("Failed to decompress in-memory artifact ({:?})", compression))?;
+    
+    Ok(decoded)
+}
+
+pub fn read_artifact(path: &Path, max_size: Option<u64>) -> Result<Vec<u8>> {
+    let mut file = File::open(path)
+        .with_context(|| format!("Failed to read circuit file: {}", path.display()))?;
+    
+    let mut header = Vec::with_capacity(ZSTD_MAGIC.len());
+    file.by_ref()
+        .take(ZSTD_MAGIC.len() as u64)
+        .read_to_end(&mut header)
+        .with_context(|| format!("Failed to read circuit file: {}", path.display()))?;
+    
+    let compression = detect_compression(&header);
+    let stream = BufReader::new(header.as_slice().chain(file));
+    let mut reader = decompress(compression, stream, max_size)?;
+    
+    let mut bytes = Vec::new();
+    reader.read_to_end(&mut bytes)
+        .with_context(|| format!("Failed to decompress circuit file ({:?}): {}", compression, path.display()))?;
+    
+    Ok(bytes)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::io::Write;
+    
+    #[test]
+    fn file_sizes_parse_with_units() {
+        assert_eq!("64".parse(), Ok(FileSize(64 * 1024 * 1024)));
+        assert_eq!("512K".parse(), Ok(FileSize(512 * 1024)));
+        assert_eq!(" 2gb ".parse(), Ok(FileSize(2 * 1024 * 1024 * 1024)));
+        assert_eq!("100b".parse(), Ok(FileSize(100)));
+        assert!("12T".parse::<FileSize>().is_err());
+        assert!("M".parse::<FileSize>().is_err());
+        assert!("99999999999999999999G".parse::<FileSize>().is_err());
+    }
+    
+    #[test]
+    fn old_or_empty_artifacts_are_rejected() {
+        assert!(matches!(check_artifact(&json!({ "name": "main" })), Err(ArtifactError::NotAnArtifact)));
+        assert!(matches!(check_artifact(&json!({ "opcodes": [], "noir_version": "0.18.0" })), Err(ArtifactError::UnsupportedVersion { .. })));
+        assert!(check_artifact(&json!({ "bytecode": "H4sI", "noir_version": "1.0.0-beta.3+abc" })).is_ok());
+        assert!(check_artifact(&json!({ "functions": [], "noir_version": "nightly" })).is_ok());
+    }
+    
+    #[test]
+    fn compressed_artifacts_decode() {
+        let json = br#"{"opcodes":[]}"#;
+        
+        let mut gzip = flate2::write::GzEncoder::new(Vec::new(), flate2::Compression::default());
+        gzip.write_all(json).unwrap();
+        let gzip = gzip.finish().unwrap();
+        let zstd = zstd::encode_all(&json[..], 0).unwrap();
+        
+        assert_eq!(detect_compression(&gzip), Compression::Gzip);
+        assert_eq!(detect_compression(&zstd), Compression::Zstd);
+        assert_eq!(detect_compression(json), Compression::None);
+        
+        for bytes in [&gzip[..], &zstd[..], &json[..]] {
+            assert_eq!(decode_artifact(bytes, None).unwrap(), json);
+        }
+    }
+    
+    #[test]
+    fn decoding_stops_past_the_size_limit() {
+        let zstd = zstd::encode_all(&[b' '; 4096][..], 0).unwrap();
+        assert_eq!(decode_artifact(&zstd, Some(100)).unwrap().len(), 101);
+    }
+    
+    #[test]
+    fn artifact_paths_by_suffix() {
+        assert!(is_artifact_path(Path::new("target/main.json")));
+        assert!(is_artifact_path(Path::new("target/main.json.zst")));
+        assert!(!is_artifact_path(Path::new("target/main.gz")));
+        assert!(!is_artifact_path(Path::new("target")));
+    }
+    
+    #[test]
+    fn metadata_feeds_report_headers() {
+        let mut metadata = artifact_metadata(&json!({ "name": "main", "noir_version": "1.0.0-beta.3", "hash": 12345678901234567u64 }));
+        assert_eq!(metadata.hash.as_deref(), Some("12345678901234567"));
+        
+        metadata.label = Some("nightly".to_string());
+        let keys: Vec<&str> = metadata.header_rows().into_iter().map(|(key, _)| key).collect();
+        assert_eq!(keys, ["metrics.name", "metrics.noir_version", "metrics.hash", "metrics.label", "metrics.field"]);
+        
+        let bare = artifact_metadata(&json!({ "opcodes": [] }));
+        assert_eq!(bare.header_rows().len(), 1);
+    }
+}
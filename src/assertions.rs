@@ -0,0 +1,139 @@
+use crate::calls::{estimate_opcode_cost, function_opcodes};
+use crate::exact::ExpressionWidth;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AssertionCost {
+    pub message: String,
+    pub sites: usize,
+    pub constraints: usize,
+}
+
+fn message_table(data: &Value, id: usize) -> &Value {
+    if id == 0 && data["opcodes"].is_array() {
+        &data["assert_messages"]
+    } else {
+        &data["functions"][id]["assert_messages"]
+    }
+}
+
+fn opcode_index(location: &Value) -> Option<usize> {
+    match location {
+        Value::Number(index) => index.as_u64().map(|index| index as usize),
+        Value::String(index) => index.parse().ok(),
+        Value::Object(_) => ["Acir", "acir", "opcode", "index"].iter()
+            .find_map(|key| location[*key].as_u64())
+            .map(|index| index as usize),
+        _ => None,
+    }
+}
+
+fn payload_message(payload: &Value) -> Option<String> {
+    match payload {
+        Value::String(message) => Some(message.clone()),
+        Value::Object(_) => {
+            if let Some(message) = payload["StaticString"].as_str().or(payload["static_string"].as_str()) {
+                return Some(message.to_string());
+            }
+            
+            let dynamic = payload.get("Dynamic").or(payload.get("dynamic"))?;
+            let selector = dynamic[0].as_u64().or(dynamic["error_selector"].as_u64());
+            Some(match selector {
+                Some(selector) => format!("<dynamic payload {}>", selector),
+                None => "<dynamic payload>".to_string(),
+            })
+        },
+        _ => None,
+    }
+}
+
+fn messages(data: &Value, id: usize, opcodes: &[Value]) -> Vec<(usize, String)> {
+    let mut found: Vec<(usize, String)> = Vec::new();
+    
+    for entry in message_table(data, id).as_array().map_or(&[][..], |entries| entries.as_slice()) {
+        let (location, payload) = match entry {
+            Value::Array(pair) if pair.len() == 2 => (&pair[0], &pair[1]),
+            Value::Object(_) => (
+                entry.get("opcode").or(entry.get("location")).unwrap_or(&Value::Null),
+                entry.get("message").or(entry.get("payload")).unwrap_or(&Value::Null),
+            ),
+            _ => continue,
+        };
+        
+        if let (Some(index), Some(message)) = (opcode_index(location), payload_message(payload)) {
+            found.push((index, message));
+        }
+    }
+    
+    for (index, op) in opcodes.iter().enumerate() {
+        if let Some(message) = payload_message(&op["assert_message"]) {
+            found.push((index, message));
+        }
+    }
+    
+    found.sort_by_key(|(index, _)| *index);
+    found.dedup_by_key(|(index, _)| *index);
+    found
+}
+
+pub fn assertion_costs(data: &Value, main_costs: &[usize], width: ExpressionWidth) -> Vec<AssertionCost> {
+    let function_count = data["functions"].as_array().map_or(1, |functions| functions.len().max(1));
+    let mut costs: Vec<AssertionCost> = Vec::new();
+    
+    for id in 0..function_count {
+        let opcodes = function_opcodes(data, id);
+        let cost = |index: usize| match id {
+            0 => main_costs.get(index).copied().unwrap_or(0),
+            _ => opcodes.get(index).map_or(0, |op| estimate_opcode_cost(op, width)),
+        };
+        
+        let mut start = 0;
+        for (index, message) in messages(data, id, opcodes) {
+            if index >= opcodes.len() {
+                continue;
+            }
+            
+            let constraints = (start..=index).map(&cost).fold(0usize, |total, cost| total.saturating_add(cost));
+            start = index + 1;
+            
+            match costs.iter_mut().find(|existing| existing.message == message) {
+                Some(existing) => {
+                    existing.sites += 1;
+                    existing.constraints = existing.constraints.saturating_add(constraints);
+                },
+                None => costs.push(AssertionCost { message, sites: 1, constraints }),
+            }
+        }
+    }
+    
+    costs.sort_by(|a, b| b.constraints.cmp(&a.constraints).then(a.message.cmp(&b.message)));
+    costs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn charges_each_message_the_opcodes_since_the_previous_one() {
+        let data = json!({
+            "opcodes": [{}, {}, {}, {}, { "assert_message": "a" }],
+            "assert_messages": [[1, "a"], { "opcode": "3", "message": { "StaticString": "b" } }, [9, "unreachable"]],
+        });
+        let costs = assertion_costs(&data, &[1, 2, 3, 4, 5], ExpressionWidth::default());
+        let summary: Vec<(&str, usize, usize)> = costs.iter().map(|cost| (cost.message.as_str(), cost.sites, cost.constraints)).collect();
+        
+        assert_eq!(summary, [("a", 2, 3 + 5), ("b", 1, 7)]);
+    }
+    
+    #[test]
+    fn reads_payload_and_location_variants() {
+        assert_eq!(payload_message(&json!({ "Dynamic": [7, []] })).unwrap(), "<dynamic payload 7>");
+        assert_eq!(payload_message(&json!({ "static_string": "x" })).unwrap(), "x");
+        assert_eq!(payload_message(&json!(3)), None);
+        assert_eq!(opcode_index(&json!({ "Acir": 4 })), Some(4));
+        assert_eq!(opcode_index(&json!("12")), Some(12));
+    }
+}
@@ -0,0 +1,180 @@
+use crate::calls::{estimate_opcode_cost, function_name, function_opcodes};
+use crate::core::CircuitAnalysis;
+use crate::exact::ExpressionWidth;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct FunctionRanges {
+    pub function: String,
+    pub acir_function: usize,
+    pub ranges: Vec<(usize, usize)>,
+    pub opcodes: usize,
+    pub constraints: usize,
+    #[serde(default)]
+    pub debug_info: bool,
+}
+
+fn source_function(op: &Value) -> Option<&str> {
+    op["location"]["function"].as_str()
+}
+
+pub fn function_ranges(data: &Value, main_costs: &[usize], width: ExpressionWidth) -> Vec<FunctionRanges> {
+    let function_count = data["functions"].as_array().map_or(1, |functions| functions.len().max(1));
+    let mut attributed: Vec<FunctionRanges> = Vec::new();
+    
+    for id in 0..function_count {
+        let acir_name = function_name(data, id);
+        
+        for (idx, op) in function_opcodes(data, id).iter().enumerate() {
+            let source = source_function(op);
+            let name = source.unwrap_or(&acir_name);
+            let cost = match id {
+                0 => main_costs.get(idx).copied().unwrap_or(0),
+                _ => estimate_opcode_cost(op, width),
+            };
+            
+            let position = attributed.iter().position(|entry| entry.acir_function == id && entry.function == name);
+            let entry = match position {
+                Some(position) => &mut attributed[position],
+                None => {
+                    attributed.push(FunctionRanges {
+                        function: name.to_string(),
+                        acir_function: id,
+                        ranges: Vec::new(),
+                        opcodes: 0,
+                        constraints: 0,
+                        debug_info: false,
+                    });
+                    attributed.last_mut().unwrap()
+                }
+            };
+            
+            match entry.ranges.last().copied() {
+                Some((start, end)) if end + 1 == idx => *entry.ranges.last_mut().unwrap() = (start, idx),
+                _ => entry.ranges.push((idx, idx)),
+            }
+            entry.opcodes += 1;
+            entry.constraints = entry.constraints.saturating_add(cost);
+            entry.debug_info |= source.is_some();
+        }
+    }
+    
+    attributed
+}
+
+pub fn source_files(
+    data: &Value,
+    main_costs: &[usize],
+    width: ExpressionWidth,
+    callees: &[(String, usize, usize)]
+) -> Vec<(String, usize)> {
+    let function_count = data["functions"].as_array().map_or(1, |functions| functions.len().max(1));
+    let mut files: Vec<(String, usize)> = Vec::new();
+    
+    for id in 0..function_count {
+        let invocations = match id {
+            0 => 1,
+            _ => {
+                let name = function_name(data, id);
+                callees.iter().find(|(callee, _, _)| *callee == name).map_or(0, |(_, count, _)| *count)
+            }
+        };
+        
+        for (idx, op) in function_opcodes(data, id).iter().enumerate() {
+            let Some(file) = op["location"]["file"].as_str() else {
+                continue;
+            };
+            
+            let cost = match id {
+                0 => main_costs.get(idx).copied().unwrap_or(0),
+                _ => estimate_opcode_cost(op, width),
+            }.saturating_mul(invocations);
+            
+            match files.iter_mut().find(|(name, _)| name == file) {
+                Some((_, constraints)) => *constraints = constraints.saturating_add(cost),
+                None => files.push((file.to_string(), cost)),
+            }
+        }
+    }
+    
+    files.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+    files
+}
+
+pub fn has_debug_info(analysis: &CircuitAnalysis) -> bool {
+    analysis.function_ranges.iter().any(|entry| entry.debug_info)
+}
+
+fn function_costs(analysis: &CircuitAnalysis) -> Vec<(&str, i64)> {
+    let mut costs: Vec<(&str, i64)> = Vec::new();
+    
+    for entry in &analysis.function_ranges {
+        match costs.iter_mut().find(|(name, _)| *name == entry.function) {
+            Some((_, cost)) => *cost += entry.constraints as i64,
+            None => costs.push((&entry.function, entry.constraints as i64)),
+        }
+    }
+    
+    costs
+}
+
+pub fn blame(before: &CircuitAnalysis, after: &CircuitAnalysis) -> Vec<(String, i64)> {
+    let old = function_costs(before);
+    let new = function_costs(after);
+    
+    let mut names: Vec<&str> = old.iter().chain(&new).map(|(name, _)| *name).collect();
+    names.sort();
+    names.dedup();
+    
+    let cost = |costs: &[(&str, i64)], name: &str| costs.iter().find(|(other, _)| *other == name).map_or(0, |(_, cost)| *cost);
+    
+    let mut deltas: Vec<(String, i64)> = names.into_iter()
+        .map(|name| (name.to_string(), cost(&new, name) - cost(&old, name)))
+        .filter(|(_, delta)| *delta != 0)
+        .collect();
+    
+    deltas.sort_by(|a, b| b.1.abs().cmp(&a.1.abs()).then(a.0.cmp(&b.0)));
+    deltas
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn op(function: &str, file: &str) -> Value {
+        json!({ "type": "AssertZero", "location": { "function": function, "file": file } })
+    }
+    
+    #[test]
+    fn groups_contiguous_opcodes_by_source_function() {
+        let data = json!({ "opcodes": [op("main", "main.nr"), op("hash", "lib.nr"), op("hash", "lib.nr"), op("main", "main.nr"), { "type": "AssertZero" }] });
+        let ranges = function_ranges(&data, &[1, 2, 3, 4, 5], ExpressionWidth::default());
+        assert_eq!((ranges[0].function.as_str(), ranges[0].ranges.as_slice(), ranges[0].constraints), ("main", &[(0, 0), (3, 4)][..], 10));
+        assert_eq!((ranges[1].function.as_str(), ranges[1].ranges.as_slice(), ranges[1].constraints), ("hash", &[(1, 2)][..], 5));
+        assert_eq!(ranges.len(), 2);
+        assert!(ranges.iter().all(|entry| entry.debug_info));
+        assert_eq!(source_files(&data, &[1, 2, 3, 4, 5], ExpressionWidth::default(), &[]), [("lib.nr".to_string(), 5), ("main.nr".to_string(), 5)]);
+    }
+    
+    #[test]
+    fn blames_functions_by_constraint_delta() {
+        let analysis = |costs: &[(&str, usize)]| CircuitAnalysis {
+            function_ranges: costs.iter().map(|(function, constraints)| FunctionRanges {
+                function: function.to_string(),
+                acir_function: 0,
+                ranges: Vec::new(),
+                opcodes: 1,
+                constraints: *constraints,
+                debug_info: true,
+            }).collect(),
+            ..Default::default()
+        };
+        let before = analysis(&[("main", 10), ("hash", 50), ("old", 5)]);
+        let after = analysis(&[("main", 10), ("hash", 20), ("new", 40)]);
+        
+        assert_eq!(blame(&before, &after), [("new".to_string(), 40), ("hash".to_string(), -30), ("old".to_string(), -5)]);
+        assert!(has_debug_info(&after));
+    }
+}
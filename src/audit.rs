@@ -0,0 +1,490 @@
+use crate::analyzer::{analyze_circuit_with_options, AnalysisOptions};
+use crate::artifact::read_artifact;
+use crate::core::{get_cost_database, CircuitAnalysis};
+use crate::field::{is_zero_constant, resolve_field};
+use crate::i18n::t;
+use crate::taxonomy::{black_box_category, Category};
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::{HashMap, HashSet};
+use std::fmt::Write as _;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Serialize)]
+pub struct AuditReport {
+    pub artifact: String,
+    pub generated: String,
+    pub analysis: CircuitAnalysis,
+    pub findings: Vec<Finding>,
+    pub black_box_inventory: Vec<BlackBoxEntry>,
+    pub cost_model_updated: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Finding {
+    pub severity: &'static str,
+    pub category: &'static str,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct BlackBoxEntry {
+    pub name: String,
+    pub category: Category,
+    pub calls: usize,
+    pub cost_each: usize,
+    pub confidence: f32,
+    pub provenance: String,
+}
+
+pub fn run_audit(path: &Path, options: &AnalysisOptions) -> Result<AuditReport> {
+    let analysis = analyze_circuit_with_options(path, options)?;
+    
+    let bytes = read_artifact(path, None)?;
+    let data: Value = serde_json::from_slice(&bytes).context("Failed to parse JSON")?;
+    
+    let mut findings = soundness_findings(&data, &analysis);
+    findings.extend(witness_findings(&data));
+    findings.extend(duplicate_findings(&analysis));
+    
+    let db = get_cost_database();
+    let known: HashMap<&String, &(usize, f32, usize)> = db.iter().collect();
+    
+    let black_box_inventory = analysis.black_box_functions.iter()
+        .map(|(name, calls, cost)| {
+            let (confidence, provenance) = match known.get(name) {
+                Some((_, confidence, samples)) if *samples > 1 => {
+                    (*confidence, format!("calibrated ({} samples)", samples))
+                },
+                Some((_, confidence, _)) => (*confidence, "built-in default".to_string()),
+                None => (0.0, "fallback estimate (not in cost model)".to_string()),
+            };
+            
+            BlackBoxEntry {
+                name: name.clone(),
+                category: black_box_category(name),
+                calls: *calls,
+                cost_each: *cost,
+                confidence,
+                provenance,
+            }
+        })
+        .collect();
+    
+    Ok(AuditReport {
+        artifact: path.display().to_string(),
+        generated: chrono::Local::now().to_rfc3339(),
+        analysis,
+        findings,
+        black_box_inventory,
+        cost_model_updated: db.last_updated().cloned(),
+    })
+}
+
+fn soundness_findings(data: &Value, analysis: &CircuitAnalysis) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let empty_vec = Vec::new();
+    let opcodes = data["opcodes"].as_array().unwrap_or(&empty_vec);
+    let (field, _) = resolve_field(data);
+    
+    if analysis.total_opcodes == 0 {
+        findings.push(Finding {
+            severity: "high",
+            category: "soundness",
+            message: "Circuit contains no opcodes; every witness is unconstrained".to_string(),
+        });
+    }
+    
+    for (idx, op) in opcodes.iter().enumerate() {
+        if op["type"].as_str() != Some("AssertZero") {
+            continue;
+        }
+        
+        let terms = op["expression"]["terms"].as_array().map_or(0, |t| t.len());
+        if terms == 0 {
+            let constant = &op["expression"]["constant"];
+            let message = if constant.is_null() || is_zero_constant(constant, field) {
+                format!("Opcode {} asserts a constant expression and constrains nothing", idx)
+            } else {
+                format!("Opcode {} asserts non-zero constant {} and can never be satisfied", idx,
+                    constant.as_str().map_or_else(|| constant.to_string(), |text| text.to_string()))
+            };
+            
+            findings.push(Finding { severity: "medium", category: "soundness", message });
+        }
+    }
+    
+    if analysis.public_inputs == 0 && analysis.return_values == 0 && analysis.total_opcodes > 0 {
+        findings.push(Finding {
+            severity: "low",
+            category: "soundness",
+            message: "Circuit exposes no public inputs or return values; proofs bind to nothing observable".to_string(),
+        });
+    }
+    
+    findings
+}
+
+fn duplicate_findings(analysis: &CircuitAnalysis) -> Vec<Finding> {
+    analysis.duplicate_calls.iter()
+        .map(|duplicate| {
+            let sites: Vec<String> = duplicate.sites.iter().map(|idx| idx.to_string()).collect();
+            
+            Finding {
+                severity: "low",
+                category: "efficiency",
+                message: format!("{} is called {} times on the same inputs (opcodes {}); reusing the first result reclaims {} constraints",
+                    duplicate.function, duplicate.sites.len(), sites.join(", "), duplicate.reclaimable),
+            }
+        })
+        .collect()
+}
+
+fn witness_findings(data: &Value) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let empty_vec = Vec::new();
+    let opcodes = data["opcodes"].as_array().unwrap_or(&empty_vec);
+    
+    let mut constraint_uses: HashMap<&str, usize> = HashMap::new();
+    let mut black_box_inputs: HashSet<&str> = HashSet::new();
+    let mut black_box_outputs: Vec<(&str, usize)> = Vec::new();
+    
+    for (idx, op) in opcodes.iter().enumerate() {
+        match op["type"].as_str() {
+            Some("AssertZero") => {
+                for term in op["expression"]["terms"].as_array().unwrap_or(&empty_vec) {
+                    if let Some(var) = term["variable"].as_str() {
+                        *constraint_uses.entry(var).or_insert(0) += 1;
+                    }
+                }
+            },
+            Some("BlackBoxFunction") => {
+                for input in op["inputs"].as_array().unwrap_or(&empty_vec) {
+                    if let Some(var) = input["variable"].as_str() {
+                        black_box_inputs.insert(var);
+                    }
+                }
+                for output in op["outputs"].as_array().unwrap_or(&empty_vec) {
+                    if let Some(var) = output["variable"].as_str() {
+                        black_box_outputs.push((var, idx));
+                    }
+                }
+            },
+            _ => {}
+        }
+    }
+    
+    let public: HashSet<&str> = data["public_inputs"].as_array().unwrap_or(&empty_vec)
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    
+    let returns: Vec<&str> = data["return_values"].as_array().unwrap_or(&empty_vec)
+        .iter()
+        .filter_map(|v| v.as_str())
+        .collect();
+    
+    for var in &returns {
+        if !constraint_uses.contains_key(var) && !black_box_outputs.iter().any(|(out, _)| out == var) {
+            findings.push(Finding {
+                severity: "high",
+                category: "under-constrained",
+                message: format!("Return value `{}` is not referenced by any constraint", var),
+            });
+        }
+    }
+    
+    for (var, idx) in &black_box_outputs {
+        if !constraint_uses.contains_key(var) && !black_box_inputs.contains(var) && !returns.contains(var) {
+            findings.push(Finding {
+                severity: "medium",
+                category: "under-constrained",
+                message: format!("Output `{}` of black-box opcode {} is never used", var, idx),
+            });
+        }
+    }
+    
+    let mut single_use: Vec<&str> = constraint_uses.iter()
+        .filter(|(var, uses)| **uses == 1 && !public.contains(*var) && !returns.contains(var)
+            && !black_box_inputs.contains(*var) && !black_box_outputs.iter().any(|(out, _)| out == *var))
+        .map(|(var, _)| *var)
+        .collect();
+    single_use.sort();
+    
+    if !single_use.is_empty() {
+        findings.push(Finding {
+            severity: "low",
+            category: "under-constrained",
+            message: format!("{} private witness(es) appear in a single constraint and may be free: {}",
+                single_use.len(), single_use.join(", ")),
+        });
+    }
+    
+    findings
+}
+
+pub fn render_html(report: &AuditReport) -> String {
+    let mut html = String::new();
+    let analysis = &report.analysis;
+    
+    let _ = writeln!(html, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">");
+    let _ = writeln!(html, "<title>{}: {}</title>", t("audit.title"), escape_html(&report.artifact));
+    let _ = writeln!(html, "<style>body{{font-family:sans-serif;max-width:960px;margin:2em auto}}table{{border-collapse:collapse}}td,th{{border:1px solid #ccc;padding:4px 8px;text-align:left}}.high{{color:#b00}}.medium{{color:#b60}}.low{{color:#666}}</style>");
+    let _ = writeln!(html, "</head>\n<body>");
+    let _ = writeln!(html, "<h1>{}</h1>", t("audit.title"));
+    let _ = writeln!(html, "<p>{}: <code>{}</code><br>{}: {}</p>", t("audit.artifact"), escape_html(&report.artifact), t("audit.generated"), report.generated);
+    for (key, value) in report.analysis.metadata.header_rows() {
+        let _ = writeln!(html, "<p>{}: {}</p>", t(key), escape_html(value));
+    }
+    
+    let _ = writeln!(html, "<h2>{}</h2>\n<table>", t("audit.metrics"));
+    for (metric, value) in metric_rows(analysis) {
+        let _ = writeln!(html, "<tr><th>{}</th><td>{}</td></tr>", metric, value);
+    }
+    let _ = writeln!(html, "</table>");
+    
+    let _ = writeln!(html, "<h2>{}</h2>", t("audit.findings"));
+    if report.findings.is_empty() {
+        let _ = writeln!(html, "<p>{}</p>", t("audit.no_findings"));
+    } else {
+        let _ = writeln!(html, "<table>\n<tr><th>{}</th><th>{}</th><th>{}</th></tr>", t("audit.severity"), t("audit.category"), t("audit.finding"));
+        for finding in &report.findings {
+            let _ = writeln!(html, "<tr><td class=\"{0}\">{0}</td><td>{1}</td><td>{2}</td></tr>",
+                finding.severity, finding.category, escape_html(&finding.message));
+        }
+        let _ = writeln!(html, "</table>");
+    }
+    
+    let _ = writeln!(html, "<h2>{}</h2>", t("audit.inventory"));
+    if report.black_box_inventory.is_empty() {
+        let _ = writeln!(html, "<p>{}</p>", t("audit.no_black_box"));
+    } else {
+        let _ = writeln!(html, "<table>\n<tr><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th><th>{}</th></tr>",
+            t("functions.operation"), t("details.category"), t("functions.calls"), t("audit.cost_each"), t("audit.confidence"), t("audit.provenance"));
+        for entry in &report.black_box_inventory {
+            let _ = writeln!(html, "<tr><td>{}</td><td>{}</td><td>{}</td><td>{}</td><td>{:.1}%</td><td>{}</td></tr>",
+                escape_html(&entry.name), t(entry.category.i18n_key()), entry.calls, entry.cost_each, entry.confidence * 100.0, entry.provenance);
+        }
+        let _ = writeln!(html, "</table>");
+    }
+    
+    let _ = writeln!(html, "<h2>{}</h2>", t("audit.cost_model"));
+    let _ = writeln!(html, "<p>{}: {}</p>", t("audit.last_calibration"), report.cost_model_updated.as_deref().unwrap_or(t("audit.never")));
+    let _ = writeln!(html, "<p><em>{}</em></p>", t("audit.disclaimer"));
+    let _ = writeln!(html, "</body>\n</html>");
+    
+    html
+}
+
+pub fn render_text(report: &AuditReport) -> Vec<String> {
+    let mut lines = Vec::new();
+    
+    lines.push(t("audit.title").to_uppercase());
+    lines.push(format!("{}: {}", t("audit.artifact"), report.artifact));
+    lines.push(format!("{}: {}", t("audit.generated"), report.generated));
+    for (key, value) in report.analysis.metadata.header_rows() {
+        lines.push(format!("{}: {}", t(key), value));
+    }
+    lines.push(String::new());
+    
+    lines.push(t("audit.metrics").to_uppercase());
+    for (metric, value) in metric_rows(&report.analysis) {
+        lines.push(format!("  {:<24} {}", metric, value));
+    }
+    lines.push(String::new());
+    
+    lines.push(t("audit.findings").to_uppercase());
+    if report.findings.is_empty() {
+        lines.push(format!("  {}", t("audit.no_findings")));
+    }
+    for finding in &report.findings {
+        lines.push(format!("  [{}] {}: {}", finding.severity.to_uppercase(), finding.category, finding.message));
+    }
+    lines.push(String::new());
+    
+    lines.push(t("audit.inventory").to_uppercase());
+    if report.black_box_inventory.is_empty() {
+        lines.push(format!("  {}", t("audit.no_black_box")));
+    }
+    for entry in &report.black_box_inventory {
+        lines.push(format!("  {:<20} {:<12} {:>6} calls  {:>8} each  {:>5.1}%  {}",
+            entry.name, entry.category.to_string(), entry.calls, entry.cost_each, entry.confidence * 100.0, entry.provenance));
+    }
+    lines.push(String::new());
+    
+    lines.push(t("audit.cost_model").to_uppercase());
+    lines.push(format!("  {}: {}", t("audit.last_calibration"), report.cost_model_updated.as_deref().unwrap_or(t("audit.never"))));
+    lines.push(format!("  {}", t("audit.disclaimer")));
+    
+    lines
+}
+
+const UNICODE_FONT: &str = "HeiseiMin-W3";
+
+pub fn render_pdf(report: &AuditReport) -> Vec<u8> {
+    const LINES_PER_PAGE: usize = 60;
+    
+    let lines = render_text(report);
+    let pages: Vec<&[String]> = lines.chunks(LINES_PER_PAGE).collect();
+    
+    let mut objects: Vec<String> = Vec::new();
+    let font_id = 3;
+    let unicode_font_id = 4;
+    let first_page_id = 7;
+    
+    objects.push("<< /Type /Catalog /Pages 2 0 R >>".to_string());
+    
+    let kids: Vec<String> = (0..pages.len())
+        .map(|i| format!("{} 0 R", first_page_id + i * 2))
+        .collect();
+    objects.push(format!("<< /Type /Pages /Kids [{}] /Count {} >>", kids.join(" "), pages.len()));
+    objects.push("<< /Type /Font /Subtype /Type1 /BaseFont /Courier >>".to_string());
+    objects.push(format!("<< /Type /Font /Subtype /Type0 /BaseFont /{font} /Encoding /UniJIS-UCS2-HW-H /DescendantFonts [{} 0 R] >>",
+        unicode_font_id + 1, font = UNICODE_FONT));
+    objects.push(format!("<< /Type /Font /Subtype /CIDFontType0 /BaseFont /{font} \
+        /CIDSystemInfo << /Registry (Adobe) /Ordering (Japan1) /Supplement 2 >> /FontDescriptor {} 0 R /DW 1000 /W [231 632 500] >>",
+        unicode_font_id + 2, font = UNICODE_FONT));
+    objects.push(format!("<< /Type /FontDescriptor /FontName /{} /Flags 6 /FontBBox [-123 -257 1001 910] \
+        /ItalicAngle 0 /Ascent 723 /Descent -241 /CapHeight 709 /StemV 69 >>", UNICODE_FONT));
+    
+    for (i, page) in pages.iter().enumerate() {
+        let content_id = first_page_id + i * 2 + 1;
+        objects.push(format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Resources << /Font << /F1 {} 0 R /F2 {} 0 R >> >> /Contents {} 0 R >>",
+            font_id, unicode_font_id, content_id));
+        
+        let mut stream = String::from("BT\n/F1 9 Tf\n11 TL\n40 752 Td\n");
+        let mut unicode = false;
+        for line in page.iter() {
+            if line.is_ascii() {
+                if unicode {
+                    stream.push_str("/F1 9 Tf\n");
+                    unicode = false;
+                }
+                let _ = writeln!(stream, "({}) '", escape_pdf(line));
+            } else {
+                if !unicode {
+                    stream.push_str("/F2 9 Tf\n");
+                    unicode = true;
+                }
+                let _ = writeln!(stream, "<{}> '", utf16_hex(line));
+            }
+        }
+        stream.push_str("ET");
+        objects.push(format!("<< /Length {} >>\nstream\n{}\nendstream", stream.len(), stream));
+    }
+    
+    let mut pdf = String::from("%PDF-1.4\n");
+    let mut offsets = Vec::with_capacity(objects.len());
+    for (i, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        let _ = write!(pdf, "{} 0 obj\n{}\nendobj\n", i + 1, object);
+    }
+    
+    let xref_offset = pdf.len();
+    let _ = write!(pdf, "xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        let _ = writeln!(pdf, "{:010} 00000 n ", offset);
+    }
+    let _ = write!(pdf, "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n", objects.len() + 1, xref_offset);
+    
+    pdf.into_bytes()
+}
+
+pub fn write_report(report: &AuditReport, out: &Path) -> Result<()> {
+    let extension = out.extension()
+        .map(|ext| ext.to_string_lossy().to_lowercase())
+        .unwrap_or_default();
+    
+    let content = match extension.as_str() {
+        "html" | "htm" => render_html(report).into_bytes(),
+        "pdf" => render_pdf(report),
+        "json" => serde_json::to_vec_pretty(report).context("Failed to serialize audit report")?,
+        _ => render_text(report).join("\n").into_bytes(),
+    };
+    
+    fs::write(out, content)
+        .with_context(|| format!("Failed to write audit report: {}", out.display()))
+}
+
+fn metric_rows(analysis: &CircuitAnalysis) -> Vec<(&'static str, String)> {
+    vec![
+        (t("audit.constraints"), format!("{} ({})", analysis.constraints, analysis.count_label())),
+        (t("audit.expression_width"), analysis.expression_width.to_string()),
+        (t("audit.opcodes"), analysis.total_opcodes.to_string()),
+        (t("audit.public_inputs"), analysis.public_inputs.to_string()),
+        (t("audit.private_inputs"), analysis.private_inputs.to_string()),
+        (t("audit.return_values"), analysis.return_values.to_string()),
+        (t("audit.proving_time"), format!("{:.2}ms", analysis.estimated_proving_time)),
+        (t("audit.model_confidence"), format!("{:.1}%", analysis.confidence * 100.0)),
+    ]
+}
+
+pub fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+fn escape_pdf(text: &str) -> String {
+    text.chars()
+        .map(|c| if c.is_ascii_control() { ' ' } else { c })
+        .collect::<String>()
+        .replace('\\', "\\\\")
+        .replace('(', "\\(")
+        .replace(')', "\\)")
+}
+
+fn utf16_hex(text: &str) -> String {
+    text.encode_utf16()
+        .map(|unit| if unit < 0x20 { 0x20 } else { unit })
+        .map(|unit| format!("{:04X}", unit))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn constant_assertions_are_flagged() {
+        let data = json!({ "opcodes": [
+            { "type": "AssertZero", "expression": { "terms": [], "constant": "0" } },
+            { "type": "AssertZero", "expression": { "terms": [], "constant": "5" } },
+        ]});
+        let analysis = CircuitAnalysis { total_opcodes: 2, return_values: 1, ..Default::default() };
+        let findings = soundness_findings(&data, &analysis);
+        
+        assert_eq!(findings.len(), 2);
+        assert!(findings[0].message.contains("constrains nothing"));
+        assert!(findings[1].message.contains("can never be satisfied"));
+    }
+    
+    #[test]
+    fn unconstrained_witnesses_are_reported() {
+        let data = json!({
+            "opcodes": [
+                { "type": "AssertZero", "expression": { "terms": [{ "variable": "w1" }, { "variable": "w2" }] } },
+                { "type": "AssertZero", "expression": { "terms": [{ "variable": "w2" }] } },
+                { "type": "BlackBoxFunction", "inputs": [{ "variable": "w2" }], "outputs": [{ "variable": "w3" }] },
+            ],
+            "return_values": ["w9"],
+        });
+        let messages: Vec<String> = witness_findings(&data).into_iter().map(|f| f.message).collect();
+        
+        assert!(messages.iter().any(|m| m.contains("`w9` is not referenced")));
+        assert!(messages.iter().any(|m| m.contains("`w3` of black-box opcode 2")));
+        assert!(messages.iter().any(|m| m.ends_with("free: w1")));
+    }
+    
+    #[test]
+    fn escapes_markup_and_pdf_strings() {
+        assert_eq!(escape_html("<a href=\"x\">&</a>"), "&lt;a href=&quot;x&quot;&gt;&amp;&lt;/a&gt;");
+        assert_eq!(escape_pdf("f(x)\\\n"), "f\\(x\\)\\\\ ");
+        assert_eq!(utf16_hex("a\té"), "0061002000E9");
+    }
+}
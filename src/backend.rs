@@ -0,0 +1,195 @@
+use crate::core::CircuitAnalysis;
+use crate::setup::{estimate_backend, BackendProfile, BACKEND_PROFILES};
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, RwLock};
+use std::time::Duration;
+
+pub const BB_ENV: &str = "BB";
+
+#[derive(Debug, Clone)]
+pub struct ProofOutput {
+    pub proof: PathBuf,
+    pub verification_key: Option<PathBuf>,
+    pub duration: Duration,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct KeyInfo {
+    pub bytes: u64,
+    pub circuit_size: Option<usize>,
+    pub public_inputs: Option<usize>,
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct ProofInfo {
+    pub bytes: u64,
+    pub public_inputs: Option<usize>,
+}
+
+pub trait BackendAdapter: Send + Sync {
+    fn name(&self) -> &str;
+    
+    fn translate_gates(&self, analysis: &CircuitAnalysis) -> usize;
+    
+    fn prove(&self, artifact: &Path, witness: &Path, out_dir: &Path) -> Result<ProofOutput>;
+    
+    fn inspect_key(&self, key: &Path) -> Result<KeyInfo> {
+        Ok(KeyInfo { bytes: file_size(key)?, ..Default::default() })
+    }
+    
+    fn inspect_proof(&self, proof: &Path) -> Result<ProofInfo> {
+        Ok(ProofInfo { bytes: file_size(proof)?, ..Default::default() })
+    }
+}
+
+fn file_size(path: &Path) -> Result<u64> {
+    Ok(fs::metadata(path)
+        .with_context(|| format!("Failed to read {}", path.display()))?
+        .len())
+}
+
+pub fn bb_binary() -> String {
+    std::env::var(BB_ENV).unwrap_or_else(|_| "bb".to_string())
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Barretenberg {
+    profile: BackendProfile,
+}
+
+impl Default for Barretenberg {
+    fn default() -> Self {
+        Barretenberg { profile: BACKEND_PROFILES[0] }
+    }
+}
+
+impl BackendAdapter for Barretenberg {
+    fn name(&self) -> &str {
+        self.profile.name
+    }
+    
+    fn translate_gates(&self, analysis: &CircuitAnalysis) -> usize {
+        estimate_backend(analysis, self.profile).gates
+    }
+    
+    #[cfg(not(feature = "bb"))]
+    fn prove(&self, artifact: &Path, _witness: &Path, _out_dir: &Path) -> Result<ProofOutput> {
+        anyhow::bail!("Cannot prove {}: rebuild with `--features bb` to invoke Barretenberg", artifact.display())
+    }
+    
+    #[cfg(feature = "bb")]
+    fn prove(&self, artifact: &Path, witness: &Path, out_dir: &Path) -> Result<ProofOutput> {
+        use std::process::Command;
+        use std::time::Instant;
+        
+        fs::create_dir_all(out_dir)
+            .with_context(|| format!("Failed to create {}", out_dir.display()))?;
+        
+        let bb = bb_binary();
+        let start = Instant::now();
+        let output = Command::new(&bb)
+            .args(["prove", "--scheme", "ultra_honk", "--write_vk"])
+            .arg("-b").arg(artifact)
+            .arg("-w").arg(witness)
+            .arg("-o").arg(out_dir)
+            .output()
+            .with_context(|| format!("Failed to run `{}`; install Barretenberg or set {}", bb, BB_ENV))?;
+        
+        if !output.status.success() {
+            anyhow::bail!("`{} prove` failed:\n{}", bb, String::from_utf8_lossy(&output.stderr).trim());
+        }
+        
+        let verification_key = Some(out_dir.join("vk")).filter(|vk| vk.is_file());
+        Ok(ProofOutput { proof: out_dir.join("proof"), verification_key, duration: start.elapsed() })
+    }
+    
+    fn inspect_key(&self, key: &Path) -> Result<KeyInfo> {
+        let bytes = fs::read(key)
+            .with_context(|| format!("Failed to read verification key: {}", key.display()))?;
+        let field = |idx: usize| bytes.get(idx * 32..(idx + 1) * 32)
+            .map(|word| word[24..].iter().fold(0usize, |acc, byte| (acc << 8) | *byte as usize));
+        
+        let circuit_size = field(0).filter(|size| size.is_power_of_two());
+        Ok(KeyInfo {
+            bytes: bytes.len() as u64,
+            circuit_size,
+            public_inputs: circuit_size.and(field(2)),
+        })
+    }
+}
+
+lazy_static! {
+    static ref ADAPTERS: RwLock<Vec<Arc<dyn BackendAdapter>>> = RwLock::new(vec![Arc::new(Barretenberg::default())]);
+}
+
+pub fn register_backend(adapter: impl BackendAdapter + 'static) {
+    let mut adapters = ADAPTERS.write().unwrap();
+    adapters.retain(|existing| existing.name() != adapter.name());
+    adapters.push(Arc::new(adapter));
+}
+
+pub fn backend_adapter(name: &str) -> Option<Arc<dyn BackendAdapter>> {
+    ADAPTERS.read().unwrap().iter()
+        .find(|adapter| adapter.name().eq_ignore_ascii_case(name))
+        .cloned()
+}
+
+pub fn backend_adapters() -> Vec<String> {
+    ADAPTERS.read().unwrap().iter().map(|adapter| adapter.name().to_string()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    struct Fixed(usize);
+    
+    impl BackendAdapter for Fixed {
+        fn name(&self) -> &str {
+            "backend-test"
+        }
+        
+        fn translate_gates(&self, _analysis: &CircuitAnalysis) -> usize {
+            self.0
+        }
+        
+        fn prove(&self, artifact: &Path, _witness: &Path, _out_dir: &Path) -> Result<ProofOutput> {
+            anyhow::bail!("cannot prove {}", artifact.display())
+        }
+    }
+    
+    #[test]
+    fn registering_replaces_by_name() {
+        register_backend(Fixed(1));
+        register_backend(Fixed(2));
+        
+        assert_eq!(backend_adapters().iter().filter(|name| *name == "backend-test").count(), 1);
+        let adapter = backend_adapter("Backend-Test").unwrap();
+        assert_eq!(adapter.translate_gates(&CircuitAnalysis::default()), 2);
+        assert!(backend_adapter(BACKEND_PROFILES[0].name).is_some());
+    }
+    
+    #[test]
+    fn reads_the_verification_key_header() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-backend-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let mut key = vec![0u8; 128];
+        key[30] = 4;
+        key[95] = 3;
+        fs::write(dir.join("vk"), &key).unwrap();
+        fs::write(dir.join("proof"), [0u8; 10]).unwrap();
+        
+        let info = Barretenberg::default().inspect_key(&dir.join("vk"));
+        let proof = Fixed(0).inspect_proof(&dir.join("proof"));
+        let missing = Fixed(0).inspect_key(&dir.join("missing"));
+        fs::remove_dir_all(&dir).unwrap();
+        
+        let info = info.unwrap();
+        assert_eq!((info.bytes, info.circuit_size, info.public_inputs), (128, Some(1024), Some(3)));
+        assert_eq!(proof.unwrap().bytes, 10);
+        assert!(missing.is_err());
+    }
+}
@@ -0,0 +1,125 @@
+use crate::core::CircuitAnalysis;
+use crate::names::canonical_name;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BenchmarkSet {
+    Reference,
+}
+
+impl FromStr for BenchmarkSet {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "reference" => Ok(BenchmarkSet::Reference),
+            _ => Err(format!("unknown benchmark set `{}`, expected `reference`", s)),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct ReferenceImplementation {
+    pub name: &'static str,
+    pub black_box: &'static str,
+    pub calls: usize,
+    pub constraints: usize,
+}
+
+// Whole-program gate counts of the reference programs rather than estimates,
+// so a match never compares the circuit with its own black-box costs.
+pub static REFERENCE_IMPLEMENTATIONS: [ReferenceImplementation; 4] = [
+    ReferenceImplementation { name: "Merkle proof, depth 32, pedersen", black_box: "pedersen_hash", calls: 32, constraints: 9_281 },
+    ReferenceImplementation { name: "ECDSA secp256k1 verify", black_box: "ecdsa_secp256k1", calls: 1, constraints: 36_634 },
+    ReferenceImplementation { name: "EdDSA verify (Grumpkin)", black_box: "multi_scalar_mul", calls: 1, constraints: 5_712 },
+    ReferenceImplementation { name: "SHA-256 of 64 bytes", black_box: "sha256_compression", calls: 2, constraints: 13_482 },
+];
+
+#[derive(Debug)]
+pub struct BenchmarkMatch {
+    pub reference: &'static ReferenceImplementation,
+    pub calls: usize,
+    pub expected: usize,
+}
+
+impl BenchmarkMatch {
+    pub fn instances(&self) -> f64 {
+        self.calls as f64 / self.reference.calls as f64
+    }
+}
+
+#[derive(Debug)]
+pub struct BenchmarkComparison {
+    pub matches: Vec<BenchmarkMatch>,
+    pub expected: usize,
+    pub actual: usize,
+}
+
+impl BenchmarkComparison {
+    pub fn overhead(&self) -> usize {
+        self.actual.saturating_sub(self.expected)
+    }
+    
+    pub fn coverage(&self) -> f64 {
+        if self.actual > 0 {
+            (self.expected.min(self.actual) as f64 / self.actual as f64) * 100.0
+        } else {
+            0.0
+        }
+    }
+}
+
+fn black_box_calls(analysis: &CircuitAnalysis, canonical: &str) -> usize {
+    analysis.black_box_functions.iter()
+        .filter(|(name, _, _)| canonical_name(name) == canonical)
+        .fold(0usize, |calls, (_, count, _)| calls.saturating_add(*count))
+}
+
+pub fn benchmark(analysis: &CircuitAnalysis, set: BenchmarkSet) -> BenchmarkComparison {
+    let references: &'static [ReferenceImplementation] = match set {
+        BenchmarkSet::Reference => &REFERENCE_IMPLEMENTATIONS,
+    };
+    
+    let matches: Vec<BenchmarkMatch> = references.iter()
+        .filter_map(|reference| {
+            let calls = black_box_calls(analysis, reference.black_box);
+            if calls == 0 {
+                return None;
+            }
+            
+            let expected = reference.constraints.saturating_mul(calls) / reference.calls;
+            Some(BenchmarkMatch { reference, calls, expected })
+        })
+        .collect();
+    
+    let expected = matches.iter().fold(0usize, |total, m| total.saturating_add(m.expected));
+    BenchmarkComparison { matches, expected, actual: analysis.constraints }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn scales_references_by_call_count() {
+        let analysis = CircuitAnalysis {
+            constraints: 30_000,
+            black_box_functions: vec![("pedersen_hash".to_string(), 16, 100), ("PedersenHash".to_string(), 16, 100), ("range".to_string(), 8, 1)],
+            ..Default::default()
+        };
+        let comparison = benchmark(&analysis, "Reference".parse().unwrap());
+        
+        assert_eq!(comparison.matches.len(), 1);
+        assert_eq!(comparison.matches[0].instances(), 1.0);
+        assert_eq!((comparison.expected, comparison.overhead()), (9_281, 30_000 - 9_281));
+        assert!((comparison.coverage() - 9_281.0 / 300.0).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn coverage_is_capped_and_safe_on_empty_circuits() {
+        let comparison = BenchmarkComparison { matches: Vec::new(), expected: 10, actual: 5 };
+        assert_eq!((comparison.coverage(), comparison.overhead()), (100.0, 0));
+        assert_eq!(BenchmarkComparison { matches: Vec::new(), expected: 0, actual: 0 }.coverage(), 0.0);
+        assert!("custom".parse::<BenchmarkSet>().is_err());
+    }
+}
@@ -0,0 +1,230 @@
+use crate::artifact::is_artifact_path;
+use anyhow::{bail, Context, Result};
+use std::fs::{self, File};
+use std::io;
+use std::path::{Component, Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+const TAR_GZ_SUFFIXES: [&str; 2] = [".tar.gz", ".tgz"];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BundleFormat {
+    Tar,
+    TarGz,
+    Zip,
+}
+
+pub fn bundle_format(path: &Path) -> Option<BundleFormat> {
+    let name = path.file_name()?.to_string_lossy().to_ascii_lowercase();
+    
+    if TAR_GZ_SUFFIXES.iter().any(|suffix| name.ends_with(suffix)) {
+        Some(BundleFormat::TarGz)
+    } else if name.ends_with(".tar") {
+        Some(BundleFormat::Tar)
+    } else if name.ends_with(".zip") {
+        Some(BundleFormat::Zip)
+    } else {
+        None
+    }
+}
+
+pub struct Bundle {
+    dir: PathBuf,
+}
+
+impl Bundle {
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+}
+
+impl Drop for Bundle {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.dir).ok();
+    }
+}
+
+fn enclosed_path(path: &Path) -> Option<PathBuf> {
+    let mut enclosed = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::Normal(part) => enclosed.push(part),
+            Component::CurDir => {},
+            _ => return None,
+        }
+    }
+    
+    (!enclosed.as_os_str().is_empty()).then_some(enclosed)
+}
+
+fn check_entry_size(inner: &Path, size: u64, cap: u64) -> Result<()> {
+    if size > cap {
+        bail!("{} is {} bytes, over the {} byte limit (--max-file-size)", inner.display(), size, cap);
+    }
+    Ok(())
+}
+
+fn write_entry(entry: impl io::Read, dir: &Path, inner: &Path, cap: u64) -> Result<()> {
+    let target = dir.join(inner);
+    if let Some(parent) = target.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    
+    let mut output = File::create(&target)
+        .with_context(|| format!("Failed to extract {}", inner.display()))?;
+    let written = io::copy(&mut entry.take(cap.saturating_add(1)), &mut output)
+        .with_context(|| format!("Failed to extract {}", inner.display()))?;
+    check_entry_size(inner, written, cap)
+}
+
+fn extract_tar(archive: impl io::Read, dir: &Path, cap: u64) -> Result<usize> {
+    let mut archive = tar::Archive::new(archive);
+    let mut extracted = 0;
+    
+    for entry in archive.entries().context("Failed to read tar archive")? {
+        let entry = entry.context("Failed to read tar entry")?;
+        let Some(inner) = enclosed_path(&entry.path()?) else {
+            continue;
+        };
+        
+        if !entry.header().entry_type().is_file() || !is_artifact_path(&inner) {
+            continue;
+        }
+        
+        check_entry_size(&inner, entry.header().size()?, cap)?;
+        write_entry(entry, dir, &inner, cap)?;
+        extracted += 1;
+    }
+    
+    Ok(extracted)
+}
+
+fn extract_zip(file: File, dir: &Path, cap: u64) -> Result<usize> {
+    let mut archive = zip::ZipArchive::new(file).context("Failed to read zip archive")?;
+    let mut extracted = 0;
+    
+    for index in 0..archive.len() {
+        let entry = archive.by_index(index).context("Failed to read zip entry")?;
+        let Some(inner) = entry.enclosed_name() else {
+            continue;
+        };
+        
+        if !entry.is_file() || !is_artifact_path(&inner) {
+            continue;
+        }
+        
+        check_entry_size(&inner, entry.size(), cap)?;
+        write_entry(entry, dir, &inner, cap)?;
+        extracted += 1;
+    }
+    
+    Ok(extracted)
+}
+
+pub fn extract_bundle(path: &Path, max_file_size: Option<u64>) -> Result<Bundle> {
+    let Some(format) = bundle_format(path) else {
+        bail!("Not a .tar, .tar.gz, .tgz or .zip bundle: {}", path.display());
+    };
+    
+    let nanos = SystemTime::now().duration_since(UNIX_EPOCH).map_or(0, |elapsed| elapsed.subsec_nanos());
+    let bundle = Bundle {
+        dir: std::env::temp_dir().join(format!("noir-profiler-bundle-{}-{}", std::process::id(), nanos)),
+    };
+    fs::create_dir_all(&bundle.dir)
+        .with_context(|| format!("Failed to create {}", bundle.dir.display()))?;
+    
+    let file = File::open(path)
+        .with_context(|| format!("Failed to open bundle: {}", path.display()))?;
+    
+    let cap = max_file_size.unwrap_or(u64::MAX);
+    let extracted = match format {
+        BundleFormat::Tar => extract_tar(file, &bundle.dir, cap),
+        BundleFormat::TarGz => extract_tar(flate2::read::GzDecoder::new(file), &bundle.dir, cap),
+        BundleFormat::Zip => extract_zip(file, &bundle.dir, cap),
+    }.with_context(|| format!("Failed to extract bundle: {}", path.display()))?;
+    
+    if extracted == 0 {
+        bail!("No circuit artifacts found in bundle: {}", path.display());
+    }
+    
+    Ok(bundle)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+    
+    fn scratch(name: &str) -> PathBuf {
+        std::env::temp_dir().join(format!("noir-profiler-{}-{}", std::process::id(), name))
+    }
+    
+    fn tar_header(path: &str, size: usize) -> tar::Header {
+        let mut header = tar::Header::new_old();
+        header.as_old_mut().name[..path.len()].copy_from_slice(path.as_bytes());
+        header.set_size(size as u64);
+        header.set_mode(0o644);
+        header.set_cksum();
+        header
+    }
+    
+    #[test]
+    fn detects_bundle_formats() {
+        assert_eq!(bundle_format(Path::new("out/Circuits.TGZ")), Some(BundleFormat::TarGz));
+        assert_eq!(bundle_format(Path::new("a.tar.gz")), Some(BundleFormat::TarGz));
+        assert_eq!(bundle_format(Path::new("a.tar")), Some(BundleFormat::Tar));
+        assert_eq!(bundle_format(Path::new("a.zip")), Some(BundleFormat::Zip));
+        assert_eq!(bundle_format(Path::new("a.json")), None);
+    }
+    
+    #[test]
+    fn tar_entries_stay_inside_the_bundle() {
+        let archive = scratch("bundle.tar.gz");
+        let mut builder = tar::Builder::new(flate2::write::GzEncoder::new(File::create(&archive).unwrap(), flate2::Compression::fast()));
+        for path in ["target/main.json", "README.md", "../escape.json"] {
+            builder.append(&tar_header(path, 2), &b"{}"[..]).unwrap();
+        }
+        builder.into_inner().unwrap().finish().unwrap();
+        
+        let bundle = extract_bundle(&archive, None);
+        fs::remove_file(&archive).ok();
+        let bundle = bundle.unwrap();
+        
+        assert!(bundle.dir().join("target/main.json").is_file());
+        assert!(!bundle.dir().join("README.md").exists());
+        assert!(!bundle.dir().parent().unwrap().join("escape.json").exists());
+        
+        let dir = bundle.dir().to_path_buf();
+        drop(bundle);
+        assert!(!dir.exists());
+    }
+    
+    #[test]
+    fn zip_entries_respect_the_size_limit() {
+        let archive = scratch("bundle.zip");
+        let mut writer = zip::ZipWriter::new(File::create(&archive).unwrap());
+        writer.start_file("big.json", zip::write::SimpleFileOptions::default()).unwrap();
+        writer.write_all(&[b' '; 64]).unwrap();
+        writer.finish().unwrap();
+        
+        let too_big = extract_bundle(&archive, Some(16)).err().map(|e| format!("{:#}", e));
+        let fits = extract_bundle(&archive, Some(64)).map(|bundle| bundle.dir().join("big.json").is_file());
+        fs::remove_file(&archive).ok();
+        
+        assert!(too_big.unwrap().contains("over the 16 byte limit"));
+        assert!(fits.unwrap());
+    }
+    
+    #[test]
+    fn bundles_without_artifacts_are_rejected() {
+        let archive = scratch("empty.tar");
+        let mut builder = tar::Builder::new(File::create(&archive).unwrap());
+        builder.append(&tar_header("notes.txt", 2), &b"hi"[..]).unwrap();
+        builder.finish().unwrap();
+        
+        let error = extract_bundle(&archive, None).err().map(|e| e.to_string());
+        fs::remove_file(&archive).ok();
+        
+        assert!(error.unwrap().starts_with("No circuit artifacts found"));
+    }
+}
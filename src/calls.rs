@@ -0,0 +1,323 @@
+use crate::core::get_operation_details;
+use crate::curve::model_cost;
+use crate::exact::ExpressionWidth;
+use anyhow::{bail, Result};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fmt;
+use std::str::FromStr;
+
+pub const CALL_OPCODE: &str = "Call";
+pub const CALL_OVERHEAD: usize = 1;
+
+pub const AGGREGATION_OVERHEAD: usize = 20_000;
+
+const MAX_CALL_DEPTH: usize = 64;
+const MAX_CALL_EXPANSIONS: usize = 1 << 16;
+
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ProvingStrategy {
+    #[default]
+    Fold,
+    Inline,
+}
+
+impl fmt::Display for ProvingStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ProvingStrategy::Fold => write!(f, "fold"),
+            ProvingStrategy::Inline => write!(f, "inline"),
+        }
+    }
+}
+
+impl FromStr for ProvingStrategy {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "fold" => Ok(ProvingStrategy::Fold),
+            "inline" => Ok(ProvingStrategy::Inline),
+            _ => Err(format!("invalid proving strategy `{}`, expected `fold` or `inline`", s)),
+        }
+    }
+}
+
+#[derive(Debug, Default)]
+pub struct CallGraph {
+    pub edges: Vec<(String, String, usize)>,
+    pub callees: Vec<(String, usize, usize)>,
+    pub inlined_constraints: usize,
+    pub folded_constraints: usize,
+    pub proof_count: usize,
+    call_overhead: usize,
+    inlined_bodies: usize,
+    folded_bodies: usize,
+}
+
+impl CallGraph {
+    pub fn set_main_constraints(&mut self, main_constraints: usize) {
+        self.folded_constraints = main_constraints.saturating_add(self.folded_bodies);
+        self.inlined_constraints = main_constraints.saturating_sub(self.call_overhead).saturating_add(self.inlined_bodies);
+    }
+    
+    pub fn aggregate_constraints(&self, strategy: ProvingStrategy) -> usize {
+        match strategy {
+            ProvingStrategy::Fold => self.folded_constraints,
+            ProvingStrategy::Inline => self.inlined_constraints,
+        }
+    }
+    
+    pub fn proofs(&self, strategy: ProvingStrategy) -> usize {
+        match strategy {
+            ProvingStrategy::Fold => self.proof_count,
+            ProvingStrategy::Inline => 1,
+        }
+    }
+}
+
+pub fn function_name(data: &Value, id: usize) -> String {
+    if let Some(name) = data["names"][id].as_str() {
+        return name.to_string();
+    }
+    
+    if let Some(name) = data["functions"][id]["name"].as_str() {
+        return name.to_string();
+    }
+    
+    if id == 0 {
+        "main".to_string()
+    } else {
+        format!("function_{}", id)
+    }
+}
+
+pub fn estimate_opcode_cost(op: &Value, width: ExpressionWidth) -> usize {
+    match op["type"].as_str().unwrap_or("Unknown") {
+        "BlackBoxFunction" => {
+            let fn_name = op["function"].as_str().unwrap_or("unknown");
+            model_cost(op).unwrap_or_else(|| get_operation_details(fn_name).0)
+        },
+        "AssertZero" => {
+            let terms = op["expression"]["terms"].as_array().map_or(0, |terms| terms.len());
+            width.assert_zero_cost(terms)
+        },
+        _ => 1,
+    }
+}
+
+pub fn function_opcodes(data: &Value, id: usize) -> &[Value] {
+    let opcodes = if id == 0 && data["opcodes"].is_array() {
+        &data["opcodes"]
+    } else {
+        &data["functions"][id]["opcodes"]
+    };
+    
+    opcodes.as_array().map_or(&[], |ops| ops.as_slice())
+}
+
+pub fn entry_points(data: &Value) -> Vec<String> {
+    let function_count = data["functions"].as_array().map_or(1, |functions| functions.len().max(1));
+    (0..function_count).map(|id| function_name(data, id)).collect()
+}
+
+pub fn entry_index(data: &Value, entry: &str) -> Option<usize> {
+    entry_points(data).iter().position(|name| name == entry)
+}
+
+pub fn select_entry(data: &Value, entry: &str) -> Result<Value> {
+    let Some(id) = entry_index(data, entry) else {
+        bail!("No entry point named `{}` (available: {})", entry, entry_points(data).join(", "));
+    };
+    
+    let mut selected = data.clone();
+    selected["opcodes"] = Value::Array(function_opcodes(data, id).to_vec());
+    Ok(selected)
+}
+
+fn callee_counts(opcodes: &[Value]) -> Vec<(usize, usize)> {
+    let mut counts: Vec<(usize, usize)> = Vec::new();
+    
+    for op in opcodes {
+        if op["type"].as_str() != Some(CALL_OPCODE) {
+            continue;
+        }
+        
+        let Some(id) = op["id"].as_u64().map(|id| id as usize) else {
+            continue;
+        };
+        
+        match counts.iter_mut().find(|(callee, _)| *callee == id) {
+            Some(entry) => entry.1 += 1,
+            None => counts.push((id, 1)),
+        }
+    }
+    
+    counts
+}
+
+fn own_cost(data: &Value, id: usize, width: ExpressionWidth) -> usize {
+    function_opcodes(data, id).iter()
+        .map(|op| if op["type"].as_str() == Some(CALL_OPCODE) {
+            CALL_OVERHEAD
+        } else {
+            estimate_opcode_cost(op, width)
+        })
+        .fold(0, usize::saturating_add)
+}
+
+fn inlined_cost(
+    data: &Value,
+    id: usize,
+    width: ExpressionWidth,
+    memo: &mut HashMap<usize, usize>,
+    stack: &mut Vec<usize>
+) -> usize {
+    if let Some(cost) = memo.get(&id) {
+        return *cost;
+    }
+    
+    if stack.contains(&id) {
+        return 0;
+    }
+    
+    stack.push(id);
+    
+    let mut cost: usize = 0;
+    for op in function_opcodes(data, id) {
+        if op["type"].as_str() == Some(CALL_OPCODE) {
+            if let Some(callee) = op["id"].as_u64() {
+                cost = cost.saturating_add(inlined_cost(data, callee as usize, width, memo, stack));
+            }
+        } else {
+            cost = cost.saturating_add(estimate_opcode_cost(op, width));
+        }
+    }
+    
+    stack.pop();
+    memo.insert(id, cost);
+    cost
+}
+
+pub fn analyze_calls(data: &Value, width: ExpressionWidth) -> Option<CallGraph> {
+    let main_calls = callee_counts(function_opcodes(data, 0));
+    
+    if main_calls.is_empty() {
+        return None;
+    }
+    
+    let mut graph = CallGraph::default();
+    let mut memo = HashMap::new();
+    let mut invocations: HashMap<usize, usize> = HashMap::new();
+    let mut queue = vec![(0usize, 1usize, 0usize)];
+    let mut visited_edges = Vec::new();
+    let mut expansions = 0;
+    
+    while let Some((caller, caller_invocations, depth)) = queue.pop() {
+        for (callee, count) in callee_counts(function_opcodes(data, caller)) {
+            let callee_invocations = caller_invocations.saturating_mul(count);
+            *invocations.entry(callee).or_insert(0) += callee_invocations;
+            
+            if !visited_edges.contains(&(caller, callee)) {
+                visited_edges.push((caller, callee));
+                graph.edges.push((function_name(data, caller), function_name(data, callee), count));
+            }
+            
+            if depth < MAX_CALL_DEPTH && expansions < MAX_CALL_EXPANSIONS {
+                expansions += 1;
+                queue.push((callee, callee_invocations, depth + 1));
+            }
+        }
+    }
+    
+    let mut callees: Vec<_> = invocations.into_iter().collect();
+    callees.sort();
+    
+    graph.proof_count = 1;
+    
+    for (callee, count) in callees {
+        let cost = inlined_cost(data, callee, width, &mut memo, &mut Vec::new());
+        graph.callees.push((function_name(data, callee), count, cost));
+        
+        let proof_cost = own_cost(data, callee, width) + AGGREGATION_OVERHEAD;
+        graph.folded_bodies = graph.folded_bodies.saturating_add(count.saturating_mul(proof_cost));
+        graph.proof_count = graph.proof_count.saturating_add(count);
+    }
+    
+    for (callee, count) in &main_calls {
+        let body = inlined_cost(data, *callee, width, &mut memo, &mut Vec::new());
+        graph.call_overhead = graph.call_overhead.saturating_add(count.saturating_mul(CALL_OVERHEAD));
+        graph.inlined_bodies = graph.inlined_bodies.saturating_add(count.saturating_mul(body));
+    }
+    graph.set_main_constraints(0);
+    
+    graph.callees.sort_by(|a, b| b.1.saturating_mul(b.2).cmp(&a.1.saturating_mul(a.2)).then(a.0.cmp(&b.0)));
+    Some(graph)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn assert_zero(terms: usize) -> Value {
+        let terms: Vec<Value> = (0..terms).map(|i| json!({ "variable": format!("w{}", i) })).collect();
+        json!({ "type": "AssertZero", "expression": { "terms": terms } })
+    }
+    
+    fn call(id: usize) -> Value {
+        json!({ "type": "Call", "id": id })
+    }
+    
+    fn program() -> Value {
+        json!({
+            "names": ["main", "hash_a", "hash_b"],
+            "functions": [
+                { "opcodes": [call(1), call(1), call(2), assert_zero(2)] },
+                { "opcodes": [assert_zero(5)] },
+                { "opcodes": [call(1), assert_zero(1)] },
+            ],
+        })
+    }
+    
+    #[test]
+    fn call_graph_counts_invocations_and_costs() {
+        let mut graph = analyze_calls(&program(), ExpressionWidth::Bounded(4)).unwrap();
+        graph.set_main_constraints(10);
+        
+        assert_eq!(graph.edges, vec![
+            ("main".to_string(), "hash_a".to_string(), 2),
+            ("main".to_string(), "hash_b".to_string(), 1),
+            ("hash_b".to_string(), "hash_a".to_string(), 1),
+        ]);
+        assert_eq!(graph.callees, vec![("hash_a".to_string(), 3, 2), ("hash_b".to_string(), 1, 3)]);
+        assert_eq!(graph.proofs(ProvingStrategy::Fold), 5);
+        assert_eq!(graph.aggregate_constraints(ProvingStrategy::Inline), 10 - 3 + 2 * 2 + 3);
+        assert_eq!(graph.aggregate_constraints(ProvingStrategy::Fold), 10 + 4 * (2 + AGGREGATION_OVERHEAD));
+    }
+    
+    #[test]
+    fn recursive_calls_terminate() {
+        let data = json!({ "functions": [{ "opcodes": [call(1)] }, { "opcodes": [call(1), assert_zero(1)] }] });
+        let graph = analyze_calls(&data, ExpressionWidth::default()).unwrap();
+        
+        assert_eq!(graph.callees.len(), 1);
+        assert_eq!(graph.callees[0].2, 1);
+    }
+    
+    #[test]
+    fn circuits_without_calls_have_no_graph() {
+        assert!(analyze_calls(&json!({ "opcodes": [assert_zero(1)] }), ExpressionWidth::default()).is_none());
+    }
+    
+    #[test]
+    fn selects_entry_points_by_name() {
+        let data = program();
+        assert_eq!(entry_points(&data), ["main", "hash_a", "hash_b"]);
+        assert_eq!(select_entry(&data, "hash_b").unwrap()["opcodes"].as_array().unwrap().len(), 2);
+        assert!(select_entry(&data, "missing").unwrap_err().to_string().contains("hash_a"));
+        assert_eq!(function_name(&json!({}), 3), "function_3");
+    }
+}
@@ -0,0 +1,4255 @@
+use anyhow::{bail, Context, Result};
+use clap::{Args, Parser, Subcommand};
+use colored::Colorize;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+use crate::table::{Row, Table};
+use std::io::Write;
+
+macro_rules! println {
+    () => { crate::output::write_line("") };
+    ($($arg:tt)*) => { crate::output::write_line(&crate::theme::render(&format!($($arg)*))) };
+}
+
+use crate::allowlist::{load_allowlist, triage};
+use crate::anonymize::{export_key, Anonymizer, AnonymousStats, DEFAULT_K, DEFAULT_ROUND};
+use crate::analyzer::{analyze_circuit_with_options, batch_analyze_paths, batch_analyze_with_options, compare_circuits_with_options, AnalysisLimits, AnalysisOptions, Skipped};
+use crate::artifact::{read_artifact, FileSize, ARTIFACT_SUFFIXES};
+use crate::benchmark::{benchmark, BenchmarkSet};
+use crate::bundle::{bundle_format, extract_bundle, Bundle};
+use crate::calls::ProvingStrategy;
+use crate::cloud::{estimate_cloud_cost, estimate_memory_gb, CloudPricing};
+use crate::audit::{run_audit, write_report as write_audit_report};
+use crate::daemon::{request_analysis, request_shutdown, socket_path, DaemonUnavailable};
+use crate::cost::{coverage_percent, lookup_cost, model_coverage, CostLookup, OperationCoverage, Provenance};
+use crate::core::{backup_cost_database, get_operation_cost, commit_cost_database, cost_database_error, get_cost_database as get_shared_cost_database, migrate_cost_database_file, remove_cost_entry, reset_cost_database, restore_cost_entry, rollback_cost_database, set_cost_database_autosave, set_cost_entry, CircuitAnalysis, CostDatabaseView, COST_DB_PATH, FALLBACK_COST, GATE_CALIBRATION_PATH};
+use crate::delta::{analysis_delta, MetricFn};
+use crate::dirs::compare_results;
+use crate::energy::EnergyModel;
+use crate::evm::{commitment_constraints, estimate_verifier, public_field_count, public_value_costs, verifier_profile, EvmThresholds, VerifierProfile, CONTRACT_SIZE_LIMIT};
+use crate::focus::{focus_report, FocusReport, Substitution};
+use crate::init::{init_project, BUDGET_FILE};
+use crate::i18n::{set_locale, t, tf, Locale};
+use crate::taxonomy::{black_box_category, operation_category, Category};
+use crate::dashboard::{build_dashboard, Dashboard, CALIBRATION_STALE_DAYS};
+use crate::sensitivity::{cost_sensitivity, COST_ERROR};
+use crate::reconcile::{reconcile, Reconciliation};
+use crate::theme::{self, ColorChoice, Theme};
+use crate::usage::{record_usage, summarize_usage, UsageEntry, UsageSummary, USAGE_LOG_PATH};
+use crate::gpu::{estimate_gpu, find_gpu_profile, GpuProfile};
+use crate::exact::{exact_count_available, ExpressionWidth};
+use crate::manifest::{build_manifest, failure_category, file_sha256, load_manifest, merge_manifest, write_manifest};
+use crate::names::{canonical_name, display_name, docs_url, raw_names, set_raw_names};
+use crate::notify::{build_payload, find_violations, load_notify_config, module_usage, module_violations, send_notification, ModuleUsage};
+use crate::heatmap::{annotate_sources, heat_level, hottest_line, render_html as render_heatmap_html, AnnotatedSource};
+use crate::hook::{install_hook, run_hook, HookKind, HookOutcome};
+use crate::passes::{Pass, PassSelection, QUICK_SKIPPED};
+use crate::patterns::{compact_count, loop_suggestions};
+use crate::pipeline::{estimate_pipeline, load_pipeline, PipelineEstimate};
+use crate::problems::{problems, render_problems};
+use crate::program::{analyze_program, ProgramReport};
+use crate::ranking::{rank_candidates, Weights, DEFAULT_WEIGHTS};
+use crate::recompile::{compile_project, nargo_binary, recompile_pair};
+use crate::remote::resolve_artifact;
+use crate::regression::{delta_interval, detect_change, relative_spread, NoiseFloor};
+use crate::report::{load_report, render_markdown, render_template, select_fields, write_report, EmitFormat, EmitSink};
+use crate::sampling::SampleRate;
+use crate::setup::{capability_warnings, estimate_backend, estimate_setup, find_backend_profile, format_bytes, BackendProfile, Support, BACKEND_PROFILES};
+use crate::shared::{find_shared_components, SharedComponent};
+use crate::suggest::{source_suggestions, write_suggestions, SUGGESTIONS_FILE};
+use crate::signing::{load_signed_report, load_signing_key, parse_public_key, sign_analysis, verify_report};
+
+#[derive(Debug)]
+pub struct AlreadyReported;
+
+impl fmt::Display for AlreadyReported {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "error already reported")
+    }
+}
+
+impl std::error::Error for AlreadyReported {}
+
+#[derive(Parser)]
+#[clap(version = "1.0", author = "Noir Team", disable_help_subcommand = true)]
+struct Cli {
+    #[clap(subcommand)]
+    command: Option<Commands>,
+    
+    #[clap(long, global = true, default_value = "4")]
+    expression_width: ExpressionWidth,
+    
+    #[clap(long, global = true, default_value = "fold")]
+    proving_strategy: ProvingStrategy,
+    
+    #[clap(long, global = true, default_value = "en")]
+    lang: Locale,
+    
+    #[clap(long, global = true, default_value = "auto")]
+    color: ColorChoice,
+    
+    #[clap(long, global = true)]
+    theme: Option<Theme>,
+    
+    #[clap(long, global = true)]
+    plain: bool,
+    
+    #[clap(long, global = true)]
+    use_daemon: bool,
+    
+    #[clap(long, global = true)]
+    raw_names: bool,
+    
+    #[clap(short, long, global = true)]
+    output: Option<PathBuf>,
+    
+    #[clap(long, global = true, requires = "output")]
+    append: bool,
+    
+    #[clap(long, global = true)]
+    label: Option<String>,
+    
+    #[clap(long, global = true)]
+    timings: bool,
+    
+    #[clap(long, global = true)]
+    sample: Option<SampleRate>,
+    
+    #[clap(long, global = true)]
+    entry: Option<String>,
+    
+    #[clap(long, global = true)]
+    profiles: Option<PathBuf>,
+    
+    #[clap(long, global = true, value_delimiter = ',')]
+    passes: Vec<Pass>,
+    
+    #[clap(long, global = true, value_delimiter = ',')]
+    skip_pass: Vec<Pass>,
+    
+    #[clap(long, global = true, conflicts_with = "passes")]
+    quick: bool,
+    
+    #[clap(long, global = true)]
+    record_usage: bool,
+}
+
+#[derive(Args)]
+struct PricingArgs {
+    #[clap(long)]
+    instance: Option<String>,
+    
+    #[clap(long)]
+    cost_per_hour: Option<f64>,
+}
+
+impl PricingArgs {
+    fn resolve(&self) -> Result<CloudPricing> {
+        CloudPricing::new(self.instance.as_deref(), self.cost_per_hour)
+    }
+}
+
+#[derive(Args)]
+struct ProverArgs {
+    #[clap(flatten)]
+    pricing: PricingArgs,
+    
+    #[clap(long)]
+    gpu: Option<String>,
+    
+    #[clap(long)]
+    joules_per_constraint: Option<f64>,
+    
+    #[clap(long)]
+    backend: Option<String>,
+    
+    #[clap(long)]
+    setup: bool,
+}
+
+struct Prover {
+    pricing: CloudPricing,
+    gpu: Option<GpuProfile>,
+    energy: Option<EnergyModel>,
+    backend: Option<BackendProfile>,
+    setup: bool,
+}
+
+impl ProverArgs {
+    fn resolve(&self) -> Result<Prover> {
+        let pricing = self.pricing.resolve()?;
+        let gpu = self.gpu.as_deref().map(find_gpu_profile).transpose()?;
+        let energy = EnergyModel::resolve(self.joules_per_constraint, gpu, pricing.instance)?;
+        let backend = self.backend.as_deref().map(find_backend_profile).transpose()?;
+        Ok(Prover { pricing, gpu, energy, backend, setup: self.setup || backend.is_some() })
+    }
+}
+
+#[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
+enum Commands {
+    Analyze { 
+        file: PathBuf,
+        
+        #[clap(short, long, default_value = "text")]
+        format: String,
+        
+        #[clap(long, requires = "out")]
+        sign: Option<PathBuf>,
+        
+        #[clap(long)]
+        out: Option<PathBuf>,
+        
+        #[clap(long, conflicts_with_all = ["sign", "out"])]
+        focus: Option<String>,
+        
+        #[clap(long, conflicts_with_all = ["sign", "focus"])]
+        template: Option<PathBuf>,
+        
+        #[clap(long, alias = "json-pointer", conflicts_with_all = ["sign", "focus", "template"])]
+        select: Option<String>,
+        
+        #[clap(long)]
+        evm: bool,
+        
+        #[clap(long)]
+        max_calldata: Option<usize>,
+        
+        #[clap(long)]
+        max_gas: Option<u64>,
+        
+        #[clap(long)]
+        benchmark_against: Option<BenchmarkSet>,
+        
+        #[clap(long)]
+        sensitivity: bool,
+        
+        #[clap(long, value_delimiter = ',')]
+        backends: Vec<String>,
+        
+        #[clap(long, num_args = 0..=1, default_missing_value = SUGGESTIONS_FILE, conflicts_with_all = ["focus", "select"])]
+        suggestions: Option<PathBuf>,
+        
+        #[clap(long, conflicts_with_all = ["format", "focus", "template", "select"])]
+        emit: Vec<EmitSink>,
+        
+        #[clap(flatten)]
+        prover: ProverArgs,
+    },
+    
+    VerifyReport {
+        report: PathBuf,
+        
+        #[clap(long)]
+        public_key: Option<String>,
+    },
+    
+    Compare {
+        file1: PathBuf,
+        
+        #[clap(required_unless_present = "recompile")]
+        file2: Option<PathBuf>,
+        
+        #[clap(long, conflicts_with = "file2")]
+        recompile: bool,
+        
+        #[clap(long, default_value = "", allow_hyphen_values = true, requires = "recompile")]
+        flags_a: String,
+        
+        #[clap(long, default_value = "", allow_hyphen_values = true, requires = "recompile")]
+        flags_b: String,
+        
+        #[clap(conflicts_with = "recompile")]
+        more: Vec<PathBuf>,
+        
+        #[clap(long, conflicts_with = "recompile")]
+        rank: bool,
+        
+        #[clap(long, conflicts_with = "recompile")]
+        weights: Option<Weights>,
+        
+        #[clap(short, long, default_value = "text", value_parser = ["text", "json-diff"])]
+        format: String,
+        
+        #[clap(long, default_value = "0%", conflicts_with_all = ["rank", "weights"])]
+        noise_floor: NoiseFloor,
+    },
+    
+    AnalyzeSrc {
+        project: PathBuf,
+        
+        #[clap(short, long, default_value = "text")]
+        format: String,
+        
+        #[clap(flatten)]
+        prover: ProverArgs,
+    },
+    
+    Audit {
+        file: PathBuf,
+        
+        #[clap(long)]
+        out: PathBuf,
+    },
+    
+    Batch {
+        #[clap(required_unless_present = "retry_failed")]
+        dir: Option<PathBuf>,
+        
+        #[clap(long, value_name = "SIZE")]
+        max_file_size: Option<FileSize>,
+        
+        #[clap(long)]
+        max_opcodes: Option<usize>,
+        
+        #[clap(long)]
+        timeout: Option<u64>,
+        
+        #[clap(long)]
+        manifest: Option<PathBuf>,
+        
+        #[clap(long)]
+        retry_failed: Option<PathBuf>,
+        
+        #[clap(long)]
+        shared: bool,
+        
+        #[clap(long)]
+        budget: Option<PathBuf>,
+    },
+    
+    Stats {
+        dir: PathBuf,
+        
+        #[clap(long, conflicts_with = "anonymize")]
+        details_dir: Option<PathBuf>,
+        
+        #[clap(long)]
+        anonymize: bool,
+        
+        #[clap(long, requires = "anonymize", default_value_t = DEFAULT_ROUND)]
+        round: usize,
+        
+        #[clap(long, requires = "anonymize", default_value_t = 0.0)]
+        noise: f64,
+        
+        #[clap(long, requires = "anonymize", default_value_t = DEFAULT_K)]
+        k: usize,
+        
+        #[clap(long, requires = "anonymize")]
+        key_file: Option<PathBuf>,
+    },
+    
+    Calibrate {
+        #[clap(required_unless_present = "dir_flag")]
+        dir: Option<PathBuf>,
+        
+        #[clap(short = 'd', long = "dir", conflicts_with = "dir")]
+        dir_flag: Option<PathBuf>,
+        
+        #[clap(short, long)]
+        reset: bool,
+        
+        #[clap(short, long)]
+        interactive: bool,
+    },
+    
+    Cost {
+        operation: String,
+        
+        #[clap(long)]
+        size: Option<usize>,
+    },
+    
+    Ops,
+    
+    CostDb {
+        #[clap(subcommand)]
+        action: CostDbAction,
+    },
+    
+    Report {
+        #[clap(subcommand)]
+        action: ReportAction,
+    },
+    
+    Init {
+        #[clap(default_value = ".")]
+        project: PathBuf,
+        
+        #[clap(long)]
+        workflow: bool,
+        
+        #[clap(long)]
+        force: bool,
+    },
+    
+    Annotate {
+        file: PathBuf,
+        
+        #[clap(long, default_value = ".")]
+        src: PathBuf,
+        
+        #[clap(long)]
+        html: Option<PathBuf>,
+    },
+    
+    Hook {
+        #[clap(subcommand)]
+        action: HookAction,
+    },
+    
+    Reconcile {
+        file: PathBuf,
+        
+        gates: PathBuf,
+        
+        #[clap(long)]
+        backend: Option<String>,
+        
+        #[clap(long)]
+        tune: bool,
+        
+        #[clap(short, long, default_value = "text")]
+        format: String,
+    },
+    
+    CompareDirs {
+        dir_a: PathBuf,
+        
+        dir_b: PathBuf,
+    },
+    
+    Pipeline {
+        file: PathBuf,
+        
+        #[clap(flatten)]
+        pricing: PricingArgs,
+    },
+    
+    Summary {
+        #[clap(default_value = ".")]
+        project: PathBuf,
+        
+        #[clap(long, default_value_t = 7)]
+        days: i64,
+        
+        #[clap(short, long, default_value = "text")]
+        format: String,
+    },
+    
+    Usage {
+        #[clap(long)]
+        since: Option<chrono::NaiveDate>,
+        
+        #[clap(short, long, default_value = "text")]
+        format: String,
+    },
+    
+    Daemon {
+        #[clap(long)]
+        socket: Option<PathBuf>,
+        
+        #[clap(long)]
+        stop: bool,
+        
+        #[clap(long, conflicts_with = "stop")]
+        metrics: Option<std::net::SocketAddr>,
+    },
+    
+    Help,
+}
+
+#[derive(Subcommand)]
+enum ReportAction {
+    Diff {
+        old: PathBuf,
+        
+        new: PathBuf,
+        
+        #[clap(long)]
+        notify: Option<PathBuf>,
+        
+        #[clap(long, requires = "notify")]
+        allow: Option<PathBuf>,
+    },
+    
+    Trend {
+        #[clap(required = true, num_args = 4..)]
+        reports: Vec<PathBuf>,
+        
+        #[clap(long, default_value_t = 0.95)]
+        confidence: f64,
+    },
+}
+
+#[derive(Subcommand)]
+enum HookAction {
+    Install {
+        #[clap(default_value = ".")]
+        project: PathBuf,
+        
+        #[clap(long, default_value = "pre-commit")]
+        hook: HookKind,
+        
+        #[clap(long)]
+        force: bool,
+    },
+    
+    Run {
+        #[clap(default_value = ".")]
+        project: PathBuf,
+        
+        #[clap(long)]
+        all: bool,
+    },
+}
+
+#[derive(Subcommand)]
+enum CostDbAction {
+    Migrate,
+    
+    Rollback {
+        #[clap(long)]
+        to: Option<String>,
+    },
+    
+    Set {
+        operation: String,
+        
+        cost: usize,
+        
+        #[clap(long)]
+        confidence: Option<f32>,
+    },
+    
+    Rm {
+        operation: String,
+    },
+    
+    List {
+        #[clap(long, default_value = "name", value_parser = ["name", "cost", "confidence", "samples"])]
+        sort: String,
+    },
+}
+
+pub fn run(args: impl Iterator<Item = String>) -> Result<()> {
+    let cli = Cli::try_parse_from(args)?;
+    set_locale(cli.lang);
+    theme::apply(cli.color, cli.theme)?;
+    set_raw_names(cli.raw_names);
+    crate::timings::set_enabled(cli.timings);
+    
+    let selecting = matches!(cli.command, Some(Commands::Analyze { select: Some(_), .. }))
+        || matches!(&cli.command, Some(Commands::Compare { format, .. }) if format == "json-diff");
+    
+    if let Some(output) = &cli.output {
+        crate::output::redirect(output, cli.append)?;
+    }
+    
+    if cli.plain {
+        crate::table::set_plain(true);
+        colored::control::set_override(false);
+    } else if !selecting && !crate::output::redirected() {
+        print_banner();
+    }
+    
+    let mut skip_pass = cli.skip_pass.clone();
+    if cli.quick {
+        skip_pass.extend(QUICK_SKIPPED);
+    }
+    
+    let options = AnalysisOptions {
+        expression_width: cli.expression_width,
+        proving_strategy: cli.proving_strategy,
+        label: cli.label.clone(),
+        sample: cli.sample.map(|rate| rate.0),
+        entry: cli.entry.clone(),
+        passes: PassSelection::new(&cli.passes, &skip_pass)?,
+        profiles: cli.profiles.clone(),
+        ..Default::default()
+    };
+    
+    if let Some(profiles) = options.profiles.as_ref().filter(|profiles| !profiles.is_file()) {
+        bail!("Profile markers not found: {}", profiles.display());
+    }
+    
+    let daemon_socket = cli.use_daemon.then(socket_path);
+    let history = cli.record_usage;
+    let quick = cli.quick;
+    
+    let uses_cost_db = !matches!(cli.command, 
+        None | Some(Commands::Help) | Some(Commands::VerifyReport { .. }) | Some(Commands::Report { .. }) | Some(Commands::Usage { .. }) 
+            | Some(Commands::Hook { action: HookAction::Install { .. } }) 
+            | Some(Commands::CostDb { action: CostDbAction::Migrate | CostDbAction::Rollback { .. } }) 
+            | Some(Commands::Calibrate { reset: true, .. }));
+    
+    if uses_cost_db {
+        if let Some(error) = cost_database_error() {
+            bail!("Cost database could not be loaded: {}\n\
+                Refusing to fall back to default costs and overwrite your calibration.\n\
+                Run `cost-db migrate` to upgrade an older format, or `calibrate --reset` to start over.", error);
+        }
+    }
+    
+    match cli.command {
+        Some(Commands::Analyze { file, format, sign, out, focus, template, select, evm, max_calldata, max_gas, benchmark_against, sensitivity, backends, suggestions, emit, prover }) => {
+            let start = Instant::now();
+            let prover = prover.resolve()?;
+            let backends: Vec<BackendProfile> = backends.iter().map(|name| find_backend_profile(name)).collect::<Result<_>>()?;
+            let format = if select.is_some() { "json".to_string() } else { format };
+            let local_file = json_errors(resolve_artifact(&file), &file, &format)?;
+            
+            if let Some(operation) = focus {
+                let report = json_errors(focus_report(&local_file, &operation, &options)
+                    .context("Failed to analyze circuit"), &file, &format)?;
+                
+                match format.as_str() {
+                    "json" => crate::output::write_line(&serde_json::to_string_pretty(&report)?),
+                    _ => print_focus_report(&report, &file),
+                }
+                return Ok(());
+            }
+            
+            let analysis = match analyze_artifact(&local_file, &options, daemon_socket.as_deref()) {
+                Ok(analysis) => analysis,
+                Err(error) => match json_errors(analyze_program(&local_file, &options), &file, &format)? {
+                    Some(program) => {
+                        if let Some(out_path) = &out {
+                            std::fs::write(out_path, serde_json::to_string_pretty(&program)?)
+                                .with_context(|| format!("Failed to write report: {}", out_path.display()))?;
+                            println!("{} Report written to {}", "OK".green().bold(), out_path.display());
+                        }
+                        
+                        match format.as_str() {
+                            "json" => crate::output::write_line(&serde_json::to_string_pretty(&program)?),
+                            _ => print_program_report(&program, &file, &prover),
+                        }
+                        return Ok(());
+                    },
+                    None => return json_errors(Err(error).context("Failed to analyze circuit"), &file, &format),
+                },
+            };
+            
+            log_usage(history, &local_file, &analysis);
+            
+            if let Some(selectors) = &select {
+                let report = serde_json::to_value(&analysis)
+                    .context("Failed to serialize analysis")?;
+                let selected = json_errors(select_fields(&report, selectors), &file, &format)?;
+                crate::output::write_line(&serde_json::to_string_pretty(&selected)?);
+                return Ok(());
+            }
+            
+            let duration = start.elapsed();
+            println!("{} {} {:.2?}", "OK".green().bold(), t("analyze.done"), duration);
+            let reporting = Instant::now();
+            
+            if let (Some(key_path), Some(out_path)) = (&sign, &out) {
+                let key = load_signing_key(key_path)?;
+                let signed = sign_analysis(&analysis, &key)?;
+                let content = serde_json::to_string_pretty(&signed)
+                    .context("Failed to serialize signed report")?;
+                std::fs::write(out_path, content)
+                    .with_context(|| format!("Failed to write report: {}", out_path.display()))?;
+                
+                println!("{} Signed report written to {}", "OK".green().bold(), out_path.display());
+                println!("Public key: {}", hex_key(&key.verifying_key()).cyan());
+                return Ok(());
+            }
+            
+            if let Some(template_path) = &template {
+                let rendered = render_template(&analysis, &file, template_path)?;
+                
+                match &out {
+                    Some(out_path) => {
+                        std::fs::write(out_path, rendered)
+                            .with_context(|| format!("Failed to write report: {}", out_path.display()))?;
+                        println!("{} Report written to {}", "OK".green().bold(), out_path.display());
+                    },
+                    None => crate::output::write_line(&rendered),
+                }
+                return Ok(());
+            }
+            
+            if let Some(suggestions_path) = &suggestions {
+                let entries = source_suggestions(&local_file, &analysis, options.limits.max_file_size)?;
+                
+                if entries.is_empty() {
+                    println!("{} No source locations with suggestions; compile with debug info so opcodes carry `location`", "[WARNING]".on_red().white().bold());
+                } else {
+                    write_suggestions(suggestions_path, &file, &entries)?;
+                    println!("{} Suggestions for {} locations written to {}", "OK".green().bold(), entries.len(), suggestions_path.display());
+                }
+            }
+            
+            if let Some(out_path) = &out {
+                write_report(&analysis, out_path)?;
+                println!("{} Report written to {}", "OK".green().bold(), out_path.display());
+            }
+            
+            let print_text = || {
+                if quick {
+                    print_core_metrics(&analysis, &file);
+                    print_uncalibrated_operations(&analysis);
+                    return;
+                }
+                
+                print_text_report(&analysis, &file, &prover);
+                
+                let thresholds = EvmThresholds { max_calldata, max_gas };
+                if evm || max_calldata.is_some() || max_gas.is_some() {
+                    print_verifier_estimate(&analysis, prover.backend.unwrap_or(BACKEND_PROFILES[0]), &thresholds);
+                }
+                
+                if let Some(set) = benchmark_against {
+                    print_benchmark(&analysis, set);
+                }
+                
+                if sensitivity {
+                    print_cost_sensitivity(&analysis);
+                }
+                
+                if !backends.is_empty() {
+                    print_backend_comparison(&analysis, &backends);
+                }
+                
+                println!("\n{} {}", "[NOTE]".on_cyan().black().bold(), t("analyze.demo"));
+            };
+            
+            let problems = || problems(&local_file, &analysis, options.limits.max_file_size).map(|problems| render_problems(&problems));
+            
+            if emit.is_empty() {
+                match format.as_str() {
+                    "json" => print_json(&analysis)?,
+                    "problems" => crate::output::write_line(problems()?.trim_end()),
+                    _ => print_text(),
+                }
+            }
+            
+            for sink in &emit {
+                match (sink.format, &sink.path) {
+                    (EmitFormat::Text, None) => print_text(),
+                    (EmitFormat::Text, Some(path)) => crate::output::capture(path, print_text)?,
+                    (EmitFormat::Json, None) => print_json(&analysis)?,
+                    (EmitFormat::Json, Some(path)) => write_report(&analysis, path)?,
+                    (EmitFormat::Markdown, None) => crate::output::write_line(&render_markdown(&analysis, &file)),
+                    (EmitFormat::Markdown, Some(path)) => std::fs::write(path, render_markdown(&analysis, &file))
+                        .with_context(|| format!("Failed to write report: {}", path.display()))?,
+                    (EmitFormat::Problems, None) => crate::output::write_line(problems()?.trim_end()),
+                    (EmitFormat::Problems, Some(path)) => std::fs::write(path, problems()?)
+                        .with_context(|| format!("Failed to write report: {}", path.display()))?,
+                }
+                
+                if let Some(path) = &sink.path {
+                    println!("{} {} report written to {}", "OK".green().bold(), sink.format, path.display());
+                }
+            }
+            
+            crate::timings::record("reporting", reporting.elapsed());
+        },
+        Some(Commands::AnalyzeSrc { project, format, prover }) => {
+            let prover = prover.resolve()?;
+            if format != "json" {
+                println!("{} Compiling {} with {}", "[NARGO]".on_magenta().white().bold(), project.display(), nargo_binary());
+            }
+            let compiled = compile_project(&project)?;
+            
+            let analysis = analyze_circuit_with_options(&compiled.artifact, &options)
+                .context("Failed to analyze circuit")?;
+            log_usage(history, &project, &analysis);
+            
+            match format.as_str() {
+                "json" => print_json(&analysis)?,
+                _ => {
+                    print_text_report(&analysis, &project, &prover);
+                    println!("\n{} {}", "[NOTE]".on_cyan().black().bold(), t("analyze.demo"));
+                }
+            }
+        },
+        Some(Commands::Init { project, workflow, force }) => {
+            println!("\n{} Setting up profiling in {}", "[INIT]".on_green().black().bold(), project.display());
+            
+            for path in init_project(&project, workflow, force, &options)? {
+                println!("  {} {}", "✓".green().bold(), path.display());
+            }
+            
+            println!("{} Commit these files; `report diff <baseline> <new> --notify {}` fails when a budget is exceeded", "[NOTE]".on_cyan().black(), BUDGET_FILE);
+        },
+        Some(Commands::Annotate { file, src, html }) => {
+            let local_file = resolve_artifact(&file)?;
+            let sources = annotate_sources(&local_file, &src, options.expression_width, options.limits.max_file_size)?;
+            
+            match &html {
+                Some(out_path) => {
+                    std::fs::write(out_path, render_heatmap_html(&file, &sources))
+                        .with_context(|| format!("Failed to write heatmap: {}", out_path.display()))?;
+                    println!("{} Heatmap for {} source files written to {}", "OK".green().bold(), sources.len(), out_path.display());
+                },
+                None => print_heatmap(&sources),
+            }
+        },
+        Some(Commands::Hook { action: HookAction::Install { project, hook, force } }) => {
+            let path = install_hook(&project, hook, force)?;
+            println!("\n{} Installed {} hook at {}", "[HOOK]".on_green().black().bold(), hook, path.display().to_string().cyan());
+            println!("{} It runs `noir-circuit-profiler --quick hook run` against the budgets in {}", "[NOTE]".on_cyan().black(), BUDGET_FILE);
+        },
+        Some(Commands::Hook { action: HookAction::Run { project, all } }) => {
+            print_hook_outcome(&run_hook(&project, all, &options)?)?;
+        },
+        Some(Commands::Audit { file, out }) => {
+            let local_file = resolve_artifact(&file)?;
+            let report = run_audit(&local_file, &options)
+                .context("Failed to audit circuit")?;
+            
+            write_audit_report(&report, &out)?;
+            
+            println!("\n{} Audit report written to {}", "[AUDIT]".on_red().white().bold(), out.display().to_string().cyan());
+            for finding in &report.findings {
+                let severity = match finding.severity {
+                    "high" => finding.severity.to_uppercase().red().bold(),
+                    "medium" => finding.severity.to_uppercase().yellow(),
+                    _ => finding.severity.to_uppercase().normal(),
+                };
+                println!("  [{}] {}: {}", severity, finding.category, finding.message);
+            }
+            println!("{} findings, {} black-box operations inventoried", report.findings.len(), report.black_box_inventory.len());
+        },
+        Some(Commands::VerifyReport { report, public_key }) => {
+            let signed = load_signed_report(&report)?;
+            let trusted_key = public_key.as_deref().map(parse_public_key).transpose()?;
+            
+            match verify_report(&signed, trusted_key.as_ref()) {
+                Ok(key) => {
+                    println!("{} Signature valid", "OK".green().bold());
+                    println!("Signed by: {}", hex_key(&key).cyan());
+                    if trusted_key.is_none() {
+                        println!("{} No --public-key given; only the embedded key was checked", "[NOTE]".on_cyan().black());
+                    }
+                },
+                Err(e) => {
+                    println!("{} {}", "INVALID".on_red().white().bold(), e);
+                    bail!("Report verification failed");
+                }
+            }
+        },
+        Some(Commands::Compare { file1, file2, recompile, flags_a, flags_b, more, rank, weights, format, noise_floor }) => {
+            if recompile {
+                print_recompile_comparison(&file1, &flags_a, &flags_b, &options, noise_floor)?;
+            } else if rank || weights.is_some() || !more.is_empty() {
+                let files: Vec<PathBuf> = std::iter::once(file1).chain(file2).chain(more).collect();
+                let weights = match weights {
+                    Some(weights) => weights,
+                    None => DEFAULT_WEIGHTS.parse().map_err(anyhow::Error::msg)?,
+                };
+                print_ranking(&files, &weights, &options)?;
+            } else if let Some(file2) = file2 {
+                match format.as_str() {
+                    "json-diff" => print_json_diff(&file1, &file2, &options, noise_floor)?,
+                    _ => print_comparison(&file1, &file2, &options, noise_floor)?,
+                }
+            }
+        },
+        Some(Commands::Batch { dir, max_file_size, max_opcodes, timeout, manifest, retry_failed, shared, budget }) => {
+            let options = AnalysisOptions {
+                limits: AnalysisLimits {
+                    max_file_size: max_file_size.map(|size| size.0),
+                    max_opcodes,
+                    timeout: timeout.map(Duration::from_secs),
+                },
+                exclude: manifest.iter().chain(retry_failed.iter()).cloned().collect(),
+                ..options
+            };
+            
+            let previous = match &retry_failed {
+                Some(path) => Some(load_manifest(path)?),
+                None => None,
+            };
+            
+            let bundle;
+            let (dir, results) = match &previous {
+                Some(previous) => {
+                    let dir = dir.unwrap_or_else(|| PathBuf::from(&previous.directory));
+                    let paths: Vec<String> = previous.failures.iter().map(|failure| failure.path.clone()).collect();
+                    
+                    if paths.is_empty() {
+                        println!("{} {}", "OK".green().bold(), tf("batch.no_failures", &[&retry_failed.unwrap_or_default().display()]));
+                        return Ok(());
+                    }
+                    
+                    println!("{} {}", "[RETRY]".on_magenta().white().bold(), tf("batch.retrying", &[&paths.len(), &dir.display()]));
+                    bundle = open_bundle(&dir, options.limits.max_file_size)?;
+                    let results = batch_analyze_paths(bundle.as_ref().map_or(dir.as_path(), |bundle| bundle.dir()), &paths, &options)
+                        .context("Failed to analyze directory")?;
+                    (dir, results)
+                },
+                None => {
+                    let dir = dir.unwrap_or_default();
+                    bundle = open_bundle(&dir, options.limits.max_file_size)?;
+                    let results = batch_analyze_with_options(bundle.as_ref().map_or(dir.as_path(), |bundle| bundle.dir()), &options)
+                        .context("Failed to analyze directory")?;
+                    (dir, results)
+                }
+            };
+            let artifact_dir = bundle.as_ref().map_or(dir.as_path(), |bundle| bundle.dir());
+            
+            if let Some(manifest_path) = manifest {
+                let mut current = build_manifest(artifact_dir, &results, options.limits.max_file_size);
+                current.directory = dir.display().to_string();
+                let batch_manifest = match previous {
+                    Some(previous) => merge_manifest(previous, current),
+                    None => current,
+                };
+                write_manifest(&batch_manifest, &manifest_path)?;
+                println!("{} {}", "OK".green().bold(), tf("batch.wrote_manifest", &[&manifest_path.display()]));
+            }
+            
+            println!("\n{} {}", "[BATCH]".on_magenta().white().bold(), t("batch.title"));
+            
+            let mut table = Table::new("{:<}  {:<}  {:<}  {:<}  {:<}");
+            table.add_row(Row::new()
+                .with_cell(t("metrics.name").bright_white().bold())
+                .with_cell(t("functions.constraints").bright_white().bold())
+                .with_cell(t("batch.mode").bright_white().bold())
+                .with_cell(t("batch.opcodes").bright_white().bold())
+                .with_cell(t("batch.ratio").bright_white().bold()));
+            
+            table.add_row(Row::new()
+                .with_cell("─".repeat(30))
+                .with_cell("─".repeat(15))
+                .with_cell("─".repeat(10))
+                .with_cell("─".repeat(15))
+                .with_cell("─".repeat(20)));
+            
+            let total = results.len();
+            let shared_components = if shared {
+                Some(find_shared_components(artifact_dir, &results, &options))
+            } else {
+                None
+            };
+            
+            let module_budgets = match &budget {
+                Some(path) => {
+                    let config = load_notify_config(path)?;
+                    let analyses: Vec<&CircuitAnalysis> = results.iter()
+                        .filter_map(|(_, result)| result.as_ref().ok())
+                        .collect();
+                    Some((module_usage(&analyses, &config.notify.modules), config.notify.fail_on_violation, path))
+                },
+                None => None,
+            };
+            
+            let mut failures: std::collections::BTreeMap<&str, usize> = std::collections::BTreeMap::new();
+            
+            for (name, result) in results {
+                if let Err(e) = &result {
+                    *failures.entry(failure_category(e)).or_insert(0) += 1;
+                }
+                
+                match result {
+                    Ok(analysis) => {
+                        log_usage(history, &artifact_dir.join(&name), &analysis);
+                        
+                        let constraint_per_op = if analysis.total_opcodes > 0 {
+                            analysis.constraints as f64 / analysis.total_opcodes as f64
+                        } else {
+                            0.0
+                        };
+                        
+                        table.add_row(Row::new()
+                            .with_cell(name.cyan())
+                            .with_cell(analysis.constraints.to_string().yellow())
+                            .with_cell(analysis.count_label())
+                            .with_cell(analysis.total_opcodes.to_string())
+                            .with_cell(format!("{:.1}x", constraint_per_op).green()));
+                    },
+                    Err(e) if e.is::<Skipped>() => {
+                        table.add_row(Row::new()
+                            .with_cell(name)
+                            .with_cell(t("batch.skipped").yellow())
+                            .with_cell("-")
+                            .with_cell("-")
+                            .with_cell(e.to_string().yellow()));
+                    },
+                    Err(e) => {
+                        table.add_row(Row::new()
+                            .with_cell(name)
+                            .with_cell(t("batch.error").red())
+                            .with_cell("-")
+                            .with_cell("-")
+                            .with_cell(e.to_string().red()));
+                    }
+                }
+            }
+            
+            print_table(&table);
+            
+            if !failures.is_empty() {
+                let failed: usize = failures.values().sum();
+                let categories: Vec<String> = failures.iter()
+                    .map(|(category, count)| format!("{} {}", category, count))
+                    .collect();
+                
+                println!("\n{} {}", "[FAILURES]".on_red().white().bold(), tf("batch.failures", &[&failed, &total, &categories.join(", ")]));
+                println!("{}", t("batch.retry_hint"));
+            }
+            
+            if let Some((components, skipped)) = shared_components {
+                print_shared_components(&components, &skipped);
+            }
+            
+            if let Some((usage, fail_on_violation, path)) = module_budgets {
+                let violations = module_violations(&usage);
+                print_module_budgets(&usage, path);
+                
+                if fail_on_violation && !violations.is_empty() {
+                    bail!("{} module budget(s) exceeded", violations.len());
+                }
+            }
+        },
+        Some(Commands::Stats { dir, anonymize: true, round, noise, k, key_file, .. }) => {
+            let secret = match &key_file {
+                Some(path) => Some(std::fs::read(path)
+                    .with_context(|| format!("Failed to read anonymization key: {}", path.display()))?),
+                None => None,
+            };
+            let key = export_key(secret.as_deref().map(|secret| secret.trim_ascii_end()));
+            
+            let results = batch_analyze_with_options(&dir, &options)
+                .context("Failed to analyze directory")?;
+            
+            let analyses: Vec<(&str, &CircuitAnalysis)> = results.iter()
+                .filter_map(|(name, result)| match result {
+                    Ok(analysis) => Some((name.as_str(), analysis)),
+                    Err(e) => {
+                        note_failed_circuit(name, e);
+                        None
+                    },
+                })
+                .collect();
+            let stats = Anonymizer { round, noise_percent: noise, k, key }.anonymize(&analyses);
+            
+            print_anonymous_stats(&stats, round, noise, k, &options);
+        },
+        Some(Commands::Stats { dir, details_dir, .. }) => {
+            let results = batch_analyze_with_options(&dir, &options)
+                .context("Failed to analyze directory")?;
+            
+            if !crate::output::continuing() {
+                println!("\n{} {}", "[STATS]".on_cyan().black().bold(), t("stats.title"));
+                println!("{}", tf("stats.collecting", &[&results.len()]));
+                
+                println!("\n# {}", t("stats.header"));
+                println!("# {}", tf("stats.generated", &[&chrono::Local::now().format("%Y-%m-%d %H:%M:%S")]));
+                println!("# {}", tf("stats.directory", &[&dir.display()]));
+                if let Some(label) = &options.label {
+                    println!("# {}", tf("stats.label", &[label]));
+                }
+                println!("# {}\n", tf("stats.note", &[&t("analyze.demo")]));
+                
+                crate::output::write_line("Circuit,Constraints,Opcodes,ExternalOps,PublicInputs,PrivateInputs,OutputCount,AvgCostPerOp,CountMode");
+            }
+            
+            for (name, result) in results {
+                match result {
+                    Ok(analysis) => {
+                        let avg_cost = if analysis.total_opcodes > 0 {
+                            analysis.constraints as f64 / analysis.total_opcodes as f64
+                        } else {
+                            0.0
+                        };
+                        
+                        let external_ops = analysis.black_box_functions.len();
+                        
+                        crate::output::write_line(&format!("{},{},{},{},{},{},{},{:.2},{}", 
+                            name,
+                            analysis.constraints,
+                            analysis.total_opcodes,
+                            external_ops,
+                            analysis.public_inputs,
+                            analysis.private_inputs,
+                            analysis.return_values,
+                            avg_cost,
+                            analysis.count_label()
+                        ));
+                        
+                        if let Some(details_dir) = &details_dir {
+                            write_detailed_stats(details_dir, &name, &analysis)?;
+                        }
+                    },
+                    Err(e) => note_failed_circuit(&name, &e),
+                }
+            }
+            
+            if !crate::output::redirected() {
+                println!("\n# {}", t("stats.complete"));
+                println!("# {}", t("stats.copy"));
+            }
+        },
+        Some(Commands::Calibrate { dir, dir_flag, reset, interactive }) => {
+            let dir = dir.or(dir_flag).context("Missing calibration directory")?;
+            println!("\n{} Cost Model Calibration:", "[CALIBRATE]".on_magenta().white().bold());
+            
+            if let Some(backup) = backup_cost_database().context("Failed to back up cost database")? {
+                println!("✓ Backed up cost database to {}", backup.display());
+            }
+            
+            if reset {
+                reset_cost_database();
+                println!("✓ Reset cost database to defaults");
+            }
+            
+            println!("Calibrating cost models using circuits in: {}", dir.display());
+            
+            let before = get_shared_cost_database();
+            if interactive {
+                set_cost_database_autosave(false);
+            }
+            
+            let results = batch_analyze_with_options(&dir, &options)
+                .context("Failed to analyze directory")?;
+            
+            if interactive {
+                let (accepted, rejected) = review_calibration(&before)?;
+                commit_cost_database().context("Failed to save cost database")?;
+                println!("\nAccepted {} change(s), rejected {}", accepted, rejected);
+            }
+            
+            let outliers = get_shared_cost_database().total_rejected().saturating_sub(before.total_rejected());
+            let successful = results.iter().filter(|(_, r)| r.is_ok()).count();
+            println!("\n{} Cost model calibration complete", "✓".green().bold());
+            println!("Processed {} circuits ({} successful)", results.len(), successful);
+            println!("Rejected {} outlier sample(s)", outliers);
+            
+            print_cost_database();
+        },
+        Some(Commands::CompareDirs { dir_a, dir_b }) => {
+            print_dir_comparison(&dir_a, &dir_b, &options)?;
+        },
+        Some(Commands::Pipeline { file, pricing }) => {
+            let pricing = pricing.resolve()?;
+            let pipeline = load_pipeline(&file)?;
+            let base_dir = file.parent().unwrap_or(std::path::Path::new("."));
+            
+            let estimate = estimate_pipeline(&pipeline, base_dir, &options)
+                .context("Failed to estimate pipeline")?;
+            
+            print_pipeline_estimate(&estimate, &pricing);
+        },
+        Some(Commands::Daemon { socket, stop, metrics }) => {
+            let socket = socket.unwrap_or_else(socket_path);
+            
+            if stop {
+                request_shutdown(&socket)?;
+                println!("{} Stopped the daemon on {}", "OK".green().bold(), socket.display());
+                return Ok(());
+            }
+            
+            run_daemon(&socket, metrics)?;
+        },
+        Some(Commands::Reconcile { file, gates, backend, tune, format }) => {
+            if tune && options.sample.is_some() {
+                bail!("--tune cannot be combined with --sample: a sampled estimate would skew the gate factor");
+            }
+            
+            let local_file = resolve_artifact(&file)?;
+            let artifact: serde_json::Value = serde_json::from_slice(&read_artifact(&local_file, options.limits.max_file_size)?)
+                .with_context(|| format!("Failed to parse circuit file: {}", file.display()))?;
+            let report: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&gates)
+                .with_context(|| format!("Failed to read gate report: {}", gates.display()))?)
+                .with_context(|| format!("Failed to parse gate report: {}", gates.display()))?;
+            
+            let analysis = analyze_circuit_with_options(&local_file, &options)
+                .context("Failed to analyze circuit")?;
+            let backend_name = backend.as_deref().map(find_backend_profile).transpose()?.unwrap_or(BACKEND_PROFILES[0]).name;
+            let mut reconciliation = reconcile(&analysis, &artifact, &report, backend_name)?;
+            let tuned = if tune {
+                Some(reconciliation.tune(&file_sha256(&local_file)?)?)
+            } else {
+                None
+            };
+            
+            match format.as_str() {
+                "json" => crate::output::write_line(&serde_json::to_string_pretty(&reconciliation)?),
+                _ => print_reconciliation(&reconciliation, &file, tuned),
+            }
+        },
+        Some(Commands::Summary { project, days, format }) => {
+            let since = chrono::Local::now().date_naive() - chrono::Duration::days(days.max(0));
+            let dashboard = build_dashboard(&project, Path::new(USAGE_LOG_PATH), since)?;
+            
+            match format.as_str() {
+                "json" => crate::output::write_line(&serde_json::to_string_pretty(&dashboard)?),
+                _ => print_dashboard(&dashboard, days),
+            }
+        },
+        Some(Commands::Usage { since, format }) => {
+            let summary = summarize_usage(Path::new(USAGE_LOG_PATH), since)?;
+            
+            match format.as_str() {
+                "json" => crate::output::write_line(&serde_json::to_string_pretty(&summary)?),
+                _ => print_usage_summary(&summary, since),
+            }
+        },
+        Some(Commands::Report { action: ReportAction::Diff { old, new, notify, allow } }) => {
+            print_report_diff(&old, &new, notify.as_deref(), allow.as_deref())?;
+        },
+        Some(Commands::Report { action: ReportAction::Trend { reports, confidence } }) => {
+            print_report_trend(&reports, confidence)?;
+        },
+        Some(Commands::CostDb { action: CostDbAction::Migrate }) => {
+            println!("\n{} Cost Database Migration:", "[COST-DB]".on_magenta().white().bold());
+            
+            let (from, to) = migrate_cost_database_file()
+                .with_context(|| format!("Failed to migrate {}", COST_DB_PATH))?;
+            
+            if from == to {
+                println!("{} {} is already at schema version {}", "✓".green().bold(), COST_DB_PATH, to);
+            } else {
+                println!("{} Migrated {} from schema version {} to {}", "✓".green().bold(), COST_DB_PATH, from, to);
+                println!("Previous file kept as {}", std::path::Path::new(COST_DB_PATH).with_extension(format!("v{}.json", from)).display());
+            }
+        },
+        Some(Commands::CostDb { action: CostDbAction::Rollback { to } }) => {
+            println!("\n{} Cost Database Rollback:", "[COST-DB]".on_magenta().white().bold());
+            
+            let (restored, snapshot) = rollback_cost_database(to.as_deref())
+                .context("Failed to roll back cost database")?;
+            
+            println!("{} Restored {} from backup {}", "✓".green().bold(), COST_DB_PATH, restored);
+            if let Some(snapshot) = snapshot {
+                println!("Previous database saved as {}", snapshot.display());
+            }
+            print_cost_database();
+        },
+        Some(Commands::CostDb { action: CostDbAction::Set { operation, cost, confidence } }) => {
+            set_cost_entry(&operation, cost, confidence)
+                .with_context(|| format!("Failed to set cost for {}", operation))?;
+            
+            println!("{} Set {} to {} constraints", "✓".green().bold(), operation, cost);
+        },
+        Some(Commands::CostDb { action: CostDbAction::Rm { operation } }) => {
+            if remove_cost_entry(&operation).with_context(|| format!("Failed to remove {}", operation))? {
+                println!("{} Removed {} from the cost database", "✓".green().bold(), operation);
+            } else {
+                bail!("No cost database entry named {}", operation);
+            }
+        },
+        Some(Commands::CostDb { action: CostDbAction::List { sort } }) => {
+            print_cost_database_sorted(&sort);
+        },
+        Some(Commands::Cost { operation, size }) => {
+            print_cost_lookup(&lookup_cost(&operation, size)?);
+        },
+        Some(Commands::Ops) => {
+            print_model_coverage(&model_coverage()?);
+        },
+        Some(Commands::Help) => {
+            print_help();
+        },
+        None => {
+            bail!("No command specified. Use --help for usage information.");
+        }
+    }
+    
+    if cli.timings {
+        print_timings();
+    }
+    
+    Ok(())
+}
+
+fn print_timings() {
+    let phases = crate::timings::phases();
+    let total: Duration = phases.iter().map(|(_, elapsed, _)| *elapsed).sum();
+    
+    println!("\n{} Profiler Timings:", "[TIMINGS]".on_blue().white().bold());
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Phase".bright_white().bold())
+        .with_cell("Runs".bright_white().bold())
+        .with_cell("Time".bright_white().bold())
+        .with_cell("% of Total".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("──────────────────────────────")
+        .with_cell("────")
+        .with_cell("──────────")
+        .with_cell("──────────"));
+    
+    for (phase, elapsed, runs) in &phases {
+        let percent = if total.as_secs_f64() > 0.0 {
+            elapsed.as_secs_f64() / total.as_secs_f64() * 100.0
+        } else {
+            0.0
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(phase.cyan())
+            .with_cell(runs)
+            .with_cell(format!("{:.2?}", elapsed).yellow())
+            .with_cell(format!("{:.1}%", percent)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    match crate::timings::peak_rss_kb() {
+        Some(kb) => println!("{} Peak RSS: {}", "[MEMORY]".on_magenta().white().bold(), format_bytes(kb as f64 * 1024.0)),
+        None => println!("{} Peak RSS is only reported on Linux", "[NOTE]".on_cyan().black()),
+    }
+}
+
+fn log_usage(enabled: bool, circuit: &Path, analysis: &CircuitAnalysis) {
+    if !enabled || analysis.sampling.is_some() {
+        return;
+    }
+    
+    if let Err(e) = record_usage(Path::new(USAGE_LOG_PATH), &UsageEntry::new(circuit, analysis)) {
+        eprintln!("{} {:#}", "[NOTE]".on_cyan().black(), e);
+    }
+}
+
+fn note_failed_circuit(name: &str, error: &anyhow::Error) {
+    eprintln!("{} {}", "[WARNING]".on_red().white().bold(), tf("stats.failed", &[&name, &format!("{:#}", error)]));
+}
+
+fn print_reconciliation(reconciliation: &Reconciliation, file: &Path, tuned: Option<bool>) {
+    println!("\n{} {}", "[RECONCILE]".on_blue().white().bold(), tf("reconcile.title", &[&reconciliation.backend, &file.display().to_string().cyan()]));
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell(t("reconcile.source").bright_white().bold())
+        .with_cell(t("reconcile.estimated").bright_white().bold())
+        .with_cell(t("reconcile.actual").bright_white().bold())
+        .with_cell(t("reconcile.difference").bright_white().bold())
+        .with_cell(t("reconcile.share").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("────────────"));
+    
+    let residual = reconciliation.residual();
+    for row in &reconciliation.rows {
+        let (actual, difference, share) = match (row.actual, row.difference()) {
+            (Some(actual), Some(difference)) => (
+                actual.to_string(),
+                format_signed_number(difference),
+                if residual != 0 { format!("{:.1}%", difference as f64 / residual as f64 * 100.0) } else { "-".to_string() },
+            ),
+            _ => ("-".to_string(), "-".normal(), "-".to_string()),
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(t(row.source.i18n_key()).cyan())
+            .with_cell(row.estimated)
+            .with_cell(actual)
+            .with_cell(difference)
+            .with_cell(share));
+    }
+    
+    table.add_row(Row::new()
+        .with_cell(t("common.total").bright_white().bold())
+        .with_cell(reconciliation.estimated.to_string().yellow())
+        .with_cell(reconciliation.actual.to_string().yellow())
+        .with_cell(format_signed_number(residual))
+        .with_cell(format!("{:.2}x", reconciliation.ratio())));
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    if !reconciliation.per_opcode {
+        println!("{} {}", "[NOTE]".on_cyan().black(), t("reconcile.total_only"));
+    }
+    match tuned {
+        Some(true) => println!("{} {}", "[TUNE]".on_green().black().bold(), tf("reconcile.tuned", &[
+            &reconciliation.backend, &format!("{:.3}", reconciliation.factor_before), &format!("{:.3}", reconciliation.factor_after), &GATE_CALIBRATION_PATH])),
+        Some(false) => println!("{} {}", "[NOTE]".on_cyan().black(), tf("reconcile.untuned", &[
+            &reconciliation.backend, &format!("{:.3}", reconciliation.factor_before)])),
+        None => {},
+    }
+}
+
+fn print_dashboard(dashboard: &Dashboard, days: i64) {
+    println!("\n{} Workspace Summary:", "[SUMMARY]".on_blue().white().bold());
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell("Metric".bright_white().bold())
+        .with_cell("Value".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────────────────────────────"));
+    
+    table.add_row(Row::new().with_cell("Circuits").with_cell(dashboard.circuits.to_string().yellow()));
+    table.add_row(Row::new().with_cell("Aggregate constraints").with_cell(dashboard.aggregate_constraints.to_string().yellow()));
+    table.add_row(Row::new().with_cell("Proving time").with_cell(format!("{:.2}ms", dashboard.estimated_proving_time)));
+    if let Some((circuit, constraints)) = &dashboard.largest {
+        table.add_row(Row::new().with_cell("Largest circuit").with_cell(format!("{} ({})", circuit.cyan(), constraints)));
+    }
+    
+    table.add_row(Row::new().with_cell(format!("Analyses, {} days", days)).with_cell(dashboard.recent_analyses));
+    let regression = match &dashboard.regression {
+        Some(change) => format!("{} {} → {} ({:+.1}%)", change.circuit.cyan(), change.before, change.after, change.percent()).red().to_string(),
+        None => "none".green().to_string(),
+    };
+    table.add_row(Row::new().with_cell("Biggest regression").with_cell(regression));
+    
+    let calibration = match (&dashboard.calibration_updated, dashboard.calibration_age_days) {
+        (Some(updated), Some(days)) => format!("{} ({} days ago, {} operations)", &updated[..updated.len().min(10)], days, dashboard.calibrated_operations),
+        _ => format!("never ({} operations)", dashboard.calibrated_operations),
+    };
+    table.add_row(Row::new()
+        .with_cell("Cost model calibrated")
+        .with_cell(if dashboard.calibration_stale() { calibration.yellow() } else { calibration.green() }));
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    if dashboard.circuits == 0 {
+        println!("{} No baselines in {}; run `init` to create them", "[NOTE]".on_cyan().black(), dashboard.baseline_dir);
+    }
+    if dashboard.unreadable_baselines > 0 {
+        println!("{} Skipped {} unreadable baseline(s) in {}", "[WARNING]".on_red().white().bold(), dashboard.unreadable_baselines, dashboard.baseline_dir);
+    }
+    if dashboard.calibration_stale() {
+        println!("{} The cost model is more than {} days old; run `calibrate` on recent circuits", "[NOTE]".on_cyan().black(), CALIBRATION_STALE_DAYS);
+    }
+}
+
+fn print_usage_summary(summary: &UsageSummary, since: Option<chrono::NaiveDate>) {
+    println!("\n{} Local Usage Statistics:", "[USAGE]".on_cyan().black().bold());
+    println!("Read from {}; nothing is sent anywhere", USAGE_LOG_PATH.cyan());
+    
+    if summary.runs == 0 {
+        match since {
+            Some(since) => println!("No analyses recorded since {}", since),
+            None => println!("No analyses recorded yet; pass `--record-usage` to `analyze`, `analyze-src` or `batch` to add to the log"),
+        }
+        return;
+    }
+    
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    let mut table = Table::new("{:<}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Metric".bright_white().bold())
+        .with_cell("Value".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────────────────────"));
+    
+    let period = match (&summary.first, &summary.last) {
+        (Some(first), Some(last)) => format!("{} – {}", &first[..first.len().min(10)], &last[..last.len().min(10)]),
+        _ => String::new(),
+    };
+    table.add_row(Row::new().with_cell("Period").with_cell(period));
+    table.add_row(Row::new().with_cell("Analyses").with_cell(summary.runs));
+    table.add_row(Row::new().with_cell("Distinct circuits").with_cell(summary.circuits.to_string().yellow()));
+    table.add_row(Row::new().with_cell("Average constraints").with_cell(format!("{:.0}", summary.average_constraints).yellow()));
+    table.add_row(Row::new().with_cell("Average opcodes").with_cell(format!("{:.0}", summary.average_opcodes)));
+    if let Some((circuit, constraints)) = &summary.largest {
+        table.add_row(Row::new().with_cell("Largest circuit").with_cell(format!("{} ({})", circuit, constraints)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    if !summary.black_boxes.is_empty() {
+        println!("\n{} Most Common Black-Box Functions:", "[USAGE]".on_cyan().black().bold());
+        print_border("╭───────────────────────────────────────────────────────────────╮");
+        
+        let mut table = Table::new("{:<}  {:>}  {:>}");
+        table.add_row(Row::new()
+            .with_cell("Function".bright_white().bold())
+            .with_cell("Circuits".bright_white().bold())
+            .with_cell("Calls".bright_white().bold()));
+        
+        table.add_row(Row::new()
+            .with_cell("────────────────────")
+            .with_cell("────────")
+            .with_cell("──────────"));
+        
+        for black_box in summary.black_boxes.iter().take(10) {
+            table.add_row(Row::new()
+                .with_cell(display_name(&black_box.name).cyan())
+                .with_cell(black_box.circuits)
+                .with_cell(black_box.calls));
+        }
+        
+        print_boxed(&table);
+        print_border("╰───────────────────────────────────────────────────────────────╯");
+    }
+    
+    println!("Averages count the latest analysis of each circuit");
+    if summary.unreadable > 0 {
+        println!("{} Skipped {} unreadable line(s) in {}", "[WARNING]".on_red().white().bold(), summary.unreadable, USAGE_LOG_PATH);
+    }
+}
+
+#[cfg(not(feature = "server"))]
+fn run_daemon(socket: &Path, _metrics: Option<std::net::SocketAddr>) -> Result<()> {
+    bail!("Cannot listen on {}: rebuild with `--features server` to run the daemon", socket.display())
+}
+
+#[cfg(feature = "server")]
+fn run_daemon(socket: &Path, metrics: Option<std::net::SocketAddr>) -> Result<()> {
+    use crate::metrics::{serve_metrics, MetricsRegistry};
+    use crate::server::serve;
+    
+    let registry = MetricsRegistry::default();
+    if let Some(addr) = metrics {
+        let addr = serve_metrics(addr, registry.clone())?;
+        println!("{} Serving http://{}/metrics", "[METRICS]".on_magenta().white().bold(), addr.to_string().cyan());
+    }
+    
+    println!("{} Listening on {} (stop with `daemon --stop`)", "[DAEMON]".on_magenta().white().bold(), socket.display().to_string().cyan());
+    serve(socket, |path, response| {
+        if let Some(analysis) = &response.analysis {
+            registry.record(path, analysis);
+        }
+        
+        let status = match (&response.error, response.cached) {
+            (Some(error), _) => format!("error: {}", error).red(),
+            (None, true) => "warm".green(),
+            (None, false) => "parsed".yellow(),
+        };
+        println!("  {} {}", path.display(), status);
+    })
+}
+
+fn analyze_artifact(path: &Path, options: &AnalysisOptions, daemon: Option<&Path>) -> Result<CircuitAnalysis> {
+    let Some(socket) = daemon.filter(|_| options.sample.is_none() && options.entry.is_none() && options.passes.is_full()) else {
+        return analyze_circuit_with_options(path, options);
+    };
+    
+    match request_analysis(socket, path, options) {
+        Err(e) if e.is::<DaemonUnavailable>() => {
+            eprintln!("{} {}; analyzing locally", "[NOTE]".on_cyan().black(), e);
+            analyze_circuit_with_options(path, options)
+        },
+        Ok(mut analysis) => {
+            analysis.metadata.label = options.label.clone();
+            Ok(analysis)
+        },
+        result => result,
+    }
+}
+
+fn review_calibration(before: &CostDatabaseView) -> Result<(usize, usize)> {
+    let after = get_shared_cost_database();
+    
+    let mut changes: Vec<_> = after.iter()
+        .filter(|(op, entry)| before.get(op) != Some(*entry))
+        .map(|(op, entry)| (op.clone(), before.get(op).copied(), *entry))
+        .collect();
+    changes.sort_by(|a, b| a.0.cmp(&b.0));
+    
+    println!("\n{} Proposed Cost Changes:", "[REVIEW]".on_yellow().black().bold());
+    
+    if changes.is_empty() {
+        println!("No cost changes proposed");
+        return Ok((0, 0));
+    }
+    
+    let mut accepted = 0;
+    let mut rejected = 0;
+    
+    for (op, old, (new_cost, _, new_samples)) in changes {
+        let (old_cost, old_samples) = old.map_or((0, 0), |(cost, _, samples)| (cost, samples));
+        let delta = if old_cost > 0 {
+            format!("{}%", format_signed_float((new_cost as f64 - old_cost as f64) / old_cost as f64 * 100.0))
+        } else {
+            "new".green().bold().to_string()
+        };
+        
+        println!("\n  {}", op.bright_white().bold());
+        println!("    cost:    {} → {} ({})", old_cost, new_cost, delta);
+        println!("    samples: {} → {}", old_samples, new_samples);
+        print!("{}", crate::theme::render("    accept? [y/N] "));
+        std::io::stdout().flush()?;
+        
+        let mut answer = String::new();
+        std::io::stdin().read_line(&mut answer)?;
+        
+        if matches!(answer.trim().to_ascii_lowercase().as_str(), "y" | "yes") {
+            accepted += 1;
+        } else {
+            restore_cost_entry(&op, before);
+            rejected += 1;
+        }
+    }
+    
+    Ok((accepted, rejected))
+}
+
+fn print_anonymous_stats(stats: &AnonymousStats, round: usize, noise: f64, k: usize, options: &AnalysisOptions) {
+    if !crate::output::continuing() {
+        println!("\n{} {}", "[STATS]".on_cyan().black().bold(), t("stats.anonymized_title"));
+        
+        println!("\n# {}", t("stats.anonymized_header"));
+        println!("# {}", tf("stats.generated", &[&chrono::Local::now().format("%Y-%m-%d")]));
+        if let Some(label) = &options.label {
+            println!("# {}", tf("stats.label", &[label]));
+        }
+        println!("# {}", tf("stats.anonymized_rounding", &[&round, &format!("{:.1}", noise), &k]));
+        println!("# {}", t("stats.anonymized_io"));
+        println!("# {}\n", tf("stats.note", &[&t("analyze.demo")]));
+        
+        crate::output::write_line("Circuit,Constraints,Opcodes,ExternalOps,PublicInputs,PrivateInputs,OutputCount,AvgCostPerOp,CountMode");
+    }
+    
+    for row in &stats.rows {
+        let avg_cost = if row.opcodes > 0 {
+            row.constraints as f64 / row.opcodes as f64
+        } else {
+            0.0
+        };
+        
+        crate::output::write_line(&format!("{},{},{},{},{},{},{},{:.2},{}", 
+            row.circuit,
+            row.constraints,
+            row.opcodes,
+            row.external_ops,
+            row.public_inputs,
+            row.private_inputs,
+            row.return_values,
+            avg_cost,
+            row.count_mode
+        ));
+    }
+    
+    if crate::output::continuing() {
+        return;
+    }
+    
+    println!("\nOperation,Circuits,Calls");
+    for op in &stats.operations {
+        println!("{},{},{}", op.operation, op.circuits, op.calls);
+    }
+    
+    if stats.rows.len() < k {
+        println!("# {}", tf("stats.below_k", &[&stats.rows.len(), &k]));
+    }
+    if stats.suppressed > 0 {
+        println!("# {}", tf("stats.suppressed", &[&stats.suppressed]));
+    }
+}
+
+fn write_detailed_stats(details_dir: &Path, name: &str, analysis: &CircuitAnalysis) -> Result<PathBuf> {
+    use std::fmt::Write as _;
+    
+    let stem = ARTIFACT_SUFFIXES.iter()
+        .find_map(|suffix| name.strip_suffix(suffix))
+        .unwrap_or(name);
+    let path = details_dir.join(format!("{}.csv", stem));
+    
+    let mut file = String::new();
+    
+    let _ = writeln!(file, "# NOIR PROFILER CIRCUIT ANALYSIS: {}", name);
+    let _ = writeln!(file, "# Generated on {}", chrono::Local::now().format("%Y-%m-%d %H:%M:%S"));
+    for (key, value) in analysis.metadata.header_rows() {
+        let _ = writeln!(file, "# {}: {}", t(key), value);
+    }
+    let _ = writeln!(file, "# NOTE: This is an experimental demo version\n");
+    
+    let _ = writeln!(file, "METRIC,VALUE");
+    let _ = writeln!(file, "Constraints,{}", analysis.constraints);
+    let _ = writeln!(file, "Count Mode,{}", analysis.count_label());
+    let _ = writeln!(file, "Opcodes,{}", analysis.total_opcodes);
+    let _ = writeln!(file, "Public Inputs,{}", analysis.public_inputs);
+    let _ = writeln!(file, "Private Inputs,{}", analysis.private_inputs);
+    let _ = writeln!(file, "Return Values,{}", analysis.return_values);
+    
+    let _ = writeln!(file, "\nOPERATION,COUNT");
+    for (op, count) in &analysis.operation_counts {
+        let _ = writeln!(file, "{},{}", op, count);
+    }
+    
+    if !analysis.black_box_functions.is_empty() {
+        let _ = writeln!(file, "\nEXTERNAL_OPERATION,CALLS,CONSTRAINTS_EACH");
+        for (name, count, cost) in &analysis.black_box_functions {
+            let _ = writeln!(file, "{},{},{}", name, count, cost);
+        }
+    }
+    
+    if !analysis.annotations.is_empty() {
+        let _ = writeln!(file, "\nLABEL,OPCODES,CONSTRAINTS");
+        for (label, opcodes, constraints) in &analysis.annotations {
+            let _ = writeln!(file, "{},{},{}", label, opcodes, constraints);
+        }
+    }
+    
+    let _ = writeln!(file, "\nCATEGORY,CONSTRAINTS,PERCENTAGE");
+    for (category, constraints) in &analysis.categories {
+        let percent = if analysis.constraints > 0 {
+            format!("{:.1}%", *constraints as f64 / analysis.constraints as f64 * 100.0)
+        } else {
+            "n/a".to_string()
+        };
+        let _ = writeln!(file, "{},{},{}", category, constraints, percent);
+    }
+    
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    std::fs::write(&path, file)
+        .with_context(|| format!("Failed to write circuit details: {}", path.display()))?;
+    
+    Ok(path)
+}
+
+fn print_core_metrics(analysis: &CircuitAnalysis, file: &Path) {
+    println!("\n{} {} {}", "[METRICS]".on_blue().white().bold(), t("metrics.title"), file.display().to_string().cyan().underline());
+    
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("metrics.metric").bright_white().bold())
+        .with_cell(t("metrics.value").bright_white().bold()));
+    
+    for (key, value) in analysis.metadata.header_rows() {
+        table.add_row(Row::new()
+            .with_cell(t(key))
+            .with_cell(value.cyan()));
+    }
+    
+    table.add_row(Row::new()
+        .with_cell(t("metrics.constraints"))
+        .with_cell(format!("{}", analysis.constraints).yellow().bold()));
+    
+    let count_mode = if analysis.sampling.is_some() {
+        analysis.count_label().red().bold()
+    } else if analysis.exact {
+        analysis.count_label().green().bold()
+    } else {
+        analysis.count_label().yellow()
+    };
+    table.add_row(Row::new()
+        .with_cell(t("metrics.count_mode"))
+        .with_cell(count_mode));
+    
+    if let Some(sampling) = &analysis.sampling {
+        table.add_row(Row::new()
+            .with_cell(t("metrics.interval"))
+            .with_cell(format!("{} – {}", sampling.constraints_low, sampling.constraints_high).yellow()));
+        
+        table.add_row(Row::new()
+            .with_cell(t("metrics.sampled"))
+            .with_cell(tf("metrics.sampled_value", &[&sampling.sampled_opcodes, &sampling.total_opcodes, &format!("{:.1}", sampling.rate * 100.0)])));
+    }
+    
+    table.add_row(Row::new()
+        .with_cell(t("metrics.expression_width"))
+        .with_cell(analysis.expression_width.to_string()));
+    
+    table.add_row(Row::new()
+        .with_cell(t("metrics.opcodes"))
+        .with_cell(format!("{}", analysis.total_opcodes).cyan()));
+    
+    table.add_row(Row::new()
+        .with_cell(t("metrics.public_inputs"))
+        .with_cell(format!("{}", analysis.public_inputs).magenta()));
+    
+    table.add_row(Row::new()
+        .with_cell(t("metrics.private_inputs"))
+        .with_cell(format!("{}", analysis.private_inputs).magenta()));
+    
+    table.add_row(Row::new()
+        .with_cell(t("metrics.io_count"))
+        .with_cell(tf("metrics.io_value", &[&(analysis.public_inputs + analysis.private_inputs), &analysis.return_values]).green().bold()));
+    
+    let proving_time = analysis.estimated_proving_time;
+    let time_display = if proving_time < 1.0 {
+        format!("{:.2}ms", proving_time).green()
+    } else if proving_time < 100.0 {
+        format!("{:.2}ms", proving_time).yellow()
+    } else if proving_time < 1000.0 {
+        format!("{:.2}ms", proving_time).red()
+    } else {
+        format!("{:.2}s", proving_time / 1000.0).red().bold()
+    };
+    
+    table.add_row(Row::new()
+        .with_cell(t("metrics.proving_time"))
+        .with_cell(time_display));
+    
+    if analysis.aggregate_constraints > 0 {
+        let efficiency = analysis.estimated_proving_time / analysis.aggregate_constraints as f64 * 1000.0;
+        table.add_row(Row::new()
+            .with_cell(t("metrics.efficiency"))
+            .with_cell(tf("metrics.efficiency_value", &[&format!("{:.3}", efficiency)]).cyan()));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+    
+    println!("\n{} {}", "[NOTE]".on_cyan().black(), t("metrics.hardware_note"));
+    
+    if let Some(sampling) = &analysis.sampling {
+        println!("{} {}", "[SAMPLED]".on_red().white().bold(), tf("metrics.sampled_note", &[&format!("{:.1}", sampling.rate * 100.0)]));
+    } else if !analysis.exact && !exact_count_available() {
+        println!("{} {}", "[NOTE]".on_cyan().black(), t("metrics.acvm_note"));
+    }
+}
+
+fn print_function_analysis(analysis: &CircuitAnalysis) {
+    if analysis.black_box_functions.is_empty() {
+        return;
+    }
+    
+    println!("\n{} {}", "[FUNCTIONS]".on_red().white().bold(), t("functions.title"));
+    
+    let black_box_constraints: usize = analysis.black_box_functions
+        .iter()
+        .map(|(_, count, cost)| count.saturating_mul(*cost))
+        .fold(0, usize::saturating_add);
+    
+    let percent = if analysis.constraints > 0 {
+        (black_box_constraints as f64 / analysis.constraints as f64) * 100.0
+    } else {
+        0.0
+    };
+    
+    print_border("╭────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("functions.operation").bright_white().bold())
+        .with_cell(t("functions.calls").bright_white().bold())
+        .with_cell(t("functions.constraints").bright_white().bold())
+        .with_cell(t("functions.share").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("──────────"));
+    
+    for (name, count, cost) in &analysis.black_box_functions {
+        let total_cost = count.saturating_mul(*cost);
+        let func_percent = if analysis.constraints > 0 {
+            (total_cost as f64 / analysis.constraints as f64) * 100.0
+        } else {
+            0.0
+        };
+        
+        let percent_cell = if func_percent > 20.0 {
+            format!("{:.1}%", func_percent).red().bold()
+        } else if func_percent > 10.0 {
+            format!("{:.1}%", func_percent).yellow()
+        } else {
+            format!("{:.1}%", func_percent).green()
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(display_name(name).cyan())
+            .with_cell(count.to_string())
+            .with_cell(total_cost.to_string().yellow())
+            .with_cell(percent_cell));
+    }
+    
+    print_boxed(&table);
+    
+    print_border("╰────────────────────────────────────────────────────────────╯");
+    
+    if !raw_names() {
+        let mut links: Vec<(String, String)> = analysis.black_box_functions.iter()
+            .filter_map(|(name, _, _)| docs_url(name).map(|url| (display_name(name), url)))
+            .collect();
+        links.dedup();
+        
+        for (name, url) in links {
+            println!("  {} {}: {}", "[DOCS]".dimmed(), name, url.blue().underline());
+        }
+    }
+    
+    if percent > 0.0 {
+        println!("\n{}: {}", 
+                "[INSIGHT]".on_yellow().black().bold(),
+                tf("functions.insight", &[&format!("{:.1}", percent)]));
+    }
+}
+
+fn print_function_comparison(analysis1: &CircuitAnalysis, analysis2: &CircuitAnalysis, noise: NoiseFloor) {
+    println!("\n{} {}", "[FUNCTIONS]".on_red().white().bold(), t("compare.functions_title"));
+    
+    let key = |name: &str| if raw_names() { name.to_string() } else { canonical_name(name) };
+    
+    let mut all_functions = Vec::new();
+    for (name, _, _) in analysis1.black_box_functions.iter().chain(&analysis2.black_box_functions) {
+        if !all_functions.iter().any(|(existing, _)| *existing == key(name)) {
+            all_functions.push((key(name), name.clone()));
+        }
+    }
+    
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("functions.operation").bright_white().bold())
+        .with_cell(t("compare.circuit1").bright_white().bold())
+        .with_cell(t("compare.circuit2").bright_white().bold())
+        .with_cell(t("compare.diff").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("──────────"));
+    
+    for (func_key, func_name) in all_functions {
+        let count1: usize = analysis1.black_box_functions
+            .iter()
+            .filter(|(name, _, _)| key(name) == func_key)
+            .map(|(_, count, _)| *count)
+            .sum();
+        
+        let count2: usize = analysis2.black_box_functions
+            .iter()
+            .filter(|(name, _, _)| key(name) == func_key)
+            .map(|(_, count, _)| *count)
+            .sum();
+        
+        table.add_row(Row::new()
+            .with_cell(display_name(&func_name).cyan())
+            .with_cell(count1.to_string())
+            .with_cell(count2.to_string())
+            .with_cell(diff_cell(count1, count2, noise)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+}
+
+fn print_structure_analysis(analysis: &CircuitAnalysis) {
+    if analysis.operation_counts.is_empty() {
+        return;
+    }
+    
+    println!("\n{} {}", "[STRUCTURE]".on_green().black().bold(), t("structure.title"));
+    
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("structure.type").bright_white().bold())
+        .with_cell(t("structure.count").bright_white().bold())
+        .with_cell(t("structure.share").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("────────────"));
+    
+    let sorted_ops = &analysis.operation_counts;
+    let display_count = std::cmp::min(8, sorted_ops.len());
+    
+    for (op_type, count) in sorted_ops.iter().take(display_count) {
+        let percent = if analysis.total_opcodes > 0 {
+            (*count as f64 / analysis.total_opcodes as f64) * 100.0
+        } else {
+            0.0
+        };
+        
+        let percent_cell = if percent > 50.0 {
+            format!("{:.1}%", percent).red().bold()
+        } else if percent > 20.0 {
+            format!("{:.1}%", percent).yellow()
+        } else {
+            format!("{:.1}%", percent).green()
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(op_type.cyan())
+            .with_cell(count.to_string())
+            .with_cell(percent_cell));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+    
+    let has_memory_ops = analysis.operation_counts
+        .iter()
+        .any(|(op, _)| operation_category(op) == Category::Memory);
+    
+    println!("\n{}: {}", 
+             "[INSIGHT]".on_yellow().black().bold(),
+             if has_memory_ops {
+                 t("structure.memory").italic()
+             } else {
+                 t("structure.scalar").italic()
+             });
+}
+
+fn print_constraint_details(analysis: &CircuitAnalysis) {
+    println!("\n{} {}", "[DETAILS]".on_blue().white().bold(), t("details.title"));
+    
+    if analysis.constraints == 0 {
+        println!("{}", t("details.empty"));
+        return;
+    }
+    
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("details.category").bright_white().bold())
+        .with_cell(t("details.constraints").bright_white().bold())
+        .with_cell(t("details.share").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("────────────")
+        .with_cell("────────────"));
+    
+    for (category, count) in &analysis.categories {
+        let percent = (*count as f64 / analysis.constraints as f64) * 100.0;
+        
+        let percent_cell = if percent > 50.0 {
+            format!("{:.1}%", percent).red().bold()
+        } else if percent > 20.0 {
+            format!("{:.1}%", percent).yellow()
+        } else {
+            format!("{:.1}%", percent).green()
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(t(category.i18n_key()).cyan())
+            .with_cell(count.to_string().yellow())
+            .with_cell(percent_cell));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+}
+
+fn print_annotation_analysis(analysis: &CircuitAnalysis) {
+    if analysis.annotations.is_empty() {
+        return;
+    }
+    
+    println!("\n{} Labeled Regions ({}):", "[LABELS]".on_magenta().white().bold(), crate::annotations::PROFILES_FILE);
+    
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell("Label".bright_white().bold())
+        .with_cell("Opcodes".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold())
+        .with_cell("% of Total".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("────────────")
+        .with_cell("────────────"));
+    
+    for (label, opcodes, constraints) in &analysis.annotations {
+        let percent = if analysis.constraints > 0 {
+            (*constraints as f64 / analysis.constraints as f64) * 100.0
+        } else {
+            0.0
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(label.cyan())
+            .with_cell(opcodes.to_string())
+            .with_cell(constraints.to_string().yellow())
+            .with_cell(format!("{:.1}%", percent)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+}
+
+fn print_call_analysis(analysis: &CircuitAnalysis) {
+    if analysis.callees.is_empty() {
+        return;
+    }
+    
+    println!("\n{} {}", "[CALLS]".on_blue().white().bold(), t("calls.title"));
+    
+    for (caller, callee, count) in &analysis.call_graph {
+        println!("  {}", tf("calls.edge", &[&caller.cyan(), &callee.cyan().bold(), count]));
+    }
+    
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("calls.function").bright_white().bold())
+        .with_cell(t("calls.invocations").bright_white().bold())
+        .with_cell(t("calls.per_call").bright_white().bold())
+        .with_cell(t("calls.all").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("───────────")
+        .with_cell("────────────────")
+        .with_cell("───────────────"));
+    
+    for (name, invocations, cost) in &analysis.callees {
+        table.add_row(Row::new()
+            .with_cell(name.cyan())
+            .with_cell(invocations.to_string())
+            .with_cell(cost.to_string().yellow())
+            .with_cell(invocations.saturating_mul(*cost).to_string()));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    let diff = analysis.inlined_constraints as i64 - analysis.constraints as i64;
+    println!("\n{}: {}",
+             "[INSIGHT]".on_yellow().black().bold(),
+             tf("calls.insight", &[
+                 &analysis.constraints.to_string().yellow(),
+                 &analysis.inlined_constraints.to_string().yellow(),
+                 &format_signed_number(diff)]));
+    println!("  {}", t("calls.inline_hint"));
+    
+    println!("\n{} {}",
+             "[AGGREGATE]".on_magenta().white().bold(),
+             tf("calls.strategy", &[
+                 &analysis.proving_strategy.to_string().cyan().bold(),
+                 &analysis.aggregate_constraints.to_string().yellow().bold(),
+                 &analysis.proof_count]));
+    if analysis.proving_strategy == ProvingStrategy::Fold {
+        println!("  {}", tf("calls.overhead", &[&crate::calls::AGGREGATION_OVERHEAD]));
+    }
+}
+
+fn print_folding_analysis(analysis: &CircuitAnalysis) {
+    let assertions = analysis.constant_assertions + analysis.foldable_assertions + analysis.witness_assertions;
+    if assertions == 0 {
+        return;
+    }
+    
+    println!("\n{} {}", "[FOLDING]".on_blue().white().bold(), t("folding.title"));
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("folding.kind").bright_white().bold())
+        .with_cell("AssertZero".bright_white().bold())
+        .with_cell(t("folding.meaning").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────")
+        .with_cell("──────────")
+        .with_cell("────────────────────────"));
+    
+    table.add_row(Row::new()
+        .with_cell("constant-only".red())
+        .with_cell(analysis.constant_assertions.to_string())
+        .with_cell(t("folding.constant")));
+    table.add_row(Row::new()
+        .with_cell("foldable".yellow())
+        .with_cell(analysis.foldable_assertions.to_string())
+        .with_cell(t("folding.foldable")));
+    table.add_row(Row::new()
+        .with_cell("witness-dependent".green())
+        .with_cell(analysis.witness_assertions.to_string())
+        .with_cell(t("folding.witness")));
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+    
+    if analysis.wasted_constraints == 0 {
+        return;
+    }
+    
+    let share = if analysis.constraints > 0 {
+        analysis.wasted_constraints as f64 / analysis.constraints as f64 * 100.0
+    } else {
+        0.0
+    };
+    
+    let sites: Vec<String> = analysis.wasted_sites.iter()
+        .take(10)
+        .map(|(idx, _)| format!("#{}", idx))
+        .collect();
+    let more = analysis.wasted_sites.len().saturating_sub(sites.len());
+    
+    println!("\n{}: {}",
+             "[INSIGHT]".on_yellow().black().bold(),
+             tf("folding.insight", &[&analysis.wasted_constraints.to_string().yellow().bold(), &format!("{:.1}", share)]));
+    println!("  {}{}", tf("folding.opcodes", &[&sites.join(", ")]), if more > 0 { format!(" {}", tf("folding.more", &[&more])) } else { String::new() });
+    println!("  {}", t("folding.hint"));
+}
+
+fn print_curve_operations(analysis: &CircuitAnalysis) {
+    if analysis.curve_operations.is_empty() {
+        return;
+    }
+    
+    println!("\n{} Embedded Curve Operations ({}):", "[CURVE]".on_magenta().white().bold(), analysis.field.embedded_curve());
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Opcode".bright_white().bold())
+        .with_cell("Operation".bright_white().bold())
+        .with_cell("Points".bright_white().bold())
+        .with_cell("Fixed".bright_white().bold())
+        .with_cell("Scalar Bits".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("──────")
+        .with_cell("──────────────────────────")
+        .with_cell("──────")
+        .with_cell("─────")
+        .with_cell("───────────")
+        .with_cell("───────────"));
+    
+    for op in &analysis.curve_operations {
+        table.add_row(Row::new()
+            .with_cell(format!("#{}", op.site))
+            .with_cell(display_name(&op.function).cyan())
+            .with_cell(op.points)
+            .with_cell(op.fixed_points)
+            .with_cell(op.scalar_bits)
+            .with_cell(op.constraints.to_string().yellow()));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    println!("{} MSM cost is modeled from point count and scalar bit-length; fixed-base points skip the doubling chain", "[NOTE]".on_cyan().black());
+}
+
+fn print_skipped_passes(analysis: &CircuitAnalysis) {
+    if !analysis.skipped_passes.is_empty() {
+        println!("\n{} Skipped analysis passes: {}; their sections are empty", "[NOTE]".on_cyan().black(), analysis.skipped_passes.join(", "));
+    }
+}
+
+fn print_missing_sections(analysis: &CircuitAnalysis) {
+    for missing in analysis.unknown_sections() {
+        println!("\n{} {}", "[WARNING]".on_red().white().bold(), missing);
+    }
+    for missing in analysis.estimated_sections() {
+        println!("\n{} {}", "[NOTE]".on_cyan().black(), missing);
+    }
+}
+
+fn print_analysis_warnings(analysis: &CircuitAnalysis) {
+    for warning in &analysis.warnings {
+        println!("\n{} {}", "[WARNING]".on_red().white().bold(), warning);
+    }
+}
+
+fn print_backend_warnings(analysis: &CircuitAnalysis, backend: Option<BackendProfile>) {
+    let Some(profile) = backend else {
+        return;
+    };
+    
+    for warning in capability_warnings(analysis, profile) {
+        let tag = match warning.support {
+            Support::Unsupported => "[UNSUPPORTED]".on_red().white().bold(),
+            _ => "[EMULATED]".on_yellow().black().bold(),
+        };
+        println!("\n{} {} ({} calls)", tag, warning, warning.calls);
+    }
+}
+
+fn print_uncalibrated_operations(analysis: &CircuitAnalysis) {
+    let Some(warning) = analysis.uncalibrated_warning() else {
+        return;
+    };
+    
+    println!("\n{} {}", "[WARNING]".on_red().white().bold(), warning);
+    println!("\n{} Operations Without a Cost Entry:", "[UNCALIBRATED]".on_yellow().black().bold());
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Operation".bright_white().bold())
+        .with_cell("Occurrences".bright_white().bold())
+        .with_cell("Assumed Cost".bright_white().bold())
+        .with_cell("Total".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("───────────")
+        .with_cell("────────────")
+        .with_cell("──────────"));
+    
+    for (name, count) in &analysis.uncalibrated_operations {
+        table.add_row(Row::new()
+            .with_cell(name.cyan())
+            .with_cell(count)
+            .with_cell(FALLBACK_COST)
+            .with_cell(count.saturating_mul(FALLBACK_COST).to_string().yellow()));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    println!("Calibrate on circuits that use these, or set a cost with `cost-db set <operation> <cost>`");
+}
+
+fn print_duplicate_calls(analysis: &CircuitAnalysis) {
+    if analysis.duplicate_calls.is_empty() {
+        return;
+    }
+    
+    println!("\n{} {}", "[DEDUP]".on_red().white().bold(), t("dedup.title"));
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("calls.function").bright_white().bold())
+        .with_cell(t("dedup.inputs").bright_white().bold())
+        .with_cell(t("batch.opcodes").bright_white().bold())
+        .with_cell(t("dedup.cost_each").bright_white().bold())
+        .with_cell(t("dedup.reclaimable").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("───────────────")
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("─────────")
+        .with_cell("───────────"));
+    
+    for duplicate in &analysis.duplicate_calls {
+        let mut inputs = duplicate.inputs.iter().take(3).cloned().collect::<Vec<_>>().join(", ");
+        if duplicate.inputs.len() > 3 {
+            inputs.push_str(&format!(" (+{})", duplicate.inputs.len() - 3));
+        }
+        
+        let sites: Vec<String> = duplicate.sites.iter().map(|idx| format!("#{}", idx)).collect();
+        
+        table.add_row(Row::new()
+            .with_cell(duplicate.function.cyan())
+            .with_cell(inputs)
+            .with_cell(sites.join(", "))
+            .with_cell(duplicate.cost_each.to_string())
+            .with_cell(duplicate.reclaimable.to_string().green().bold()));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    let reclaimable: usize = analysis.duplicate_calls.iter().map(|duplicate| duplicate.reclaimable).sum();
+    println!("\n{}: {}",
+             "[INSIGHT]".on_yellow().black().bold(),
+             tf("dedup.insight", &[&reclaimable.to_string().green().bold()]));
+}
+
+fn print_lints(analysis: &CircuitAnalysis) {
+    if analysis.lints.is_empty() {
+        return;
+    }
+    
+    println!("\n{} {}", "[LINT]".on_green().black().bold(), t("lint.title"));
+    
+    for lint in &analysis.lints {
+        println!("  {} {} ({})",
+                 format!("[{}]", lint.rule).cyan(),
+                 lint.message,
+                 tf("lint.saves", &[&lint.savings.to_string().green().bold()]));
+    }
+    
+    let savings: usize = analysis.lints.iter().map(|lint| lint.savings).sum();
+    println!("  {}", tf("lint.total", &[&savings.to_string().green().bold(), &analysis.lints.len()]));
+}
+
+fn print_assertion_costs(analysis: &CircuitAnalysis) {
+    if analysis.assertions.is_empty() {
+        return;
+    }
+    
+    println!("\n{} Most Expensive Assertions:", "[ASSERTS]".on_red().white().bold());
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Message".bright_white().bold())
+        .with_cell("Sites".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold())
+        .with_cell("% of Total".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("──────────────────────────────")
+        .with_cell("─────")
+        .with_cell("───────────")
+        .with_cell("──────────"));
+    
+    for assertion in analysis.assertions.iter().take(10) {
+        let percent = if analysis.constraints > 0 {
+            assertion.constraints as f64 / analysis.constraints as f64 * 100.0
+        } else {
+            0.0
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(format!("\"{}\"", assertion.message).cyan())
+            .with_cell(assertion.sites)
+            .with_cell(assertion.constraints.to_string().yellow())
+            .with_cell(format!("{:.1}%", percent)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    println!("{} Each assertion is charged for its own opcode and the opcodes since the previous assertion", "[NOTE]".on_cyan().black());
+}
+
+fn print_cost_sensitivity(analysis: &CircuitAnalysis) {
+    let entries = cost_sensitivity(analysis, COST_ERROR);
+    
+    println!("\n{} Cost Sensitivity (each black-box cost off by ±{:.0}%):", "[SENSITIVITY]".on_magenta().white().bold(), COST_ERROR * 100.0);
+    if entries.is_empty() {
+        println!("No black-box functions; the estimate does not depend on the cost model");
+        return;
+    }
+    
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Operation".bright_white().bold())
+        .with_cell("Calls".bright_white().bold())
+        .with_cell("Cost".bright_white().bold())
+        .with_cell("Share".bright_white().bold())
+        .with_cell("± Constraints".bright_white().bold())
+        .with_cell("± Time".bright_white().bold())
+        .with_cell("Confidence".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────")
+        .with_cell("────────")
+        .with_cell("──────")
+        .with_cell("─────────────")
+        .with_cell("──────────")
+        .with_cell("──────────"));
+    
+    for entry in &entries {
+        let confidence = if entry.fallback {
+            "fallback".red()
+        } else {
+            format!("{:.1}%", entry.confidence * 100.0).normal()
+        };
+        let share = format!("{:.1}%", entry.share);
+        
+        table.add_row(Row::new()
+            .with_cell(display_name(&entry.operation).cyan())
+            .with_cell(entry.calls)
+            .with_cell(entry.cost)
+            .with_cell(if entry.worth_calibrating() { share.yellow().bold() } else { share.normal() })
+            .with_cell(format!("{:.0}", entry.constraints_delta))
+            .with_cell(format!("{:.2}ms", entry.proving_time_delta))
+            .with_cell(confidence));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    
+    let calibrate: Vec<String> = entries.iter()
+        .filter(|entry| entry.worth_calibrating())
+        .map(|entry| display_name(&entry.operation))
+        .collect();
+    if let Some(top) = entries.first() {
+        println!("The estimate is most sensitive to {}: a {:.0}% error moves the total by {:.1}%",
+            display_name(&top.operation).cyan().bold(), COST_ERROR * 100.0, top.share * COST_ERROR);
+    }
+    if !calibrate.is_empty() {
+        println!("{} Worth calibrating: {}", "[CALIBRATE]".on_yellow().black().bold(), calibrate.join(", "));
+    }
+}
+
+fn print_benchmark(analysis: &CircuitAnalysis, set: BenchmarkSet) {
+    let comparison = benchmark(analysis, set);
+    
+    println!("\n{} {}", "[BENCHMARK]".on_blue().white().bold(), t("benchmark.title"));
+    
+    if comparison.matches.is_empty() {
+        println!("  {}", t("benchmark.none"));
+        return;
+    }
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell(t("benchmark.reference").bright_white().bold())
+        .with_cell(t("functions.calls").bright_white().bold())
+        .with_cell(t("benchmark.instances").bright_white().bold())
+        .with_cell(t("benchmark.expected").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────────────────")
+        .with_cell("─────")
+        .with_cell("─────────")
+        .with_cell("──────────"));
+    
+    for m in &comparison.matches {
+        table.add_row(Row::new()
+            .with_cell(m.reference.name.cyan())
+            .with_cell(m.calls)
+            .with_cell(format!("{:.1}x", m.instances()))
+            .with_cell(m.expected.to_string().yellow()));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    let coverage = comparison.coverage();
+    if coverage >= 90.0 {
+        println!("{} {}",
+            "[OK]".on_green().black().bold(),
+            tf("benchmark.explained", &[&comparison.actual, &comparison.expected, &format!("{:.1}", coverage)]));
+    } else {
+        println!("{} {}",
+            "[WARNING]".on_red().white().bold(),
+            tf("benchmark.overhead", &[&comparison.actual, &comparison.expected, &comparison.overhead().to_string().red().bold(), &format!("{:.1}", coverage)]));
+    }
+}
+
+fn print_pattern_analysis(analysis: &CircuitAnalysis) {
+    if analysis.repeated_patterns.is_empty() {
+        return;
+    }
+    
+    let estimated_total: usize = analysis.repeated_patterns.iter()
+        .map(|pattern| pattern.constraints)
+        .sum::<usize>()
+        .max(1);
+    let circuit_constraints = analysis.constraints.max(estimated_total);
+    
+    println!("\n{} Repeated Opcode Patterns (unrolled loops):", "[PATTERNS]".on_magenta().white().bold());
+    
+    for pattern in &analysis.repeated_patterns {
+        let share = pattern.constraints as f64 / circuit_constraints as f64 * 100.0;
+        let end = pattern.start + pattern.length - 1;
+        
+        println!("  pattern of {} opcodes repeated {} times ({:.0}% of circuit), {} constraints per iteration",
+                 pattern.length.to_string().cyan().bold(),
+                 pattern.repeats.to_string().yellow().bold(),
+                 share,
+                 compact_count(pattern.per_iteration).yellow());
+        
+        match &pattern.location {
+            Some(location) => println!("    loop body: opcodes #{}-#{} ({})", pattern.start, end, location.cyan()),
+            None => println!("    loop body: opcodes #{}-#{}", pattern.start, end),
+        }
+    }
+    
+    if let Some(top) = analysis.repeated_patterns.first() {
+        println!("\n{}: The loop body at opcodes #{}-#{} runs {} times; every opcode removed from it saves {} opcodes",
+                 "[INSIGHT]".on_yellow().black().bold(),
+                 top.start,
+                 top.start + top.length - 1,
+                 top.repeats,
+                 top.repeats);
+        
+        print_suggestions(&loop_suggestions(top));
+    }
+}
+
+fn print_annotation_comparison(analysis1: &CircuitAnalysis, analysis2: &CircuitAnalysis, noise: NoiseFloor) {
+    println!("\n{} {}", "[LABELS]".on_magenta().white().bold(), t("compare.labels_title"));
+    
+    let mut all_labels = Vec::new();
+    for (label, _, _) in analysis1.annotations.iter().chain(&analysis2.annotations) {
+        if !all_labels.contains(label) {
+            all_labels.push(label.clone());
+        }
+    }
+    
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("compare.label").bright_white().bold())
+        .with_cell(t("compare.circuit1").bright_white().bold())
+        .with_cell(t("compare.circuit2").bright_white().bold())
+        .with_cell(t("compare.diff").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("──────────"));
+    
+    for label in all_labels {
+        let constraints1 = analysis1.annotations
+            .iter()
+            .find(|(name, _, _)| name == &label)
+            .map(|(_, _, constraints)| *constraints)
+            .unwrap_or(0);
+        
+        let constraints2 = analysis2.annotations
+            .iter()
+            .find(|(name, _, _)| name == &label)
+            .map(|(_, _, constraints)| *constraints)
+            .unwrap_or(0);
+        
+        table.add_row(Row::new()
+            .with_cell(label.cyan())
+            .with_cell(constraints1.to_string())
+            .with_cell(constraints2.to_string())
+            .with_cell(diff_cell(constraints1, constraints2, noise)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+}
+
+fn print_text_report(analysis: &CircuitAnalysis, file: &Path, prover: &Prover) {
+    print_core_metrics(analysis, file);
+    print_uncalibrated_operations(analysis);
+    print_missing_sections(analysis);
+    print_analysis_warnings(analysis);
+    print_backend_warnings(analysis, prover.backend);
+    print_skipped_passes(analysis);
+    print_function_analysis(analysis);
+    print_structure_analysis(analysis);
+    print_constraint_details(analysis);
+    print_annotation_analysis(analysis);
+    print_call_analysis(analysis);
+    print_folding_analysis(analysis);
+    print_duplicate_calls(analysis);
+    print_curve_operations(analysis);
+    print_lints(analysis);
+    print_assertion_costs(analysis);
+    print_pattern_analysis(analysis);
+    print_cloud_cost(analysis, &prover.pricing);
+    print_gpu_estimate(analysis, prover.gpu);
+    print_energy_estimate(analysis, prover.energy.as_ref());
+    if prover.setup {
+        print_setup_estimate(analysis, prover.backend);
+    }
+}
+
+fn print_program_report(program: &ProgramReport, file: &Path, prover: &Prover) {
+    println!("\n{} Program bundle {} with {} circuits:", "[PROGRAM]".on_blue().white().bold(), 
+        file.display().to_string().cyan().underline(), program.members.len());
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Circuit".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold())
+        .with_cell("Opcodes".bright_white().bold())
+        .with_cell("Proving Time".bright_white().bold())
+        .with_cell("Share".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("───────────")
+        .with_cell("──────────")
+        .with_cell("────────────")
+        .with_cell("──────"));
+    
+    for member in &program.members {
+        let share = if program.aggregate_constraints > 0 {
+            member.analysis.aggregate_constraints as f64 / program.aggregate_constraints as f64 * 100.0
+        } else {
+            0.0
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(member.name.cyan())
+            .with_cell(member.analysis.aggregate_constraints.to_string().yellow())
+            .with_cell(member.analysis.total_opcodes)
+            .with_cell(format!("{:.2}ms", member.analysis.estimated_proving_time))
+            .with_cell(format!("{:.1}%", share)));
+    }
+    
+    table.add_row(Row::new()
+        .with_cell("Total".bright_white().bold())
+        .with_cell(program.aggregate_constraints.to_string().yellow().bold())
+        .with_cell(program.total_opcodes)
+        .with_cell(format!("{:.2}ms", program.estimated_proving_time))
+        .with_cell("100.0%"));
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    for member in &program.members {
+        println!("\n{} {}", "[MEMBER]".on_magenta().white().bold(), member.name.bright_white().bold());
+        print_text_report(&member.analysis, file, prover);
+    }
+    
+    println!("\n{} {}", "[NOTE]".on_cyan().black().bold(), t("analyze.demo"));
+}
+
+fn print_json(analysis: &CircuitAnalysis) -> Result<()> {
+    let json = serde_json::to_string_pretty(analysis)
+        .context("Failed to serialize analysis")?;
+    crate::output::write_line(&json);
+    Ok(())
+}
+
+fn print_border(border: &str) {
+    if !crate::table::plain() {
+        println!("{}", border);
+    }
+}
+
+fn print_boxed(table: &Table) {
+    if crate::table::plain() {
+        crate::output::write_line(&table.to_tsv());
+    } else {
+        println!("│ {}│", table.to_string().replace("\n", "\n│ "));
+    }
+}
+
+fn print_table(table: &Table) {
+    if crate::table::plain() {
+        crate::output::write_line(&table.to_tsv());
+    } else {
+        println!("{}", table);
+    }
+}
+
+fn format_signed_number(num: i64) -> colored::ColoredString {
+    if num < 0 {
+        format!("-{}", num.abs()).red().bold()
+    } else if num > 0 {
+        format!("+{}", num).green().bold()
+    } else {
+        "0".normal()
+    }
+}
+
+fn print_banner() {
+    println!("{}", 
+r"
+  ███╗   ██╗ ██████╗ ██╗██████╗     ██████╗ ██████╗  ██████╗ ███████╗██╗██╗     ███████╗██████╗ 
+  ████╗  ██║██╔═══██╗██║██╔══██╗    ██╔══██╗██╔══██╗██╔═══██╗██╔════╝██║██║     ██╔════╝██╔══██╗
+  ██╔██╗ ██║██║   ██║██║██████╔╝    ██████╔╝██████╔╝██║   ██║█████╗  ██║██║     █████╗  ██████╔╝
+  ██║╚██╗██║██║   ██║██║██╔══██╗    ██╔═══╝ ██╔══██╗██║   ██║██╔══╝  ██║██║     ██╔══╝  ██╔══██╗
+  ██║ ╚████║╚██████╔╝██║██║  ██║    ██║     ██║  ██║╚██████╔╝██║     ██║███████╗███████╗██║  ██║
+  ╚═╝  ╚═══╝ ╚═════╝ ╚═╝╚═╝  ╚═╝    ╚═╝     ╚═╝  ╚═╝ ╚═════╝ ╚═╝     ╚═╝╚══════╝╚══════╝╚═╝  ╚═╝
+"
+.bright_cyan().bold());
+    println!("{}", "  Circuit analysis tool - experimental demo version".bright_cyan().italic());
+    println!("  {}", "────────────────────────────────────────────────────────────────────────────────".bright_cyan());
+}
+
+fn print_help() {
+    println!("\n{} Noir Circuit Analysis Guide - Experimental Demo", "[HELP]".on_cyan().black().bold());
+    
+    println!("\n{} Creating Test Circuits:", "[CREATE]".on_green().black().bold());
+    println!("  1. Write a simple Noir program");
+    println!("  2. Compile with 'nargo compile'");
+    println!("  3. Analyze the generated ACIR file with this tool");
+    
+    println!("\n{} Examples:", "[USAGE]".on_green().black().bold());
+    println!("  {}  ./np.sh analyze target/main.json", "Analyze:".bright_white().bold());
+    println!("  {}   noir-circuit-profiler analyze-src path/to/project", "Source:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler init path/to/project --workflow", "Init:".bright_white().bold());
+    println!("  {}  ./np.sh compare circuit1.json circuit2.json", "Compare:".bright_white().bold());
+    println!("  {}    noir-circuit-profiler compare old.json new.json --format json-diff", "JSON diff:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler compare old.json new.json --noise-floor 0.5%", "Noise:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler report diff old.json new.json", "Reports:".bright_white().bold());
+    println!("  {}         noir-circuit-profiler compare-dirs main/target feature/target", "Dirs:".bright_white().bold());
+    println!("  {}         noir-circuit-profiler compare a.json b.json c.json --weights constraints=0.6,proving_time=0.3,memory=0.1", "Rank:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler compare my_project --recompile --flags-a \"--force-brillig\" --flags-b \"\"", "Flags:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler pipeline rollup.yaml", "Pipeline:".bright_white().bold());
+    println!("  {}       noir-circuit-profiler daemon & noir-circuit-profiler analyze circuit.json --use-daemon", "Daemon:".bright_white().bold());
+    println!("  {}      cargo install noir-circuit-profiler --features server,nargo", "Install:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler analyze circuit.json --instance c7i.8xlarge", "Cloud:".bright_white().bold());
+    println!("  {}          noir-circuit-profiler analyze circuit.json --gpu h100", "GPU:".bright_white().bold());
+    println!("  {}       noir-circuit-profiler analyze circuit.json --joules-per-constraint 0.005", "Energy:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler analyze circuit.json --setup", "Setup:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler analyze circuit.json --backends ultra-honk,ultra-plonk,groth16", "Backends:".bright_white().bold());
+    println!("  {}    noir-circuit-profiler reconcile target/main.json gates.json", "Reconcile:".bright_white().bold());
+    println!("  {}          noir-circuit-profiler analyze circuit.json --evm --max-gas 3000000", "EVM:".bright_white().bold());
+    println!("  {}       noir-circuit-profiler batch circuits_dir --shared", "Shared:".bright_white().bold());
+    println!("  {}     ./np.sh stats circuits_dir > research_data.csv", "Research:".bright_white().bold());
+    println!("  {}       noir-circuit-profiler --record-usage analyze circuit.json", "Record:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler usage --since 2026-07-01", "Usage:".bright_white().bold());
+    println!("  {}      noir-circuit-profiler summary --days 1", "Summary:".bright_white().bold());
+    println!("  {}     ./np.sh analyze circuit.json --format json > analysis.json", "Export:".bright_white().bold());
+    println!("  {}        ./np.sh analyze circuit.json --focus keccak256", "Focus:".bright_white().bold());
+    println!("  {}  noir-circuit-profiler analyze circuit.json --sensitivity", "Sensitivity:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler analyze contract.json --entry transfer", "Entry:".bright_white().bold());
+    println!("  {}       noir-circuit-profiler --timings analyze circuit.json --skip-pass attribution,suggestions", "Passes:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler --quick analyze circuit.json", "Quick:".bright_white().bold());
+    println!("  {}         noir-circuit-profiler hook install --hook pre-push", "Hook:".bright_white().bold());
+    println!("  {}         noir-circuit-profiler analyze circuit.json --emit text --emit json=report.json --emit markdown=summary.md", "Emit:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler --plain analyze circuit.json --format problems", "Problems:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler annotate target/main.json --src . --html heatmap.html", "Annotate:".bright_white().bold());
+    println!("  {}       noir-circuit-profiler analyze circuit.json --select .constraints,.black_box_functions", "Select:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler analyze circuit.json --template report.md.j2 --out report.md", "Template:".bright_white().bold());
+    println!("  {}     ./np.sh calibrate example_circuits", "Calibrate:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler calibrate example_circuits --interactive", "Review:".bright_white().bold());
+    println!("  {}        noir-circuit-profiler audit circuit.json --out report.html", "Audit:".bright_white().bold());
+    println!("  {}      noir-circuit-profiler cost-db migrate", "Cost DB:".bright_white().bold());
+    println!("  {}     noir-circuit-profiler cost-db rollback --to 20240101-120000-000", "Rollback:".bright_white().bold());
+    println!("  {}         noir-circuit-profiler cost-db set sha256 38799 --confidence 0.95", "Edit:".bright_white().bold());
+    println!("  {}         noir-circuit-profiler cost keccak256 --size 200", "Cost:".bright_white().bold());
+    println!("  {}          noir-circuit-profiler ops", "Ops:".bright_white().bold());
+}
+
+fn print_comparison(file1: &Path, file2: &Path, options: &AnalysisOptions, noise: NoiseFloor) -> Result<()> {
+    let local_file1 = resolve_artifact(file1)?;
+    let local_file2 = resolve_artifact(file2)?;
+    
+    let (analysis1, analysis2) = compare_circuits_with_options(&local_file1, &local_file2, options)
+        .context("Failed to compare circuits")?;
+    
+    println!("\n{} {}", "[COMPARE]".on_blue().white().bold(), t("compare.title"));
+    
+    print_analysis_diff(&analysis1, &analysis2, file1, file2, noise);
+    Ok(())
+}
+
+fn print_json_diff(file1: &Path, file2: &Path, options: &AnalysisOptions, noise: NoiseFloor) -> Result<()> {
+    let local_file1 = resolve_artifact(file1)?;
+    let local_file2 = resolve_artifact(file2)?;
+    
+    let (analysis1, analysis2) = compare_circuits_with_options(&local_file1, &local_file2, options)
+        .context("Failed to compare circuits")?;
+    
+    let delta = analysis_delta(&file1.display().to_string(), &analysis1, &file2.display().to_string(), &analysis2, noise);
+    crate::output::write_line(&serde_json::to_string_pretty(&delta).context("Failed to serialize diff")?);
+    Ok(())
+}
+
+fn error_kind(error: &anyhow::Error) -> &'static str {
+    for cause in error.chain() {
+        if cause.is::<Skipped>() {
+            return "limit";
+        }
+        if cause.is::<serde_json::Error>() {
+            return "parse";
+        }
+        if cause.is::<std::io::Error>() {
+            return "io";
+        }
+    }
+    
+    "analysis"
+}
+
+fn json_errors<T>(result: Result<T>, path: &Path, format: &str) -> Result<T> {
+    match result {
+        Err(error) if format == "json" => Err(report_json_error(error, path)),
+        result => result,
+    }
+}
+
+fn report_json_error(error: anyhow::Error, path: &Path) -> anyhow::Error {
+    let report = serde_json::json!({
+        "error": {
+            "kind": error_kind(&error),
+            "path": path.display().to_string(),
+            "detail": format!("{:#}", error),
+        }
+    });
+    crate::output::write_line(&report.to_string());
+    
+    AlreadyReported.into()
+}
+
+fn print_focus_report(report: &FocusReport, file: &Path) {
+    println!("\n{} Focus: {} in {}", "[FOCUS]".on_blue().white().bold(), 
+        report.operation.yellow().bold(), file.display().to_string().cyan().underline());
+    
+    let per_call = report.sites.iter().map(|site| site.cost).sum::<usize>() / report.sites.len().max(1);
+    let invocations: usize = report.sites.iter().map(|site| site.invocations).sum();
+    
+    println!("  Call sites:        {}", report.sites.len().to_string().cyan());
+    println!("  Invocations:       {}", invocations.to_string().cyan());
+    println!("  Avg cost per call: {}", per_call.to_string().yellow());
+    println!("  Total cost:        {} of {} constraints ({})", 
+        report.total_cost.to_string().yellow().bold(), report.circuit_constraints, format!("{:.1}%", report.share).red().bold());
+    
+    println!("\n{} Call Sites:", "[SITES]".on_cyan().black().bold());
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}  {:>}  {:<}");
+    table.add_row(Row::new()
+        .with_cell("Function".bright_white().bold())
+        .with_cell("Opcode #".bright_white().bold())
+        .with_cell("Inputs".bright_white().bold())
+        .with_cell("Outputs".bright_white().bold())
+        .with_cell("Cost".bright_white().bold())
+        .with_cell("Calls".bright_white().bold())
+        .with_cell("Location".bright_white().bold()));
+    
+    for site in &report.sites {
+        table.add_row(Row::new()
+            .with_cell(site.function.cyan())
+            .with_cell(site.index)
+            .with_cell(site.inputs)
+            .with_cell(site.outputs)
+            .with_cell(site.cost.to_string().yellow())
+            .with_cell(site.invocations)
+            .with_cell(site.location.as_deref().unwrap_or("-")));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    print_suggestions(&report.suggestions);
+}
+
+fn print_suggestions(suggestions: &[Substitution]) {
+    if suggestions.is_empty() {
+        return;
+    }
+    
+    println!("\n{} Substitutions:", "[SUGGEST]".on_green().black().bold());
+    for suggestion in suggestions {
+        match (&suggestion.alternative, suggestion.alternative_cost) {
+            (Some(alternative), Some(cost)) => 
+                println!("  → {} ({} constraints in cost model): {}", alternative.green().bold(), cost, suggestion.advice),
+            (Some(alternative), None) => 
+                println!("  → {}: {}", alternative.green().bold(), suggestion.advice),
+            _ => println!("  → {}", suggestion.advice),
+        }
+    }
+}
+
+fn print_cloud_cost(analysis: &CircuitAnalysis, pricing: &CloudPricing) {
+    let Some(cost) = estimate_cloud_cost(analysis.estimated_proving_time, analysis.aggregate_constraints, pricing) else {
+        return;
+    };
+    
+    println!("\n{} {}", "[CLOUD]".on_green().black().bold(), t("cloud.title"));
+    
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("metrics.metric").bright_white().bold())
+        .with_cell(t("metrics.value").bright_white().bold()));
+    
+    if let Some(instance) = cost.instance {
+        table.add_row(Row::new()
+            .with_cell(t("cloud.instance"))
+            .with_cell(format!("{} ({} vCPU, {} GB)", instance.name, instance.vcpus, instance.memory_gb).cyan()));
+    }
+    
+    table.add_row(Row::new()
+        .with_cell(t("cloud.hourly_rate"))
+        .with_cell(format!("${:.3}/h", cost.cost_per_hour)));
+    
+    let memory = format!("{:.2} GB", cost.memory_gb);
+    table.add_row(Row::new()
+        .with_cell(t("cloud.peak_memory"))
+        .with_cell(match cost.fits_in_memory {
+            Some(false) => tf("cloud.exceeds_memory", &[&memory]).red().bold(),
+            _ => memory.normal(),
+        }));
+    
+    table.add_row(Row::new()
+        .with_cell(t("cloud.per_proof"))
+        .with_cell(format!("${:.6}", cost.per_proof).yellow().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell(t("cloud.per_million"))
+        .with_cell(format!("${:.2}", cost.per_million).yellow().bold()));
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+}
+
+fn print_gpu_estimate(analysis: &CircuitAnalysis, profile: Option<GpuProfile>) {
+    let Some(profile) = profile else {
+        return;
+    };
+    
+    let estimate = estimate_gpu(analysis.estimated_proving_time, analysis.aggregate_constraints, profile);
+    
+    println!("\n{} {} {}", "[GPU]".on_green().black().bold(), t("gpu.title"), profile.name.cyan().underline());
+    
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell(t("gpu.phase").bright_white().bold())
+        .with_cell("CPU (ms)".bright_white().bold())
+        .with_cell("GPU (ms)".bright_white().bold())
+        .with_cell(t("gpu.speedup").bright_white().bold()));
+    
+    for phase in &estimate.phases {
+        table.add_row(Row::new()
+            .with_cell(t(phase.phase))
+            .with_cell(format!("{:.3}", phase.cpu_ms))
+            .with_cell(format!("{:.3}", phase.gpu_ms))
+            .with_cell(format!("{:.1}x", phase.speedup()).cyan()));
+    }
+    
+    table.add_row(Row::new()
+        .with_cell(t("common.total").bright_white().bold())
+        .with_cell(format!("{:.3}", estimate.cpu_ms()))
+        .with_cell(format!("{:.3}", estimate.gpu_ms()))
+        .with_cell(format!("{:.1}x", estimate.speedup()).yellow().bold()));
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+    
+    println!("{} {}", "[NOTE]".on_cyan().black(), t("gpu.note"));
+    
+    let memory_gb = estimate_memory_gb(analysis.aggregate_constraints);
+    if memory_gb > profile.memory_gb {
+        println!("{} {}", "[WARNING]".on_red().white().bold(), tf("gpu.memory_warning", &[&format!("{:.1}", memory_gb), &profile.memory_gb]));
+    }
+}
+
+fn print_energy_estimate(analysis: &CircuitAnalysis, model: Option<&EnergyModel>) {
+    let Some(model) = model else {
+        return;
+    };
+    
+    let estimate = model.estimate(analysis.aggregate_constraints);
+    
+    println!("\n{} {}", "[ENERGY]".on_green().black().bold(), 
+        tf("energy.title", &[&estimate.source.cyan(), &estimate.joules_per_constraint]));
+    println!("  {:<19} {}", t("energy.per_proof"), format!("{:.2} J ({:.4} Wh)", estimate.joules_per_proof, estimate.joules_per_proof / 3600.0).yellow().bold());
+    println!("  {:<19} {}", t("energy.per_million"), format!("{:.2} kWh", estimate.kwh_per_million).yellow().bold());
+}
+
+fn print_setup_estimate(analysis: &CircuitAnalysis, backend: Option<BackendProfile>) {
+    let profiles = match backend {
+        Some(profile) => vec![profile],
+        None => BACKEND_PROFILES.to_vec(),
+    };
+    
+    println!("\n{} {}", "[SETUP]".on_green().black().bold(), t("setup.title"));
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell(t("setup.backend").bright_white().bold())
+        .with_cell(t("setup.circuit_size").bright_white().bold())
+        .with_cell(t("setup.proving_key").bright_white().bold())
+        .with_cell(t("setup.keygen").bright_white().bold())
+        .with_cell(t("setup.load").bright_white().bold())
+        .with_cell(t("setup.peak_memory").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────")
+        .with_cell("────────────")
+        .with_cell("───────────")
+        .with_cell("───────────")
+        .with_cell("─────────")
+        .with_cell("───────────"));
+    
+    for profile in profiles {
+        let estimate = estimate_setup(analysis.aggregate_constraints, profile);
+        
+        table.add_row(Row::new()
+            .with_cell(estimate.profile.name.cyan())
+            .with_cell(format!("2^{}", estimate.circuit_size.trailing_zeros()))
+            .with_cell(format_bytes(estimate.proving_key_bytes).yellow().bold())
+            .with_cell(format!("{:.2}", estimate.keygen_ms))
+            .with_cell(format!("{:.2}", estimate.load_ms))
+            .with_cell(format!("{:.2} GB", estimate.memory_gb)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    println!("{} {}", "[NOTE]".on_cyan().black(), t("setup.note"));
+}
+
+fn print_backend_comparison(analysis: &CircuitAnalysis, backends: &[BackendProfile]) {
+    println!("\n{} Backend Comparison:", "[BACKENDS]".on_blue().white().bold());
+    print_border("╭───────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}  {:<}");
+    table.add_row(Row::new()
+        .with_cell("Backend".bright_white().bold())
+        .with_cell("Gates".bright_white().bold())
+        .with_cell("Circuit Size".bright_white().bold())
+        .with_cell("Proving Time".bright_white().bold())
+        .with_cell("Memory".bright_white().bold())
+        .with_cell("Notes".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────")
+        .with_cell("──────────")
+        .with_cell("────────────")
+        .with_cell("────────────")
+        .with_cell("──────────")
+        .with_cell("────────────────────"));
+    
+    let estimates: Vec<_> = backends.iter().map(|profile| estimate_backend(analysis, *profile)).collect();
+    let fastest = estimates.iter()
+        .filter(|estimate| estimate.unsupported.is_empty())
+        .map(|estimate| estimate.proving_time_ms)
+        .fold(f64::INFINITY, f64::min);
+    
+    for estimate in &estimates {
+        let time = format!("{:.2}ms", estimate.proving_time_ms);
+        let notes = if !estimate.unsupported.is_empty() {
+            format!("unsupported: {}", estimate.unsupported.join(", ")).red()
+        } else if estimate.emulated > 0 {
+            format!("{} emulated operation(s)", estimate.emulated).yellow()
+        } else {
+            "all native".green()
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(estimate.profile.name.cyan())
+            .with_cell(estimate.gates)
+            .with_cell(format!("2^{}", estimate.circuit_size.trailing_zeros()))
+            .with_cell(if estimate.unsupported.is_empty() && estimate.proving_time_ms == fastest { time.green().bold() } else { time.normal() })
+            .with_cell(format!("{:.2} GB", estimate.memory_gb))
+            .with_cell(notes));
+    }
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────────────────╯");
+    println!("Gates include the expected emulation overhead; run with `--backend <name>` for per-operation warnings");
+}
+
+fn print_verifier_estimate(analysis: &CircuitAnalysis, backend: BackendProfile, thresholds: &EvmThresholds) {
+    let public_values = if analysis.public_values.is_empty() {
+        analysis.public_inputs + analysis.return_values
+    } else {
+        public_field_count(&analysis.public_values)
+    };
+    let estimate = estimate_verifier(analysis.aggregate_constraints, public_values, verifier_profile(&backend));
+    
+    println!("\n{} {} {}", "[EVM]".on_blue().white().bold(), t("evm.title"), estimate.profile.backend.cyan().underline());
+    
+    print_border("╭───────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell(t("metrics.metric").bright_white().bold())
+        .with_cell(t("metrics.value").bright_white().bold()));
+    
+    let bytecode = tf("evm.bytecode_value", &[&estimate.bytecode_bytes, &CONTRACT_SIZE_LIMIT]);
+    table.add_row(Row::new()
+        .with_cell(t("evm.bytecode"))
+        .with_cell(if estimate.fits_contract_limit() { bytecode.green() } else { bytecode.red().bold() }));
+    
+    table.add_row(Row::new()
+        .with_cell(t("evm.public_values"))
+        .with_cell(public_values));
+    
+    table.add_row(Row::new()
+        .with_cell(t("evm.proof_size"))
+        .with_cell(tf("evm.bytes", &[&estimate.proof_bytes])));
+    
+    table.add_row(Row::new()
+        .with_cell(t("evm.calldata"))
+        .with_cell(tf("evm.calldata_value", &[&estimate.calldata_bytes, &estimate.calldata_gas])));
+    
+    table.add_row(Row::new()
+        .with_cell(t("evm.verification_gas"))
+        .with_cell(estimate.verification_gas.to_string().yellow()));
+    
+    table.add_row(Row::new()
+        .with_cell(t("evm.total_gas"))
+        .with_cell(estimate.total_gas().to_string().yellow().bold()));
+    
+    print_boxed(&table);
+    print_border("╰───────────────────────────────────────────────────╯");
+    
+    for warning in estimate.warnings(thresholds) {
+        println!("{} {}", "[WARNING]".on_red().white().bold(), warning);
+    }
+    
+    print_public_value_costs(analysis, estimate.profile);
+}
+
+fn print_public_value_costs(analysis: &CircuitAnalysis, profile: VerifierProfile) {
+    if analysis.public_values.is_empty() {
+        return;
+    }
+    
+    let costs = public_value_costs(&analysis.public_values, profile);
+    
+    println!("\n{} {}", "[CALLDATA]".on_blue().white().bold(), t("evm.costs_title"));
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:>}  {:>}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell(t("metrics.value").bright_white().bold())
+        .with_cell(t("folding.kind").bright_white().bold())
+        .with_cell(t("evm.fields").bright_white().bold())
+        .with_cell(t("evm.calldata").bright_white().bold())
+        .with_cell(t("evm.calldata_gas").bright_white().bold())
+        .with_cell(t("evm.hashing_gas").bright_white().bold())
+        .with_cell(t("evm.verifier_gas").bright_white().bold())
+        .with_cell(t("evm.total_gas").bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────")
+        .with_cell("──────")
+        .with_cell("────────")
+        .with_cell("────────────")
+        .with_cell("───────────")
+        .with_cell("────────────")
+        .with_cell("─────────"));
+    
+    for cost in &costs {
+        table.add_row(Row::new()
+            .with_cell(cost.name.cyan())
+            .with_cell(&cost.kind)
+            .with_cell(cost.fields)
+            .with_cell(format!("{} B", cost.calldata_bytes))
+            .with_cell(cost.calldata_gas)
+            .with_cell(cost.hashing_gas)
+            .with_cell(cost.verifier_gas)
+            .with_cell(cost.total_gas().to_string().yellow()));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    let Some(candidate) = costs.iter().filter(|cost| cost.fields > 1).max_by_key(|cost| cost.commitment_savings()) else {
+        return;
+    };
+    
+    println!("\n{}: {}",
+             "[INSIGHT]".on_yellow().black().bold(),
+             tf("evm.commitment", &[
+                 &candidate.name,
+                 &candidate.fields,
+                 &candidate.total_gas(),
+                 &candidate.commitment_savings().to_string().green().bold()]));
+    
+    match get_operation_cost("poseidon2") {
+        Some(hash_cost) => println!("  {}", tf("evm.commitment_constraints", &[&commitment_constraints(candidate.fields, hash_cost)])),
+        None => println!("  {}", t("evm.commitment_uncalibrated")),
+    }
+}
+
+fn print_module_budgets(usage: &[ModuleUsage], path: &Path) {
+    if usage.is_empty() {
+        println!("\n{} No `modules` budgets in {}", "[WARNING]".on_red().white().bold(), path.display());
+        return;
+    }
+    
+    println!("\n{} Module Budgets:", "[BUDGET]".on_yellow().black().bold());
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:<}");
+    table.add_row(Row::new()
+        .with_cell("Module".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold())
+        .with_cell("Budget".bright_white().bold())
+        .with_cell("Circuits".bright_white().bold())
+        .with_cell("Status".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("───────────")
+        .with_cell("──────────")
+        .with_cell("────────")
+        .with_cell("──────"));
+    
+    for module in usage {
+        let status = match module.limit {
+            Some(limit) if module.constraints > limit => "OVER".red().bold(),
+            Some(_) => "OK".green().bold(),
+            None => "-".normal(),
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(module.module.cyan())
+            .with_cell(module.constraints.to_string().yellow())
+            .with_cell(module.limit.map_or_else(|| "-".to_string(), |limit| limit.to_string()))
+            .with_cell(module.circuits)
+            .with_cell(status));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    if usage.iter().all(|module| module.circuits == 0) {
+        println!("{} No constraints were attributed to any module; compile with debug info so opcodes carry `location`", 
+                 "[NOTE]".on_cyan().black());
+    }
+}
+
+fn print_shared_components(components: &[SharedComponent], skipped: &[String]) {
+    println!("\n{} Shared Components Across Circuits:", "[SHARED]".on_blue().white().bold());
+    
+    for skipped in skipped {
+        println!("{} Skipped {}", "[WARNING]".on_red().white().bold(), skipped);
+    }
+    
+    if components.is_empty() {
+        println!("No function bodies or loop patterns appear in more than one circuit");
+        return;
+    }
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:>}  {:<}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Component".bright_white().bold())
+        .with_cell("Kind".bright_white().bold())
+        .with_cell("Opcodes".bright_white().bold())
+        .with_cell("Circuit".bright_white().bold())
+        .with_cell("Uses".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("────────")
+        .with_cell("───────")
+        .with_cell("────────────────────")
+        .with_cell("────")
+        .with_cell("───────────"));
+    
+    for component in components {
+        for (idx, usage) in component.uses.iter().enumerate() {
+            let (name, kind, opcodes) = if idx == 0 {
+                (component.name.cyan(), component.kind.normal(), component.opcodes.to_string())
+            } else {
+                ("".normal(), "".normal(), String::new())
+            };
+            
+            table.add_row(Row::new()
+                .with_cell(name)
+                .with_cell(kind)
+                .with_cell(opcodes)
+                .with_cell(&usage.circuit)
+                .with_cell(usage.occurrences)
+                .with_cell(usage.constraints.to_string().yellow()));
+        }
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    if let Some(top) = components.first() {
+        println!("\n{}: `{}` ({} constraints each) appears in {} circuits for {} constraints in total",
+                 "[INSIGHT]".on_yellow().black().bold(),
+                 top.name,
+                 top.constraints_each,
+                 top.uses.len(),
+                 top.total_constraints().to_string().yellow().bold());
+        println!("  Extract shared components into a library crate, or prove them once as a separate circuit with a shared proving key");
+    }
+}
+
+fn print_pipeline_estimate(estimate: &PipelineEstimate, pricing: &CloudPricing) {
+    let title = estimate.name.as_deref().unwrap_or("pipeline");
+    println!("\n{} Proving Pipeline: {}", "[PIPELINE]".on_blue().white().bold(), title.cyan().underline());
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Stage".bright_white().bold())
+        .with_cell("Mode".bright_white().bold())
+        .with_cell("Proofs".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold())
+        .with_cell("Latency (ms)".bright_white().bold())
+        .with_cell("Compute (ms)".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────")
+        .with_cell("───────────")
+        .with_cell("────────────")
+        .with_cell("────────────"));
+    
+    for stage in &estimate.stages {
+        table.add_row(Row::new()
+            .with_cell(stage.name.cyan())
+            .with_cell(format!("{:?}", stage.mode).to_lowercase())
+            .with_cell(stage.proofs)
+            .with_cell(stage.constraints.to_string().yellow())
+            .with_cell(format!("{:.2}", stage.latency_ms))
+            .with_cell(format!("{:.2}", stage.compute_ms)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    println!("\n{} End-to-end latency: {} ms", "[LATENCY]".on_magenta().white().bold(), format!("{:.2}", estimate.latency_ms).yellow().bold());
+    println!("{} Total compute: {} ms across {} constraints", "[COMPUTE]".on_cyan().black().bold(), format!("{:.2}", estimate.compute_ms).yellow().bold(), estimate.constraints);
+    println!("Final proofs: {}", estimate.final_proofs);
+    
+    if let Some(rate) = pricing.hourly_rate() {
+        let run_cost = estimate.compute_ms / 3_600_000.0 * rate;
+        println!("{} Cost per pipeline run: ${:.6} (${:.2} per million runs)", 
+            "[CLOUD]".on_green().black().bold(), run_cost, run_cost * 1_000_000.0);
+    }
+}
+
+fn print_recompile_comparison(project: &Path, flags_a: &str, flags_b: &str, options: &AnalysisOptions, noise: NoiseFloor) -> Result<()> {
+    println!("\n{} Recompiling {} with two flag sets", "[RECOMPILE]".on_magenta().white().bold(), project.display().to_string().cyan());
+    println!("  A: nargo compile {}", flags_a.yellow());
+    println!("  B: nargo compile {}", flags_b.yellow());
+    
+    let (artifact_a, artifact_b) = recompile_pair(project, flags_a, flags_b)
+        .context("Failed to recompile project")?;
+    
+    let (analysis1, analysis2) = compare_circuits_with_options(&artifact_a, &artifact_b, options)
+        .context("Failed to compare circuits")?;
+    
+    println!("\n{} Comparison Results:", "[COMPARE]".on_blue().white().bold());
+    
+    let label = |flags: &str| PathBuf::from(format!("{} [{}]", project.display(), if flags.is_empty() { "default flags" } else { flags }));
+    print_analysis_diff(&analysis1, &analysis2, &label(flags_a), &label(flags_b), noise);
+    Ok(())
+}
+
+fn open_bundle(path: &Path, max_file_size: Option<u64>) -> Result<Option<Bundle>> {
+    if bundle_format(path).is_none() {
+        return Ok(None);
+    }
+    
+    let bundle = extract_bundle(path, max_file_size)?;
+    println!("{} Extracted {}", "[BUNDLE]".on_magenta().white().bold(), path.display());
+    Ok(Some(bundle))
+}
+
+fn print_dir_comparison(dir_a: &Path, dir_b: &Path, options: &AnalysisOptions) -> Result<()> {
+    let results_a = batch_analyze_with_options(dir_a, options)
+        .with_context(|| format!("Failed to analyze {}", dir_a.display()))?;
+    let results_b = batch_analyze_with_options(dir_b, options)
+        .with_context(|| format!("Failed to analyze {}", dir_b.display()))?;
+    
+    let rows = compare_results(&results_a, &results_b);
+    
+    println!("\n{} {} → {}:", "[COMPARE]".on_blue().white().bold(), dir_a.display().to_string().cyan(), dir_b.display().to_string().cyan());
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:>}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Circuit".bright_white().bold())
+        .with_cell("Before".bright_white().bold())
+        .with_cell("After".bright_white().bold())
+        .with_cell("Diff".bright_white().bold())
+        .with_cell("Change".bright_white().bold())
+        .with_cell("Proving Δ (ms)".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("──────────────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("────────")
+        .with_cell("──────────────"));
+    
+    let (mut regressed, mut improved) = (0, 0);
+    for row in &rows {
+        let count = |value: Option<usize>| value.map_or("-".normal(), |value| value.to_string().normal());
+        
+        let (diff, change, time) = match (row.diff(), row.time_before.zip(row.time_after)) {
+            (Some(diff), Some((before, after))) => {
+                if diff > 0 { regressed += 1 } else if diff < 0 { improved += 1 }
+                
+                let percent = row.percent().map_or("-".to_string(), |percent| format!("{:+.1}%", percent));
+                let change = if diff > 0 { percent.red().bold() } else if diff < 0 { percent.green() } else { percent.normal() };
+                (format_signed_number(diff), change, format_signed_float(after - before))
+            },
+            _ if row.before.is_none() => ("added".cyan(), "-".normal(), "-".normal()),
+            _ => ("removed".yellow(), "-".normal(), "-".normal()),
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(row.circuit.cyan())
+            .with_cell(count(row.before))
+            .with_cell(count(row.after))
+            .with_cell(diff)
+            .with_cell(change)
+            .with_cell(time));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    for row in rows.iter().filter(|row| row.diff().is_some_and(|diff| diff != 0) && !row.blame.is_empty()) {
+        let culprits: Vec<String> = row.blame.iter()
+            .take(5)
+            .map(|(function, delta)| format!("{} {}", function, format_signed_number(*delta)))
+            .collect();
+        
+        println!("{} {}: {}", "[BLAME]".on_red().white().bold(), row.circuit.cyan(), culprits.join(", "));
+    }
+    
+    let failed = results_a.iter().chain(&results_b).filter(|(_, result)| result.is_err()).count();
+    println!("{} {} regressed, {} improved, {} unchanged{}",
+             "[SUMMARY]".on_magenta().white().bold(), regressed, improved,
+             rows.iter().filter(|row| row.diff() == Some(0)).count(),
+             if failed > 0 { format!(", {} artifact(s) failed to analyze", failed) } else { String::new() });
+    
+    Ok(())
+}
+
+fn print_ranking(files: &[PathBuf], weights: &Weights, options: &AnalysisOptions) -> Result<()> {
+    let mut candidates = Vec::new();
+    for file in files {
+        let local_file = resolve_artifact(file)?;
+        let analysis = analyze_circuit_with_options(&local_file, options)
+            .with_context(|| format!("Failed to analyze {}", file.display()))?;
+        candidates.push((file.display().to_string(), analysis));
+    }
+    
+    let ranked = rank_candidates(&candidates, weights);
+    
+    println!("\n{} Ranking {} candidates ({}):", "[RANK]".on_blue().white().bold(), ranked.len(), weights.to_string().cyan());
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:>}  {:<}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("#".bright_white().bold())
+        .with_cell("Circuit".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold())
+        .with_cell("Proving Time".bright_white().bold())
+        .with_cell("Memory".bright_white().bold())
+        .with_cell("Score".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("──")
+        .with_cell("──────────────────────────────")
+        .with_cell("───────────")
+        .with_cell("────────────")
+        .with_cell("────────")
+        .with_cell("─────"));
+    
+    for (idx, candidate) in ranked.iter().enumerate() {
+        let score = format!("{:.3}", candidate.score);
+        table.add_row(Row::new()
+            .with_cell(idx + 1)
+            .with_cell(candidate.name.cyan())
+            .with_cell(candidate.constraints.to_string().yellow())
+            .with_cell(format!("{:.2}ms", candidate.proving_time))
+            .with_cell(format!("{:.2} GB", candidate.memory_gb))
+            .with_cell(if idx == 0 { score.green().bold() } else { score.normal() }));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    println!("{} Score is the weighted ratio to the best value of each objective; 1.000 is best on every objective", "[NOTE]".on_cyan().black());
+    
+    if let [best, runner_up, ..] = ranked.as_slice() {
+        if runner_up.score > 0.0 {
+            println!("\n{} Use {}: its score is {:.1}% lower than {}",
+                     "[RECOMMEND]".on_green().black().bold(),
+                     best.name.green().bold(),
+                     (1.0 - best.score / runner_up.score) * 100.0,
+                     runner_up.name.cyan());
+        }
+    }
+    
+    Ok(())
+}
+
+fn print_report_diff(old: &Path, new: &Path, notify: Option<&Path>, allow: Option<&Path>) -> Result<()> {
+    let analysis1 = load_report(old)?;
+    let analysis2 = load_report(new)?;
+    
+    println!("\n{} Report Comparison:", "[COMPARE]".on_blue().white().bold());
+    
+    print_analysis_diff(&analysis1, &analysis2, old, new, NoiseFloor::default());
+    
+    if let Some(config) = notify {
+        notify_violations(&analysis1, &analysis2, new, config, allow)?;
+    }
+    Ok(())
+}
+
+fn print_report_trend(reports: &[PathBuf], min_confidence: f64) -> Result<()> {
+    let analyses = reports.iter()
+        .map(|path| load_report(path))
+        .collect::<Result<Vec<_>>>()?;
+    
+    println!("\n{} Trend over {} reports (oldest first):", "[TREND]".on_blue().white().bold(), analyses.len());
+    
+    let metrics: [(&str, MetricFn); 2] = [
+        ("Constraints", |analysis| analysis.aggregate_constraints as f64),
+        ("Proving Time (ms)", |analysis| analysis.estimated_proving_time),
+    ];
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:>}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Metric".bright_white().bold())
+        .with_cell("Change At".bright_white().bold())
+        .with_cell("Before".bright_white().bold())
+        .with_cell("After".bright_white().bold())
+        .with_cell("Shift".bright_white().bold())
+        .with_cell("Confidence".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("─────────────────")
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("────────")
+        .with_cell("──────────"));
+    
+    let mut verdicts = Vec::new();
+    for (metric, value) in metrics {
+        let values: Vec<f64> = analyses.iter().map(value).collect();
+        
+        match detect_change(&values) {
+            Some(change) => {
+                let confidence = format!("{:.1}%", change.confidence * 100.0);
+                table.add_row(Row::new()
+                    .with_cell(metric)
+                    .with_cell(reports[change.index].display().to_string().cyan())
+                    .with_cell(format!("{:.2}", change.before_mean))
+                    .with_cell(format!("{:.2}", change.after_mean))
+                    .with_cell(format_signed_float(change.shift_percent))
+                    .with_cell(if change.is_regression(min_confidence) { confidence.red().bold() } else { confidence.normal() }));
+                verdicts.push((metric, Some(change)));
+            },
+            None => {
+                table.add_row(Row::new()
+                    .with_cell(metric)
+                    .with_cell("-")
+                    .with_cell("-")
+                    .with_cell("-")
+                    .with_cell("-")
+                    .with_cell("-"));
+                verdicts.push((metric, None));
+            }
+        }
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    for (metric, change) in verdicts {
+        match change {
+            Some(change) if change.is_regression(min_confidence) => {
+                println!("{} {} rose {:.1}% from {}; {:.1}% confident this is a real regression",
+                         "[REGRESSION]".on_red().white().bold(), metric, change.shift_percent,
+                         reports[change.index].display(), change.confidence * 100.0);
+            },
+            Some(change) => {
+                println!("{} {}: shift of {:+.1}% is within noise ({:.1}% confidence, need {:.1}%)",
+                         "[STABLE]".on_green().black().bold(), metric, change.shift_percent,
+                         change.confidence * 100.0, min_confidence * 100.0);
+            },
+            None => {
+                println!("{} {}: no change point", "[STABLE]".on_green().black().bold(), metric);
+            }
+        }
+    }
+    
+    Ok(())
+}
+
+fn print_heatmap(sources: &[AnnotatedSource]) {
+    let hottest = hottest_line(sources);
+    let width = hottest.to_string().len().max(1);
+    
+    for source in sources {
+        println!("\n{} {} ({} constraints)", "[ANNOTATE]".on_blue().white().bold(), source.file.cyan(), source.constraints);
+        
+        if source.path.is_none() {
+            println!("{} Source not found under --src; pass the project directory", "[WARNING]".on_red().white().bold());
+            continue;
+        }
+        
+        for line in &source.lines {
+            let count = format!("{:>width$}", if line.constraints > 0 { line.constraints.to_string() } else { "-".to_string() }, width = width);
+            let count = match heat_level(line.constraints, hottest) {
+                0 => count.dimmed(),
+                1 => count.green(),
+                2 => count.yellow(),
+                3 => count.red(),
+                _ => count.red().bold(),
+            };
+            println!("{} │ {:>4} │ {}", count, line.number, line.text);
+        }
+    }
+}
+
+fn print_hook_outcome(outcome: &HookOutcome) -> Result<()> {
+    let HookOutcome::Checked { results, fail_on_violation } = outcome else {
+        println!("{} No staged circuit changes; skipping budget check", "[HOOK]".on_green().black().bold());
+        return Ok(());
+    };
+    
+    println!("\n{} Checking {} circuit(s) against {}", "[HOOK]".on_blue().white().bold(), results.len(), BUDGET_FILE);
+    
+    let mut violated = 0;
+    for result in results {
+        let change = match result.baseline {
+            Some(baseline) => format!(" ({} vs baseline)", format_signed_number(result.constraints as i64 - baseline as i64)),
+            None => " (no baseline)".dimmed().to_string(),
+        };
+        
+        if result.violations.is_empty() {
+            println!("  {} {}: {} constraints{}", "✓".green().bold(), result.circuit, result.constraints, change);
+            continue;
+        }
+        
+        violated += 1;
+        println!("  {} {}: {} constraints{}", "✗".red().bold(), result.circuit, result.constraints, change);
+        for violation in &result.violations {
+            println!("      {} {}", "•".red(), violation.message);
+        }
+    }
+    
+    if violated > 0 && *fail_on_violation {
+        bail!("{} circuit(s) over budget; fix them or update {} (skip once with --no-verify)", violated, BUDGET_FILE);
+    }
+    Ok(())
+}
+
+fn notify_violations(old: &CircuitAnalysis, new: &CircuitAnalysis, circuit: &Path, config: &Path, allow: Option<&Path>) -> Result<()> {
+    let config = load_notify_config(config)?;
+    let allowlist = allow.map(load_allowlist).transpose()?.unwrap_or_default();
+    
+    let circuits: Vec<String> = new.metadata.name.iter().cloned()
+        .chain(circuit.file_stem().map(|stem| stem.to_string_lossy().to_string()))
+        .collect();
+    let triage = triage(&allowlist, &circuits, find_violations(old, new, &config.notify), chrono::Local::now().date_naive());
+    
+    if !triage.allowed.is_empty() {
+        println!("\n{} {} accepted regression(s), not blocking:", "[ALLOWED]".on_yellow().black().bold(), triage.allowed.len());
+        for (violation, entry) in &triage.allowed {
+            println!("  {} {} ({})", "•".yellow(), violation.message, entry.describe().cyan());
+        }
+    }
+    
+    let violations = triage.blocking;
+    if violations.is_empty() {
+        println!("\n{} No thresholds violated", "[NOTIFY]".on_green().black().bold());
+        return Ok(());
+    }
+    
+    println!("\n{} {} threshold(s) violated:", "[NOTIFY]".on_red().white().bold(), violations.len());
+    for violation in &violations {
+        println!("  {} {}", "•".red(), violation.message);
+    }
+    
+    if let Some(webhook) = &config.notify.webhook {
+        let circuit = circuit.display().to_string();
+        send_notification(webhook, &build_payload(&circuit, &violations))?;
+        println!("{} Posted to {}", "✓".green().bold(), webhook.cyan());
+    }
+    
+    if config.notify.fail_on_violation {
+        bail!("{} budget(s) exceeded for {}", violations.len(), circuit.display());
+    }
+    Ok(())
+}
+
+fn print_analysis_diff(analysis1: &CircuitAnalysis, analysis2: &CircuitAnalysis, file1: &Path, file2: &Path, noise: NoiseFloor) {
+    print_core_metrics(analysis1, file1);
+    print_core_metrics(analysis2, file2);
+    
+    let diff = analysis2.constraints as i64 - analysis1.constraints as i64;
+    let size_noise = noise.within(analysis1.constraints as f64, analysis2.constraints as f64);
+    
+    println!("\n{} {}{}",
+        "[DIFF]".on_yellow().black().bold(),
+        tf("compare.size_diff", &[&format_signed_number(diff)]),
+        if size_noise { format!(" ({})", tf("compare.below_floor", &[&t("compare.within_noise").dimmed(), &noise])) } else { String::new() });
+    
+    let interval = delta_interval(
+        analysis1.estimated_proving_time, relative_spread(analysis1.confidence),
+        analysis2.estimated_proving_time, relative_spread(analysis2.confidence));
+    let verdict = if noise.within(analysis1.estimated_proving_time, analysis2.estimated_proving_time) {
+        t("compare.within_noise").dimmed()
+    } else if interval.low > 0.0 {
+        t("compare.regression").red().bold()
+    } else if interval.high < 0.0 {
+        t("compare.improvement").green().bold()
+    } else {
+        t("compare.indistinguishable").normal()
+    };
+    println!("{} {}", 
+        "[PERFORMANCE]".on_magenta().white().bold(),
+        tf("compare.proving_impact", &[&format_signed_float(interval.low), &format_signed_float(interval.high), &verdict]));
+    
+    let time_per_constraint1 = if analysis1.aggregate_constraints > 0 {
+        analysis1.estimated_proving_time / analysis1.aggregate_constraints as f64 * 1000.0
+    } else { 0.0 };
+    
+    let time_per_constraint2 = if analysis2.aggregate_constraints > 0 {
+        analysis2.estimated_proving_time / analysis2.aggregate_constraints as f64 * 1000.0
+    } else { 0.0 };
+    
+    println!("\n{} {}", "[EFFICIENCY]".on_cyan().black().bold(), t("compare.efficiency_title"));
+    println!("  {}", tf("compare.efficiency_row", &[&1, &format!("{:.3}", time_per_constraint1)]));
+    println!("  {}", tf("compare.efficiency_row", &[&2, &format!("{:.3}", time_per_constraint2)]));
+    
+    if diff.abs() > 100 && !size_noise {
+        use crate::core::find_operations_by_cost;
+        
+        let matching_ops = find_operations_by_cost(diff.unsigned_abs() as usize, 5.0);
+        
+        if !matching_ops.is_empty() {
+            println!("\n{} {}", "[ANALYSIS]".on_green().black().bold(), t("compare.operations_title"));
+            
+            for (op_name, cost, confidence) in matching_ops.iter().take(3) {
+                let diff_percent = (*cost as f64 - diff.unsigned_abs() as f64).abs() / *cost as f64 * 100.0;
+                let match_quality = if diff_percent < 1.0 {
+                    t("compare.match_strong").yellow()
+                } else if diff_percent < 3.0 {
+                    t("compare.match_possible").cyan()
+                } else {
+                    t("compare.match_resembles").normal()
+                };
+                
+                println!("  {}", tf("compare.match", &[
+                    &match_quality,
+                    &op_name.cyan().bold(), 
+                    &cost.to_string().yellow(), 
+                    &format!("{:.1}", confidence * 100.0)]));
+            }
+            
+            println!("  {}", t("compare.match_note"));
+        }
+    }
+    
+    if !analysis1.black_box_functions.is_empty() || !analysis2.black_box_functions.is_empty() {
+        print_function_comparison(analysis1, analysis2, noise);
+    }
+    
+    if !analysis1.annotations.is_empty() || !analysis2.annotations.is_empty() {
+        print_annotation_comparison(analysis1, analysis2, noise);
+    }
+}
+
+fn diff_cell(before: usize, after: usize, noise: NoiseFloor) -> colored::ColoredString {
+    if noise.within(before as f64, after as f64) {
+        t("compare.within_noise").dimmed()
+    } else {
+        format_signed_number(after as i64 - before as i64)
+    }
+}
+
+fn hex_key(key: &ed25519_dalek::VerifyingKey) -> String {
+    hex::encode(key.to_bytes())
+}
+
+fn format_signed_float(num: f64) -> colored::ColoredString {
+    if num < 0.0 {
+        format!("-{:.2}", num.abs()).red().bold()
+    } else if num > 0.0 {
+        format!("+{:.2}", num).green().bold()
+    } else {
+        "0.00".normal()
+    }
+}
+
+fn rejected_cell(rejected: usize) -> colored::ColoredString {
+    if rejected > 0 {
+        rejected.to_string().yellow()
+    } else {
+        "0".normal()
+    }
+}
+
+fn sparkline(values: &[usize]) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    
+    let (Some(min), Some(max)) = (values.iter().min(), values.iter().max()) else {
+        return "-".to_string();
+    };
+    
+    let range = (max - min).max(1) as f64;
+    values.iter()
+        .map(|value| BARS[(((value - min) as f64 / range) * (BARS.len() - 1) as f64).round() as usize])
+        .collect()
+}
+
+fn min_max(values: &[usize]) -> String {
+    match (values.iter().min(), values.iter().max()) {
+        (Some(min), Some(max)) => format!("{}-{}", min, max),
+        _ => "-".to_string(),
+    }
+}
+
+fn print_cost_database() {
+    print_cost_database_sorted("name");
+}
+
+fn print_cost_lookup(lookup: &CostLookup) {
+    println!("\n{} {}:", "[COST]".on_blue().white().bold(), display_name(&lookup.operation));
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell("Property".bright_white().bold())
+        .with_cell("Value".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────────────────────────"));
+    
+    table.add_row(Row::new()
+        .with_cell("Operation")
+        .with_cell(canonical_name(&lookup.operation).cyan()));
+    
+    if let Some(entry) = &lookup.entry {
+        table.add_row(Row::new()
+            .with_cell("Database Entry")
+            .with_cell(entry.cyan()));
+    }
+    
+    if let Some(size) = lookup.size {
+        table.add_row(Row::new()
+            .with_cell("Input Size")
+            .with_cell(format!("{} → {} {}{}", size, lookup.units, lookup.unit, if lookup.units == 1 { "" } else { "s" })));
+    }
+    
+    table.add_row(Row::new()
+        .with_cell(format!("Cost per {}", lookup.unit))
+        .with_cell(lookup.unit_cost));
+    
+    table.add_row(Row::new()
+        .with_cell("Modeled Cost")
+        .with_cell(format!("{} constraints", lookup.cost).yellow().bold()));
+    
+    let confidence = format!("{:.1}%", lookup.confidence * 100.0);
+    table.add_row(Row::new()
+        .with_cell("Confidence")
+        .with_cell(if lookup.confidence > 0.9 { confidence.green().bold() } else if lookup.confidence > 0.85 { confidence.yellow() } else { confidence.red() }));
+    
+    table.add_row(Row::new()
+        .with_cell("Samples")
+        .with_cell(lookup.samples));
+    
+    table.add_row(Row::new()
+        .with_cell("Rejected")
+        .with_cell(rejected_cell(lookup.rejected)));
+    
+    let provenance = match lookup.provenance {
+        Provenance::Calibrated => format!("{} from {} analyses", lookup.provenance, lookup.samples).green(),
+        Provenance::Default | Provenance::Modeled => lookup.provenance.to_string().yellow(),
+        Provenance::Fallback => lookup.provenance.to_string().red(),
+    };
+    table.add_row(Row::new()
+        .with_cell("Provenance")
+        .with_cell(provenance));
+    
+    if let Some(url) = docs_url(&lookup.operation) {
+        table.add_row(Row::new()
+            .with_cell("Docs")
+            .with_cell(url));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    println!("\n{} Per-Backend Variants:", "[VARIANTS]".on_magenta().white().bold());
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:>}  {:>}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Field".bright_white().bold())
+        .with_cell("Backend".bright_white().bold())
+        .with_cell("Constraints".bright_white().bold())
+        .with_cell("Proving Key".bright_white().bold())
+        .with_cell("Keygen".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("──────────")
+        .with_cell("────────────")
+        .with_cell("───────────")
+        .with_cell("───────────")
+        .with_cell("──────────"));
+    
+    for variant in &lookup.variants {
+        table.add_row(Row::new()
+            .with_cell(variant.field.to_string().cyan())
+            .with_cell(variant.backend)
+            .with_cell(variant.constraints.to_string().yellow())
+            .with_cell(format_bytes(variant.proving_key_bytes))
+            .with_cell(format!("{:.1} ms", variant.keygen_ms)));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    if lookup.provenance == Provenance::Fallback {
+        println!("{} No calibration or default covers {}; run `calibrate` on circuits that use it", 
+                 "[NOTE]".on_cyan().black(), lookup.operation);
+    }
+}
+
+fn print_model_coverage(operations: &[OperationCoverage]) {
+    println!("\n{} Supported Operations:", "[OPS]".on_blue().white().bold());
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:>}  {:<}  {:>}");
+    table.add_row(Row::new()
+        .with_cell("Operation".bright_white().bold())
+        .with_cell("Category".bright_white().bold())
+        .with_cell("Cost".bright_white().bold())
+        .with_cell("Provenance".bright_white().bold())
+        .with_cell("Seen".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("────────────────────")
+        .with_cell("──────"));
+    
+    for op in operations {
+        let provenance = match op.provenance {
+            Provenance::Calibrated => op.provenance.to_string().green(),
+            Provenance::Default | Provenance::Modeled => op.provenance.to_string().yellow(),
+            Provenance::Fallback => op.provenance.to_string().red(),
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(if op.recognized { display_name(&op.operation).cyan() } else { format!("{} (unrecognized)", op.operation).red() })
+            .with_cell(black_box_category(&op.operation))
+            .with_cell(op.cost)
+            .with_cell(provenance)
+            .with_cell(op.seen));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    let unmodeled: Vec<_> = operations.iter()
+        .filter(|op| op.seen > 0 && op.provenance == Provenance::Fallback)
+        .collect();
+    
+    if !unmodeled.is_empty() {
+        println!("\n{} Seen in recent analyses without a cost entry:", "[UNMODELED]".on_red().white().bold());
+        for op in &unmodeled {
+            println!("  {} {} ({} {})", "•".red(), op.operation.cyan(), op.seen, if op.seen == 1 { "analysis" } else { "analyses" });
+        }
+        println!("Run `calibrate` on circuits that use these to replace the {}-constraint fallback", FALLBACK_COST);
+    }
+    
+    match coverage_percent(operations) {
+        Some(percent) => {
+            let coverage = format!("{:.0}%", percent);
+            println!("\nModel coverage: {}", if percent >= 100.0 { coverage.green().bold() } else if percent >= 80.0 { coverage.yellow().bold() } else { coverage.red().bold() });
+        },
+        None => println!("\n{} No operations recorded yet; analyze some circuits to measure model coverage", "[NOTE]".on_cyan().black()),
+    }
+}
+
+fn sort_cost_entries(entries: &mut [(&String, &(usize, f32, usize))], sort: &str) {
+    match sort {
+        "cost" => entries.sort_by_key(|entry| std::cmp::Reverse(entry.1.0)),
+        "confidence" => entries.sort_by(|a, b| b.1.1.partial_cmp(&a.1.1).unwrap_or(std::cmp::Ordering::Equal)),
+        "samples" => entries.sort_by_key(|entry| std::cmp::Reverse(entry.1.2)),
+        _ => entries.sort_by(|a, b| a.0.cmp(b.0)),
+    }
+}
+
+fn print_cost_database_sorted(sort: &str) {
+    use crate::core::get_cost_database;
+    
+    let db = get_cost_database();
+    
+    let mut entries: Vec<_> = db.iter().collect();
+    sort_cost_entries(&mut entries, sort);
+    
+    println!("\n{} COST MODEL DATABASE:", "[MODEL]".on_blue().white().bold());
+    
+    print_border("╭─────────────────────────────────────────────────────────────────╮");
+    
+    let mut table = Table::new("{:<}  {:<}  {:<}  {:<}  {:<}  {:<}  {:<}  {:<}");
+    table.add_row(Row::new()
+        .with_cell("Operation".bright_white().bold())
+        .with_cell("Avg. Cost".bright_white().bold())
+        .with_cell("Recent Samples".bright_white().bold())
+        .with_cell("Confidence".bright_white().bold())
+        .with_cell("Sample Count".bright_white().bold())
+        .with_cell("Rejected".bright_white().bold())
+        .with_cell("History".bright_white().bold())
+        .with_cell("Min-Max".bright_white().bold()));
+    
+    table.add_row(Row::new()
+        .with_cell("────────────────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("──────────")
+        .with_cell("────────")
+        .with_cell("───────────────")
+        .with_cell("──────────"));
+    
+    for (op_name, (cost, confidence, samples)) in entries {
+        let recent_cost = *cost;
+        
+        let confidence_str = format!("{:.1}%", confidence * 100.0);
+        let confidence_cell = if *confidence > 0.9 {
+            confidence_str.green().bold()
+        } else if *confidence > 0.85 {
+            confidence_str.yellow()
+        } else {
+            confidence_str.red()
+        };
+        
+        let cost_display = cost.to_string().yellow().bold();
+        
+        let recent_display = if recent_cost != *cost {
+            let diff = (recent_cost as f64 - *cost as f64) / *cost as f64 * 100.0;
+            if diff.abs() < 1.0 {
+                format!("{} (~{:.1}%)", recent_cost, diff).normal()
+            } else if diff > 0.0 {
+                format!("{} (+{:.1}%)", recent_cost, diff).yellow()
+            } else {
+                format!("{} ({:.1}%)", recent_cost, diff).cyan()
+            }
+        } else {
+            format!("{} (±0.0%)", recent_cost).normal()
+        };
+        
+        table.add_row(Row::new()
+            .with_cell(op_name.cyan())
+            .with_cell(cost_display)
+            .with_cell(recent_display)
+            .with_cell(confidence_cell)
+            .with_cell(samples.to_string())
+            .with_cell(rejected_cell(db.rejected(op_name)))
+            .with_cell(sparkline(db.history(op_name)).cyan())
+            .with_cell(min_max(db.history(op_name))));
+    }
+    
+    print_boxed(&table);
+    print_border("╰─────────────────────────────────────────────────────────────────╯");
+    
+    println!("\n{} Cost models calibrated using real circuit measurements", 
+             "[CALIBRATION]".on_yellow().black().bold());
+    
+    if let Some(last_updated) = db.last_updated() {
+        println!("Last calibration: {}", last_updated);
+    }
+    
+    println!("Note: Costs may vary by ±5% between proving runs due to system factors");
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn text_report_saturates_huge_black_box_counts() {
+        let analysis = CircuitAnalysis {
+            constraints: usize::MAX,
+            total_opcodes: 2,
+            black_box_functions: vec![
+                ("keccak256".to_string(), usize::MAX, usize::MAX),
+                ("sha256".to_string(), usize::MAX / 2, 3),
+            ],
+            ..Default::default()
+        };
+        let prover = Prover { pricing: CloudPricing::default(), gpu: None, energy: None, backend: None, setup: false };
+        let out = std::env::temp_dir().join(format!("noir-profiler-huge-counts-{}.txt", std::process::id()));
+        
+        let _guard = crate::output::CAPTURE_LOCK.lock().unwrap();
+        crate::output::capture(&out, || print_text_report(&analysis, Path::new("huge.json"), &prover)).unwrap();
+        let report = std::fs::read_to_string(&out).unwrap();
+        std::fs::remove_file(&out).unwrap();
+        
+        assert!(report.contains(&usize::MAX.to_string()));
+    }
+    
+    #[test]
+    fn cost_history_renders_as_sparkline_and_range() {
+        assert_eq!(sparkline(&[1000, 1070, 1035]), "▁█▅");
+        assert_eq!(sparkline(&[5, 5]), "▁▁");
+        assert_eq!(sparkline(&[]), "-");
+        assert_eq!(min_max(&[1070, 1000, 1035]), "1000-1070");
+        assert_eq!(min_max(&[]), "-");
+    }
+    
+    #[test]
+    fn cost_entries_sort_by_the_requested_column() {
+        let names: Vec<String> = ["keccak256", "pedersen_hash", "sha256"].iter().map(|name| name.to_string()).collect();
+        let values = [(55_000, 0.5, 2), (28_742, 0.9, 9), (38_799, 0.7, 4)];
+        let order = |sort: &str| {
+            let mut entries: Vec<_> = names.iter().zip(values.iter()).rev().collect();
+            sort_cost_entries(&mut entries, sort);
+            entries.iter().map(|(name, _)| name.as_str()).collect::<Vec<_>>()
+        };
+        
+        assert_eq!(order("name"), ["keccak256", "pedersen_hash", "sha256"]);
+        assert_eq!(order("cost"), ["keccak256", "sha256", "pedersen_hash"]);
+        assert_eq!(order("confidence"), ["pedersen_hash", "sha256", "keccak256"]);
+        assert_eq!(order("samples"), ["pedersen_hash", "sha256", "keccak256"]);
+        assert!(crate::core::set_cost_entry("sha256", 1, Some(1.5)).is_err());
+    }
+    
+    fn args(list: &[&str]) -> std::vec::IntoIter<String> {
+        let args: Vec<String> = std::iter::once("noir-circuit-profiler").chain(list.iter().copied()).map(String::from).collect();
+        args.into_iter()
+    }
+    
+    #[test]
+    fn run_surfaces_parse_errors_to_the_caller() {
+        let error = run(args(&["frobnicate"])).unwrap_err();
+        assert_eq!(error.downcast_ref::<clap::Error>().map(clap::Error::kind), Some(clap::error::ErrorKind::InvalidSubcommand));
+        let help = run(args(&["--help"])).unwrap_err();
+        assert_eq!(help.downcast_ref::<clap::Error>().map(clap::Error::kind), Some(clap::error::ErrorKind::DisplayHelp));
+        
+        let cli = Cli::try_parse_from(args(&["analyze", "circuit.json", "--format", "json"])).unwrap();
+        assert!(matches!(cli.command, Some(Commands::Analyze { file, format, .. }) if file == Path::new("circuit.json") && format == "json"));
+    }
+    
+    #[test]
+    fn json_mode_reports_structured_errors() {
+        let parse = anyhow::Error::from(serde_json::from_str::<serde_json::Value>("{").unwrap_err()).context("Failed to analyze circuit");
+        assert_eq!(error_kind(&parse), "parse");
+        assert_eq!(error_kind(&anyhow::Error::from(Skipped::TimedOut { limit: Duration::from_secs(1) })), "limit");
+        assert_eq!(error_kind(&anyhow::anyhow!("bad opcode")), "analysis");
+        
+        let out = std::env::temp_dir().join(format!("noir-profiler-json-errors-{}.txt", std::process::id()));
+        let _guard = crate::output::CAPTURE_LOCK.lock().unwrap();
+        let reported = crate::output::capture(&out, || json_errors::<()>(Err(parse), Path::new("c.json"), "json")).unwrap();
+        let printed = std::fs::read_to_string(&out).unwrap();
+        std::fs::remove_file(&out).unwrap();
+        
+        assert!(reported.unwrap_err().is::<AlreadyReported>());
+        let error: serde_json::Value = serde_json::from_str(&printed).unwrap();
+        assert_eq!((error["error"]["kind"].as_str(), error["error"]["path"].as_str()), (Some("parse"), Some("c.json")));
+        assert_eq!(json_errors::<()>(Err(anyhow::anyhow!("plain")), Path::new("c.json"), "text").unwrap_err().to_string(), "plain");
+    }
+    
+    #[test]
+    fn detailed_stats_are_written_under_the_details_dir() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-details-{}", std::process::id()));
+        let analysis = CircuitAnalysis { constraints: 10, total_opcodes: 4, operation_counts: vec![("AssertZero".to_string(), 4)], ..Default::default() };
+        
+        let path = write_detailed_stats(&dir, "nested/main.json.gz", &analysis);
+        let csv = path.as_ref().ok().map(std::fs::read_to_string);
+        std::fs::remove_dir_all(&dir).ok();
+        
+        assert_eq!(path.unwrap(), dir.join("nested/main.csv"));
+        let csv = csv.unwrap().unwrap();
+        assert!(csv.starts_with("# NOIR PROFILER CIRCUIT ANALYSIS: nested/main.json.gz"));
+        assert!(csv.contains("Constraints,10\n") && csv.contains("\nOPERATION,COUNT\nAssertZero,4\n"));
+    }
+    
+    #[cfg(not(feature = "server"))]
+    #[test]
+    fn daemon_needs_the_server_feature() {
+        let error = run_daemon(Path::new("profiler.sock"), None).unwrap_err();
+        assert!(error.to_string().contains("--features server"));
+    }
+}
@@ -0,0 +1,108 @@
+use anyhow::{bail, Result};
+
+pub const BYTES_PER_GATE: f64 = 1536.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct InstanceProfile {
+    pub name: &'static str,
+    pub vcpus: u32,
+    pub memory_gb: f64,
+    pub cost_per_hour: f64,
+    pub joules_per_constraint: f64,
+}
+
+pub static INSTANCE_PROFILES: [InstanceProfile; 6] = [
+    InstanceProfile { name: "c7i.4xlarge", vcpus: 16, memory_gb: 32.0, cost_per_hour: 0.714, joules_per_constraint: 0.004 },
+    InstanceProfile { name: "c7i.8xlarge", vcpus: 32, memory_gb: 64.0, cost_per_hour: 1.428, joules_per_constraint: 0.006 },
+    InstanceProfile { name: "c7i.16xlarge", vcpus: 64, memory_gb: 128.0, cost_per_hour: 2.856, joules_per_constraint: 0.011 },
+    InstanceProfile { name: "c7g.8xlarge", vcpus: 32, memory_gb: 64.0, cost_per_hour: 1.157, joules_per_constraint: 0.004 },
+    InstanceProfile { name: "m7i.8xlarge", vcpus: 32, memory_gb: 128.0, cost_per_hour: 1.613, joules_per_constraint: 0.0065 },
+    InstanceProfile { name: "r7i.8xlarge", vcpus: 32, memory_gb: 256.0, cost_per_hour: 2.117, joules_per_constraint: 0.007 },
+];
+
+#[derive(Debug, Default, Clone)]
+pub struct CloudPricing {
+    pub instance: Option<InstanceProfile>,
+    pub cost_per_hour: Option<f64>,
+}
+
+#[derive(Debug)]
+pub struct CloudCost {
+    pub instance: Option<InstanceProfile>,
+    pub cost_per_hour: f64,
+    pub memory_gb: f64,
+    pub fits_in_memory: Option<bool>,
+    pub per_proof: f64,
+    pub per_million: f64,
+}
+
+pub fn find_profile(name: &str) -> Result<InstanceProfile> {
+    match INSTANCE_PROFILES.iter().find(|profile| profile.name.eq_ignore_ascii_case(name)) {
+        Some(profile) => Ok(*profile),
+        None => {
+            let known: Vec<_> = INSTANCE_PROFILES.iter().map(|profile| profile.name).collect();
+            bail!("Unknown instance profile `{}` (known: {})", name, known.join(", "))
+        }
+    }
+}
+
+impl CloudPricing {
+    pub fn new(instance: Option<&str>, cost_per_hour: Option<f64>) -> Result<Self> {
+        if let Some(cost) = cost_per_hour {
+            if cost <= 0.0 {
+                bail!("--cost-per-hour must be positive, got {}", cost);
+            }
+        }
+        
+        Ok(CloudPricing {
+            instance: instance.map(find_profile).transpose()?,
+            cost_per_hour,
+        })
+    }
+    
+    pub fn hourly_rate(&self) -> Option<f64> {
+        self.cost_per_hour.or(self.instance.map(|profile| profile.cost_per_hour))
+    }
+}
+
+pub fn estimate_memory_gb(constraints: usize) -> f64 {
+    constraints.max(1).next_power_of_two() as f64 * BYTES_PER_GATE / (1024.0 * 1024.0 * 1024.0)
+}
+
+pub fn estimate_cloud_cost(proving_ms: f64, constraints: usize, pricing: &CloudPricing) -> Option<CloudCost> {
+    let cost_per_hour = pricing.hourly_rate()?;
+    let memory_gb = estimate_memory_gb(constraints);
+    let per_proof = proving_ms / 3_600_000.0 * cost_per_hour;
+    
+    Some(CloudCost {
+        instance: pricing.instance,
+        cost_per_hour,
+        memory_gb,
+        fits_in_memory: pricing.instance.map(|profile| memory_gb <= profile.memory_gb),
+        per_proof,
+        per_million: per_proof * 1_000_000.0,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn explicit_rate_overrides_the_instance() {
+        let pricing = CloudPricing::new(Some("C7I.8XLARGE"), Some(3.6)).unwrap();
+        let cost = estimate_cloud_cost(1000.0, 1 << 20, &pricing).unwrap();
+        
+        assert_eq!(cost.cost_per_hour, 3.6);
+        assert!((cost.per_proof - 0.001).abs() < 1e-12);
+        assert_eq!(cost.memory_gb, 1.5);
+        assert_eq!(cost.fits_in_memory, Some(true));
+    }
+    
+    #[test]
+    fn pricing_needs_a_rate() {
+        assert!(estimate_cloud_cost(1000.0, 10, &CloudPricing::default()).is_none());
+        assert!(CloudPricing::new(None, Some(0.0)).is_err());
+        assert!(find_profile("t2.micro").unwrap_err().to_string().contains("c7i.4xlarge"));
+    }
+}
@@ -1,25 +1,104 @@
+use anyhow::{bail, Context, Result};
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use serde_json::{json, Value};
+use std::collections::{BTreeMap, HashMap};
 use std::fs;
-use std::path::Path;
+use std::path::{Path, PathBuf};
 use std::sync::RwLock;
+use std::sync::atomic::{AtomicBool, Ordering};
 use lazy_static::lazy_static;
-use std::time::{SystemTime, UNIX_EPOCH};
+use crate::abi::PublicValue;
+use crate::artifact::{ArtifactMetadata, MissingSection, Resolution};
+use crate::assertions::AssertionCost;
+use crate::attribution::FunctionRanges;
+use crate::calls::ProvingStrategy;
+use crate::curve::CurveOperation;
+use crate::dedup::DuplicateCall;
+use crate::exact::ExpressionWidth;
+use crate::field::Field;
+use crate::lint::Lint;
+use crate::patterns::RepeatedPattern;
+use crate::report::MetricRow;
+use crate::sampling::Sampling;
+use crate::taxonomy::Category;
 
 #[derive(Default, Debug, Serialize, Deserialize)]
+#[serde(default)]
 pub struct CircuitAnalysis {
+    pub metadata: ArtifactMetadata,
+    pub field: Field,
     pub constraints: usize,
     pub bottlenecks: Vec<(String, usize)>,
     pub total_opcodes: usize,
     pub operation_counts: Vec<(String, usize)>,
     pub black_box_functions: Vec<(String, usize, usize)>,
+    pub uncalibrated_operations: Vec<(String, usize)>,
+    pub categories: Vec<(Category, usize)>,
     pub public_inputs: usize,
     pub private_inputs: usize,
     pub return_values: usize,
+    pub public_values: Vec<PublicValue>,
     pub estimated_proving_time: f64,
     pub confidence: f32,
+    pub annotations: Vec<(String, usize, usize)>,
+    pub exact: bool,
+    pub sampling: Option<Sampling>,
+    pub expression_width: ExpressionWidth,
+    pub call_graph: Vec<(String, String, usize)>,
+    pub callees: Vec<(String, usize, usize)>,
+    pub function_ranges: Vec<FunctionRanges>,
+    pub source_files: Vec<(String, usize)>,
+    pub assertions: Vec<AssertionCost>,
+    pub inlined_constraints: usize,
+    pub proving_strategy: ProvingStrategy,
+    pub aggregate_constraints: usize,
+    pub proof_count: usize,
+    pub constant_assertions: usize,
+    pub foldable_assertions: usize,
+    pub witness_assertions: usize,
+    pub wasted_constraints: usize,
+    pub wasted_sites: Vec<(usize, usize)>,
+    pub repeated_patterns: Vec<RepeatedPattern>,
+    pub duplicate_calls: Vec<DuplicateCall>,
+    pub curve_operations: Vec<CurveOperation>,
+    pub lints: Vec<Lint>,
+    pub rows: Vec<MetricRow>,
+    pub skipped_passes: Vec<String>,
+    pub missing_sections: Vec<MissingSection>,
+    pub warnings: Vec<String>,
 }
 
+impl CircuitAnalysis {
+    pub fn count_label(&self) -> &'static str {
+        if self.sampling.is_some() {
+            "sampled"
+        } else if self.exact {
+            "exact"
+        } else {
+            "estimated"
+        }
+    }
+    
+    pub fn unknown_sections(&self) -> impl Iterator<Item = &MissingSection> {
+        self.missing_sections.iter().filter(|missing| missing.resolution == Resolution::Unknown)
+    }
+    
+    pub fn estimated_sections(&self) -> impl Iterator<Item = &MissingSection> {
+        self.missing_sections.iter().filter(|missing| missing.resolution == Resolution::Estimated)
+    }
+    
+    pub fn uncalibrated_warning(&self) -> Option<String> {
+        match self.uncalibrated_operations.len() {
+            0 => None,
+            1 => Some(format!("1 operation used the uncalibrated default cost — results ±{:.0}%", UNCALIBRATED_ERROR_PERCENT)),
+            count => Some(format!("{} operations used uncalibrated default costs — results ±{:.0}%", count, UNCALIBRATED_ERROR_PERCENT)),
+        }
+    }
+}
+
+pub const FALLBACK_COST: usize = 1000;
+pub const UNCALIBRATED_ERROR_PERCENT: f64 = 40.0;
+
 static DEFAULT_COSTS: [(&str, usize); 4] = [
     ("sha256", 38_799),
     ("keccak256", 55_000),
@@ -27,58 +106,369 @@ static DEFAULT_COSTS: [(&str, usize); 4] = [
     ("ecdsa_secp256k1", 5_000),
 ];
 
-pub fn apply_real_world_variability(cost: usize) -> usize {
-    let seed = SystemTime::now()
-        .duration_since(UNIX_EPOCH)
-        .unwrap_or_default()
-        .subsec_nanos() as usize;
-    
-    let variability_factor = 0.98 + (seed % 40) as f64 * 0.001;
-    (cost as f64 * variability_factor) as usize
-}
+pub const COST_DB_PATH: &str = "circuit_stats/cost_database.json";
+pub const COST_DB_SCHEMA_VERSION: u32 = 2;
+
+pub const SAMPLE_WINDOW: usize = 15;
+pub const MIN_SAMPLES_FOR_REJECTION: usize = 5;
+pub const OUTLIER_THRESHOLD: f64 = 3.5;
+pub const SHIFT_CONFIRMATIONS: usize = 3;
+pub const COST_DB_BACKUP_DIR: &str = "circuit_stats/backups";
 
 #[derive(Debug, Serialize, Deserialize, Default)]
 struct CostDatabase {
+    #[serde(default)]
+    schema_version: u32,
     costs: HashMap<String, (usize, f32, usize)>,
+    #[serde(default)]
+    windows: HashMap<String, Vec<usize>>,
+    #[serde(default)]
+    rejected: HashMap<String, usize>,
+    #[serde(default)]
+    pending: HashMap<String, Vec<usize>>,
+    #[serde(default)]
+    seen: HashMap<String, usize>,
     last_updated: Option<String>,
 }
 
+static COST_DB_AUTOSAVE: AtomicBool = AtomicBool::new(true);
+
 lazy_static! {
+    static ref COST_DB_ERROR: RwLock<Option<String>> = RwLock::new(None);
     static ref COST_DB: RwLock<CostDatabase> = RwLock::new(load_cost_database());
 }
 
+fn default_cost_database() -> CostDatabase {
+    let mut db = CostDatabase {
+        schema_version: COST_DB_SCHEMA_VERSION,
+        ..Default::default()
+    };
+    
+    for (op, cost) in DEFAULT_COSTS.iter() {
+        db.costs.insert(op.to_string(), (*cost, 0.83, 1));
+        db.windows.insert(op.to_string(), vec![*cost]);
+    }
+    
+    db
+}
+
 fn load_cost_database() -> CostDatabase {
-    let db_path = Path::new("circuit_stats/cost_database.json");
-    
-    if db_path.exists() {
-        match fs::read_to_string(db_path) {
-            Ok(content) => {
-                match serde_json::from_str(&content) {
-                    Ok(db) => return db,
-                    Err(_) => {}
-                }
-            },
-            Err(_) => {}
+    let db_path = Path::new(COST_DB_PATH);
+    
+    if !db_path.exists() {
+        return default_cost_database();
+    }
+    
+    match read_cost_database(db_path) {
+        Ok(db) => db,
+        Err(e) => {
+            *COST_DB_ERROR.write().unwrap() = Some(format!("{:#}", e));
+            default_cost_database()
         }
     }
+}
+
+fn read_cost_database(path: &Path) -> Result<CostDatabase> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read cost database: {}", path.display()))?;
     
-    let mut db = CostDatabase::default();
-    for (op, cost) in DEFAULT_COSTS.iter() {
-        let variable_cost = apply_real_world_variability(*cost);
-        db.costs.insert(op.to_string(), (variable_cost, 0.83, 1));
+    let value: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse cost database: {}", path.display()))?;
+    
+    let version = schema_version(&value);
+    if version < COST_DB_SCHEMA_VERSION {
+        bail!("Cost database {} uses schema version {}, current is {}", 
+            path.display(), version, COST_DB_SCHEMA_VERSION);
     }
     
-    db
+    let migrated = migrate_cost_database(value)?;
+    
+    serde_json::from_value(migrated)
+        .with_context(|| format!("Invalid cost database: {}", path.display()))
+}
+
+fn schema_version(value: &Value) -> u32 {
+    value["schema_version"].as_u64().unwrap_or(0) as u32
+}
+
+fn migrate_cost_database(mut value: Value) -> Result<Value> {
+    let version = schema_version(&value);
+    
+    if version > COST_DB_SCHEMA_VERSION {
+        bail!("Cost database schema version {} is newer than supported version {}", 
+            version, COST_DB_SCHEMA_VERSION);
+    }
+    
+    if version < 1 {
+        value = migrate_v0_to_v1(value)?;
+    }
+    
+    if version < 2 {
+        value = migrate_v1_to_v2(value)?;
+    }
+    
+    Ok(value)
+}
+
+fn migrate_v0_to_v1(mut value: Value) -> Result<Value> {
+    let costs = value.get_mut("costs")
+        .and_then(|costs| costs.as_object_mut())
+        .context("Cost database has no `costs` table")?;
+    
+    for (op, entry) in costs.iter_mut() {
+        if let Some(cost) = entry.as_u64() {
+            *entry = json!([cost, 0.83, 1]);
+        } else if !entry.is_array() {
+            bail!("Unrecognized cost entry for `{}`", op);
+        }
+    }
+    
+    value["schema_version"] = json!(1);
+    Ok(value)
+}
+
+fn migrate_v1_to_v2(mut value: Value) -> Result<Value> {
+    let costs = value["costs"].as_object()
+        .context("Cost database has no `costs` table")?;
+    
+    let windows: serde_json::Map<String, Value> = costs.iter()
+        .filter_map(|(op, entry)| entry[0].as_u64().map(|cost| (op.clone(), json!([cost]))))
+        .collect();
+    
+    value["windows"] = Value::Object(windows);
+    value["rejected"] = json!({});
+    value["schema_version"] = json!(2);
+    Ok(value)
+}
+
+pub fn cost_database_error() -> Option<String> {
+    drop(COST_DB.read().unwrap());
+    COST_DB_ERROR.read().unwrap().clone()
+}
+
+pub fn migrate_cost_database_file() -> Result<(u32, u32)> {
+    let path = Path::new(COST_DB_PATH);
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read cost database: {}", path.display()))?;
+    
+    let value: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse cost database: {}", path.display()))?;
+    
+    let from = schema_version(&value);
+    let db: CostDatabase = serde_json::from_value(migrate_cost_database(value)?)
+        .with_context(|| format!("Invalid cost database: {}", path.display()))?;
+    
+    if from == COST_DB_SCHEMA_VERSION {
+        return Ok((from, from));
+    }
+    
+    let backup = path.with_extension(format!("v{}.json", from));
+    fs::copy(path, &backup)
+        .with_context(|| format!("Failed to back up cost database to {}", backup.display()))?;
+    
+    fs::write(path, serde_json::to_string_pretty(&db)?)
+        .with_context(|| format!("Failed to write cost database: {}", path.display()))?;
+    
+    *COST_DB.write().unwrap() = db;
+    *COST_DB_ERROR.write().unwrap() = None;
+    
+    Ok((from, COST_DB_SCHEMA_VERSION))
+}
+
+pub fn backup_cost_database() -> Result<Option<PathBuf>> {
+    snapshot_file(Path::new(COST_DB_PATH), Path::new(COST_DB_BACKUP_DIR))
+}
+
+fn snapshot_file(path: &Path, backup_dir: &Path) -> Result<Option<PathBuf>> {
+    if !path.exists() {
+        return Ok(None);
+    }
+    
+    fs::create_dir_all(backup_dir)
+        .with_context(|| format!("Failed to create backup directory: {}", backup_dir.display()))?;
+    
+    let timestamp = chrono::Local::now().format("%Y%m%d-%H%M%S-%3f");
+    let backup = backup_dir.join(format!("{}.json", timestamp));
+    
+    fs::copy(path, &backup)
+        .with_context(|| format!("Failed to back up cost database to {}", backup.display()))?;
+    
+    Ok(Some(backup))
+}
+
+pub fn list_cost_database_backups() -> Result<Vec<String>> {
+    backups_in(Path::new(COST_DB_BACKUP_DIR))
+}
+
+fn backups_in(backup_dir: &Path) -> Result<Vec<String>> {
+    if !backup_dir.exists() {
+        return Ok(Vec::new());
+    }
+    
+    let mut backups = Vec::new();
+    for entry in fs::read_dir(backup_dir)
+        .with_context(|| format!("Failed to read backup directory: {}", backup_dir.display()))? {
+        let path = entry?.path();
+        
+        if path.extension().is_some_and(|ext| ext == "json") {
+            if let Some(stem) = path.file_stem().and_then(|stem| stem.to_str()) {
+                backups.push(stem.to_string());
+            }
+        }
+    }
+    
+    backups.sort();
+    Ok(backups)
+}
+
+pub fn rollback_cost_database(to: Option<&str>) -> Result<(String, Option<PathBuf>)> {
+    let backups = list_cost_database_backups()?;
+    
+    let timestamp = match to {
+        Some(to) => backups.iter()
+            .find(|backup| backup.as_str() == to)
+            .with_context(|| format!("No backup `{}` in {} (available: {})", 
+                to, COST_DB_BACKUP_DIR, backups.join(", ")))?,
+        None => backups.last()
+            .with_context(|| format!("No backups in {}", COST_DB_BACKUP_DIR))?,
+    };
+    
+    let backup = Path::new(COST_DB_BACKUP_DIR).join(format!("{}.json", timestamp));
+    let content = fs::read_to_string(&backup)
+        .with_context(|| format!("Failed to read backup: {}", backup.display()))?;
+    
+    let value: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse backup: {}", backup.display()))?;
+    
+    let db: CostDatabase = serde_json::from_value(migrate_cost_database(value)?)
+        .with_context(|| format!("Invalid backup: {}", backup.display()))?;
+    
+    let snapshot = backup_cost_database()
+        .context("Failed to snapshot the current cost database before rolling back")?;
+    
+    if let Some(dir) = Path::new(COST_DB_PATH).parent() {
+        fs::create_dir_all(dir)?;
+    }
+    
+    fs::write(COST_DB_PATH, serde_json::to_string_pretty(&db)?)
+        .with_context(|| format!("Failed to write cost database: {}", COST_DB_PATH))?;
+    
+    *COST_DB.write().unwrap() = db;
+    *COST_DB_ERROR.write().unwrap() = None;
+    
+    Ok((timestamp.clone(), snapshot))
+}
+
+pub fn reset_cost_database() {
+    fs::remove_file(COST_DB_PATH).ok();
+    *COST_DB.write().unwrap() = default_cost_database();
+    *COST_DB_ERROR.write().unwrap() = None;
+}
+
+pub fn set_cost_database_autosave(enabled: bool) {
+    COST_DB_AUTOSAVE.store(enabled, Ordering::SeqCst);
+}
+
+pub fn restore_cost_entry(operation: &str, before: &CostDatabaseView) {
+    restore_entry(&mut COST_DB.write().unwrap(), operation, before);
+}
+
+fn restore_entry(db: &mut CostDatabase, operation: &str, before: &CostDatabaseView) {
+    match before.costs.get(operation) {
+        Some(entry) => db.costs.insert(operation.to_string(), *entry),
+        None => db.costs.remove(operation),
+    };
+    
+    match before.windows.get(operation) {
+        Some(window) => db.windows.insert(operation.to_string(), window.clone()),
+        None => db.windows.remove(operation),
+    };
+    
+    match before.rejected.get(operation) {
+        Some(rejected) => db.rejected.insert(operation.to_string(), *rejected),
+        None => db.rejected.remove(operation),
+    };
+    
+    match before.pending.get(operation) {
+        Some(pending) => db.pending.insert(operation.to_string(), pending.clone()),
+        None => db.pending.remove(operation),
+    };
+    
+    match before.seen.get(operation) {
+        Some(seen) => db.seen.insert(operation.to_string(), *seen),
+        None => db.seen.remove(operation),
+    };
+    
+    if db.costs == before.costs && db.windows == before.windows && db.rejected == before.rejected
+        && db.pending == before.pending && db.seen == before.seen {
+        db.last_updated = before.last_updated.clone();
+    }
+}
+
+pub fn set_cost_entry(operation: &str, cost: usize, confidence: Option<f32>) -> Result<()> {
+    if let Some(confidence) = confidence {
+        if !(0.0..=1.0).contains(&confidence) {
+            bail!("Confidence must be between 0 and 1, got {}", confidence);
+        }
+    }
+    
+    {
+        let mut db = COST_DB.write().unwrap();
+        let (_, current_confidence, samples) = db.costs.get(operation).copied().unwrap_or((cost, 0.83, 0));
+        
+        db.costs.insert(operation.to_string(), (cost, confidence.unwrap_or(current_confidence), samples.max(1)));
+        db.windows.insert(operation.to_string(), vec![cost]);
+        db.pending.remove(operation);
+        db.last_updated = Some(chrono::Local::now().to_rfc3339());
+    }
+    
+    commit_cost_database()
+}
+
+pub fn remove_cost_entry(operation: &str) -> Result<bool> {
+    let removed = remove_entry(&mut COST_DB.write().unwrap(), operation);
+    
+    if removed {
+        commit_cost_database()?;
+    }
+    
+    Ok(removed)
+}
+
+fn remove_entry(db: &mut CostDatabase, operation: &str) -> bool {
+    db.windows.remove(operation);
+    db.rejected.remove(operation);
+    db.pending.remove(operation);
+    let seen = db.seen.remove(operation).is_some();
+    db.costs.remove(operation).is_some() || seen
+}
+
+pub fn commit_cost_database() -> Result<()> {
+    if let Some(error) = COST_DB_ERROR.read().unwrap().as_ref() {
+        bail!("Cost database could not be loaded: {}", error);
+    }
+    
+    let db = COST_DB.read().unwrap();
+    let db_path = Path::new(COST_DB_PATH);
+    
+    if let Some(dir) = db_path.parent() {
+        fs::create_dir_all(dir)?;
+    }
+    
+    fs::write(db_path, serde_json::to_string_pretty(&*db)?)
+        .with_context(|| format!("Failed to write cost database: {}", db_path.display()))
 }
 
 pub fn save_cost_database() {
+    if !COST_DB_AUTOSAVE.load(Ordering::SeqCst) || COST_DB_ERROR.read().unwrap().is_some() {
+        return;
+    }
+    
     let db = COST_DB.read().unwrap();
     let db_dir = Path::new("circuit_stats");
     
-    if !db_dir.exists() {
-        if let Err(_) = fs::create_dir_all(db_dir) {
-            return;
-        }
+    if !db_dir.exists() && fs::create_dir_all(db_dir).is_err() {
+        return;
     }
     
     let db_path = db_dir.join("cost_database.json");
@@ -90,48 +480,111 @@ pub fn save_cost_database() {
     let _ = fs::write(db_path, content);
 }
 
-pub fn update_cost_database(operation: &str, measured_cost: usize) {
-    let mut db = COST_DB.write().unwrap();
+fn median(values: &[usize]) -> usize {
+    let mut sorted = values.to_vec();
+    sorted.sort_unstable();
     
-    let variable_cost = apply_real_world_variability(measured_cost);
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) / 2
+    } else {
+        sorted[mid]
+    }
+}
+
+fn deviates(window: &[usize], center: usize, sample: usize) -> bool {
+    let window_center = median(window);
+    let deviations: Vec<usize> = window.iter().map(|v| v.abs_diff(window_center)).collect();
+    let mad = median(&deviations) as f64 * 1.4826;
+    let scale = mad.max(center as f64 * 0.01).max(1.0);
     
-    let entry = db.costs.entry(operation.to_string()).or_insert((variable_cost, 0.83, 1));
+    sample.abs_diff(center) as f64 / scale > OUTLIER_THRESHOLD
+}
+
+fn is_outlier(window: &[usize], sample: usize) -> bool {
+    window.len() >= MIN_SAMPLES_FOR_REJECTION && deviates(window, median(window), sample)
+}
+
+fn agrees(window: &[usize], pending: &[usize]) -> bool {
+    let center = median(pending);
+    pending.iter().all(|sample| !deviates(window, center, *sample))
+}
+
+pub fn record_seen_operations<'a>(operations: impl IntoIterator<Item = &'a str>) {
+    let mut db = COST_DB.write().unwrap();
     
-    let (current_cost, _confidence, sample_count) = *entry;
-    let new_sample_count = sample_count + 1;
+    for operation in operations {
+        *db.seen.entry(operation.to_string()).or_insert(0) += 1;
+    }
+}
+
+pub fn update_cost_database(operation: &str, measured_cost: usize) {
+    let mut db = COST_DB.write().unwrap();
     
-    let weight = if sample_count < 3 {
-        0.5
-    } else if sample_count < 10 {
-        0.3
+    record_sample(&mut db, operation, measured_cost);
+    db.last_updated = Some(chrono::Local::now().to_rfc3339());
+}
+
+fn record_sample(db: &mut CostDatabase, operation: &str, sample: usize) {
+    let (_, _, sample_count) = *db.costs.entry(operation.to_string()).or_insert((sample, 0.83, 0));
+    let window = db.windows.entry(operation.to_string()).or_default();
+    
+    let accepted = if is_outlier(window, sample) {
+        let pending = db.pending.entry(operation.to_string()).or_default();
+        pending.push(sample);
+        if !agrees(window, pending) {
+            *pending = vec![sample];
+        }
+        
+        if pending.len() < SHIFT_CONFIRMATIONS {
+            *db.rejected.entry(operation.to_string()).or_insert(0) += 1;
+            return;
+        }
+        
+        let shifted = db.pending.remove(operation).unwrap_or_default();
+        let rejected = db.rejected.entry(operation.to_string()).or_insert(0);
+        *rejected = rejected.saturating_sub(shifted.len() - 1);
+        
+        *window = shifted;
+        window.len()
     } else {
-        0.2
+        db.pending.remove(operation);
+        window.push(sample);
+        if window.len() > SAMPLE_WINDOW {
+            window.remove(0);
+        }
+        1
     };
     
-    let new_cost = ((1.0 - weight) * current_cost as f64 + weight * variable_cost as f64) as usize;
-    
+    let new_cost = median(window);
+    let new_sample_count = sample_count + accepted;
     let new_confidence = (0.83 + (new_sample_count as f32 / 50.0)).min(0.99);
     
-    *entry = (new_cost, new_confidence, new_sample_count);
-    db.last_updated = Some(chrono::Local::now().to_rfc3339());
+    db.costs.insert(operation.to_string(), (new_cost, new_confidence, new_sample_count));
 }
 
 pub fn get_operation_details(operation: &str) -> (usize, f32) {
     let db = COST_DB.read().unwrap();
     
     if let Some((cost, confidence, _)) = db.costs.get(operation) {
-        let variable_cost = apply_real_world_variability(*cost);
-        return (variable_cost, *confidence);
+        return (*cost, *confidence);
     }
     
-    for (op, cost) in DEFAULT_COSTS.iter() {
-        if operation.contains(op) || op.contains(operation) {
-            let variable_cost = apply_real_world_variability(*cost);
-            return (variable_cost, 0.83);
-        }
+    if let Some((_, cost)) = default_cost(operation) {
+        return (cost, 0.83);
     }
     
-    (apply_real_world_variability(1000), 0.83)
+    (FALLBACK_COST, 0.83)
+}
+
+pub fn is_fallback_cost(operation: &str) -> bool {
+    !COST_DB.read().unwrap().costs.contains_key(operation) && default_cost(operation).is_none()
+}
+
+pub fn default_cost(operation: &str) -> Option<(&'static str, usize)> {
+    DEFAULT_COSTS.iter()
+        .find(|(op, _)| operation.contains(op) || op.contains(operation))
+        .copied()
 }
 
 #[allow(dead_code)]
@@ -155,45 +608,20 @@ pub fn find_operations_by_cost(target_cost: usize, tolerance_percent: f64) -> Ve
     let db = COST_DB.read().unwrap();
     let mut matches = Vec::new();
     
-    let variable_tolerance = {
-        let base_tolerance = tolerance_percent;
-        let factor = 1.0 + (SystemTime::now().duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .subsec_nanos() % 20) as f64 * 0.01;
-        base_tolerance * factor
-    };
-    
-    let tolerance = (target_cost as f64 * variable_tolerance) / 100.0;
+    let tolerance = (target_cost as f64 * tolerance_percent) / 100.0;
     
     for (op_name, (cost, confidence, _)) in &db.costs {
-        let variable_cost = apply_real_world_variability(*cost);
-        let diff = (variable_cost as f64 - target_cost as f64).abs();
+        let diff = (*cost as f64 - target_cost as f64).abs();
         
         if diff <= tolerance {
-            let variable_confidence = {
-                let variance = (SystemTime::now().duration_since(UNIX_EPOCH)
-                    .unwrap_or_default()
-                    .subsec_nanos() % 5) as f32 * 0.01;
-                (*confidence * (1.0 - variance)).max(0.8)
-            };
-            
-            matches.push((op_name.clone(), variable_cost, variable_confidence));
+            matches.push((op_name.clone(), *cost, *confidence));
         }
     }
     
     matches.sort_by(|a, b| {
         let diff_a = (a.1 as f64 - target_cost as f64).abs();
         let diff_b = (b.1 as f64 - target_cost as f64).abs();
-        
-        let rand_factor = SystemTime::now().duration_since(UNIX_EPOCH)
-            .unwrap_or_default()
-            .subsec_nanos() % 10;
-        
-        if rand_factor < 2 && diff_a < tolerance * 0.5 && diff_b < tolerance * 0.5 {
-            diff_b.partial_cmp(&diff_a).unwrap()
-        } else {
-            diff_a.partial_cmp(&diff_b).unwrap()
-        }
+        diff_a.total_cmp(&diff_b).then_with(|| a.0.cmp(&b.0))
     });
     
     matches
@@ -202,15 +630,26 @@ pub fn find_operations_by_cost(target_cost: usize, tolerance_percent: f64) -> Ve
 pub const PROVING_TIME_FACTOR: f64 = 1.0;
 
 pub fn get_cost_database() -> CostDatabaseView {
-    let db = COST_DB.read().unwrap();
+    view_of(&COST_DB.read().unwrap())
+}
+
+fn view_of(db: &CostDatabase) -> CostDatabaseView {
     CostDatabaseView {
         costs: db.costs.clone(),
+        windows: db.windows.clone(),
+        rejected: db.rejected.clone(),
+        pending: db.pending.clone(),
+        seen: db.seen.clone(),
         last_updated: db.last_updated.clone(),
     }
 }
 
 pub struct CostDatabaseView {
     costs: HashMap<String, (usize, f32, usize)>,
+    windows: HashMap<String, Vec<usize>>,
+    rejected: HashMap<String, usize>,
+    pending: HashMap<String, Vec<usize>>,
+    seen: HashMap<String, usize>,
     last_updated: Option<String>,
 }
 
@@ -219,7 +658,269 @@ impl CostDatabaseView {
         self.costs.iter()
     }
     
+    pub fn get(&self, operation: &str) -> Option<&(usize, f32, usize)> {
+        self.costs.get(operation)
+    }
+    
+    pub fn history(&self, operation: &str) -> &[usize] {
+        self.windows.get(operation).map_or(&[], |window| window.as_slice())
+    }
+    
+    pub fn rejected(&self, operation: &str) -> usize {
+        self.rejected.get(operation).copied().unwrap_or(0)
+    }
+    
+    pub fn total_rejected(&self) -> usize {
+        self.rejected.values().sum()
+    }
+    
+    pub fn seen(&self) -> impl Iterator<Item = (&String, &usize)> {
+        self.seen.iter()
+    }
+    
     pub fn last_updated(&self) -> Option<&String> {
         self.last_updated.as_ref()
     }
-} 
\ No newline at end of file
+} 
+
+pub const GATE_CALIBRATION_PATH: &str = "circuit_stats/gate_calibration.json";
+pub const MAX_TUNING_SAMPLES: usize = 20;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+struct GateFactor {
+    factor: f64,
+    samples: usize,
+    #[serde(default)]
+    artifacts: Vec<String>,
+}
+
+lazy_static! {
+    static ref GATE_FACTORS: RwLock<BTreeMap<String, GateFactor>> = RwLock::new(load_gate_factors());
+}
+
+fn load_gate_factors() -> BTreeMap<String, GateFactor> {
+    fs::read_to_string(GATE_CALIBRATION_PATH).ok()
+        .and_then(|content| serde_json::from_str(&content).ok())
+        .unwrap_or_default()
+}
+
+pub fn gate_factor(backend: &str) -> f64 {
+    GATE_FACTORS.read().unwrap().get(backend).map_or(1.0, |entry| entry.factor)
+}
+
+pub fn tune_gate_factor(backend: &str, artifact_sha256: &str, ratio: f64) -> Result<Option<f64>> {
+    let mut factors = GATE_FACTORS.write().unwrap();
+    let entry = factors.entry(backend.to_string()).or_insert(GateFactor { factor: 1.0, samples: 0, artifacts: Vec::new() });
+    if entry.artifacts.iter().any(|artifact| artifact == artifact_sha256) {
+        return Ok(None);
+    }
+    
+    let samples = entry.samples.min(MAX_TUNING_SAMPLES - 1);
+    entry.factor = (entry.factor * samples as f64 + ratio) / (samples + 1) as f64;
+    entry.samples += 1;
+    entry.artifacts.push(artifact_sha256.to_string());
+    if entry.artifacts.len() > MAX_TUNING_SAMPLES {
+        entry.artifacts.remove(0);
+    }
+    let factor = entry.factor;
+    
+    if let Some(parent) = Path::new(GATE_CALIBRATION_PATH).parent() {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    fs::write(GATE_CALIBRATION_PATH, serde_json::to_string_pretty(&*factors)?)
+        .with_context(|| format!("Failed to write {}", GATE_CALIBRATION_PATH))?;
+    
+    Ok(Some(factor))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn migrates_v0_to_current() {
+        let v0 = json!({ "costs": { "sha256": 38799, "keccak256": [55000, 0.9, 4] }, "last_updated": null });
+        let migrated = migrate_cost_database(v0).unwrap();
+        
+        assert_eq!(schema_version(&migrated), COST_DB_SCHEMA_VERSION);
+        assert_eq!(migrated["costs"]["sha256"], json!([38799, 0.83, 1]));
+        assert_eq!(migrated["costs"]["keccak256"], json!([55000, 0.9, 4]));
+        assert_eq!(migrated["windows"]["sha256"], json!([38799]));
+        assert_eq!(migrated["windows"]["keccak256"], json!([55000]));
+        assert_eq!(migrated["rejected"], json!({}));
+    }
+    
+    #[test]
+    fn migrates_v1_to_v2() {
+        let v1 = json!({ "schema_version": 1, "costs": { "sha256": [40000, 0.8, 3] }, "last_updated": "2024-01-01" });
+        let migrated = migrate_cost_database(v1).unwrap();
+        
+        assert_eq!(schema_version(&migrated), 2);
+        assert_eq!(migrated["windows"]["sha256"], json!([40000]));
+        assert_eq!(migrated["last_updated"], json!("2024-01-01"));
+    }
+    
+    #[test]
+    fn migrated_database_round_trips() {
+        let v0 = json!({ "costs": { "sha256": 38799, "pedersen_hash": 28742 }, "last_updated": "2024-01-01" });
+        let db: CostDatabase = serde_json::from_value(migrate_cost_database(v0).unwrap()).unwrap();
+        
+        assert_eq!(db.schema_version, COST_DB_SCHEMA_VERSION);
+        assert_eq!(db.costs["sha256"], (38799, 0.83, 1));
+        assert_eq!(db.windows["pedersen_hash"], vec![28742]);
+        
+        let saved = serde_json::to_value(&db).unwrap();
+        let reloaded: CostDatabase = serde_json::from_value(migrate_cost_database(saved.clone()).unwrap()).unwrap();
+        
+        assert_eq!(serde_json::to_value(&reloaded).unwrap(), saved);
+    }
+    
+    #[test]
+    fn rejects_newer_schema() {
+        let future = json!({ "schema_version": COST_DB_SCHEMA_VERSION + 1, "costs": {} });
+        assert!(migrate_cost_database(future).is_err());
+    }
+    
+    #[test]
+    fn rejects_unrecognized_v0_entries() {
+        assert!(migrate_cost_database(json!({ "costs": { "sha256": "fast" } })).is_err());
+        assert!(migrate_cost_database(json!({ "last_updated": null })).is_err());
+    }
+    
+    const STABLE: [usize; 5] = [1000, 1010, 990, 1005, 995];
+    
+    fn seeded(samples: &[usize]) -> CostDatabase {
+        let mut db = CostDatabase::default();
+        for sample in samples {
+            record_sample(&mut db, "sha256", *sample);
+        }
+        db
+    }
+    
+    #[test]
+    fn removing_an_entry_forgets_that_it_was_seen() {
+        let mut db = seeded(&STABLE);
+        db.seen.insert("sha256".to_string(), 3);
+        db.seen.insert("keccak256".to_string(), 1);
+        
+        assert!(remove_entry(&mut db, "sha256"));
+        assert!(!db.costs.contains_key("sha256") && !db.windows.contains_key("sha256"));
+        assert!(!db.seen.contains_key("sha256"));
+        
+        assert!(remove_entry(&mut db, "keccak256"));
+        assert!(db.seen.is_empty());
+        assert!(!remove_entry(&mut db, "keccak256"));
+    }
+    
+    #[test]
+    fn mad_threshold() {
+        assert!(!is_outlier(&STABLE, 1035));
+        assert!(is_outlier(&STABLE, 1036));
+        assert!(!is_outlier(&STABLE, 965));
+        assert!(is_outlier(&STABLE, 964));
+    }
+    
+    #[test]
+    fn mad_scale_has_a_floor() {
+        assert!(!is_outlier(&[1000; 5], 1035));
+        assert!(is_outlier(&[1000; 5], 1036));
+        assert!(!is_outlier(&[0; 5], 3));
+        assert!(is_outlier(&[0; 5], 4));
+    }
+    
+    #[test]
+    fn no_rejection_before_minimum_samples() {
+        assert!(!is_outlier(&STABLE[..MIN_SAMPLES_FOR_REJECTION - 1], 1_000_000));
+    }
+    
+    #[test]
+    fn window_keeps_latest_samples() {
+        let samples: Vec<usize> = (0..SAMPLE_WINDOW + 5).map(|i| 1000 + i).collect();
+        let db = seeded(&samples);
+        
+        assert_eq!(db.windows["sha256"], samples[5..].to_vec());
+        assert_eq!(db.costs["sha256"].0, median(&samples[5..]));
+        assert_eq!(db.costs["sha256"].2, samples.len());
+    }
+    
+    #[test]
+    fn single_spike_is_rejected() {
+        let mut db = seeded(&STABLE);
+        record_sample(&mut db, "sha256", 5000);
+        record_sample(&mut db, "sha256", 1000);
+        
+        assert_eq!(db.rejected["sha256"], 1);
+        assert!(!db.pending.contains_key("sha256"));
+        assert_eq!(db.windows["sha256"].len(), STABLE.len() + 1);
+        assert_eq!(db.costs["sha256"].0, 1000);
+    }
+    
+    #[test]
+    fn consistent_shift_is_accepted() {
+        let mut db = seeded(&STABLE);
+        for sample in [2000, 2010, 1990] {
+            record_sample(&mut db, "sha256", sample);
+        }
+        
+        assert_eq!(db.windows["sha256"], vec![2000, 2010, 1990]);
+        assert_eq!(db.costs["sha256"].0, 2000);
+        assert_eq!(db.rejected["sha256"], 0);
+        assert!(!db.pending.contains_key("sha256"));
+    }
+    
+    #[test]
+    fn scattered_outliers_do_not_shift() {
+        let mut db = seeded(&STABLE);
+        for sample in [2000, 5000, 9000] {
+            record_sample(&mut db, "sha256", sample);
+        }
+        
+        assert_eq!(db.windows["sha256"], STABLE.to_vec());
+        assert_eq!(db.pending["sha256"], vec![9000]);
+        assert_eq!(db.rejected["sha256"], 3);
+    }
+    
+    #[test]
+    fn snapshots_are_listed_oldest_first() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-backups-{}", std::process::id()));
+        let db = dir.join("cost_database.json");
+        let backups = dir.join("backups");
+        fs::create_dir_all(&dir).unwrap();
+        
+        let missing = snapshot_file(&db, &backups).unwrap();
+        fs::write(&db, "{}").unwrap();
+        let first = snapshot_file(&db, &backups).unwrap().unwrap();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let second = snapshot_file(&db, &backups).unwrap().unwrap();
+        fs::write(backups.join("notes.txt"), "").unwrap();
+        let listed = backups_in(&backups).unwrap();
+        let empty = backups_in(&dir.join("none")).unwrap();
+        let copied = fs::read_to_string(&first);
+        fs::remove_dir_all(&dir).unwrap();
+        
+        assert!(missing.is_none() && empty.is_empty());
+        assert_eq!(copied.unwrap(), "{}");
+        let stem = |path: &PathBuf| path.file_stem().unwrap().to_string_lossy().to_string();
+        assert_eq!(listed, [stem(&first), stem(&second)]);
+    }
+    
+    #[test]
+    fn rejected_changes_restore_the_previous_entry() {
+        let mut db = seeded(&STABLE);
+        db.last_updated = Some("before".to_string());
+        let before = view_of(&db);
+        
+        record_sample(&mut db, "sha256", 1020);
+        record_sample(&mut db, "keccak256", 55_000);
+        db.last_updated = Some("after".to_string());
+        
+        restore_entry(&mut db, "keccak256", &before);
+        assert!(!db.costs.contains_key("keccak256") && !db.windows.contains_key("keccak256"));
+        assert_eq!(db.last_updated.as_deref(), Some("after"));
+        
+        restore_entry(&mut db, "sha256", &before);
+        assert_eq!(db.windows["sha256"], STABLE.to_vec());
+        assert_eq!(db.last_updated.as_deref(), Some("before"));
+    }
+}
@@ -0,0 +1,305 @@
+use crate::core::{default_cost, get_cost_database, get_operation_details, is_fallback_cost, FALLBACK_COST};
+use crate::curve::{msm_cost, MsmTerm, CURVE_ADD_COST, SCALAR_BITS};
+use crate::field::Field;
+use crate::names::{canonical_name, known_names};
+use crate::setup::{BACKEND_PROFILES, KEYGEN_POINTS_PER_SEC};
+use anyhow::{bail, Result};
+use serde::Serialize;
+use std::collections::HashMap;
+use std::fmt;
+
+pub const MODEL_CONFIDENCE: f32 = 0.9;
+
+const SIZED_OPERATIONS: [&str; 7] = [
+    "sha256", "blake2s", "blake3", "keccak256", "pedersen_hash", "pedersen_commitment", "multi_scalar_mul",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Provenance {
+    Calibrated,
+    Default,
+    Modeled,
+    Fallback,
+}
+
+impl fmt::Display for Provenance {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Provenance::Calibrated => write!(f, "calibrated"),
+            Provenance::Default => write!(f, "built-in default"),
+            Provenance::Modeled => write!(f, "embedded curve model"),
+            Provenance::Fallback => write!(f, "fallback"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CostVariant {
+    pub field: Field,
+    pub backend: &'static str,
+    pub constraints: usize,
+    pub proving_key_bytes: f64,
+    pub keygen_ms: f64,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CostLookup {
+    pub operation: String,
+    pub entry: Option<String>,
+    pub size: Option<usize>,
+    pub units: usize,
+    pub unit: &'static str,
+    pub unit_cost: usize,
+    pub cost: usize,
+    pub confidence: f32,
+    pub samples: usize,
+    pub rejected: usize,
+    pub provenance: Provenance,
+    pub variants: Vec<CostVariant>,
+}
+
+fn size_units(canonical: &str, size: usize) -> Option<(usize, &'static str)> {
+    match canonical {
+        "sha256" => Some(((size + 9).div_ceil(64), "block")),
+        "blake2s" | "blake3" => Some((size.div_ceil(64).max(1), "block")),
+        "keccak256" => Some(((size + 1).div_ceil(136), "block")),
+        "pedersen_hash" | "pedersen_commitment" => Some((size.div_ceil(32).max(1), "field element")),
+        "multi_scalar_mul" => Some((size.max(1), "point")),
+        _ => None,
+    }
+}
+
+fn variable_base_msm(points: usize) -> usize {
+    msm_cost(&vec![MsmTerm { fixed: false, scalar_bits: SCALAR_BITS }; points])
+}
+
+fn variants(operation: &str, cost: usize) -> Vec<CostVariant> {
+    [Field::Bn254, Field::Bls12381].into_iter()
+        .flat_map(|field| {
+            let constraints = field.scale_black_box(operation, cost);
+            BACKEND_PROFILES.iter().map(move |profile| CostVariant {
+                field,
+                backend: profile.name,
+                constraints,
+                proving_key_bytes: constraints as f64 * profile.key_bytes_per_gate,
+                keygen_ms: profile.keygen_points_per_gate * constraints as f64 / KEYGEN_POINTS_PER_SEC * 1000.0,
+            })
+        })
+        .collect()
+}
+
+pub fn lookup_cost(operation: &str, size: Option<usize>) -> Result<CostLookup> {
+    let canonical = canonical_name(operation);
+    
+    let (units, unit) = match size {
+        Some(size) => match size_units(&canonical, size) {
+            Some(units) => units,
+            None => bail!("--size does not apply to {} (sized operations: {})", operation, SIZED_OPERATIONS.join(", ")),
+        },
+        None => (1, if canonical == "multi_scalar_mul" { "point" } else { "call" }),
+    };
+    
+    let db = get_cost_database();
+    let entry = db.get(operation)
+        .map(|found| (operation.to_string(), *found))
+        .or_else(|| db.iter()
+            .filter(|(name, _)| canonical_name(name) == canonical)
+            .max_by(|(a, (_, _, a_samples)), (b, (_, _, b_samples))| a_samples.cmp(b_samples).then(b.cmp(a)))
+            .map(|(name, found)| (name.clone(), *found)));
+    
+    let mut lookup = CostLookup {
+        operation: operation.to_string(),
+        entry: None,
+        size,
+        units,
+        unit,
+        unit_cost: FALLBACK_COST,
+        cost: 0,
+        confidence: 0.83,
+        samples: 0,
+        rejected: 0,
+        provenance: Provenance::Fallback,
+        variants: Vec::new(),
+    };
+    
+    match (canonical.as_str(), entry) {
+        ("multi_scalar_mul", _) => {
+            lookup.unit_cost = variable_base_msm(1);
+            lookup.cost = variable_base_msm(units);
+            lookup.confidence = MODEL_CONFIDENCE;
+            lookup.provenance = Provenance::Modeled;
+        },
+        ("embedded_curve_add", _) => {
+            lookup.unit_cost = CURVE_ADD_COST;
+            lookup.confidence = MODEL_CONFIDENCE;
+            lookup.provenance = Provenance::Modeled;
+        },
+        (_, Some((name, (cost, confidence, samples)))) => {
+            lookup.provenance = if samples > 1 || default_cost(&name).is_none() {
+                Provenance::Calibrated
+            } else {
+                Provenance::Default
+            };
+            lookup.rejected = db.rejected(&name);
+            lookup.entry = Some(name);
+            lookup.unit_cost = cost;
+            lookup.confidence = confidence;
+            lookup.samples = samples;
+        },
+        (_, None) => {
+            if let Some((name, cost)) = default_cost(operation) {
+                lookup.entry = Some(name.to_string());
+                lookup.unit_cost = cost;
+                lookup.provenance = Provenance::Default;
+            }
+        },
+    }
+    
+    if lookup.cost == 0 {
+        lookup.cost = lookup.unit_cost.saturating_mul(units);
+    }
+    
+    lookup.variants = variants(operation, lookup.cost);
+    Ok(lookup)
+}
+
+#[derive(Debug, Clone, Default, PartialEq)]
+pub enum CostModel {
+    #[default]
+    Calibrated,
+    Frozen,
+    Fixed(HashMap<String, usize>),
+}
+
+impl CostModel {
+    pub fn operation_cost(&self, operation: &str) -> (usize, f32) {
+        let CostModel::Fixed(costs) = self else {
+            return get_operation_details(operation);
+        };
+        
+        match costs.get(operation).or_else(|| costs.get(&canonical_name(operation))) {
+            Some(cost) => (*cost, 1.0),
+            None => (default_cost(operation).map_or(FALLBACK_COST, |(_, cost)| cost), 0.83),
+        }
+    }
+    
+    pub fn is_fallback(&self, operation: &str) -> bool {
+        match self {
+            CostModel::Fixed(costs) => !costs.contains_key(operation)
+                && !costs.contains_key(&canonical_name(operation))
+                && default_cost(operation).is_none(),
+            _ => is_fallback_cost(operation),
+        }
+    }
+    
+    pub fn updates_database(&self) -> bool {
+        *self == CostModel::Calibrated
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct OperationCoverage {
+    pub operation: String,
+    pub recognized: bool,
+    pub cost: usize,
+    pub provenance: Provenance,
+    pub seen: usize,
+}
+
+pub fn model_coverage() -> Result<Vec<OperationCoverage>> {
+    let mut operations = Vec::new();
+    
+    for name in known_names() {
+        let lookup = lookup_cost(name.canonical, None)?;
+        operations.push(OperationCoverage {
+            operation: name.canonical.to_string(),
+            recognized: true,
+            cost: lookup.cost,
+            provenance: lookup.provenance,
+            seen: 0,
+        });
+    }
+    
+    for (name, count) in get_cost_database().seen() {
+        let canonical = canonical_name(name);
+        match operations.iter_mut().find(|known| known.operation == canonical) {
+            Some(known) => known.seen += count,
+            None => {
+                let lookup = lookup_cost(name, None)?;
+                operations.push(OperationCoverage {
+                    operation: name.clone(),
+                    recognized: false,
+                    cost: lookup.cost,
+                    provenance: lookup.provenance,
+                    seen: *count,
+                });
+            },
+        }
+    }
+    
+    operations.sort_by(|a, b| b.seen.cmp(&a.seen).then(a.operation.cmp(&b.operation)));
+    Ok(operations)
+}
+
+pub fn coverage_percent(operations: &[OperationCoverage]) -> Option<f64> {
+    let seen: Vec<_> = operations.iter().filter(|op| op.seen > 0).collect();
+    if seen.is_empty() {
+        return None;
+    }
+    
+    let modeled = seen.iter().filter(|op| op.provenance != Provenance::Fallback).count();
+    Some(modeled as f64 / seen.len() as f64 * 100.0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn sizes_convert_to_hash_blocks() {
+        assert_eq!(size_units("sha256", 55), Some((1, "block")));
+        assert_eq!(size_units("sha256", 56), Some((2, "block")));
+        assert_eq!(size_units("keccak256", 136), Some((2, "block")));
+        assert_eq!(size_units("pedersen_hash", 0), Some((1, "field element")));
+        assert_eq!(size_units("range", 8), None);
+    }
+    
+    #[test]
+    fn msm_lookups_use_the_curve_model() {
+        let lookup = lookup_cost("multi_scalar_mul", Some(3)).unwrap();
+        
+        assert_eq!(lookup.provenance, Provenance::Modeled);
+        assert_eq!(lookup.cost, variable_base_msm(3));
+        assert_eq!(lookup.variants.len(), 2 * BACKEND_PROFILES.len());
+        assert!(lookup_cost("range", Some(8)).unwrap_err().to_string().starts_with("--size does not apply to range"));
+    }
+    
+    #[test]
+    fn fixed_models_resolve_aliases_and_defaults() {
+        let model = CostModel::Fixed(HashMap::from([("keccak256".to_string(), 7)]));
+        
+        assert_eq!(model.operation_cost("Keccak"), (7, 1.0));
+        assert_eq!(model.operation_cost("mystery_gadget"), (FALLBACK_COST, 0.83));
+        assert!(model.is_fallback("mystery_gadget") && !model.is_fallback("keccak256"));
+        assert!(!model.updates_database() && CostModel::Calibrated.updates_database());
+    }
+    
+    #[test]
+    fn coverage_counts_only_seen_operations() {
+        let coverage = |provenance, seen| OperationCoverage { operation: String::new(), recognized: true, cost: 1, provenance, seen };
+        
+        assert_eq!(coverage_percent(&[coverage(Provenance::Default, 0)]), None);
+        assert_eq!(coverage_percent(&[coverage(Provenance::Default, 2), coverage(Provenance::Fallback, 1), coverage(Provenance::Fallback, 0)]), Some(50.0));
+    }
+    
+    #[test]
+    fn coverage_lists_every_known_operation() {
+        let operations = model_coverage().unwrap();
+        
+        for name in known_names() {
+            assert!(operations.iter().any(|op| op.operation == name.canonical && op.recognized));
+        }
+        assert!(operations.windows(2).all(|pair| pair[0].seen >= pair[1].seen));
+    }
+}
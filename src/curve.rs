@@ -0,0 +1,205 @@
+use crate::names::canonical_name;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+pub const SCALAR_BITS: u32 = 254;
+pub const LIMB_BITS: u32 = 128;
+
+pub const MSM_BASE_COST: usize = 150;
+pub const DOUBLE_PER_BIT: f64 = 6.0;
+pub const VARIABLE_ADD_PER_BIT: f64 = 4.0;
+pub const FIXED_ADD_PER_BIT: f64 = 1.0;
+pub const CURVE_ADD_COST: usize = 12;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct MsmTerm {
+    pub fixed: bool,
+    pub scalar_bits: u32,
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CurveOperation {
+    pub site: usize,
+    pub function: String,
+    pub points: usize,
+    pub fixed_points: usize,
+    pub scalar_bits: u32,
+    pub constraints: usize,
+}
+
+impl CurveOperation {
+    pub fn fixed_base(&self) -> bool {
+        self.points > 0 && self.fixed_points == self.points
+    }
+}
+
+fn is_constant(input: &Value) -> bool {
+    input.get("variable").is_none() && input.get("constant").is_some()
+}
+
+fn limb_bits(input: &Value) -> u32 {
+    if is_constant(input) {
+        return match input["constant"].as_str() {
+            Some("0") | Some("0x0") => 0,
+            _ => LIMB_BITS,
+        };
+    }
+    
+    input["num_bits"].as_u64().map_or(LIMB_BITS, |bits| (bits as u32).min(LIMB_BITS))
+}
+
+fn scalar_bits(lo: &Value, hi: &Value) -> u32 {
+    match limb_bits(hi) {
+        0 => limb_bits(lo),
+        hi_bits => (LIMB_BITS + hi_bits).min(SCALAR_BITS),
+    }
+}
+
+fn flatten(inputs: &Value) -> Vec<&Value> {
+    inputs.as_array()
+        .map(|inputs| inputs.iter()
+            .flat_map(|input| match input.as_array() {
+                Some(nested) => nested.iter().collect::<Vec<_>>(),
+                None => vec![input],
+            })
+            .collect())
+        .unwrap_or_default()
+}
+
+fn msm_terms(op: &Value) -> Vec<MsmTerm> {
+    let raw: String = op["function"].as_str().unwrap_or("")
+        .chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect();
+    
+    let (points, scalars) = if op["points"].is_array() && op["scalars"].is_array() {
+        (flatten(&op["points"]), flatten(&op["scalars"]))
+    } else {
+        let inputs = flatten(&op["inputs"]);
+        
+        if raw == "fixedbasescalarmul" && inputs.len() >= 2 {
+            return vec![MsmTerm { fixed: true, scalar_bits: scalar_bits(inputs[0], inputs[1]) }];
+        }
+        
+        let width = if inputs.len().is_multiple_of(5) { 5 } else if inputs.len().is_multiple_of(4) { 4 } else { 0 };
+        if width == 0 {
+            return vec![MsmTerm { fixed: false, scalar_bits: SCALAR_BITS }];
+        }
+        
+        let count = inputs.len() / width;
+        let (points, scalars) = inputs.split_at(count * (width - 2));
+        (points.to_vec(), scalars.to_vec())
+    };
+    
+    let count = scalars.len() / 2;
+    if count == 0 {
+        return vec![MsmTerm { fixed: false, scalar_bits: SCALAR_BITS }];
+    }
+    
+    let point_width = (points.len() / count).max(1);
+    (0..count)
+        .map(|i| MsmTerm {
+            fixed: points.chunks(point_width).nth(i).is_some_and(|coords| coords.iter().all(|coord| is_constant(coord))),
+            scalar_bits: scalar_bits(scalars[2 * i], scalars[2 * i + 1]),
+        })
+        .collect()
+}
+
+pub fn msm_cost(terms: &[MsmTerm]) -> usize {
+    let doublings = terms.iter()
+        .filter(|term| !term.fixed)
+        .map(|term| term.scalar_bits)
+        .max()
+        .unwrap_or(0);
+    
+    let additions: f64 = terms.iter()
+        .map(|term| {
+            let per_bit = if term.fixed { FIXED_ADD_PER_BIT } else { VARIABLE_ADD_PER_BIT };
+            term.scalar_bits as f64 * per_bit
+        })
+        .sum();
+    
+    MSM_BASE_COST + (doublings as f64 * DOUBLE_PER_BIT + additions).round() as usize
+}
+
+pub fn model_cost(op: &Value) -> Option<usize> {
+    match canonical_name(op["function"].as_str()?).as_str() {
+        "multi_scalar_mul" => Some(msm_cost(&msm_terms(op))),
+        "embedded_curve_add" => Some(CURVE_ADD_COST),
+        _ => None,
+    }
+}
+
+pub fn curve_operations(opcodes: &[Value], opcode_costs: &[usize]) -> Vec<CurveOperation> {
+    opcodes.iter()
+        .enumerate()
+        .filter(|(_, op)| op["type"].as_str() == Some("BlackBoxFunction"))
+        .filter_map(|(site, op)| {
+            let function = canonical_name(op["function"].as_str()?);
+            let terms = match function.as_str() {
+                "multi_scalar_mul" => msm_terms(op),
+                "embedded_curve_add" => Vec::new(),
+                _ => return None,
+            };
+            
+            Some(CurveOperation {
+                site,
+                function,
+                points: terms.len(),
+                fixed_points: terms.iter().filter(|term| term.fixed).count(),
+                scalar_bits: terms.iter().map(|term| term.scalar_bits).max().unwrap_or(0),
+                constraints: opcode_costs.get(site).copied().unwrap_or(0),
+            })
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn msm() -> Value {
+        json!({
+            "type": "BlackBoxFunction",
+            "function": "multi_scalar_mul",
+            "points": [
+                [{ "constant": "1" }, { "constant": "2" }, { "constant": "0" }],
+                [{ "variable": "x" }, { "variable": "y" }, { "constant": "0" }],
+            ],
+            "scalars": [
+                [{ "variable": "lo0", "num_bits": 64 }, { "constant": "0" }],
+                [{ "variable": "lo1" }, { "variable": "hi1" }],
+            ],
+        })
+    }
+    
+    #[test]
+    fn reads_fixed_points_and_scalar_widths() {
+        assert_eq!(msm_terms(&msm()), [
+            MsmTerm { fixed: true, scalar_bits: 64 },
+            MsmTerm { fixed: false, scalar_bits: SCALAR_BITS },
+        ]);
+    }
+    
+    #[test]
+    fn costs_doublings_once_and_additions_per_term() {
+        let expected = MSM_BASE_COST + SCALAR_BITS as usize * 6 + 64 + SCALAR_BITS as usize * 4;
+        
+        assert_eq!(model_cost(&msm()), Some(expected));
+        assert_eq!(model_cost(&json!({ "function": "embedded_curve_add" })), Some(CURVE_ADD_COST));
+        assert_eq!(model_cost(&json!({ "function": "sha256" })), None);
+    }
+    
+    #[test]
+    fn lists_curve_operations_with_their_sites() {
+        let opcodes = [json!({ "type": "AssertZero" }), msm(), json!({ "type": "BlackBoxFunction", "function": "embedded_curve_add" })];
+        let operations = curve_operations(&opcodes, &[1, 2000, 12]);
+        
+        assert_eq!(operations.len(), 2);
+        assert_eq!((operations[0].site, operations[0].points, operations[0].fixed_points), (1, 2, 1));
+        assert!(!operations[0].fixed_base() && !operations[1].fixed_base());
+    }
+}
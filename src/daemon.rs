@@ -0,0 +1,130 @@
+use crate::analyzer::{AnalysisLimits, AnalysisOptions};
+use crate::calls::ProvingStrategy;
+use crate::core::CircuitAnalysis;
+use crate::exact::ExpressionWidth;
+use anyhow::{anyhow, Context, Result};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const SOCKET_ENV: &str = "NOIR_PROFILER_SOCKET";
+pub const SOCKET_FILE: &str = "noir-profiler.sock";
+
+pub fn socket_path() -> PathBuf {
+    match std::env::var(SOCKET_ENV) {
+        Ok(path) if !path.is_empty() => PathBuf::from(path),
+        _ => std::env::temp_dir().join(SOCKET_FILE),
+    }
+}
+
+#[derive(Debug)]
+pub struct DaemonUnavailable {
+    pub socket: PathBuf,
+    pub reason: String,
+}
+
+impl fmt::Display for DaemonUnavailable {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "no daemon listening on {} ({})", self.socket.display(), self.reason)
+    }
+}
+
+impl std::error::Error for DaemonUnavailable {}
+
+#[derive(Debug, Serialize, Deserialize)]
+#[serde(tag = "command", rename_all = "lowercase")]
+pub enum DaemonRequest {
+    Analyze {
+        path: PathBuf,
+        expression_width: ExpressionWidth,
+        proving_strategy: ProvingStrategy,
+        #[serde(default)]
+        limits: AnalysisLimits,
+    },
+    Shutdown,
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct DaemonResponse {
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub analysis: Option<CircuitAnalysis>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub error: Option<String>,
+    #[serde(default)]
+    pub cached: bool,
+}
+
+#[cfg(unix)]
+fn exchange(socket: &Path, request: &DaemonRequest) -> Result<DaemonResponse> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::UnixStream;
+    
+    let mut stream = UnixStream::connect(socket)
+        .map_err(|e| DaemonUnavailable { socket: socket.to_path_buf(), reason: e.to_string() })?;
+    
+    writeln!(stream, "{}", serde_json::to_string(request)?)
+        .with_context(|| format!("Failed to send request to {}", socket.display()))?;
+    
+    let mut line = String::new();
+    BufReader::new(&stream).read_line(&mut line)
+        .with_context(|| format!("Failed to read response from {}", socket.display()))?;
+    
+    serde_json::from_str(&line)
+        .with_context(|| format!("Invalid response from daemon on {}", socket.display()))
+}
+
+#[cfg(not(unix))]
+fn exchange(socket: &Path, _request: &DaemonRequest) -> Result<DaemonResponse> {
+    Err(DaemonUnavailable { socket: socket.to_path_buf(), reason: "unix domain sockets are not supported".to_string() }.into())
+}
+
+pub fn request_analysis(socket: &Path, path: &Path, options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    let path = fs::canonicalize(path)
+        .with_context(|| format!("Failed to read circuit file: {}", path.display()))?;
+    
+    let response = exchange(socket, &DaemonRequest::Analyze {
+        path,
+        expression_width: options.expression_width,
+        proving_strategy: options.proving_strategy,
+        limits: options.limits.clone(),
+    })?;
+    
+    match (response.analysis, response.error) {
+        (Some(analysis), _) => Ok(analysis),
+        (None, Some(error)) => Err(anyhow!(error)),
+        (None, None) => Err(anyhow!("Empty response from daemon on {}", socket.display())),
+    }
+}
+
+pub fn request_shutdown(socket: &Path) -> Result<()> {
+    exchange(socket, &DaemonRequest::Shutdown).map(|_| ())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn requests_are_tagged_by_command() {
+        let request = DaemonRequest::Analyze {
+            path: PathBuf::from("circuit.json"),
+            expression_width: ExpressionWidth::Unbounded,
+            proving_strategy: ProvingStrategy::Inline,
+            limits: AnalysisLimits::default(),
+        };
+        let json = serde_json::to_value(&request).unwrap();
+        
+        assert_eq!(json["command"], "analyze");
+        assert_eq!(json["expression_width"], "unbounded");
+        assert_eq!(serde_json::to_string(&DaemonRequest::Shutdown).unwrap(), r#"{"command":"shutdown"}"#);
+    }
+    
+    #[test]
+    fn missing_daemons_are_reported_as_unavailable() {
+        let socket = std::env::temp_dir().join(format!("noir-profiler-absent-{}.sock", std::process::id()));
+        let error = request_shutdown(&socket).unwrap_err();
+        
+        assert!(error.downcast_ref::<DaemonUnavailable>().is_some());
+    }
+}
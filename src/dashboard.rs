@@ -0,0 +1,115 @@
+use crate::core::get_cost_database;
+use crate::init::BASELINE_DIR;
+use crate::report::load_report;
+use crate::usage::{biggest_regression, load_usage, UsageChange};
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+
+pub const CALIBRATION_STALE_DAYS: i64 = 30;
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct Dashboard {
+    pub baseline_dir: String,
+    pub circuits: usize,
+    pub aggregate_constraints: usize,
+    pub estimated_proving_time: f64,
+    pub largest: Option<(String, usize)>,
+    pub unreadable_baselines: usize,
+    pub recent_analyses: usize,
+    pub regression: Option<UsageChange>,
+    pub calibrated_operations: usize,
+    pub calibration_updated: Option<String>,
+    pub calibration_age_days: Option<i64>,
+}
+
+impl Dashboard {
+    pub fn calibration_stale(&self) -> bool {
+        self.calibration_age_days.is_none_or(|days| days > CALIBRATION_STALE_DAYS)
+    }
+}
+
+pub fn build_dashboard(project: &Path, log: &Path, since: NaiveDate) -> Result<Dashboard> {
+    let baseline_dir = project.join(BASELINE_DIR);
+    let mut dashboard = Dashboard { baseline_dir: baseline_dir.display().to_string(), ..Default::default() };
+    
+    if baseline_dir.is_dir() {
+        let mut reports: Vec<_> = fs::read_dir(&baseline_dir)
+            .with_context(|| format!("Failed to read baselines: {}", baseline_dir.display()))?
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        reports.sort();
+        
+        for path in reports {
+            let Ok(analysis) = load_report(&path) else {
+                dashboard.unreadable_baselines += 1;
+                continue;
+            };
+            
+            let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+            dashboard.circuits += 1;
+            dashboard.aggregate_constraints = dashboard.aggregate_constraints.saturating_add(analysis.aggregate_constraints);
+            dashboard.estimated_proving_time += analysis.estimated_proving_time;
+            if dashboard.largest.as_ref().is_none_or(|(_, largest)| analysis.aggregate_constraints > *largest) {
+                dashboard.largest = Some((name, analysis.aggregate_constraints));
+            }
+        }
+    }
+    
+    let (entries, _) = load_usage(log)?;
+    dashboard.recent_analyses = entries.iter()
+        .filter(|entry| entry.date().is_some_and(|date| date >= since))
+        .count();
+    dashboard.regression = biggest_regression(&entries, Some(since));
+    
+    let db = get_cost_database();
+    dashboard.calibrated_operations = db.iter().count();
+    dashboard.calibration_updated = db.last_updated().cloned();
+    dashboard.calibration_age_days = db.last_updated()
+        .and_then(|updated| DateTime::parse_from_rfc3339(updated).ok())
+        .map(|updated| (chrono::Local::now().date_naive() - updated.date_naive()).num_days());
+    
+    Ok(dashboard)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::usage::{record_usage, UsageEntry};
+    
+    #[test]
+    fn aggregates_baselines_and_recent_usage() {
+        let project = std::env::temp_dir().join(format!("noir-profiler-dashboard-{}", std::process::id()));
+        let baselines = project.join(BASELINE_DIR);
+        fs::create_dir_all(&baselines).unwrap();
+        fs::write(baselines.join("main.json"), r#"{ "aggregate_constraints": 300, "estimated_proving_time": 1.5 }"#).unwrap();
+        fs::write(baselines.join("leaf.json"), r#"{ "aggregate_constraints": 100, "estimated_proving_time": 0.5 }"#).unwrap();
+        fs::write(baselines.join("broken.json"), "not json").unwrap();
+        fs::write(baselines.join("notes.txt"), "ignored").unwrap();
+        
+        let log = project.join("usage.jsonl");
+        for (timestamp, constraints) in [("2026-01-01T00:00:00Z", 100), ("2026-02-01T00:00:00Z", 120), ("2026-02-02T00:00:00Z", 180)] {
+            let entry = UsageEntry { timestamp: timestamp.into(), circuit: "main".into(), constraints, total_opcodes: 0, black_box_functions: Vec::new() };
+            record_usage(&log, &entry).unwrap();
+        }
+        
+        let dashboard = build_dashboard(&project, &log, NaiveDate::from_ymd_opt(2026, 2, 1).unwrap());
+        fs::remove_dir_all(&project).unwrap();
+        let dashboard = dashboard.unwrap();
+        
+        assert_eq!((dashboard.circuits, dashboard.unreadable_baselines), (2, 1));
+        assert_eq!((dashboard.aggregate_constraints, dashboard.estimated_proving_time), (400, 2.0));
+        assert_eq!(dashboard.largest, Some(("main".to_string(), 300)));
+        assert_eq!(dashboard.recent_analyses, 2);
+        assert_eq!(dashboard.regression.map(|change| (change.before, change.after)), Some((120, 180)));
+    }
+    
+    #[test]
+    fn missing_calibration_is_stale() {
+        assert!(Dashboard::default().calibration_stale());
+        assert!(!Dashboard { calibration_age_days: Some(CALIBRATION_STALE_DAYS), ..Default::default() }.calibration_stale());
+    }
+}
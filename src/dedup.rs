@@ -0,0 +1,101 @@
+use crate::progress::Deadline;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DuplicateCall {
+    pub function: String,
+    pub inputs: Vec<String>,
+    pub sites: Vec<usize>,
+    pub cost_each: usize,
+    pub reclaimable: usize,
+}
+
+fn input_key(input: &Value) -> String {
+    match input["variable"].as_str() {
+        Some(variable) => variable.to_string(),
+        None => input.to_string(),
+    }
+}
+
+pub fn find_duplicate_calls(opcodes: &[Value], opcode_costs: &[usize], deadline: &Deadline) -> Vec<DuplicateCall> {
+    let mut groups: HashMap<(String, Vec<String>), Vec<usize>> = HashMap::new();
+    
+    for (idx, op) in opcodes.iter().enumerate() {
+        if deadline.expired_at(idx) {
+            break;
+        }
+        
+        if op["type"].as_str() != Some("BlackBoxFunction") {
+            continue;
+        }
+        
+        let inputs: Vec<String> = match op["inputs"].as_array() {
+            Some(inputs) if !inputs.is_empty() => inputs.iter().map(input_key).collect(),
+            _ => continue,
+        };
+        
+        let function = op["function"].as_str().unwrap_or("unknown").to_string();
+        groups.entry((function, inputs)).or_default().push(idx);
+    }
+    
+    let mut duplicates: Vec<DuplicateCall> = groups.into_iter()
+        .filter(|(_, sites)| sites.len() > 1)
+        .map(|((function, inputs), sites)| {
+            let cost_each = opcode_costs.get(sites[0]).copied().unwrap_or(0);
+            
+            DuplicateCall {
+                function,
+                inputs,
+                reclaimable: cost_each.saturating_mul(sites.len() - 1),
+                cost_each,
+                sites,
+            }
+        })
+        .collect();
+    
+    duplicates.sort_by(|a, b| b.reclaimable.cmp(&a.reclaimable).then(a.sites[0].cmp(&b.sites[0])));
+    duplicates
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::progress::CancellationToken;
+    use serde_json::json;
+    
+    fn call(function: &str, inputs: &[&str]) -> Value {
+        let inputs: Vec<Value> = inputs.iter().map(|input| json!({ "variable": input })).collect();
+        json!({ "type": "BlackBoxFunction", "function": function, "inputs": inputs })
+    }
+    
+    #[test]
+    fn groups_calls_on_identical_inputs() {
+        let opcodes = [
+            call("sha256", &["a", "b"]),
+            call("keccak256", &["a"]),
+            call("sha256", &["a", "b"]),
+            call("sha256", &["b", "a"]),
+            call("keccak256", &["a"]),
+            call("sha256", &["a", "b"]),
+            call("keccak256", &[]),
+            call("keccak256", &[]),
+        ];
+        let duplicates = find_duplicate_calls(&opcodes, &[10, 50, 10, 10, 50, 10, 50, 50], &Deadline::default());
+        
+        assert_eq!(duplicates.len(), 2);
+        assert_eq!((duplicates[0].function.as_str(), duplicates[0].reclaimable), ("keccak256", 50));
+        assert_eq!(duplicates[1].sites, [0, 2, 5]);
+        assert_eq!(duplicates[1].reclaimable, 20);
+    }
+    
+    #[test]
+    fn cancelled_scans_stop_early() {
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let opcodes = [call("sha256", &["a"]), call("sha256", &["a"])];
+        
+        assert!(find_duplicate_calls(&opcodes, &[10, 10], &Deadline::new(None, Some(cancel))).is_empty());
+    }
+}
@@ -0,0 +1,175 @@
+use crate::core::CircuitAnalysis;
+use crate::names::canonical_name;
+use crate::regression::{delta_interval, relative_spread, DeltaInterval, NoiseFloor};
+use serde::Serialize;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Change {
+    pub op: &'static str,
+    pub path: String,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<f64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<f64>,
+    pub delta: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub percent: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct AnalysisDelta {
+    pub old: String,
+    pub new: String,
+    pub changes: Vec<Change>,
+    pub noise_floor: NoiseFloor,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub within_noise: Vec<String>,
+    pub proving_time: DeltaInterval,
+    pub verdict: &'static str,
+}
+
+fn escape(segment: &str) -> String {
+    segment.replace('~', "~0").replace('/', "~1")
+}
+
+fn change(path: String, old: Option<f64>, new: Option<f64>) -> Option<Change> {
+    let op = match (old, new) {
+        (None, Some(_)) => "add",
+        (Some(_), None) => "remove",
+        (Some(old), Some(new)) if old != new => "replace",
+        _ => return None,
+    };
+    
+    let (before, after) = (old.unwrap_or(0.0), new.unwrap_or(0.0));
+    Some(Change {
+        op,
+        path,
+        old,
+        new,
+        delta: after - before,
+        percent: (before != 0.0).then(|| (after - before) / before * 100.0),
+    })
+}
+
+fn keyed_changes(prefix: &str, old: BTreeMap<String, f64>, new: BTreeMap<String, f64>) -> Vec<Change> {
+    let mut keys: Vec<&String> = old.keys().chain(new.keys()).collect();
+    keys.sort();
+    keys.dedup();
+    
+    keys.into_iter()
+        .filter_map(|key| change(format!("{}/{}", prefix, escape(key)), old.get(key).copied(), new.get(key).copied()))
+        .collect()
+}
+
+fn black_box_totals(analysis: &CircuitAnalysis) -> (BTreeMap<String, f64>, BTreeMap<String, f64>) {
+    let mut calls = BTreeMap::new();
+    let mut constraints = BTreeMap::new();
+    
+    for (name, count, cost) in &analysis.black_box_functions {
+        let name = canonical_name(name);
+        *calls.entry(name.clone()).or_insert(0.0) += *count as f64;
+        *constraints.entry(name).or_insert(0.0) += count.saturating_mul(*cost) as f64;
+    }
+    
+    (calls, constraints)
+}
+
+pub type MetricFn = fn(&CircuitAnalysis) -> f64;
+
+pub fn analysis_delta(old_name: &str, old: &CircuitAnalysis, new_name: &str, new: &CircuitAnalysis, noise: NoiseFloor) -> AnalysisDelta {
+    let scalars: [(&str, MetricFn); 7] = [
+        ("constraints", |analysis| analysis.constraints as f64),
+        ("aggregate_constraints", |analysis| analysis.aggregate_constraints as f64),
+        ("total_opcodes", |analysis| analysis.total_opcodes as f64),
+        ("public_inputs", |analysis| analysis.public_inputs as f64),
+        ("private_inputs", |analysis| analysis.private_inputs as f64),
+        ("proof_count", |analysis| analysis.proof_count as f64),
+        ("estimated_proving_time", |analysis| analysis.estimated_proving_time),
+    ];
+    
+    let mut changes: Vec<Change> = scalars.iter()
+        .filter_map(|(metric, value)| change(format!("/{}", metric), Some(value(old)), Some(value(new))))
+        .collect();
+    
+    let categories = |analysis: &CircuitAnalysis| analysis.categories.iter()
+        .map(|(category, constraints)| (category.to_string(), *constraints as f64))
+        .collect();
+    changes.extend(keyed_changes("/categories", categories(old), categories(new)));
+    
+    let operations = |analysis: &CircuitAnalysis| analysis.operation_counts.iter()
+        .map(|(op, count)| (op.clone(), *count as f64))
+        .collect();
+    changes.extend(keyed_changes("/operation_counts", operations(old), operations(new)));
+    
+    let (old_calls, old_constraints) = black_box_totals(old);
+    let (new_calls, new_constraints) = black_box_totals(new);
+    changes.extend(keyed_changes("/black_box_functions/calls", old_calls, new_calls));
+    changes.extend(keyed_changes("/black_box_functions/constraints", old_constraints, new_constraints));
+    
+    let (within_noise, changes): (Vec<Change>, Vec<Change>) = changes.into_iter()
+        .partition(|change| matches!((change.old, change.new), (Some(old), Some(new)) if noise.within(old, new)));
+    
+    let proving_time = delta_interval(
+        old.estimated_proving_time, relative_spread(old.confidence),
+        new.estimated_proving_time, relative_spread(new.confidence));
+    let verdict = if noise.within(old.estimated_proving_time, new.estimated_proving_time) {
+        "within noise"
+    } else {
+        proving_time.verdict()
+    };
+    
+    AnalysisDelta {
+        old: old_name.to_string(),
+        new: new_name.to_string(),
+        changes,
+        noise_floor: noise,
+        within_noise: within_noise.into_iter().map(|change| change.path).collect(),
+        verdict,
+        proving_time,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn changes_are_classified_and_escaped() {
+        assert_eq!(escape("a/b~c"), "a~1b~0c");
+        assert_eq!(change("/x".into(), None, Some(2.0)).unwrap().op, "add");
+        assert_eq!(change("/x".into(), Some(2.0), None).unwrap().delta, -2.0);
+        assert!(change("/x".into(), Some(2.0), Some(2.0)).is_none());
+        
+        let replaced = change("/x".into(), Some(4.0), Some(5.0)).unwrap();
+        assert_eq!((replaced.op, replaced.percent), ("replace", Some(25.0)));
+        assert_eq!(change("/x".into(), Some(0.0), Some(5.0)).unwrap().percent, None);
+    }
+    
+    #[test]
+    fn black_box_aliases_share_a_path() {
+        let old = CircuitAnalysis { constraints: 1000, black_box_functions: vec![("Keccak".into(), 2, 100)], ..Default::default() };
+        let new = CircuitAnalysis { constraints: 1500, black_box_functions: vec![("keccak256".into(), 3, 100)], ..Default::default() };
+        let delta = analysis_delta("old", &old, "new", &new, NoiseFloor::default());
+        
+        let paths: Vec<(&str, &str)> = delta.changes.iter().map(|change| (change.op, change.path.as_str())).collect();
+        assert_eq!(paths, [
+            ("replace", "/constraints"),
+            ("replace", "/black_box_functions/calls/keccak256"),
+            ("replace", "/black_box_functions/constraints/keccak256"),
+        ]);
+        assert_eq!(delta.changes[2].delta, 100.0);
+    }
+    
+    #[test]
+    fn small_changes_fall_within_noise() {
+        let old = CircuitAnalysis { constraints: 1000, total_opcodes: 100, estimated_proving_time: 2.0, ..Default::default() };
+        let new = CircuitAnalysis { constraints: 1005, total_opcodes: 150, estimated_proving_time: 2.01, ..Default::default() };
+        let delta = analysis_delta("old", &old, "new", &new, NoiseFloor(1.0));
+        
+        assert_eq!(delta.within_noise, ["/constraints", "/estimated_proving_time"]);
+        assert_eq!(delta.changes.len(), 1);
+        assert_eq!(delta.changes[0].path, "/total_opcodes");
+        assert_eq!(delta.verdict, "within noise");
+    }
+}
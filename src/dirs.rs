@@ -0,0 +1,167 @@
+use crate::attribution::{self, has_debug_info};
+use crate::core::CircuitAnalysis;
+use anyhow::Result;
+use std::collections::HashMap;
+use std::path::Path;
+
+pub enum Pairing<'a> {
+    Matched { a: &'a str, b: &'a str },
+    OnlyA(&'a str),
+    OnlyB(&'a str),
+}
+
+fn file_name(name: &str) -> &str {
+    Path::new(name).file_name().and_then(|name| name.to_str()).unwrap_or(name)
+}
+
+pub fn pair_artifacts<'a>(names_a: &[&'a str], names_b: &[&'a str]) -> Vec<Pairing<'a>> {
+    let mut unmatched_b: Vec<&str> = names_b.to_vec();
+    let mut pairs = Vec::new();
+    let mut leftover_a = Vec::new();
+    
+    for name in names_a {
+        match unmatched_b.iter().position(|other| other == name) {
+            Some(idx) => pairs.push(Pairing::Matched { a: name, b: unmatched_b.remove(idx) }),
+            None => leftover_a.push(*name),
+        }
+    }
+    
+    let mut by_file: HashMap<&str, Vec<&str>> = HashMap::new();
+    for name in &unmatched_b {
+        by_file.entry(file_name(name)).or_default().push(name);
+    }
+    let a_counts = leftover_a.iter().fold(HashMap::new(), |mut counts: HashMap<&str, usize>, name| {
+        *counts.entry(file_name(name)).or_default() += 1;
+        counts
+    });
+    
+    for name in leftover_a {
+        let key = file_name(name);
+        match by_file.get(key) {
+            Some(candidates) if candidates.len() == 1 && a_counts[key] == 1 => {
+                let b = candidates[0];
+                unmatched_b.retain(|other| *other != b);
+                pairs.push(Pairing::Matched { a: name, b });
+            },
+            _ => pairs.push(Pairing::OnlyA(name)),
+        }
+    }
+    
+    pairs.extend(unmatched_b.into_iter().map(Pairing::OnlyB));
+    pairs
+}
+
+pub struct DirComparison {
+    pub circuit: String,
+    pub before: Option<usize>,
+    pub after: Option<usize>,
+    pub time_before: Option<f64>,
+    pub time_after: Option<f64>,
+    pub blame: Vec<(String, i64)>,
+}
+
+impl DirComparison {
+    pub fn diff(&self) -> Option<i64> {
+        Some(self.after? as i64 - self.before? as i64)
+    }
+    
+    pub fn percent(&self) -> Option<f64> {
+        let before = self.before?;
+        (before > 0).then(|| self.diff().unwrap_or(0) as f64 / before as f64 * 100.0)
+    }
+}
+
+fn successful(results: &[(String, Result<CircuitAnalysis>)]) -> Vec<(&str, &CircuitAnalysis)> {
+    results.iter()
+        .filter_map(|(name, result)| result.as_ref().ok().map(|analysis| (name.as_str(), analysis)))
+        .collect()
+}
+
+pub fn compare_results<'a>(
+    results_a: &'a [(String, Result<CircuitAnalysis>)],
+    results_b: &'a [(String, Result<CircuitAnalysis>)]
+) -> Vec<DirComparison> {
+    let (a, b) = (successful(results_a), successful(results_b));
+    let lookup = |side: &[(&str, &'a CircuitAnalysis)], name: &str| side.iter().find(|(other, _)| *other == name).map(|(_, analysis)| *analysis);
+    
+    let names_a: Vec<&str> = a.iter().map(|(name, _)| *name).collect();
+    let names_b: Vec<&str> = b.iter().map(|(name, _)| *name).collect();
+    
+    let mut rows: Vec<DirComparison> = pair_artifacts(&names_a, &names_b).into_iter()
+        .map(|pairing| {
+            let (circuit, before, after) = match pairing {
+                Pairing::Matched { a: name_a, b: name_b } if name_a == name_b => (name_a.to_string(), lookup(&a, name_a), lookup(&b, name_b)),
+                Pairing::Matched { a: name_a, b: name_b } => (format!("{} → {}", name_a, name_b), lookup(&a, name_a), lookup(&b, name_b)),
+                Pairing::OnlyA(name) => (name.to_string(), lookup(&a, name), None),
+                Pairing::OnlyB(name) => (name.to_string(), None, lookup(&b, name)),
+            };
+            
+            let blame = match (before, after) {
+                (Some(before), Some(after)) if has_debug_info(before) && has_debug_info(after) => attribution::blame(before, after),
+                _ => Vec::new(),
+            };
+            
+            DirComparison {
+                circuit,
+                before: before.map(|analysis| analysis.aggregate_constraints),
+                after: after.map(|analysis| analysis.aggregate_constraints),
+                time_before: before.map(|analysis| analysis.estimated_proving_time),
+                time_after: after.map(|analysis| analysis.estimated_proving_time),
+                blame,
+            }
+        })
+        .collect();
+    
+    rows.sort_by(|x, y| y.diff().unwrap_or(i64::MIN).cmp(&x.diff().unwrap_or(i64::MIN)).then(x.circuit.cmp(&y.circuit)));
+    rows
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn analysis(constraints: usize) -> Result<CircuitAnalysis> {
+        Ok(CircuitAnalysis { aggregate_constraints: constraints, ..Default::default() })
+    }
+    
+    #[test]
+    fn pairs_moved_artifacts_by_unique_file_name() {
+        let pairs = pair_artifacts(&["a.json", "old/b.json", "x/c.json", "y/c.json"], &["a.json", "new/b.json", "z/c.json", "d.json"]);
+        let labels: Vec<String> = pairs.iter().map(|pairing| match pairing {
+            Pairing::Matched { a, b } => format!("{}={}", a, b),
+            Pairing::OnlyA(name) => format!("{}-", name),
+            Pairing::OnlyB(name) => format!("+{}", name),
+        }).collect();
+        
+        assert_eq!(labels, ["a.json=a.json", "old/b.json=new/b.json", "x/c.json-", "y/c.json-", "+z/c.json", "+d.json"]);
+    }
+    
+    #[test]
+    fn rows_are_sorted_by_growth() {
+        let before = vec![("a.json".to_string(), analysis(100)), ("b.json".to_string(), analysis(100)), ("gone.json".to_string(), analysis(5))];
+        let after = vec![("a.json".to_string(), analysis(150)), ("b.json".to_string(), analysis(80)), ("c.json".to_string(), Err(anyhow::anyhow!("broken")))];
+        let rows = compare_results(&before, &after);
+        
+        let circuits: Vec<&str> = rows.iter().map(|row| row.circuit.as_str()).collect();
+        assert_eq!(circuits, ["a.json", "b.json", "gone.json"]);
+        assert_eq!((rows[0].diff(), rows[0].percent()), (Some(50), Some(50.0)));
+        assert_eq!((rows[2].diff(), rows[2].after), (None, None));
+    }
+    
+    #[test]
+    fn blames_functions_when_both_sides_have_debug_info() {
+        let traced = |entries: &[(&str, usize)], debug_info: bool| -> Result<CircuitAnalysis> {
+            let function_ranges = entries.iter()
+                .map(|(function, constraints)| attribution::FunctionRanges { function: function.to_string(), acir_function: 0, ranges: Vec::new(), opcodes: 1, constraints: *constraints, debug_info })
+                .collect();
+            Ok(CircuitAnalysis { aggregate_constraints: entries.iter().map(|(_, constraints)| constraints).sum(), function_ranges, ..Default::default() })
+        };
+        
+        let before = vec![("main.json".to_string(), traced(&[("main", 10), ("hash", 40)], true))];
+        let after = vec![("main.json".to_string(), traced(&[("main", 12), ("hash", 90)], true))];
+        assert_eq!(compare_results(&before, &after)[0].blame, [("hash".to_string(), 50), ("main".to_string(), 2)]);
+        
+        let stripped = vec![("main.json".to_string(), traced(&[("main", 12), ("hash", 90)], false))];
+        assert!(compare_results(&before, &stripped)[0].blame.is_empty());
+    }
+}
@@ -0,0 +1,82 @@
+use crate::cloud::InstanceProfile;
+use crate::gpu::GpuProfile;
+use anyhow::{bail, Result};
+
+pub const JOULES_PER_KWH: f64 = 3_600_000.0;
+
+#[derive(Debug, Clone)]
+pub struct EnergyModel {
+    pub source: String,
+    pub joules_per_constraint: f64,
+}
+
+#[derive(Debug)]
+pub struct EnergyEstimate {
+    pub source: String,
+    pub joules_per_constraint: f64,
+    pub joules_per_proof: f64,
+    pub kwh_per_million: f64,
+}
+
+impl EnergyModel {
+    pub fn resolve(
+        joules_per_constraint: Option<f64>,
+        gpu: Option<GpuProfile>,
+        instance: Option<InstanceProfile>
+    ) -> Result<Option<Self>> {
+        if let Some(joules) = joules_per_constraint {
+            if joules <= 0.0 {
+                bail!("--joules-per-constraint must be positive, got {}", joules);
+            }
+            
+            return Ok(Some(EnergyModel { source: "custom".to_string(), joules_per_constraint: joules }));
+        }
+        
+        let model = match (gpu, instance) {
+            (Some(gpu), _) => Some(EnergyModel { source: gpu.name.to_string(), joules_per_constraint: gpu.joules_per_constraint }),
+            (None, Some(instance)) => Some(EnergyModel { source: instance.name.to_string(), joules_per_constraint: instance.joules_per_constraint }),
+            (None, None) => None,
+        };
+        
+        Ok(model)
+    }
+    
+    pub fn estimate(&self, constraints: usize) -> EnergyEstimate {
+        let joules_per_proof = constraints as f64 * self.joules_per_constraint;
+        
+        EnergyEstimate {
+            source: self.source.clone(),
+            joules_per_constraint: self.joules_per_constraint,
+            joules_per_proof,
+            kwh_per_million: joules_per_proof * 1_000_000.0 / JOULES_PER_KWH,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cloud::find_profile;
+    use crate::gpu::find_gpu_profile;
+    
+    #[test]
+    fn custom_rate_wins_over_profiles() {
+        let gpu = find_gpu_profile("a100").ok();
+        let instance = find_profile("c7i.8xlarge").ok();
+        
+        assert_eq!(EnergyModel::resolve(Some(0.5), gpu, instance).unwrap().unwrap().source, "custom");
+        assert_eq!(EnergyModel::resolve(None, gpu, instance).unwrap().unwrap().source, "a100");
+        assert_eq!(EnergyModel::resolve(None, None, instance).unwrap().unwrap().source, "c7i.8xlarge");
+        assert!(EnergyModel::resolve(None, None, None).unwrap().is_none());
+        assert!(EnergyModel::resolve(Some(-1.0), None, None).is_err());
+    }
+    
+    #[test]
+    fn estimates_kwh_per_million_proofs() {
+        let model = EnergyModel { source: "custom".to_string(), joules_per_constraint: 0.01 };
+        let estimate = model.estimate(360);
+        
+        assert!((estimate.joules_per_proof - 3.6).abs() < 1e-12);
+        assert!((estimate.kwh_per_million - 1.0).abs() < 1e-12);
+    }
+}
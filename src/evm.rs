@@ -0,0 +1,204 @@
+use crate::abi::PublicValue;
+use crate::i18n::tf;
+use crate::setup::BackendProfile;
+
+pub const CONTRACT_SIZE_LIMIT: usize = 24_576;
+pub const CALLDATA_GAS_PER_BYTE: u64 = 16;
+pub const FIELD_BYTES: usize = 32;
+pub const KECCAK_GAS_PER_WORD: u64 = 6;
+pub const POSEIDON2_RATE: usize = 3;
+
+#[derive(Debug, Clone, Copy)]
+pub struct VerifierProfile {
+    pub backend: &'static str,
+    pub base_bytes: usize,
+    pub bytes_per_round: usize,
+    pub bytes_per_public_input: usize,
+    pub proof_base_bytes: usize,
+    pub proof_bytes_per_round: usize,
+    pub base_gas: u64,
+    pub gas_per_round: u64,
+    pub gas_per_public_input: u64,
+}
+
+pub static VERIFIER_PROFILES: [VerifierProfile; 3] = [
+    VerifierProfile { backend: "ultra-honk", base_bytes: 18_500, bytes_per_round: 160, bytes_per_public_input: 0,
+        proof_base_bytes: 5_184, proof_bytes_per_round: 288, base_gas: 1_900_000, gas_per_round: 25_000, gas_per_public_input: 800 },
+    VerifierProfile { backend: "ultra-plonk", base_bytes: 14_000, bytes_per_round: 0, bytes_per_public_input: 0,
+        proof_base_bytes: 2_144, proof_bytes_per_round: 0, base_gas: 300_000, gas_per_round: 0, gas_per_public_input: 700 },
+    VerifierProfile { backend: "groth16", base_bytes: 3_500, bytes_per_round: 0, bytes_per_public_input: 150,
+        proof_base_bytes: 256, proof_bytes_per_round: 0, base_gas: 207_700, gas_per_round: 0, gas_per_public_input: 7_160 },
+];
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct EvmThresholds {
+    pub max_calldata: Option<usize>,
+    pub max_gas: Option<u64>,
+}
+
+#[derive(Debug)]
+pub struct VerifierEstimate {
+    pub profile: VerifierProfile,
+    pub public_inputs: usize,
+    pub bytecode_bytes: usize,
+    pub proof_bytes: usize,
+    pub calldata_bytes: usize,
+    pub calldata_gas: u64,
+    pub verification_gas: u64,
+}
+
+impl VerifierEstimate {
+    pub fn fits_contract_limit(&self) -> bool {
+        self.bytecode_bytes <= CONTRACT_SIZE_LIMIT
+    }
+    
+    pub fn total_gas(&self) -> u64 {
+        self.verification_gas.saturating_add(self.calldata_gas)
+    }
+    
+    pub fn warnings(&self, thresholds: &EvmThresholds) -> Vec<String> {
+        let mut warnings = Vec::new();
+        
+        if !self.fits_contract_limit() {
+            warnings.push(tf("evm.bytecode_warning", &[&self.bytecode_bytes, &CONTRACT_SIZE_LIMIT]));
+        }
+        
+        if let Some(limit) = thresholds.max_calldata {
+            if self.calldata_bytes > limit {
+                warnings.push(tf("evm.calldata_warning", &[&self.calldata_bytes, &self.public_inputs, &limit]));
+            }
+        }
+        
+        if let Some(limit) = thresholds.max_gas {
+            if self.total_gas() > limit {
+                warnings.push(tf("evm.gas_warning", &[&self.total_gas(), &self.public_inputs, &limit]));
+            }
+        }
+        
+        warnings
+    }
+}
+
+#[derive(Debug)]
+pub struct PublicValueCost {
+    pub name: String,
+    pub kind: String,
+    pub fields: usize,
+    pub calldata_bytes: usize,
+    pub calldata_gas: u64,
+    pub hashing_gas: u64,
+    pub verifier_gas: u64,
+}
+
+impl PublicValueCost {
+    pub fn total_gas(&self) -> u64 {
+        self.calldata_gas.saturating_add(self.hashing_gas).saturating_add(self.verifier_gas)
+    }
+    
+    pub fn commitment_savings(&self) -> u64 {
+        if self.fields <= 1 {
+            return 0;
+        }
+        
+        self.total_gas().saturating_sub(self.total_gas() / self.fields as u64)
+    }
+}
+
+pub fn public_field_count(values: &[PublicValue]) -> usize {
+    values.iter().fold(0usize, |total, value| total.saturating_add(value.fields))
+}
+
+pub fn public_value_costs(values: &[PublicValue], profile: VerifierProfile) -> Vec<PublicValueCost> {
+    let mut costs: Vec<PublicValueCost> = values.iter()
+        .map(|value| {
+            let calldata_bytes = FIELD_BYTES.saturating_mul(value.fields);
+            
+            PublicValueCost {
+                name: value.name.clone(),
+                kind: value.kind.clone(),
+                fields: value.fields,
+                calldata_bytes,
+                calldata_gas: (calldata_bytes as u64).saturating_mul(CALLDATA_GAS_PER_BYTE),
+                hashing_gas: KECCAK_GAS_PER_WORD.saturating_mul(value.fields as u64),
+                verifier_gas: profile.gas_per_public_input.saturating_mul(value.fields as u64),
+            }
+        })
+        .collect();
+    
+    costs.sort_by(|a, b| b.total_gas().cmp(&a.total_gas()).then(a.name.cmp(&b.name)));
+    costs
+}
+
+pub fn commitment_constraints(fields: usize, hash_cost: usize) -> usize {
+    fields.div_ceil(POSEIDON2_RATE).saturating_mul(hash_cost)
+}
+
+pub fn verifier_profile(backend: &BackendProfile) -> VerifierProfile {
+    VERIFIER_PROFILES.iter()
+        .find(|profile| profile.backend == backend.name)
+        .copied()
+        .unwrap_or(VERIFIER_PROFILES[0])
+}
+
+pub fn estimate_verifier(constraints: usize, public_inputs: usize, profile: VerifierProfile) -> VerifierEstimate {
+    let rounds = constraints.max(2).checked_next_power_of_two()
+        .map_or(usize::BITS, |size| size.trailing_zeros()) as usize;
+    
+    let bytecode_bytes = profile.base_bytes
+        .saturating_add(profile.bytes_per_round.saturating_mul(rounds))
+        .saturating_add(profile.bytes_per_public_input.saturating_mul(public_inputs));
+    let proof_bytes = profile.proof_base_bytes.saturating_add(profile.proof_bytes_per_round.saturating_mul(rounds));
+    let calldata_bytes = proof_bytes.saturating_add(FIELD_BYTES.saturating_mul(public_inputs));
+    
+    VerifierEstimate {
+        profile,
+        public_inputs,
+        bytecode_bytes,
+        proof_bytes,
+        calldata_bytes,
+        calldata_gas: (calldata_bytes as u64).saturating_mul(CALLDATA_GAS_PER_BYTE),
+        verification_gas: profile.base_gas
+            .saturating_add(profile.gas_per_round.saturating_mul(rounds as u64))
+            .saturating_add(profile.gas_per_public_input.saturating_mul(public_inputs as u64)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn value(name: &str, fields: usize) -> PublicValue {
+        PublicValue { name: name.to_string(), kind: "input".to_string(), fields }
+    }
+    
+    #[test]
+    fn honk_verifier_grows_with_circuit_rounds() {
+        let estimate = estimate_verifier(1000, 2, VERIFIER_PROFILES[0]);
+        
+        assert_eq!(estimate.bytecode_bytes, 18_500 + 10 * 160);
+        assert_eq!(estimate.proof_bytes, 5_184 + 10 * 288);
+        assert_eq!(estimate.calldata_bytes, estimate.proof_bytes + 64);
+        assert_eq!(estimate.total_gas(), 1_900_000 + 10 * 25_000 + 2 * 800 + estimate.calldata_bytes as u64 * 16);
+        assert!(estimate.fits_contract_limit());
+    }
+    
+    #[test]
+    fn warns_past_each_threshold() {
+        let estimate = estimate_verifier(1000, 200, VERIFIER_PROFILES[2]);
+        
+        assert!(!estimate.fits_contract_limit());
+        assert_eq!(estimate.warnings(&EvmThresholds::default()).len(), 1);
+        assert_eq!(estimate.warnings(&EvmThresholds { max_calldata: Some(1), max_gas: Some(1) }).len(), 3);
+    }
+    
+    #[test]
+    fn public_values_are_ranked_by_gas() {
+        let costs = public_value_costs(&[value("flag", 1), value("leaves", 8)], VERIFIER_PROFILES[2]);
+        
+        assert_eq!(costs[0].name, "leaves");
+        assert_eq!(costs[0].calldata_bytes, 256);
+        assert_eq!(costs[0].commitment_savings(), costs[0].total_gas() - costs[0].total_gas() / 8);
+        assert_eq!(costs[1].commitment_savings(), 0);
+        assert_eq!(commitment_constraints(8, 100), 300);
+    }
+}
@@ -0,0 +1,135 @@
+use anyhow::Result;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+pub const DEFAULT_EXPRESSION_WIDTH: usize = 4;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ExpressionWidth {
+    Bounded(usize),
+    Unbounded,
+}
+
+impl Default for ExpressionWidth {
+    fn default() -> Self {
+        ExpressionWidth::Bounded(DEFAULT_EXPRESSION_WIDTH)
+    }
+}
+
+impl fmt::Display for ExpressionWidth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ExpressionWidth::Bounded(width) => write!(f, "{}", width),
+            ExpressionWidth::Unbounded => write!(f, "unbounded"),
+        }
+    }
+}
+
+impl FromStr for ExpressionWidth {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        if s.eq_ignore_ascii_case("unbounded") {
+            return Ok(ExpressionWidth::Unbounded);
+        }
+        
+        match s.parse::<usize>() {
+            Ok(width) if width >= 3 => Ok(ExpressionWidth::Bounded(width)),
+            _ => Err(format!("invalid expression width `{}`, expected an integer >= 3 or `unbounded`", s)),
+        }
+    }
+}
+
+impl ExpressionWidth {
+    pub fn assert_zero_cost(&self, terms: usize) -> usize {
+        match self {
+            _ if terms == 0 => 1,
+            ExpressionWidth::Bounded(width) => terms.div_ceil(*width),
+            ExpressionWidth::Unbounded => 1,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct ExactCount {
+    pub opcodes: usize,
+    pub assert_zero: usize,
+    pub expression_width: ExpressionWidth,
+}
+
+pub fn exact_count_available() -> bool {
+    cfg!(feature = "acvm")
+}
+
+#[cfg(not(feature = "acvm"))]
+pub fn exact_count(_data: &Value, _expression_width: ExpressionWidth, _function: usize) -> Result<Option<ExactCount>> {
+    Ok(None)
+}
+
+#[cfg(feature = "acvm")]
+pub fn exact_count(data: &Value, expression_width: ExpressionWidth, function: usize) -> Result<Option<ExactCount>> {
+    use acvm::acir::circuit::{ExpressionWidth as AcvmExpressionWidth, Opcode, Program};
+    use anyhow::Context;
+    use base64::Engine;
+    
+    let bytecode = match data["bytecode"].as_str() {
+        Some(bytecode) => bytecode,
+        None => return Ok(None),
+    };
+    
+    let bytes = base64::engine::general_purpose::STANDARD.decode(bytecode)
+        .context("Artifact bytecode is not valid base64")?;
+    
+    let program = Program::deserialize_program(&bytes)
+        .context("Failed to deserialize ACIR program")?;
+    
+    let function_count = program.functions.len();
+    let circuit = program.functions.into_iter().nth(function)
+        .with_context(|| format!("ACIR program has {} functions, no function {}", function_count, function))?;
+    
+    let width = match expression_width {
+        ExpressionWidth::Bounded(width) => AcvmExpressionWidth::Bounded { width },
+        ExpressionWidth::Unbounded => AcvmExpressionWidth::Unbounded,
+    };
+    let (circuit, _) = std::panic::catch_unwind(|| acvm::compiler::compile(circuit, width))
+        .map_err(|_| anyhow::anyhow!("ACVM failed to transform circuit for expression width {}", expression_width))?;
+    
+    Ok(Some(ExactCount {
+        opcodes: circuit.opcodes.len(),
+        assert_zero: circuit.opcodes.iter()
+            .filter(|op| matches!(op, Opcode::AssertZero(_)))
+            .count(),
+        expression_width,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn parses_expression_widths() {
+        assert_eq!("4".parse(), Ok(ExpressionWidth::Bounded(4)));
+        assert_eq!("Unbounded".parse(), Ok(ExpressionWidth::Unbounded));
+        assert!("2".parse::<ExpressionWidth>().is_err());
+        assert!("wide".parse::<ExpressionWidth>().is_err());
+    }
+    
+    #[test]
+    fn assert_zero_cost_splits_by_width() {
+        assert_eq!(ExpressionWidth::Bounded(4).assert_zero_cost(0), 1);
+        assert_eq!(ExpressionWidth::Bounded(4).assert_zero_cost(4), 1);
+        assert_eq!(ExpressionWidth::Bounded(4).assert_zero_cost(9), 3);
+        assert_eq!(ExpressionWidth::Unbounded.assert_zero_cost(9), 1);
+    }
+    
+    #[test]
+    fn artifacts_without_bytecode_have_no_exact_count() {
+        let count = exact_count(&json!({ "opcodes": [] }), ExpressionWidth::default(), 0).unwrap();
+        assert!(count.is_none());
+    }
+}
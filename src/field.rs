@@ -0,0 +1,179 @@
+use crate::names::canonical_name;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+const BN254_MODULUS: &str = "30644e72e131a029b85045b68181585d2833e84879b9709143e1f593f0000001";
+const BLS12_381_MODULUS: &str = "73eda753299d7d483339d80809a1d80553bda402fffe5bfeffffffff00000001";
+
+const EMBEDDED_CURVE_OPS: [&str; 5] = [
+    "multi_scalar_mul", "embedded_curve_add", "pedersen_commitment", "pedersen_hash", "schnorr_verify",
+];
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum Field {
+    #[default]
+    #[serde(rename = "bn254")]
+    Bn254,
+    #[serde(rename = "bls12_381")]
+    Bls12381,
+}
+
+impl Field {
+    pub fn embedded_curve(&self) -> &'static str {
+        match self {
+            Field::Bn254 => "Grumpkin",
+            Field::Bls12381 => "Jubjub",
+        }
+    }
+    
+    pub fn black_box_factor(&self, function: &str) -> f64 {
+        match self {
+            Field::Bn254 => 1.0,
+            Field::Bls12381 if EMBEDDED_CURVE_OPS.contains(&canonical_name(function).as_str()) => 1.2,
+            Field::Bls12381 => 1.0,
+        }
+    }
+    
+    pub fn proving_time_factor(&self) -> f64 {
+        match self {
+            Field::Bn254 => 1.0,
+            Field::Bls12381 => 1.35,
+        }
+    }
+    
+    pub fn scale_black_box(&self, function: &str, cost: usize) -> usize {
+        (cost as f64 * self.black_box_factor(function)).round() as usize
+    }
+    
+    pub fn modulus(&self) -> &'static str {
+        match self {
+            Field::Bn254 => BN254_MODULUS,
+            Field::Bls12381 => BLS12_381_MODULUS,
+        }
+    }
+}
+
+impl fmt::Display for Field {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Field::Bn254 => write!(f, "BN254"),
+            Field::Bls12381 => write!(f, "BLS12-381"),
+        }
+    }
+}
+
+impl FromStr for Field {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let normalized: String = s.trim()
+            .trim_start_matches("0x")
+            .chars()
+            .filter(|c| c.is_ascii_alphanumeric())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        
+        match normalized.as_str() {
+            "bn254" | "bn256" | "altbn128" | BN254_MODULUS => Ok(Field::Bn254),
+            "bls12381" | BLS12_381_MODULUS => Ok(Field::Bls12381),
+            _ => Err(format!("unknown field `{}`, expected `bn254` or `bls12_381`", s)),
+        }
+    }
+}
+
+fn decimal_to_hex(digits: &str) -> Option<String> {
+    let mut limbs: Vec<u32> = Vec::new();
+    for digit in digits.chars() {
+        let mut carry = digit.to_digit(10)? as u64;
+        for limb in limbs.iter_mut() {
+            let value = *limb as u64 * 10 + carry;
+            *limb = value as u32;
+            carry = value >> 32;
+        }
+        if carry > 0 {
+            limbs.push(carry as u32);
+        }
+    }
+    
+    Some(limbs.iter().rev().map(|limb| format!("{:08x}", limb)).collect())
+}
+
+pub fn parse_constant(value: &Value) -> Option<String> {
+    let text = match value {
+        Value::Number(number) => number.as_u64()?.to_string(),
+        Value::String(text) => text.trim().trim_start_matches('-').to_string(),
+        _ => return None,
+    };
+    
+    let hex = match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) if !hex.is_empty() && hex.chars().all(|c| c.is_ascii_hexdigit()) => hex.to_ascii_lowercase(),
+        Some(_) => return None,
+        None if !text.is_empty() => decimal_to_hex(&text)?,
+        None => return None,
+    };
+    
+    Some(hex.trim_start_matches('0').to_string())
+}
+
+pub fn is_zero_constant(value: &Value, field: Field) -> bool {
+    parse_constant(value).is_some_and(|hex| hex.is_empty() || hex == field.modulus().trim_start_matches('0'))
+}
+
+pub fn declared_field(data: &Value) -> Option<String> {
+    ["field", "curve", "field_modulus"].iter()
+        .find_map(|key| data[*key].as_str())
+        .map(|text| text.to_string())
+}
+
+pub fn resolve_field(data: &Value) -> (Field, String) {
+    match declared_field(data) {
+        Some(declared) => match declared.parse::<Field>() {
+            Ok(field) => (field, field.to_string()),
+            Err(_) => (Field::default(), format!("{} ({} costs assumed)", declared, Field::default())),
+        },
+        None => (Field::default(), format!("{} (assumed)", Field::default())),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    const BN254_DECIMAL: &str = "21888242871839275222246405745257275088548364400416034343698204186575808495617";
+    
+    #[test]
+    fn parses_decimal_and_hex_constants() {
+        assert_eq!(parse_constant(&json!("255")).unwrap(), "ff");
+        assert_eq!(parse_constant(&json!("0x00FF")).unwrap(), "ff");
+        assert_eq!(parse_constant(&json!("-5")).unwrap(), "5");
+        assert_eq!(parse_constant(&json!(4294967296u64)).unwrap(), "100000000");
+        assert_eq!(parse_constant(&json!("0")).unwrap(), "");
+        assert!(parse_constant(&json!("0xzz")).is_none() && parse_constant(&json!("12a")).is_none());
+    }
+    
+    #[test]
+    fn the_modulus_is_zero_in_its_own_field() {
+        assert!(is_zero_constant(&json!(BN254_DECIMAL), Field::Bn254));
+        assert!(!is_zero_constant(&json!(BN254_DECIMAL), Field::Bls12381));
+        assert!(is_zero_constant(&json!(format!("0x{}", BLS12_381_MODULUS)), Field::Bls12381));
+        assert!(!is_zero_constant(&json!("1"), Field::Bn254));
+    }
+    
+    #[test]
+    fn resolves_declared_fields() {
+        assert_eq!(resolve_field(&json!({ "field": "BLS12-381" })).0, Field::Bls12381);
+        assert_eq!(resolve_field(&json!({ "field_modulus": format!("0x{}", BN254_MODULUS) })).0, Field::Bn254);
+        assert_eq!(resolve_field(&json!({ "curve": "pasta" })), (Field::Bn254, "pasta (BN254 costs assumed)".to_string()));
+        assert_eq!(resolve_field(&json!({})).1, "BN254 (assumed)");
+    }
+    
+    #[test]
+    fn bls_scales_embedded_curve_operations() {
+        assert_eq!(Field::Bls12381.scale_black_box("pedersen_hash", 100), 120);
+        assert_eq!(Field::Bls12381.scale_black_box("sha256", 100), 100);
+        assert_eq!(Field::Bn254.scale_black_box("pedersen_hash", 100), 100);
+    }
+}
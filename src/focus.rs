@@ -0,0 +1,190 @@
+use crate::analyzer::{analyze_circuit_with_options, AnalysisOptions};
+use crate::artifact::read_artifact;
+use crate::calls::{estimate_opcode_cost, function_name, function_opcodes};
+use crate::core::get_operation_cost;
+use anyhow::{bail, Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+static SUBSTITUTIONS: [(&str, &str, &str); 7] = [
+    ("keccak256", "poseidon2", "use Poseidon2 for in-circuit hashing when the digest does not have to match Ethereum"),
+    ("sha256", "poseidon2", "use Poseidon2 unless the digest must be checked against an external SHA-256 value"),
+    ("blake2s", "poseidon2", "use Poseidon2 for internal commitments"),
+    ("blake3", "poseidon2", "use Poseidon2 for internal commitments"),
+    ("pedersen_hash", "poseidon2", "Poseidon2 is usually cheaper than Pedersen hashing in UltraHonk"),
+    ("ecdsa_secp256k1", "schnorr_verify", "verify Schnorr signatures over the embedded curve when the signer can change schemes"),
+    ("AssertZero", "", "merge linear combinations and avoid intermediate witnesses; a wider --expression-width backend packs more terms per gate"),
+];
+
+#[derive(Debug, Serialize)]
+pub struct FocusSite {
+    pub function: String,
+    pub index: usize,
+    pub inputs: usize,
+    pub outputs: usize,
+    pub cost: usize,
+    pub invocations: usize,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Substitution {
+    pub alternative: Option<String>,
+    pub alternative_cost: Option<usize>,
+    pub advice: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct FocusReport {
+    pub operation: String,
+    pub sites: Vec<FocusSite>,
+    pub total_cost: usize,
+    pub circuit_constraints: usize,
+    pub share: f64,
+    pub suggestions: Vec<Substitution>,
+}
+
+fn matches_operation(op: &Value, operation: &str) -> bool {
+    let op_type = op["type"].as_str().unwrap_or("Unknown");
+    
+    if op_type.eq_ignore_ascii_case(operation) {
+        return true;
+    }
+    
+    op_type == "BlackBoxFunction"
+        && op["function"].as_str().is_some_and(|name| name.eq_ignore_ascii_case(operation))
+}
+
+pub fn site_location(op: &Value) -> Option<String> {
+    let file = op["location"]["file"].as_str()?;
+    
+    match op["location"]["line"].as_u64() {
+        Some(line) => Some(format!("{}:{}", file, line)),
+        None => Some(file.to_string()),
+    }
+}
+
+fn function_invocations(data: &Value, id: usize, callees: &[(String, usize, usize)]) -> usize {
+    if id == 0 {
+        return 1;
+    }
+    
+    let name = function_name(data, id);
+    callees.iter()
+        .find(|(callee, _, _)| *callee == name)
+        .map_or(0, |(_, count, _)| *count)
+}
+
+pub fn suggestions(operation: &str) -> Vec<Substitution> {
+    SUBSTITUTIONS.iter()
+        .filter(|(op, _, _)| op.eq_ignore_ascii_case(operation))
+        .map(|(_, alternative, advice)| Substitution {
+            alternative: (!alternative.is_empty()).then(|| alternative.to_string()),
+            alternative_cost: (!alternative.is_empty()).then(|| get_operation_cost(alternative)).flatten(),
+            advice: advice.to_string(),
+        })
+        .collect()
+}
+
+pub fn focus_report(path: &Path, operation: &str, options: &AnalysisOptions) -> Result<FocusReport> {
+    let analysis = analyze_circuit_with_options(path, options)?;
+    
+    let json = read_artifact(path, options.limits.max_file_size)?;
+    let data: Value = serde_json::from_slice(&json)
+        .with_context(|| format!("Failed to parse circuit JSON: {}", path.display()))?;
+    
+    let function_count = data["functions"].as_array().map_or(1, |functions| functions.len().max(1));
+    let mut sites = Vec::new();
+    
+    for id in 0..function_count {
+        let invocations = function_invocations(&data, id, &analysis.callees);
+        
+        for (index, op) in function_opcodes(&data, id).iter().enumerate() {
+            if !matches_operation(op, operation) {
+                continue;
+            }
+            
+            sites.push(FocusSite {
+                function: function_name(&data, id),
+                index,
+                inputs: op["inputs"].as_array().map_or(0, |inputs| inputs.len()),
+                outputs: op["outputs"].as_array().map_or(0, |outputs| outputs.len()),
+                cost: estimate_opcode_cost(op, options.expression_width),
+                invocations,
+                location: site_location(op),
+            });
+        }
+    }
+    
+    if sites.is_empty() {
+        bail!("No `{}` operations found in {}", operation, path.display());
+    }
+    
+    let total_cost = sites.iter()
+        .map(|site| site.cost.saturating_mul(site.invocations))
+        .fold(0usize, |total, cost| total.saturating_add(cost));
+    
+    let circuit_constraints = if analysis.inlined_constraints > 0 {
+        analysis.inlined_constraints
+    } else {
+        analysis.constraints
+    };
+    
+    let share = if circuit_constraints > 0 {
+        (total_cost as f64 / circuit_constraints as f64 * 100.0).min(100.0)
+    } else {
+        0.0
+    };
+    
+    Ok(FocusReport {
+        operation: operation.to_string(),
+        sites,
+        total_cost,
+        circuit_constraints,
+        share,
+        suggestions: suggestions(operation),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn matches_opcode_types_and_black_box_names() {
+        let hash = json!({ "type": "BlackBoxFunction", "function": "sha256" });
+        
+        assert!(matches_operation(&hash, "SHA256"));
+        assert!(matches_operation(&hash, "blackboxfunction"));
+        assert!(!matches_operation(&hash, "keccak256"));
+        assert!(matches_operation(&json!({ "type": "AssertZero" }), "assertzero"));
+    }
+    
+    #[test]
+    fn locations_include_the_line_when_known() {
+        assert_eq!(site_location(&json!({ "location": { "file": "main.nr", "line": 7 } })).as_deref(), Some("main.nr:7"));
+        assert_eq!(site_location(&json!({ "location": { "file": "main.nr" } })).as_deref(), Some("main.nr"));
+        assert_eq!(site_location(&json!({})), None);
+    }
+    
+    #[test]
+    fn invocations_come_from_the_call_graph() {
+        let data = json!({ "names": ["main", "leaf"] });
+        let callees = vec![("leaf".to_string(), 4, 10)];
+        
+        assert_eq!(function_invocations(&data, 0, &callees), 1);
+        assert_eq!(function_invocations(&data, 1, &callees), 4);
+        assert_eq!(function_invocations(&data, 2, &callees), 0);
+    }
+    
+    #[test]
+    fn assert_zero_gets_advice_without_an_alternative() {
+        let advice = suggestions("assertzero");
+        
+        assert_eq!(advice.len(), 1);
+        assert!(advice[0].alternative.is_none());
+        assert!(suggestions("range").is_empty());
+    }
+}
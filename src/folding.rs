@@ -0,0 +1,122 @@
+use crate::progress::Deadline;
+use serde_json::Value;
+use std::collections::HashSet;
+
+#[derive(Debug, Default)]
+pub struct FoldingReport {
+    pub constant_only: usize,
+    pub foldable: usize,
+    pub witness_dependent: usize,
+    pub wasted_constraints: usize,
+    pub sites: Vec<(usize, usize)>,
+}
+
+fn is_zero(coefficient: &str) -> bool {
+    let digits = coefficient.trim().trim_start_matches('-');
+    let digits = digits.strip_prefix("0x").unwrap_or(digits);
+    
+    !digits.is_empty() && digits.chars().all(|c| c == '0')
+}
+
+fn live_variables(op: &Value) -> Vec<&str> {
+    op["expression"]["terms"].as_array()
+        .into_iter()
+        .flatten()
+        .filter(|term| !term["coefficient"].as_str().is_some_and(is_zero))
+        .filter_map(|term| term["variable"].as_str())
+        .collect()
+}
+
+fn input_names<'a>(data: &'a Value, key: &str) -> impl Iterator<Item = &'a str> {
+    data[key].as_array()
+        .into_iter()
+        .flatten()
+        .filter_map(|v| v.as_str())
+}
+
+pub fn classify_assertions(data: &Value, opcodes: &[Value], opcode_costs: &[usize], deadline: &Deadline) -> FoldingReport {
+    let inputs: HashSet<&str> = input_names(data, "public_inputs")
+        .chain(input_names(data, "private_inputs"))
+        .collect();
+    
+    let mut known: HashSet<&str> = HashSet::new();
+    let mut report = FoldingReport::default();
+    
+    for (idx, op) in opcodes.iter().enumerate() {
+        if deadline.expired_at(idx) {
+            break;
+        }
+        
+        if op["type"].as_str() != Some("AssertZero") {
+            continue;
+        }
+        
+        let variables = live_variables(op);
+        let unknown: Vec<&str> = variables.iter()
+            .copied()
+            .filter(|var| !known.contains(var))
+            .collect();
+        
+        let cost = opcode_costs.get(idx).copied().unwrap_or(0);
+        
+        let derived = match unknown.as_slice() {
+            [var] if !inputs.contains(var) => Some(*var),
+            _ => None,
+        };
+        
+        if let Some(var) = derived {
+            known.insert(var);
+        }
+        
+        if variables.is_empty() {
+            report.constant_only += 1;
+        } else if unknown.is_empty() || (derived.is_some() && variables.len() > 1) {
+            report.foldable += 1;
+        } else {
+            report.witness_dependent += 1;
+            continue;
+        }
+        
+        report.wasted_constraints += cost;
+        report.sites.push((idx, cost));
+    }
+    
+    report
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn assert_zero(terms: &[(&str, &str)]) -> Value {
+        let terms: Vec<Value> = terms.iter()
+            .map(|(coefficient, variable)| json!({ "coefficient": coefficient, "variable": variable }))
+            .collect();
+        json!({ "type": "AssertZero", "expression": { "terms": terms } })
+    }
+    
+    #[test]
+    fn classifies_constant_foldable_and_witness_assertions() {
+        let data = json!({ "public_inputs": ["x"], "private_inputs": ["y"] });
+        let opcodes = [
+            assert_zero(&[("0x00", "x")]),
+            assert_zero(&[("1", "x"), ("1", "y")]),
+            assert_zero(&[("1", "a"), ("-1", "b")]),
+            assert_zero(&[("1", "c")]),
+            assert_zero(&[("1", "c"), ("-1", "d")]),
+            json!({ "type": "BlackBoxFunction" }),
+        ];
+        let report = classify_assertions(&data, &opcodes, &[3, 1, 1, 1, 2, 9], &Deadline::default());
+        
+        assert_eq!((report.constant_only, report.foldable, report.witness_dependent), (1, 1, 3));
+        assert_eq!(report.sites, [(0, 3), (4, 2)]);
+        assert_eq!(report.wasted_constraints, 5);
+    }
+    
+    #[test]
+    fn recognizes_zero_coefficients() {
+        assert!(is_zero("0") && is_zero("-0x000") && is_zero(" 00 "));
+        assert!(!is_zero("") && !is_zero("0x") && !is_zero("10"));
+    }
+}
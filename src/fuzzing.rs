@@ -0,0 +1,155 @@
+use serde_json::{json, Value};
+
+const OPCODE_TYPES: [&str; 8] = [
+    "AssertZero", "BlackBoxFunction", "Call", "MemoryOp", "MemoryInit", "BrilligCall", "Directive", "",
+];
+
+const FUNCTIONS: [&str; 16] = [
+    "sha256", "Sha256Compression", "blake2s", "blake3", "keccak256", "keccakf1600", "pedersen_hash",
+    "ecdsa_secp256k1", "multi_scalar_mul", "FixedBaseScalarMul", "embedded_curve_add", "range", "and",
+    "poseidon2_permutation", "recursive_aggregation", "unknown_function",
+];
+
+const FUNCTION_NAMES: [&str; 3] = ["main", "helper", "hash_leaf"];
+
+const MAX_OPCODES: usize = 64;
+
+struct Source<'a> {
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl Source<'_> {
+    fn byte(&mut self) -> u8 {
+        let byte = self.bytes.get(self.pos).copied().unwrap_or(0);
+        self.pos += 1;
+        byte
+    }
+    
+    fn below(&mut self, n: usize) -> usize {
+        self.byte() as usize % n.max(1)
+    }
+    
+    fn large(&mut self) -> u64 {
+        (0..8).fold(0, |value, _| value << 8 | self.byte() as u64)
+    }
+    
+    fn exhausted(&self) -> bool {
+        self.pos >= self.bytes.len()
+    }
+}
+
+fn operand(source: &mut Source) -> Value {
+    match source.below(4) {
+        0 => json!({"constant": format!("{:#x}", source.large())}),
+        1 => json!({"variable": format!("_{}", source.below(32)), "num_bits": source.below(300)}),
+        2 => json!([operand(source), operand(source)]),
+        _ => json!({"variable": format!("_{}", source.below(32))}),
+    }
+}
+
+fn operands(source: &mut Source) -> Vec<Value> {
+    (0..source.below(12)).map(|_| operand(source)).collect()
+}
+
+fn opcode(source: &mut Source, function_count: usize) -> Value {
+    let kind = OPCODE_TYPES[source.below(OPCODE_TYPES.len())];
+    let mut op = json!({"type": kind});
+    
+    match kind {
+        "AssertZero" => {
+            let terms: Vec<Value> = (0..source.below(10))
+                .map(|_| json!({"variable": format!("_{}", source.below(32))}))
+                .collect();
+            op["expression"] = json!({"terms": terms});
+        },
+        "BlackBoxFunction" => {
+            op["function"] = json!(FUNCTIONS[source.below(FUNCTIONS.len())]);
+            op["inputs"] = Value::Array(operands(source));
+            op["outputs"] = Value::Array(operands(source));
+            if source.byte().is_multiple_of(4) {
+                op["points"] = Value::Array(operands(source));
+                op["scalars"] = Value::Array(operands(source));
+            }
+        },
+        "Call" => {
+            op["id"] = match source.below(8) {
+                0 => json!(source.large()),
+                1 => json!(-1),
+                _ => json!(source.below(function_count + 1)),
+            };
+        },
+        _ => {},
+    }
+    
+    if source.byte().is_multiple_of(3) {
+        op["location"] = json!({
+            "file": format!("src/{}.nr", ["main", "lib", "utils"][source.below(3)]),
+            "line": source.below(200),
+            "function": FUNCTION_NAMES[source.below(3)],
+        });
+    }
+    
+    op
+}
+
+pub fn arbitrary_artifact(bytes: &[u8]) -> Value {
+    let mut source = Source { bytes, pos: 0 };
+    let function_count = 1 + source.below(4);
+    
+    let functions: Vec<Value> = (0..function_count)
+        .map(|id| {
+            let mut opcodes = Vec::new();
+            while opcodes.len() < MAX_OPCODES && !source.exhausted() && !source.byte().is_multiple_of(8) {
+                opcodes.push(opcode(&mut source, function_count));
+            }
+            json!({"name": format!("f{}", id), "opcodes": opcodes})
+        })
+        .collect();
+    
+    let mut artifact = json!({
+        "noir_version": format!("0.{}.0", source.below(40)),
+        "public_inputs": operands(&mut source),
+        "return_values": operands(&mut source),
+        "functions": functions,
+    });
+    
+    if source.byte().is_multiple_of(2) {
+        artifact["opcodes"] = artifact["functions"][0]["opcodes"].clone();
+    }
+    
+    if source.byte().is_multiple_of(4) {
+        artifact["witnesses"] = json!({"_0": 0, "_1": 1});
+    }
+    
+    artifact
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::AnalysisBuilder;
+    use crate::cost::CostModel;
+    use std::collections::HashMap;
+    
+    #[test]
+    fn artifacts_are_deterministic_and_bounded() {
+        let empty = arbitrary_artifact(&[]);
+        assert_eq!(empty["functions"].as_array().unwrap().len(), 1);
+        assert!(empty["opcodes"].is_array());
+        
+        let bytes: Vec<u8> = (0..=255).cycle().take(4096).collect();
+        assert_eq!(arbitrary_artifact(&bytes), arbitrary_artifact(&bytes));
+        assert!(arbitrary_artifact(&bytes)["functions"].as_array().unwrap().iter()
+            .all(|function| function["opcodes"].as_array().unwrap().len() <= MAX_OPCODES));
+    }
+    
+    #[test]
+    fn generated_artifacts_analyze_without_panicking() {
+        let analyzer = AnalysisBuilder::new().cost_model(CostModel::Fixed(HashMap::new())).build();
+        for seed in 0..64u32 {
+            let bytes: Vec<u8> = (0..512u32).map(|idx| (idx.wrapping_mul(2654435761).wrapping_add(seed * 97) >> 7) as u8).collect();
+            let _ = analyzer.analyze_value(&arbitrary_artifact(&bytes));
+        }
+    }
+}
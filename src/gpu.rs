@@ -0,0 +1,117 @@
+use anyhow::{bail, Result};
+
+pub const MSM_SHARE: f64 = 0.60;
+pub const FFT_SHARE: f64 = 0.25;
+pub const WITNESS_SHARE: f64 = 0.15;
+
+pub const MSM_POLYNOMIALS: f64 = 20.0;
+pub const FFT_POLYNOMIALS: f64 = 12.0;
+
+#[derive(Debug, Clone, Copy)]
+pub struct GpuProfile {
+    pub name: &'static str,
+    pub msm_points_per_sec: f64,
+    pub fft_butterflies_per_sec: f64,
+    pub memory_gb: f64,
+    pub joules_per_constraint: f64,
+}
+
+pub static GPU_PROFILES: [GpuProfile; 4] = [
+    GpuProfile { name: "l4", msm_points_per_sec: 3.0e7, fft_butterflies_per_sec: 6.0e9, memory_gb: 24.0, joules_per_constraint: 0.0008 },
+    GpuProfile { name: "rtx-4090", msm_points_per_sec: 1.0e8, fft_butterflies_per_sec: 2.0e10, memory_gb: 24.0, joules_per_constraint: 0.0015 },
+    GpuProfile { name: "a100", msm_points_per_sec: 8.0e7, fft_butterflies_per_sec: 1.5e10, memory_gb: 80.0, joules_per_constraint: 0.0012 },
+    GpuProfile { name: "h100", msm_points_per_sec: 1.6e8, fft_butterflies_per_sec: 3.0e10, memory_gb: 80.0, joules_per_constraint: 0.0010 },
+];
+
+#[derive(Debug)]
+pub struct PhaseEstimate {
+    pub phase: &'static str,
+    pub cpu_ms: f64,
+    pub gpu_ms: f64,
+}
+
+impl PhaseEstimate {
+    pub fn speedup(&self) -> f64 {
+        if self.gpu_ms > 0.0 {
+            self.cpu_ms / self.gpu_ms
+        } else {
+            1.0
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct GpuEstimate {
+    pub profile: GpuProfile,
+    pub phases: Vec<PhaseEstimate>,
+}
+
+impl GpuEstimate {
+    pub fn cpu_ms(&self) -> f64 {
+        self.phases.iter().map(|phase| phase.cpu_ms).sum()
+    }
+    
+    pub fn gpu_ms(&self) -> f64 {
+        self.phases.iter().map(|phase| phase.gpu_ms).sum()
+    }
+    
+    pub fn speedup(&self) -> f64 {
+        let gpu = self.gpu_ms();
+        if gpu > 0.0 {
+            self.cpu_ms() / gpu
+        } else {
+            1.0
+        }
+    }
+}
+
+pub fn find_gpu_profile(name: &str) -> Result<GpuProfile> {
+    match GPU_PROFILES.iter().find(|profile| profile.name.eq_ignore_ascii_case(name)) {
+        Some(profile) => Ok(*profile),
+        None => {
+            let known: Vec<_> = GPU_PROFILES.iter().map(|profile| profile.name).collect();
+            bail!("Unknown GPU profile `{}` (known: {})", name, known.join(", "))
+        }
+    }
+}
+
+pub fn estimate_gpu(proving_ms: f64, constraints: usize, profile: GpuProfile) -> GpuEstimate {
+    let size = constraints.max(2).next_power_of_two() as f64;
+    
+    let msm_gpu = MSM_POLYNOMIALS * size / profile.msm_points_per_sec * 1000.0;
+    let fft_gpu = FFT_POLYNOMIALS * size * size.log2() / profile.fft_butterflies_per_sec * 1000.0;
+    let witness = proving_ms * WITNESS_SHARE;
+    
+    GpuEstimate {
+        profile,
+        phases: vec![
+            PhaseEstimate { phase: "gpu.msm", cpu_ms: proving_ms * MSM_SHARE, gpu_ms: msm_gpu },
+            PhaseEstimate { phase: "gpu.fft", cpu_ms: proving_ms * FFT_SHARE, gpu_ms: fft_gpu },
+            PhaseEstimate { phase: "gpu.witness", cpu_ms: witness, gpu_ms: witness },
+        ],
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn phases_split_the_cpu_time() {
+        let estimate = estimate_gpu(1000.0, 1 << 16, find_gpu_profile("H100").unwrap());
+        
+        assert_eq!(estimate.phases.len(), 3);
+        assert!((estimate.cpu_ms() - 1000.0).abs() < 1e-9);
+        assert_eq!(estimate.phases[2].speedup(), 1.0);
+        assert!(estimate.speedup() > 1.0);
+    }
+    
+    #[test]
+    fn faster_profiles_prove_faster() {
+        let l4 = estimate_gpu(1000.0, 1 << 20, find_gpu_profile("l4").unwrap());
+        let h100 = estimate_gpu(1000.0, 1 << 20, find_gpu_profile("h100").unwrap());
+        
+        assert!(h100.gpu_ms() < l4.gpu_ms());
+        assert!(find_gpu_profile("v100").is_err());
+    }
+}
@@ -0,0 +1,198 @@
+use crate::artifact::read_artifact;
+use crate::audit::escape_html;
+use crate::calls::{estimate_opcode_cost, function_opcodes};
+use crate::exact::ExpressionWidth;
+use anyhow::{bail, Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::path::{Component, Path, PathBuf};
+
+pub const HEAT_LEVELS: [f64; 3] = [0.05, 0.20, 0.50];
+
+const HEAT_COLORS: [&str; 5] = ["#ffffff", "#fff5cc", "#ffe08a", "#ffb366", "#ff7a7a"];
+
+#[derive(Debug, Clone)]
+pub struct SourceLine {
+    pub number: u64,
+    pub text: String,
+    pub constraints: usize,
+}
+
+#[derive(Debug, Clone)]
+pub struct AnnotatedSource {
+    pub file: String,
+    pub path: Option<PathBuf>,
+    pub constraints: usize,
+    pub lines: Vec<SourceLine>,
+}
+
+pub fn heat_level(constraints: usize, hottest: usize) -> usize {
+    if constraints == 0 {
+        return 0;
+    }
+    
+    let ratio = constraints as f64 / hottest.max(1) as f64;
+    1 + HEAT_LEVELS.iter().filter(|level| ratio >= **level).count()
+}
+
+pub fn hottest_line(sources: &[AnnotatedSource]) -> usize {
+    sources.iter()
+        .flat_map(|source| source.lines.iter().map(|line| line.constraints))
+        .max()
+        .unwrap_or(0)
+}
+
+pub fn line_costs(data: &Value, width: ExpressionWidth) -> BTreeMap<String, BTreeMap<u64, usize>> {
+    let mut costs: BTreeMap<String, BTreeMap<u64, usize>> = BTreeMap::new();
+    
+    for op in function_opcodes(data, 0) {
+        let (Some(file), Some(line)) = (op["location"]["file"].as_str(), op["location"]["line"].as_u64()) else {
+            continue;
+        };
+        
+        let cost = costs.entry(file.to_string()).or_default().entry(line).or_insert(0);
+        *cost = cost.saturating_add(estimate_opcode_cost(op, width));
+    }
+    
+    costs
+}
+
+fn resolve_source(src: &Path, file: &str) -> Option<PathBuf> {
+    let file = Path::new(file);
+    if file.is_absolute() && file.is_file() {
+        return Some(file.to_path_buf());
+    }
+    
+    let components: Vec<Component> = file.components()
+        .filter(|component| matches!(component, Component::Normal(_)))
+        .collect();
+    
+    (0..components.len())
+        .map(|skip| src.join(components[skip..].iter().collect::<PathBuf>()))
+        .find(|candidate| candidate.is_file())
+}
+
+pub fn annotate_sources(artifact: &Path, src: &Path, width: ExpressionWidth, max_file_size: Option<u64>) -> Result<Vec<AnnotatedSource>> {
+    let json = read_artifact(artifact, max_file_size)?;
+    let data: Value = serde_json::from_slice(&json)
+        .with_context(|| format!("Failed to parse circuit JSON: {}", artifact.display()))?;
+    
+    let costs = line_costs(&data, width);
+    if costs.is_empty() {
+        bail!("{} has no source locations; compile with debug info so opcodes carry `location`", artifact.display());
+    }
+    
+    let mut sources = Vec::new();
+    for (file, lines) in costs {
+        let path = resolve_source(src, &file);
+        let content = match &path {
+            Some(path) => fs::read_to_string(path)
+                .with_context(|| format!("Failed to read source: {}", path.display()))?,
+            None => String::new(),
+        };
+        
+        sources.push(AnnotatedSource {
+            constraints: lines.values().fold(0usize, |total, cost| total.saturating_add(*cost)),
+            lines: content.lines().enumerate()
+                .map(|(idx, text)| SourceLine {
+                    number: idx as u64 + 1,
+                    text: text.to_string(),
+                    constraints: lines.get(&(idx as u64 + 1)).copied().unwrap_or(0),
+                })
+                .collect(),
+            file,
+            path,
+        });
+    }
+    
+    sources.sort_by(|a, b| b.constraints.cmp(&a.constraints).then(a.file.cmp(&b.file)));
+    Ok(sources)
+}
+
+pub fn render_html(artifact: &Path, sources: &[AnnotatedSource]) -> String {
+    let hottest = hottest_line(sources);
+    let total = sources.iter().fold(0usize, |total, source| total.saturating_add(source.constraints));
+    let mut html = String::new();
+    
+    let _ = writeln!(html, "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">");
+    let _ = writeln!(html, "<title>Constraint heatmap: {}</title>", escape_html(&artifact.display().to_string()));
+    let _ = write!(html, "<style>body{{font-family:sans-serif;margin:2em}}table{{border-collapse:collapse;font-family:monospace;font-size:13px}}td{{padding:0 8px;white-space:pre}}.n{{color:#999;text-align:right}}.c{{text-align:right;border-right:1px solid #ccc}}");
+    for (level, color) in HEAT_COLORS.iter().enumerate() {
+        let _ = write!(html, ".h{}{{background:{}}}", level, color);
+    }
+    let _ = writeln!(html, "</style>\n</head>\n<body>");
+    let _ = writeln!(html, "<h1>Constraint heatmap</h1>");
+    let _ = writeln!(html, "<p><code>{}</code>: {} constraints attributed to {} source files</p>", escape_html(&artifact.display().to_string()), total, sources.len());
+    
+    for source in sources {
+        let share = source.constraints as f64 / total.max(1) as f64 * 100.0;
+        let _ = writeln!(html, "<h2><code>{}</code></h2>", escape_html(&source.file));
+        let _ = writeln!(html, "<p>{} constraints ({:.1}%)</p>", source.constraints, share);
+        
+        if source.path.is_none() {
+            let _ = writeln!(html, "<p><em>Source not found under --src</em></p>");
+            continue;
+        }
+        
+        let _ = writeln!(html, "<table>");
+        for line in &source.lines {
+            let count = if line.constraints > 0 { line.constraints.to_string() } else { String::new() };
+            let _ = writeln!(html, "<tr class=\"h{}\"><td class=\"c\">{}</td><td class=\"n\">{}</td><td>{}</td></tr>",
+                heat_level(line.constraints, hottest), count, line.number, escape_html(&line.text));
+        }
+        let _ = writeln!(html, "</table>");
+    }
+    
+    let _ = writeln!(html, "</body>\n</html>");
+    html
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn assert_zero(file: &str, line: u64) -> Value {
+        json!({ "type": "AssertZero", "expression": { "terms": [{ "variable": "w" }] }, "location": { "file": file, "line": line } })
+    }
+    
+    #[test]
+    fn heat_levels_scale_with_the_hottest_line() {
+        assert_eq!(heat_level(0, 100), 0);
+        assert_eq!(heat_level(1, 100), 1);
+        assert_eq!(heat_level(20, 100), 3);
+        assert_eq!(heat_level(100, 100), 4);
+    }
+    
+    #[test]
+    fn annotates_sources_found_by_suffix() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-heatmap-{}", std::process::id()));
+        fs::create_dir_all(dir.join("src")).unwrap();
+        fs::write(dir.join("src/main.nr"), "fn main(x: Field) {\n    assert(x < 3);\n}\n").unwrap();
+        let artifact = dir.join("circuit.json");
+        let data = json!({ "opcodes": [
+            assert_zero("/build/project/src/main.nr", 2),
+            assert_zero("/build/project/src/main.nr", 2),
+            assert_zero("dep/lib.nr", 1),
+        ] });
+        fs::write(&artifact, data.to_string()).unwrap();
+        
+        let sources = annotate_sources(&artifact, &dir, ExpressionWidth::default(), None);
+        fs::write(&artifact, json!({ "opcodes": [{ "type": "AssertZero" }] }).to_string()).unwrap();
+        let undebugged = annotate_sources(&artifact, &dir, ExpressionWidth::default(), None);
+        fs::remove_dir_all(&dir).unwrap();
+        let sources = sources.unwrap();
+        
+        assert!(undebugged.is_err());
+        assert_eq!(sources.iter().map(|source| source.file.as_str()).collect::<Vec<_>>(), ["/build/project/src/main.nr", "dep/lib.nr"]);
+        assert_eq!(sources[0].lines.iter().map(|line| line.constraints).collect::<Vec<_>>(), [0, 2, 0]);
+        assert!(sources[1].path.is_none() && sources[1].lines.is_empty());
+        
+        let html = render_html(&artifact, &sources);
+        assert!(html.contains("<td>    assert(x &lt; 3);</td>"));
+        assert!(html.contains("<tr class=\"h4\"><td class=\"c\">2</td><td class=\"n\">2</td>"));
+        assert!(html.contains("Source not found under --src"));
+    }
+}
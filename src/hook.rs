@@ -0,0 +1,266 @@
+use crate::analyzer::{analyze_circuit_with_options, AnalysisOptions};
+use crate::artifact::is_artifact_path;
+use crate::init::{circuit_name, BASELINE_DIR, BUDGET_FILE};
+use crate::notify::{find_violations, load_notify_config, Violation};
+use crate::recompile::compile_all;
+use crate::report::load_report;
+use anyhow::{bail, Context, Result};
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::process::Command;
+use std::str::FromStr;
+
+pub const HOOK_MARKER: &str = "# installed by noir-circuit-profiler hook install";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum HookKind {
+    #[default]
+    PreCommit,
+    PrePush,
+}
+
+impl HookKind {
+    pub fn name(&self) -> &'static str {
+        match self {
+            HookKind::PreCommit => "pre-commit",
+            HookKind::PrePush => "pre-push",
+        }
+    }
+}
+
+impl FromStr for HookKind {
+    type Err = String;
+    
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().replace('_', "-").as_str() {
+            "pre-commit" => Ok(HookKind::PreCommit),
+            "pre-push" => Ok(HookKind::PrePush),
+            _ => Err(format!("invalid hook `{}`, expected pre-commit or pre-push", s)),
+        }
+    }
+}
+
+impl fmt::Display for HookKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+#[derive(Debug)]
+pub struct HookResult {
+    pub circuit: String,
+    pub constraints: usize,
+    pub baseline: Option<usize>,
+    pub violations: Vec<Violation>,
+}
+
+#[derive(Debug)]
+pub enum HookOutcome {
+    NoCircuitChanges,
+    Checked { results: Vec<HookResult>, fail_on_violation: bool },
+}
+
+fn git(repo: &Path, args: &[&str]) -> Result<String> {
+    git_with_index(repo, None, args)
+}
+
+fn git_with_index(repo: &Path, index: Option<&Path>, args: &[&str]) -> Result<String> {
+    let mut command = Command::new("git");
+    if let Some(index) = index {
+        command.env("GIT_INDEX_FILE", index);
+    }
+    
+    let output = command
+        .args(args)
+        .current_dir(repo)
+        .output()
+        .context("Failed to run `git`")?;
+    
+    if !output.status.success() {
+        bail!("`git {}` failed in {}: {}", args.join(" "), repo.display(), String::from_utf8_lossy(&output.stderr).trim());
+    }
+    Ok(String::from_utf8_lossy(&output.stdout).trim().to_string())
+}
+
+pub fn hook_script(kind: HookKind) -> String {
+    let scope = match kind {
+        HookKind::PreCommit => "",
+        HookKind::PrePush => " --all",
+    };
+    
+    format!("#!/bin/sh
+{marker}
+# Blocks the {kind} when a circuit exceeds the budgets in {budget}.
+# Skip once with `git {command} --no-verify`.
+exec noir-circuit-profiler --plain --quick hook run{scope}
+", marker = HOOK_MARKER, kind = kind, budget = BUDGET_FILE, scope = scope,
+        command = if kind == HookKind::PreCommit { "commit" } else { "push" })
+}
+
+pub fn install_hook(project: &Path, kind: HookKind, force: bool) -> Result<PathBuf> {
+    if !project.join(BUDGET_FILE).exists() {
+        bail!("No {} in {}; run `init` first to create budgets", BUDGET_FILE, project.display());
+    }
+    
+    let hooks = PathBuf::from(git(project, &["rev-parse", "--git-path", "hooks"])?);
+    let hooks = if hooks.is_absolute() { hooks } else { project.join(hooks) };
+    let path = hooks.join(kind.name());
+    
+    if path.exists() && !force {
+        let existing = fs::read_to_string(&path).unwrap_or_default();
+        if !existing.contains(HOOK_MARKER) {
+            bail!("{} already exists and was not installed by this tool; pass --force to overwrite", path.display());
+        }
+    }
+    
+    fs::create_dir_all(&hooks)
+        .with_context(|| format!("Failed to create {}", hooks.display()))?;
+    fs::write(&path, hook_script(kind))
+        .with_context(|| format!("Failed to write {}", path.display()))?;
+    
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o755))
+            .with_context(|| format!("Failed to make {} executable", path.display()))?;
+    }
+    
+    Ok(path)
+}
+
+pub fn staged_files(project: &Path) -> Result<Vec<PathBuf>> {
+    Ok(git(project, &["diff", "--cached", "--name-only", "--relative", "--diff-filter=ACMR"])?
+        .lines()
+        .filter(|line| !line.is_empty())
+        .map(PathBuf::from)
+        .collect())
+}
+
+pub fn is_circuit_change(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "nr")
+        || path.file_name().is_some_and(|name| name == "Nargo.toml" || name == BUDGET_FILE)
+        || is_artifact_path(path)
+}
+
+struct ExportedTree {
+    dir: PathBuf,
+}
+
+impl Drop for ExportedTree {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.dir).ok();
+    }
+}
+
+fn export_tree(project: &Path, head: bool) -> Result<(ExportedTree, PathBuf)> {
+    let root = PathBuf::from(git(project, &["rev-parse", "--show-toplevel"])?);
+    let prefix = git(project, &["rev-parse", "--show-prefix"])?;
+    let tree = ExportedTree {
+        dir: std::env::temp_dir().join(format!("noir-profiler-hook-{}", std::process::id())),
+    };
+    fs::remove_dir_all(&tree.dir).ok();
+    fs::create_dir_all(&tree.dir)
+        .with_context(|| format!("Failed to create {}", tree.dir.display()))?;
+    
+    let checkout = format!("--prefix={}/", tree.dir.join("tree").display());
+    if head {
+        let index = tree.dir.join("index");
+        git_with_index(&root, Some(&index), &["read-tree", "HEAD"])?;
+        git_with_index(&root, Some(&index), &["checkout-index", "--all", &checkout])?;
+    } else {
+        git(&root, &["checkout-index", "--all", &checkout])?;
+    }
+    
+    let exported = tree.dir.join("tree").join(prefix);
+    Ok((tree, exported))
+}
+
+pub fn run_hook(project: &Path, all: bool, options: &AnalysisOptions) -> Result<HookOutcome> {
+    let budget = project.join(BUDGET_FILE);
+    if !budget.exists() {
+        bail!("No {} in {}; run `init` first to create budgets", BUDGET_FILE, project.display());
+    }
+    
+    if !all && !staged_files(project)?.iter().any(|path| is_circuit_change(path)) {
+        return Ok(HookOutcome::NoCircuitChanges);
+    }
+    
+    let config = load_notify_config(&budget)?;
+    let baseline_dir = project.join(BASELINE_DIR);
+    
+    let (tree, exported) = export_tree(project, all)?;
+    let artifacts = compile_all(&exported, "--silence-warnings", &tree.dir.join("target"))?;
+    
+    let mut results = Vec::new();
+    for artifact in &artifacts {
+        let circuit = circuit_name(artifact);
+        let analysis = analyze_circuit_with_options(artifact, options)
+            .with_context(|| format!("Failed to analyze {}", circuit))?;
+        let baseline_path = baseline_dir.join(format!("{}.json", circuit));
+        let baseline = baseline_path.exists().then(|| load_report(&baseline_path)).transpose()?;
+        
+        results.push(HookResult {
+            violations: find_violations(baseline.as_ref().unwrap_or(&analysis), &analysis, &config.notify),
+            constraints: analysis.aggregate_constraints,
+            baseline: baseline.map(|baseline| baseline.aggregate_constraints),
+            circuit,
+        });
+    }
+    
+    Ok(HookOutcome::Checked { results, fail_on_violation: config.notify.fail_on_violation })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn hook_kinds_and_scripts() {
+        assert_eq!("Pre_Push".parse::<HookKind>(), Ok(HookKind::PrePush));
+        assert!("post-merge".parse::<HookKind>().is_err());
+        
+        let script = hook_script(HookKind::PrePush);
+        assert!(script.starts_with("#!/bin/sh\n") && script.contains(HOOK_MARKER));
+        assert!(script.contains("hook run --all") && script.contains("git push --no-verify"));
+        assert!(hook_script(HookKind::PreCommit).ends_with("hook run\n"));
+    }
+    
+    #[test]
+    fn circuit_changes_are_recognized() {
+        assert!(is_circuit_change(Path::new("src/main.nr")));
+        assert!(is_circuit_change(Path::new("circuits/Nargo.toml")));
+        assert!(is_circuit_change(Path::new(BUDGET_FILE)));
+        assert!(!is_circuit_change(Path::new("README.md")));
+    }
+    
+    #[test]
+    fn installs_hooks_into_a_repository() {
+        let project = std::env::temp_dir().join(format!("noir-profiler-hook-install-{}", std::process::id()));
+        fs::create_dir_all(&project).unwrap();
+        git(&project, &["init", "-q"]).unwrap();
+        
+        let missing_budget = install_hook(&project, HookKind::PreCommit, false);
+        fs::write(project.join(BUDGET_FILE), "notify: {}\n").unwrap();
+        let installed = install_hook(&project, HookKind::PreCommit, false);
+        let reinstalled = install_hook(&project, HookKind::PreCommit, false);
+        
+        let foreign = project.join(".git/hooks/pre-push");
+        fs::write(&foreign, "#!/bin/sh\n").unwrap();
+        let refused = install_hook(&project, HookKind::PrePush, false);
+        let forced = install_hook(&project, HookKind::PrePush, true);
+        
+        fs::write(project.join("main.nr"), "fn main() {}\n").unwrap();
+        git(&project, &["add", "main.nr"]).unwrap();
+        let staged = staged_files(&project);
+        let script = fs::read_to_string(project.join(".git/hooks/pre-commit"));
+        fs::remove_dir_all(&project).unwrap();
+        
+        assert!(missing_budget.is_err());
+        assert!(installed.is_ok() && reinstalled.is_ok());
+        assert!(script.unwrap().contains(HOOK_MARKER));
+        assert!(refused.unwrap_err().to_string().contains("--force"));
+        assert!(forced.is_ok());
+        assert_eq!(staged.unwrap(), [PathBuf::from("main.nr")]);
+    }
+}
@@ -0,0 +1,365 @@
+use std::fmt;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicU8, Ordering};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Locale {
+    #[default]
+    En,
+    Es,
+    Ja,
+}
+
+impl FromStr for Locale {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let language = s.split(['-', '_', '.']).next().unwrap_or(s);
+        
+        match language.to_ascii_lowercase().as_str() {
+            "en" => Ok(Locale::En),
+            "es" => Ok(Locale::Es),
+            "ja" => Ok(Locale::Ja),
+            _ => Err(format!("unsupported language `{}`, expected one of: en, es, ja", s)),
+        }
+    }
+}
+
+impl fmt::Display for Locale {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Locale::En => write!(f, "en"),
+            Locale::Es => write!(f, "es"),
+            Locale::Ja => write!(f, "ja"),
+        }
+    }
+}
+
+static LOCALE: AtomicU8 = AtomicU8::new(0);
+
+pub fn set_locale(locale: Locale) {
+    LOCALE.store(locale as u8, Ordering::Relaxed);
+}
+
+pub fn locale() -> Locale {
+    match LOCALE.load(Ordering::Relaxed) {
+        1 => Locale::Es,
+        2 => Locale::Ja,
+        _ => Locale::En,
+    }
+}
+
+// key, [en, es, ja]
+static MESSAGES: [(&str, [&str; 3]); 221] = [
+    ("analyze.done", ["Analyzed in", "Analizado en", "解析時間"]),
+    ("analyze.demo", ["This is an experimental demo version", "Esta es una versión experimental de demostración", "これは実験的なデモ版です"]),
+    ("metrics.title", ["Circuit Analysis:", "Análisis del circuito:", "回路解析:"]),
+    ("metrics.metric", ["Metric", "Métrica", "指標"]),
+    ("metrics.value", ["Value", "Valor", "値"]),
+    ("metrics.name", ["Circuit", "Circuito", "回路"]),
+    ("metrics.noir_version", ["Noir Version", "Versión de Noir", "Noir バージョン"]),
+    ("metrics.hash", ["Artifact Hash", "Hash del artefacto", "アーティファクトハッシュ"]),
+    ("metrics.label", ["Run Label", "Etiqueta de ejecución", "実行ラベル"]),
+    ("metrics.field", ["Field", "Campo", "体"]),
+    ("metrics.constraints", ["Total Constraints", "Restricciones totales", "制約数合計"]),
+    ("metrics.count_mode", ["Count Mode", "Modo de conteo", "カウント方式"]),
+    ("metrics.interval", ["95% Interval", "Intervalo del 95%", "95% 区間"]),
+    ("metrics.sampled", ["Sampled Opcodes", "Opcodes muestreados", "サンプリングしたオペコード"]),
+    ("metrics.sampled_value", ["{} of {} ({}%)", "{} de {} ({}%)", "{} / {} ({}%)"]),
+    ("metrics.expression_width", ["Expression Width", "Ancho de expresión", "式の幅"]),
+    ("metrics.opcodes", ["Total ACIR Opcodes", "Opcodes ACIR totales", "ACIR オペコード数合計"]),
+    ("metrics.public_inputs", ["Public Inputs", "Entradas públicas", "公開入力"]),
+    ("metrics.private_inputs", ["Private Inputs", "Entradas privadas", "秘密入力"]),
+    ("metrics.io_count", ["Input/Output Count", "Entradas/salidas", "入力/出力数"]),
+    ("metrics.io_value", ["{} in / {} out", "{} entradas / {} salidas", "入力 {} / 出力 {}"]),
+    ("metrics.proving_time", ["Est. Proving Time", "Tiempo de prueba est.", "推定証明時間"]),
+    ("metrics.efficiency", ["Proving Efficiency", "Eficiencia de prueba", "証明効率"]),
+    ("metrics.efficiency_value", ["{} μs/constraint", "{} μs/restricción", "{} μs/制約"]),
+    ("metrics.hardware_note", ["Proving time estimates vary by hardware configuration", "Las estimaciones de tiempo de prueba varían según el hardware", "証明時間の推定値はハードウェア構成によって異なります"]),
+    ("metrics.sampled_note", ["Extrapolated from {}% of opcodes; counts in every section are scaled estimates, and call graph, attribution, folding, lint and pattern sections are skipped", "Extrapolado a partir del {}% de los opcodes; los conteos de todas las secciones son estimaciones escaladas y se omiten las secciones de grafo de llamadas, atribución, plegado, lint y patrones", "オペコードの {}% から外挿しました。各セクションの件数はスケールした推定値で、コールグラフ・帰属・フォールディング・lint・パターンのセクションは省略されます"]),
+    ("metrics.acvm_note", ["Build with `--features acvm` for exact arithmetic constraint counts", "Compila con `--features acvm` para obtener conteos exactos de restricciones aritméticas", "算術制約を正確に数えるには `--features acvm` 付きでビルドしてください"]),
+    ("functions.title", ["External Operations Analysis:", "Análisis de operaciones externas:", "外部演算の解析:"]),
+    ("functions.operation", ["Operation", "Operación", "演算"]),
+    ("functions.calls", ["Calls", "Llamadas", "呼び出し"]),
+    ("functions.constraints", ["Constraints", "Restricciones", "制約"]),
+    ("functions.share", ["% Circuit", "% circuito", "回路比"]),
+    ("functions.insight", ["External operations account for {}% of total constraints", "Las operaciones externas suponen el {}% de las restricciones", "外部演算が制約全体の {}% を占めています"]),
+    ("structure.title", ["Circuit Structure Analysis:", "Análisis de la estructura del circuito:", "回路構造の解析:"]),
+    ("structure.type", ["Operation Type", "Tipo de operación", "演算の種類"]),
+    ("structure.count", ["Count", "Cantidad", "件数"]),
+    ("structure.share", ["% of Opcodes", "% de opcodes", "オペコード比"]),
+    ("structure.memory", ["Circuit uses memory operations, suggesting array or structured data usage", "El circuito usa operaciones de memoria, lo que sugiere arrays o datos estructurados", "メモリ演算が使われており、配列や構造化データの利用が考えられます"]),
+    ("structure.scalar", ["No memory operations detected, suggesting primarily scalar field operations", "No se detectaron operaciones de memoria; predominan las operaciones escalares de campo", "メモリ演算は検出されず、主にスカラー体演算で構成されています"]),
+    ("details.title", ["Constraint Distribution:", "Distribución de restricciones:", "制約の内訳:"]),
+    ("details.empty", ["No constraints detected in circuit.", "No se detectaron restricciones en el circuito.", "回路に制約は検出されませんでした。"]),
+    ("details.category", ["Category", "Categoría", "カテゴリ"]),
+    ("details.constraints", ["Constraints", "Restricciones", "制約"]),
+    ("details.share", ["% of Total", "% del total", "全体比"]),
+    ("details.hashing", ["Hashing", "Hashing", "ハッシュ"]),
+    ("details.signatures", ["Signatures", "Firmas", "署名"]),
+    ("details.arithmetic", ["Arithmetic Operations", "Operaciones aritméticas", "算術演算"]),
+    ("details.memory", ["Memory", "Memoria", "メモリ"]),
+    ("details.range", ["Range Checks", "Comprobaciones de rango", "範囲チェック"]),
+    ("details.control", ["Control Flow", "Flujo de control", "制御フロー"]),
+    ("audit.title", ["Circuit audit report", "Informe de auditoría del circuito", "回路監査レポート"]),
+    ("audit.artifact", ["Artifact", "Artefacto", "アーティファクト"]),
+    ("audit.generated", ["Generated", "Generado", "生成日時"]),
+    ("audit.metrics", ["Metrics", "Métricas", "指標"]),
+    ("audit.findings", ["Findings", "Hallazgos", "検出事項"]),
+    ("audit.no_findings", ["No findings.", "Sin hallazgos.", "検出事項はありません。"]),
+    ("audit.severity", ["Severity", "Severidad", "重要度"]),
+    ("audit.category", ["Category", "Categoría", "カテゴリ"]),
+    ("audit.finding", ["Finding", "Hallazgo", "内容"]),
+    ("audit.inventory", ["Black-box inventory", "Inventario de funciones black-box", "ブラックボックス関数一覧"]),
+    ("audit.no_black_box", ["No black-box functions.", "Sin funciones black-box.", "ブラックボックス関数はありません。"]),
+    ("audit.cost_each", ["Cost each", "Coste unitario", "1回あたりのコスト"]),
+    ("audit.confidence", ["Confidence", "Confianza", "信頼度"]),
+    ("audit.provenance", ["Provenance", "Procedencia", "出所"]),
+    ("audit.cost_model", ["Cost model provenance", "Procedencia del modelo de costes", "コストモデルの出所"]),
+    ("audit.last_calibration", ["Last calibration", "Última calibración", "最終キャリブレーション"]),
+    ("audit.never", ["never (built-in defaults)", "nunca (valores por defecto)", "なし (組み込みの既定値)"]),
+    ("audit.disclaimer", ["Constraint counts are estimates from an experimental cost model.", "Los conteos de restricciones son estimaciones de un modelo de costes experimental.", "制約数は実験的なコストモデルによる推定値です。"]),
+    ("audit.constraints", ["Total constraints", "Restricciones totales", "制約数合計"]),
+    ("audit.expression_width", ["Expression width", "Ancho de expresión", "式の幅"]),
+    ("audit.opcodes", ["ACIR opcodes", "Opcodes ACIR", "ACIR オペコード数"]),
+    ("audit.public_inputs", ["Public inputs", "Entradas públicas", "公開入力"]),
+    ("audit.private_inputs", ["Private inputs", "Entradas privadas", "秘密入力"]),
+    ("audit.return_values", ["Return values", "Valores de retorno", "戻り値"]),
+    ("audit.proving_time", ["Est. proving time", "Tiempo de prueba est.", "推定証明時間"]),
+    ("audit.model_confidence", ["Model confidence", "Confianza del modelo", "モデルの信頼度"]),
+    ("compare.title", ["Comparison Results:", "Resultados de la comparación:", "比較結果:"]),
+    ("compare.size_diff", ["Circuit Size Difference: {} constraints", "Diferencia de tamaño del circuito: {} restricciones", "回路サイズの差: {} 制約"]),
+    ("compare.within_noise", ["within noise", "dentro del ruido", "ノイズの範囲内"]),
+    ("compare.below_floor", ["{} below {}", "{}, por debajo de {}", "{}、{} 未満"]),
+    ("compare.proving_impact", ["Proving Time Impact: {} to {} ms ({})", "Impacto en el tiempo de prueba: de {} a {} ms ({})", "証明時間への影響: {} 〜 {} ms ({})"]),
+    ("compare.regression", ["likely regression", "probable regresión", "退行の可能性が高い"]),
+    ("compare.improvement", ["likely improvement", "probable mejora", "改善の可能性が高い"]),
+    ("compare.indistinguishable", ["not statistically distinguishable", "sin diferencia estadísticamente significativa", "統計的に区別できない"]),
+    ("compare.efficiency_title", ["Proving Efficiency:", "Eficiencia de prueba:", "証明効率:"]),
+    ("compare.efficiency_row", ["Circuit {}: {} μs per constraint", "Circuito {}: {} μs por restricción", "回路 {}: 制約あたり {} μs"]),
+    ("compare.operations_title", ["Potential Operations Detected:", "Posibles operaciones detectadas:", "検出された可能性のある演算:"]),
+    ("compare.match", ["Circuit difference {} {} ({} constraints, {}% confidence)", "Diferencia del circuito: {} {} ({} restricciones, {}% de confianza)", "回路の差分: {} {} ({} 制約、信頼度 {}%)"]),
+    ("compare.match_strong", ["strong similarity to", "gran similitud con", "強い類似:"]),
+    ("compare.match_possible", ["possible", "posible", "可能性あり:"]),
+    ("compare.match_resembles", ["resembles", "se parece a", "類似:"]),
+    ("compare.match_note", ["Note: Actual operation costs may vary based on circuit architecture and proving system", "Nota: los costes reales de las operaciones pueden variar según la arquitectura del circuito y el sistema de prueba", "注: 実際の演算コストは回路構成や証明システムによって異なる場合があります"]),
+    ("compare.functions_title", ["External Operations Comparison:", "Comparación de operaciones externas:", "外部演算の比較:"]),
+    ("compare.labels_title", ["Labeled Regions Comparison:", "Comparación de regiones etiquetadas:", "ラベル付き領域の比較:"]),
+    ("compare.label", ["Label", "Etiqueta", "ラベル"]),
+    ("compare.circuit1", ["Circuit 1", "Circuito 1", "回路 1"]),
+    ("compare.circuit2", ["Circuit 2", "Circuito 2", "回路 2"]),
+    ("compare.diff", ["Diff", "Dif.", "差分"]),
+    ("batch.no_failures", ["No failures to retry in {}", "No hay fallos que reintentar en {}", "{} に再試行する失敗はありません"]),
+    ("batch.retrying", ["Retrying {} failed artifact(s) from {}", "Reintentando {} artefacto(s) fallido(s) de {}", "失敗した {} 件のアーティファクトを {} から再試行します"]),
+    ("batch.wrote_manifest", ["Wrote manifest to {}", "Manifiesto escrito en {}", "マニフェストを {} に書き出しました"]),
+    ("batch.title", ["Batch Analysis Results:", "Resultados del análisis por lotes:", "一括解析の結果:"]),
+    ("batch.mode", ["Mode", "Modo", "方式"]),
+    ("batch.opcodes", ["Opcodes", "Opcodes", "オペコード"]),
+    ("batch.ratio", ["Constraint/Opcode", "Restricción/opcode", "制約/オペコード"]),
+    ("batch.skipped", ["SKIPPED", "OMITIDO", "スキップ"]),
+    ("batch.error", ["ERROR", "ERROR", "エラー"]),
+    ("batch.failures", ["{} of {} artifact(s) failed: {}", "Fallaron {} de {} artefacto(s): {}", "{} / {} 件のアーティファクトが失敗しました: {}"]),
+    ("batch.retry_hint", ["Re-run only the failures with: batch --retry-failed <manifest> --manifest <manifest>", "Vuelve a ejecutar solo los fallos con: batch --retry-failed <manifest> --manifest <manifest>", "失敗分だけを再実行するには: batch --retry-failed <manifest> --manifest <manifest>"]),
+    ("stats.title", ["Research Statistics Collection:", "Recopilación de estadísticas de investigación:", "研究用統計の収集:"]),
+    ("stats.collecting", ["Collecting detailed metrics from {} circuits...", "Recopilando métricas detalladas de {} circuitos...", "{} 個の回路から詳細な指標を収集しています..."]),
+    ("stats.header", ["NOIR PROFILER STATISTICS DATA - EXCEL/CSV FORMAT", "DATOS ESTADÍSTICOS DE NOIR PROFILER - FORMATO EXCEL/CSV", "NOIR PROFILER 統計データ - EXCEL/CSV 形式"]),
+    ("stats.generated", ["Generated on {}", "Generado el {}", "生成日時: {}"]),
+    ("stats.directory", ["Directory: {}", "Directorio: {}", "ディレクトリ: {}"]),
+    ("stats.label", ["Label: {}", "Etiqueta: {}", "ラベル: {}"]),
+    ("stats.note", ["NOTE: {}", "NOTA: {}", "注: {}"]),
+    ("stats.failed", ["{} left out of the statistics: {}", "{} queda fuera de las estadísticas: {}", "{} は統計から除外されました: {}"]),
+    ("stats.complete", ["Statistics collection complete", "Recopilación de estadísticas completada", "統計の収集が完了しました"]),
+    ("stats.copy", ["Copy the data above for Excel/CSV analysis", "Copia los datos anteriores para analizarlos en Excel/CSV", "上のデータをコピーして Excel/CSV で分析してください"]),
+    ("stats.anonymized_title", ["Anonymized Statistics Export:", "Exportación de estadísticas anonimizadas:", "匿名化した統計のエクスポート:"]),
+    ("stats.anonymized_header", ["NOIR PROFILER ANONYMIZED STATISTICS - EXCEL/CSV FORMAT", "ESTADÍSTICAS ANONIMIZADAS DE NOIR PROFILER - FORMATO EXCEL/CSV", "NOIR PROFILER 匿名化統計 - EXCEL/CSV 形式"]),
+    ("stats.anonymized_rounding", ["Counts rounded to multiples of {}, noise ±{}%, operations used by fewer than {} circuits suppressed", "Conteos redondeados a múltiplos de {}, ruido ±{}%, se suprimen las operaciones usadas por menos de {} circuitos", "件数は {} の倍数に丸め、ノイズ ±{}%、使用回路が {} 未満の演算は非表示"]),
+    ("stats.anonymized_io", ["Input and output counts rounded up to a power of two", "Conteos de entradas y salidas redondeados hacia arriba a una potencia de dos", "入力数と出力数は2のべき乗に切り上げ"]),
+    ("stats.below_k", ["WARNING: only {} circuits; every operation is suppressed below k = {}", "ADVERTENCIA: solo {} circuitos; todas las operaciones se suprimen por debajo de k = {}", "警告: 回路は {} 個のみです。k = {} 未満のため全演算を非表示にしました"]),
+    ("stats.suppressed", ["{} rare operation(s) suppressed", "{} operación(es) poco frecuente(s) suprimida(s)", "まれな演算 {} 件を非表示にしました"]),
+    ("calls.title", ["Function Call Graph:", "Grafo de llamadas de funciones:", "関数呼び出しグラフ:"]),
+    ("calls.edge", ["{} -> {} ({} call sites)", "{} -> {} ({} puntos de llamada)", "{} -> {} (呼び出し箇所 {})"]),
+    ("calls.function", ["Function", "Función", "関数"]),
+    ("calls.invocations", ["Invocations", "Invocaciones", "呼び出し回数"]),
+    ("calls.per_call", ["Constraints/Call", "Restricciones/llamada", "制約/呼び出し"]),
+    ("calls.all", ["All Invocations", "Todas las invocaciones", "全呼び出し"]),
+    ("calls.insight", ["Main circuit is {} constraints with calls kept separate, {} if every call is inlined ({})", "El circuito principal tiene {} restricciones con las llamadas separadas y {} si se integran todas en línea ({})", "呼び出しを分けた場合のメイン回路は {} 制約、すべてインライン化すると {} ({})"]),
+    ("calls.inline_hint", ["Separate functions are proven once per invocation; #[inline] trades that for a larger main circuit", "Las funciones separadas se prueban una vez por invocación; #[inline] lo cambia por un circuito principal más grande", "分離された関数は呼び出しごとに証明されます。#[inline] はその代わりにメイン回路を大きくします"]),
+    ("calls.strategy", ["Proving strategy {}: {} constraints across {} proof(s)", "Estrategia de prueba {}: {} restricciones en {} prueba(s)", "証明戦略 {}: {} 制約 (証明 {} 件)"]),
+    ("calls.overhead", ["Includes ~{} constraints of aggregation overhead per folded proof", "Incluye ~{} restricciones de sobrecarga de agregación por prueba plegada", "フォールドした証明ごとに約 {} 制約の集約オーバーヘッドを含みます"]),
+    ("folding.title", ["Constant vs Witness-Dependent Constraints:", "Restricciones constantes frente a dependientes del testigo:", "定数制約とウィットネス依存制約:"]),
+    ("folding.kind", ["Kind", "Tipo", "種類"]),
+    ("folding.meaning", ["Meaning", "Significado", "意味"]),
+    ("folding.constant", ["no witness terms", "sin términos de testigo", "ウィットネス項なし"]),
+    ("folding.foldable", ["derives a known constant", "deriva una constante conocida", "既知の定数を導出"]),
+    ("folding.witness", ["needed at proving time", "necesaria al probar", "証明時に必要"]),
+    ("folding.insight", ["{} constraints ({}%) could have been evaluated at compile time", "{} restricciones ({}%) podrían haberse evaluado en tiempo de compilación", "{} 制約 ({}%) はコンパイル時に評価できた可能性があります"]),
+    ("folding.opcodes", ["Opcodes: {}", "Opcodes: {}", "オペコード: {}"]),
+    ("folding.more", ["(+{} more)", "(+{} más)", "(他 {} 件)"]),
+    ("folding.hint", ["Look for values that are known at compile time but pass through a witness (e.g. unconstrained results or\n  arithmetic on constants); making them `comptime`/literal lets the compiler fold them away", "Busca valores conocidos en tiempo de compilación que pasan por un testigo (p. ej. resultados unconstrained o\n  aritmética sobre constantes); hacerlos `comptime`/literales permite que el compilador los pliegue", "コンパイル時に判明しているのにウィットネスを経由している値 (unconstrained の結果や定数同士の演算など) を\n  探してください。`comptime` やリテラルにすればコンパイラが畳み込めます"]),
+    ("dedup.title", ["Duplicate Black-Box Calls:", "Llamadas black-box duplicadas:", "重複したブラックボックス呼び出し:"]),
+    ("dedup.inputs", ["Inputs", "Entradas", "入力"]),
+    ("dedup.cost_each", ["Cost Each", "Coste unitario", "1回のコスト"]),
+    ("dedup.reclaimable", ["Reclaimable", "Recuperable", "削減可能"]),
+    ("dedup.insight", ["{} constraints are certain savings: each call above repeats an earlier one on the same input witnesses,\n  so compute the result once and reuse it", "{} restricciones son un ahorro seguro: cada llamada anterior repite otra previa con los mismos testigos de entrada,\n  así que calcula el resultado una vez y reutilízalo", "{} 制約は確実に削減できます。上の各呼び出しは同じ入力ウィットネスで前の呼び出しを繰り返しているため、\n  結果を一度だけ計算して再利用してください"]),
+    ("lint.title", ["Suggestions:", "Sugerencias:", "提案:"]),
+    ("lint.saves", ["saves ~{} constraints", "ahorra ~{} restricciones", "約 {} 制約を削減"]),
+    ("lint.total", ["{} constraints reclaimable across {} suggestion(s)", "{} restricciones recuperables en {} sugerencia(s)", "削減可能な制約 {} (提案 {} 件)"]),
+    ("benchmark.title", ["Reference Implementations:", "Implementaciones de referencia:", "参照実装:"]),
+    ("benchmark.none", ["No reference pattern (pedersen Merkle proof, ECDSA, EdDSA, SHA-256) found in this circuit", "No se encontró ningún patrón de referencia (prueba Merkle con pedersen, ECDSA, EdDSA, SHA-256) en este circuito", "この回路に参照パターン (pedersen の Merkle 証明、ECDSA、EdDSA、SHA-256) は見つかりませんでした"]),
+    ("benchmark.reference", ["Reference", "Referencia", "参照"]),
+    ("benchmark.instances", ["Instances", "Instancias", "インスタンス"]),
+    ("benchmark.expected", ["Expected", "Esperado", "期待値"]),
+    ("benchmark.explained", ["{} constraints vs {} for reference implementations of the same primitives ({}% explained)", "{} restricciones frente a {} de las implementaciones de referencia de las mismas primitivas ({}% explicado)", "{} 制約 (同じプリミティブの参照実装では {}、{}% を説明)"]),
+    ("benchmark.overhead", ["{} constraints vs {} for reference implementations of the same primitives: {} come from other logic or heavier code around the primitives ({}% explained)", "{} restricciones frente a {} de las implementaciones de referencia de las mismas primitivas: {} provienen de otra lógica o de código más pesado alrededor de las primitivas ({}% explicado)", "{} 制約 (同じプリミティブの参照実装では {}): {} 制約は他のロジックやプリミティブ周辺の重いコードに由来します ({}% を説明)"]),
+    ("common.total", ["Total", "Total", "合計"]),
+    ("cloud.title", ["Cloud Cost Estimate:", "Estimación de coste en la nube:", "クラウドコストの見積もり:"]),
+    ("cloud.instance", ["Instance", "Instancia", "インスタンス"]),
+    ("cloud.hourly_rate", ["Hourly Rate", "Tarifa por hora", "時間単価"]),
+    ("cloud.peak_memory", ["Est. Peak Memory", "Memoria máxima est.", "推定ピークメモリ"]),
+    ("cloud.exceeds_memory", ["{} (exceeds instance memory)", "{} (supera la memoria de la instancia)", "{} (インスタンスのメモリを超過)"]),
+    ("cloud.per_proof", ["Cost per Proof", "Coste por prueba", "証明1件あたりのコスト"]),
+    ("cloud.per_million", ["Cost per Million Proofs", "Coste por millón de pruebas", "証明100万件あたりのコスト"]),
+    ("gpu.title", ["GPU Proving Estimate:", "Estimación de prueba en GPU:", "GPU 証明の見積もり:"]),
+    ("gpu.phase", ["Phase", "Fase", "フェーズ"]),
+    ("gpu.speedup", ["Speedup", "Aceleración", "高速化"]),
+    ("gpu.msm", ["MSM", "MSM", "MSM"]),
+    ("gpu.fft", ["FFT", "FFT", "FFT"]),
+    ("gpu.witness", ["Witness Gen", "Gen. del testigo", "ウィットネス生成"]),
+    ("gpu.note", ["Witness generation stays on the CPU; MSM and FFT use the profile's throughput", "La generación del testigo se queda en la CPU; MSM y FFT usan el rendimiento del perfil", "ウィットネス生成は CPU のまま、MSM と FFT はプロファイルのスループットを使います"]),
+    ("gpu.memory_warning", ["Estimated {} GB exceeds the {} GB of GPU memory", "Los {} GB estimados superan los {} GB de memoria de la GPU", "推定 {} GB は GPU メモリ {} GB を超えています"]),
+    ("energy.title", ["Energy Estimate ({}, {} J/constraint):", "Estimación de energía ({}, {} J/restricción):", "エネルギーの見積もり ({}、{} J/制約):"]),
+    ("energy.per_proof", ["Per proof:", "Por prueba:", "証明1件あたり:"]),
+    ("energy.per_million", ["Per million proofs:", "Por millón de pruebas:", "証明100万件あたり:"]),
+    ("setup.title", ["One-Time Setup Estimate:", "Estimación de la configuración inicial:", "初回セットアップの見積もり:"]),
+    ("setup.backend", ["Backend", "Backend", "バックエンド"]),
+    ("setup.circuit_size", ["Circuit Size", "Tamaño del circuito", "回路サイズ"]),
+    ("setup.proving_key", ["Proving Key", "Clave de prueba", "証明鍵"]),
+    ("setup.keygen", ["Keygen (ms)", "Gen. de claves (ms)", "鍵生成 (ms)"]),
+    ("setup.load", ["Load (ms)", "Carga (ms)", "読み込み (ms)"]),
+    ("setup.peak_memory", ["Peak Memory", "Memoria máxima", "ピークメモリ"]),
+    ("setup.note", ["Keys are generated once per circuit; cache them in CI to pay the load time instead of keygen on cold starts", "Las claves se generan una vez por circuito; guárdalas en caché en CI para pagar el tiempo de carga en lugar de la generación en arranques en frío", "鍵は回路ごとに一度だけ生成されます。CI でキャッシュすれば、コールドスタート時に鍵生成ではなく読み込み時間だけで済みます"]),
+    ("evm.title", ["Solidity Verifier Estimate:", "Estimación del verificador Solidity:", "Solidity 検証器の見積もり:"]),
+    ("evm.bytecode", ["Verifier Bytecode", "Bytecode del verificador", "検証器のバイトコード"]),
+    ("evm.bytecode_value", ["{} bytes (limit {})", "{} bytes (límite {})", "{} バイト (上限 {})"]),
+    ("evm.public_values", ["Public Values", "Valores públicos", "公開値"]),
+    ("evm.proof_size", ["Proof Size", "Tamaño de la prueba", "証明サイズ"]),
+    ("evm.bytes", ["{} bytes", "{} bytes", "{} バイト"]),
+    ("evm.calldata", ["Calldata", "Calldata", "コールデータ"]),
+    ("evm.calldata_value", ["{} bytes ({} gas)", "{} bytes ({} de gas)", "{} バイト (ガス {})"]),
+    ("evm.verification_gas", ["Verification Gas", "Gas de verificación", "検証ガス"]),
+    ("evm.total_gas", ["Total Gas", "Gas total", "合計ガス"]),
+    ("evm.bytecode_warning", ["verifier bytecode of {} bytes exceeds the EIP-170 contract size limit of {} bytes", "el bytecode del verificador de {} bytes supera el límite de tamaño de contrato de EIP-170 de {} bytes", "検証器のバイトコード {} バイトが EIP-170 のコントラクトサイズ上限 {} バイトを超えています"]),
+    ("evm.calldata_warning", ["calldata of {} bytes ({} public values) exceeds --max-calldata {}", "la calldata de {} bytes ({} valores públicos) supera --max-calldata {}", "コールデータ {} バイト (公開値 {}) が --max-calldata {} を超えています"]),
+    ("evm.gas_warning", ["verification costs ~{} gas ({} public values) and exceeds --max-gas {}", "la verificación cuesta ~{} de gas ({} valores públicos) y supera --max-gas {}", "検証に約 {} ガス (公開値 {}) かかり、--max-gas {} を超えています"]),
+    ("evm.costs_title", ["Public Value Costs:", "Costes de los valores públicos:", "公開値のコスト:"]),
+    ("evm.fields", ["Fields", "Campos", "フィールド数"]),
+    ("evm.calldata_gas", ["Calldata Gas", "Gas de calldata", "コールデータのガス"]),
+    ("evm.hashing_gas", ["Hashing Gas", "Gas de hash", "ハッシュのガス"]),
+    ("evm.verifier_gas", ["Verifier Gas", "Gas del verificador", "検証器のガス"]),
+    ("evm.commitment", ["`{}` spans {} fields and costs {} gas per verification; publishing a hash commitment to it instead saves ~{} gas", "`{}` ocupa {} campos y cuesta {} de gas por verificación; publicar en su lugar un compromiso hash ahorra ~{} de gas", "`{}` は {} フィールドにまたがり、検証ごとに {} ガスかかります。代わりにハッシュコミットメントを公開すると約 {} ガス削減できます"]),
+    ("evm.commitment_constraints", ["Hashing it with Poseidon2 adds ~{} constraints to the circuit", "Aplicarle hash con Poseidon2 añade ~{} restricciones al circuito", "Poseidon2 でハッシュすると回路に約 {} 制約が加わります"]),
+    ("evm.commitment_uncalibrated", ["The in-circuit hash adds constraints; calibrate `poseidon2` to see how many", "El hash dentro del circuito añade restricciones; calibra `poseidon2` para ver cuántas", "回路内のハッシュで制約が増えます。`poseidon2` をキャリブレーションすると件数がわかります"]),
+    ("reconcile.title", ["Estimate vs {} Gate Report: {}", "Estimación frente al informe de puertas de {}: {}", "見積もりと {} ゲートレポートの比較: {}"]),
+    ("reconcile.source", ["Source", "Origen", "要因"]),
+    ("reconcile.estimated", ["Estimated", "Estimado", "推定"]),
+    ("reconcile.actual", ["Actual", "Real", "実測"]),
+    ("reconcile.difference", ["Difference", "Diferencia", "差"]),
+    ("reconcile.share", ["Share of Gap", "Parte de la brecha", "差に占める割合"]),
+    ("reconcile.lookup_expansion", ["lookup expansion", "expansión de lookups", "ルックアップ展開"]),
+    ("reconcile.range_decomposition", ["range decomposition", "descomposición de rangos", "範囲分解"]),
+    ("reconcile.arithmetization", ["arithmetization", "aritmetización", "算術化"]),
+    ("reconcile.copy_constraints", ["copy constraints", "restricciones de copia", "コピー制約"]),
+    ("reconcile.total_only", ["The gate report has no usable `gates_per_opcode`; only the total is reconciled", "El informe de puertas no tiene un `gates_per_opcode` utilizable; solo se concilia el total", "ゲートレポートに使える `gates_per_opcode` がないため、合計のみ照合します"]),
+    ("reconcile.tuned", ["{} gate factor {} → {} (saved to {})", "factor de puertas de {}: {} → {} (guardado en {})", "{} のゲート係数 {} → {} ({} に保存)"]),
+    ("reconcile.untuned", ["{} gate factor left at {}: this artifact already tuned it, or the estimate is empty", "factor de puertas de {} sin cambios en {}: este artefacto ya lo ajustó o la estimación está vacía", "{} のゲート係数は {} のまま: このアーティファクトで調整済みか、見積もりが空です"]),
+];
+
+pub fn t(key: &'static str) -> &'static str {
+    match MESSAGES.iter().find(|(id, _)| *id == key) {
+        Some((_, translations)) => translations[locale() as usize],
+        None => {
+            debug_assert!(false, "missing message id `{}`", key);
+            key
+        }
+    }
+}
+
+pub fn tf(key: &'static str, args: &[&dyn fmt::Display]) -> String {
+    let mut message = t(key).to_string();
+    let mut start = 0;
+    
+    for arg in args {
+        let Some(pos) = message[start..].find("{}").map(|pos| pos + start) else {
+            break;
+        };
+        
+        let value = arg.to_string();
+        message.replace_range(pos..pos + 2, &value);
+        start = pos + value.len();
+    }
+    
+    message
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    use std::path::Path;
+    
+    fn message_ids(source: &str) -> Vec<&str> {
+        source.match_indices('"')
+            .filter_map(|(start, _)| {
+                let rest = &source[start + 1..];
+                let end = rest.find(|c: char| !(c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.'))?;
+                rest[end..].starts_with('"').then(|| &rest[..end])
+            })
+            .filter(|id| match id.split_once('.') {
+                Some((namespace, name)) => !name.is_empty() && !name.contains('.')
+                    && MESSAGES.iter().any(|(known, _)| known.split_once('.').is_some_and(|(known, _)| known == namespace)),
+                None => false,
+            })
+            .collect()
+    }
+    
+    #[test]
+    fn every_message_id_in_the_source_is_defined() {
+        let src = Path::new(env!("CARGO_MANIFEST_DIR")).join("src");
+        let mut missing = Vec::new();
+        
+        for entry in fs::read_dir(&src).unwrap() {
+            let path = entry.unwrap().path();
+            if path.extension().is_none_or(|ext| ext != "rs") || path.ends_with("i18n.rs") {
+                continue;
+            }
+            
+            let source = fs::read_to_string(&path).unwrap();
+            for id in message_ids(&source) {
+                if !MESSAGES.iter().any(|(known, _)| *known == id) {
+                    missing.push(format!("{}: {}", path.display(), id));
+                }
+            }
+        }
+        
+        assert!(missing.is_empty(), "message ids without translations: {:?}", missing);
+    }
+    
+    #[test]
+    fn every_locale_translates_every_message() {
+        for (id, translations) in MESSAGES.iter() {
+            let placeholders = translations[0].matches("{}").count();
+            
+            for (locale, text) in [Locale::En, Locale::Es, Locale::Ja].iter().zip(translations) {
+                assert!(!text.is_empty(), "`{}` is empty in {}", id, locale);
+                assert_eq!(text.matches("{}").count(), placeholders, "`{}` has a different number of placeholders in {}", id, locale);
+            }
+        }
+    }
+    
+    #[test]
+    fn message_ids_are_unique() {
+        for (i, (id, _)) in MESSAGES.iter().enumerate() {
+            assert!(MESSAGES[i + 1..].iter().all(|(other, _)| other != id), "`{}` is defined twice", id);
+        }
+    }
+}
@@ -0,0 +1,201 @@
+use crate::analyzer::{analyze_circuit_with_options, AnalysisOptions};
+use crate::artifact::{is_artifact_path, ARTIFACT_SUFFIXES};
+use crate::core::CircuitAnalysis;
+use crate::recompile::compile_project;
+use crate::report::write_report;
+use anyhow::{bail, Context, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const BASELINE_DIR: &str = "noir-profiler/baseline";
+pub const BUDGET_FILE: &str = "circuits.budget.yaml";
+pub const WORKFLOW_FILE: &str = ".github/workflows/noir-profiler.yml";
+
+pub const BUDGET_HEADROOM: f64 = 1.10;
+pub const BUDGET_REGRESSION_PERCENT: f64 = 5.0;
+
+pub fn circuit_name(path: &Path) -> String {
+    let name = path.file_name().unwrap_or_default().to_string_lossy().to_string();
+    
+    ARTIFACT_SUFFIXES.iter()
+        .find_map(|suffix| name.strip_suffix(suffix))
+        .unwrap_or(&name)
+        .to_string()
+}
+
+fn project_artifacts(project: &Path) -> Vec<PathBuf> {
+    let mut artifacts: Vec<PathBuf> = fs::read_dir(project.join("target"))
+        .map(|entries| entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.is_file() && is_artifact_path(path))
+            .collect())
+        .unwrap_or_default();
+    
+    artifacts.sort();
+    artifacts
+}
+
+pub fn baseline_analyses(project: &Path, options: &AnalysisOptions) -> Result<Vec<(String, CircuitAnalysis)>> {
+    let artifacts = project_artifacts(project);
+    
+    if !artifacts.is_empty() {
+        return artifacts.iter()
+            .map(|path| Ok((circuit_name(path), analyze_circuit_with_options(path, options)
+                .with_context(|| format!("Failed to analyze {}", path.display()))?)))
+            .collect();
+    }
+    
+    let compiled = compile_project(project)?;
+    let analysis = analyze_circuit_with_options(&compiled.artifact, options)
+        .context("Failed to analyze circuit")?;
+    
+    Ok(vec![(circuit_name(&compiled.artifact), analysis)])
+}
+
+pub fn budget_yaml(baselines: &[(String, CircuitAnalysis)]) -> String {
+    let largest = baselines.iter()
+        .map(|(_, analysis)| analysis.aggregate_constraints)
+        .max()
+        .unwrap_or(0);
+    let budget = ((largest as f64 * BUDGET_HEADROOM / 1000.0).ceil() * 1000.0) as usize;
+    
+    format!("# Constraint budgets checked by `noir-circuit-profiler report diff --notify {}`.
+# max_constraints applies to every circuit: the largest baseline ({}) plus {:.0}% headroom.
+notify:
+  fail_on_violation: true
+  max_constraints: {}
+  max_regression_percent: {}
+  # webhook: https://hooks.slack.com/services/T000/B000/XXXX
+  # categories:
+  #   hashing:
+  #     max_percent: 60
+  # modules:
+  #   crypto/:
+  #     max_constraints: 120000
+", BUDGET_FILE, largest, (BUDGET_HEADROOM - 1.0) * 100.0, budget, BUDGET_REGRESSION_PERCENT)
+}
+
+pub fn workflow_yaml(noir_version: Option<&str>) -> String {
+    let toolchain = noir_version
+        .map(|version| format!("v{}", version.split('+').next().unwrap_or(version)))
+        .unwrap_or_else(|| "stable".to_string());
+    
+    format!("name: noir-profiler
+
+on:
+  pull_request:
+
+jobs:
+  constraints:
+    runs-on: ubuntu-latest
+    steps:
+      - uses: actions/checkout@v4
+      - uses: noir-lang/noirup@v0.1.3
+        with:
+          toolchain: {toolchain}
+      - run: cargo install --git https://github.com/symulacr/noir-profiler noir-circuit-profiler
+      - run: nargo compile --silence-warnings
+      - name: Check constraint budgets
+        run: |
+          for baseline in {baseline}/*.json; do
+            name=$(basename \"$baseline\" .json)
+            noir-circuit-profiler --plain analyze \"target/$name.json\" --out \"$RUNNER_TEMP/$name.json\" > /dev/null
+            noir-circuit-profiler --plain report diff \"$baseline\" \"$RUNNER_TEMP/$name.json\" --notify {budget}
+          done
+", toolchain = toolchain, baseline = BASELINE_DIR, budget = BUDGET_FILE)
+}
+
+pub fn init_project(project: &Path, workflow: bool, force: bool, options: &AnalysisOptions) -> Result<Vec<PathBuf>> {
+    if !project.join("Nargo.toml").exists() {
+        bail!("No Nargo.toml in {}", project.display());
+    }
+    
+    let mut targets = vec![project.join(BUDGET_FILE)];
+    if workflow {
+        targets.push(project.join(WORKFLOW_FILE));
+    }
+    
+    if !force {
+        if let Some(existing) = targets.iter().find(|path| path.exists()) {
+            bail!("{} already exists; pass --force to overwrite", existing.display());
+        }
+    }
+    
+    let baselines = baseline_analyses(project, options)?;
+    let baseline_dir = project.join(BASELINE_DIR);
+    fs::create_dir_all(&baseline_dir)
+        .with_context(|| format!("Failed to create {}", baseline_dir.display()))?;
+    
+    let mut written = Vec::new();
+    for (name, analysis) in &baselines {
+        let path = baseline_dir.join(format!("{}.json", name));
+        write_report(analysis, &path)?;
+        written.push(path);
+    }
+    
+    let budget = project.join(BUDGET_FILE);
+    fs::write(&budget, budget_yaml(&baselines))
+        .with_context(|| format!("Failed to write {}", budget.display()))?;
+    written.push(budget);
+    
+    if workflow {
+        let path = project.join(WORKFLOW_FILE);
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent)
+                .with_context(|| format!("Failed to create {}", parent.display()))?;
+        }
+        
+        let noir_version = baselines.iter().find_map(|(_, analysis)| analysis.metadata.noir_version.as_deref());
+        fs::write(&path, workflow_yaml(noir_version))
+            .with_context(|| format!("Failed to write {}", path.display()))?;
+        written.push(path);
+    }
+    
+    Ok(written)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notify::NotifyConfig;
+    
+    #[test]
+    fn names_circuits_without_artifact_suffixes() {
+        assert_eq!(circuit_name(Path::new("target/main.json.gz")), "main");
+        assert_eq!(circuit_name(Path::new("target/main.json")), "main");
+        assert_eq!(circuit_name(Path::new("target/notes.txt")), "notes.txt");
+    }
+    
+    #[test]
+    fn budget_adds_headroom_to_the_largest_baseline() {
+        let baselines: Vec<(String, CircuitAnalysis)> = [12_345, 800].iter()
+            .map(|constraints| ("c".to_string(), CircuitAnalysis { aggregate_constraints: *constraints, ..Default::default() }))
+            .collect();
+        let config: NotifyConfig = serde_yaml::from_str(&budget_yaml(&baselines)).unwrap();
+        
+        assert_eq!(config.notify.max_constraints, Some(14_000));
+        assert_eq!(config.notify.max_regression_percent, Some(BUDGET_REGRESSION_PERCENT));
+        assert!(config.notify.fail_on_violation);
+    }
+    
+    #[test]
+    fn workflow_pins_the_compiler_version() {
+        assert!(workflow_yaml(Some("1.0.0-beta.3+abc")).contains("toolchain: v1.0.0-beta.3\n"));
+        assert!(workflow_yaml(None).contains("toolchain: stable\n"));
+    }
+    
+    #[test]
+    fn refuses_to_overwrite_without_force() {
+        let project = std::env::temp_dir().join(format!("noir-profiler-init-{}", std::process::id()));
+        fs::create_dir_all(&project).unwrap();
+        let missing = init_project(&project, false, false, &AnalysisOptions::default()).err().map(|e| e.to_string());
+        
+        fs::write(project.join("Nargo.toml"), "").unwrap();
+        fs::write(project.join(BUDGET_FILE), "").unwrap();
+        let existing = init_project(&project, false, false, &AnalysisOptions::default()).err().map(|e| e.to_string());
+        fs::remove_dir_all(&project).ok();
+        
+        assert!(missing.unwrap().starts_with("No Nargo.toml"));
+        assert!(existing.unwrap().ends_with("already exists; pass --force to overwrite"));
+    }
+}
@@ -0,0 +1,341 @@
+use crate::curve::{curve_operations, MSM_BASE_COST};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+
+pub struct LintContext<'a> {
+    pub data: &'a Value,
+    pub opcodes: &'a [Value],
+    pub opcode_costs: &'a [usize],
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Lint {
+    pub rule: String,
+    pub severity: String,
+    pub message: String,
+    pub sites: Vec<usize>,
+    pub savings: usize,
+}
+
+type LintRule = fn(&LintContext) -> Vec<Lint>;
+
+pub static BUILTIN_RULES: [(&str, LintRule); 3] = [
+    ("range-consolidation", range_consolidation),
+    ("copy-chains", copy_chains),
+    ("fixed-base-msm", fixed_base_msm),
+];
+
+pub fn run_lints(ctx: &LintContext) -> Vec<Lint> {
+    let mut lints: Vec<Lint> = BUILTIN_RULES.iter()
+        .flat_map(|(_, rule)| rule(ctx))
+        .collect();
+    
+    lints.sort_by(|a, b| b.savings.cmp(&a.savings).then(a.sites.cmp(&b.sites)));
+    lints
+}
+
+fn range_check(op: &Value) -> Option<(&str, u32)> {
+    if op["type"].as_str() != Some("BlackBoxFunction")
+        || !op["function"].as_str().is_some_and(|name| name.eq_ignore_ascii_case("range")) {
+        return None;
+    }
+    
+    let input = op["inputs"].as_array()?.first()?;
+    let bits = input["num_bits"].as_u64()
+        .or_else(|| op["num_bits"].as_u64())?;
+    
+    Some((input["variable"].as_str()?, bits as u32))
+}
+
+fn bits_for_terms(count: usize) -> u32 {
+    usize::BITS - count.saturating_sub(1).leading_zeros()
+}
+
+fn derived_bound(op: &Value, bounds: &HashMap<String, (u32, Option<usize>)>) -> Option<(String, u32)> {
+    let terms = op["expression"]["terms"].as_array()?;
+    if op["expression"]["constant"].as_str().is_some_and(|constant| constant != "0") {
+        return None;
+    }
+    
+    let mut output = None;
+    let mut inputs = Vec::new();
+    
+    for term in terms {
+        let variable = term["variable"].as_str()?;
+        match term["coefficient"].as_str()? {
+            "1" => inputs.push(bounds.get(variable)?.0),
+            "-1" if output.is_none() => output = Some(variable),
+            _ => return None,
+        }
+    }
+    
+    let widest = inputs.iter().copied().max()?;
+    Some((output?.to_string(), widest + bits_for_terms(inputs.len())))
+}
+
+fn range_consolidation(ctx: &LintContext) -> Vec<Lint> {
+    let mut bounds: HashMap<String, (u32, Option<usize>)> = HashMap::new();
+    let mut checks: HashMap<&str, Vec<(usize, u32)>> = HashMap::new();
+    let mut lints = Vec::new();
+    
+    for (idx, op) in ctx.opcodes.iter().enumerate() {
+        if let Some((variable, bits)) = range_check(op) {
+            match bounds.get(variable).copied() {
+                Some((bound, Some(source))) if bound <= bits => {
+                    lints.push(Lint {
+                        rule: "range-consolidation".to_string(),
+                        severity: "low".to_string(),
+                        message: format!("{}-bit range check on `{}` is implied by earlier checks (the AssertZero at #{} bounds it to {} bits); drop it",
+                            bits, variable, source, bound),
+                        sites: vec![idx],
+                        savings: ctx.opcode_costs.get(idx).copied().unwrap_or(0),
+                    });
+                },
+                Some((bound, _)) if bound <= bits => {
+                    checks.entry(variable).or_default().push((idx, bits));
+                },
+                _ => {
+                    checks.entry(variable).or_default().push((idx, bits));
+                    bounds.insert(variable.to_string(), (bits, None));
+                }
+            }
+            continue;
+        }
+        
+        if op["type"].as_str() == Some("AssertZero") {
+            if let Some((variable, bits)) = derived_bound(op, &bounds) {
+                if bits < 254 && !bounds.contains_key(&variable) {
+                    bounds.insert(variable, (bits, Some(idx)));
+                }
+            }
+        }
+    }
+    
+    let mut repeated: Vec<(&str, Vec<(usize, u32)>)> = checks.into_iter()
+        .filter(|(_, sites)| sites.len() > 1)
+        .collect();
+    repeated.sort_by_key(|(_, sites)| sites[0].0);
+    
+    for (variable, sites) in repeated {
+        let Some(&(keep, tightest)) = sites.iter().min_by_key(|(idx, bits)| (*bits, *idx)) else {
+            continue;
+        };
+        
+        let dropped: Vec<usize> = sites.iter()
+            .map(|(idx, _)| *idx)
+            .filter(|idx| *idx != keep)
+            .collect();
+        
+        let savings = dropped.iter()
+            .map(|idx| ctx.opcode_costs.get(*idx).copied().unwrap_or(0))
+            .sum();
+        let dropped_list: Vec<String> = dropped.iter().map(|idx| format!("#{}", idx)).collect();
+        
+        lints.push(Lint {
+            rule: "range-consolidation".to_string(),
+            severity: "low".to_string(),
+            message: format!("`{}` is range-checked {} times; keep the {}-bit check at #{} and drop {}",
+                variable, sites.len(), tightest, keep, dropped_list.join(", ")),
+            sites: dropped,
+            savings,
+        });
+    }
+    
+    lints
+}
+
+fn copied_pair(op: &Value) -> Option<(&str, &str)> {
+    if op["type"].as_str() != Some("AssertZero") {
+        return None;
+    }
+    
+    if op["expression"]["constant"].as_str().is_some_and(|constant| constant != "0") {
+        return None;
+    }
+    
+    match op["expression"]["terms"].as_array()?.as_slice() {
+        [left, right] => {
+            let left_coefficient = left["coefficient"].as_str()?;
+            let right_coefficient = right["coefficient"].as_str()?;
+            
+            let negated = left_coefficient.strip_prefix('-') == Some(right_coefficient)
+                || right_coefficient.strip_prefix('-') == Some(left_coefficient);
+            
+            if negated {
+                Some((left["variable"].as_str()?, right["variable"].as_str()?))
+            } else {
+                None
+            }
+        },
+        _ => None,
+    }
+}
+
+fn find_root<'a>(parents: &mut HashMap<&'a str, &'a str>, variable: &'a str) -> &'a str {
+    let mut root = variable;
+    while let Some(parent) = parents.get(root).copied().filter(|parent| *parent != root) {
+        root = parent;
+    }
+    
+    parents.insert(variable, root);
+    root
+}
+
+fn copy_chains(ctx: &LintContext) -> Vec<Lint> {
+    let mut parents: HashMap<&str, &str> = HashMap::new();
+    let mut copies: Vec<(usize, &str)> = Vec::new();
+    
+    for (idx, op) in ctx.opcodes.iter().enumerate() {
+        let Some((left, right)) = copied_pair(op) else {
+            continue;
+        };
+        
+        let left_root = find_root(&mut parents, left);
+        let right_root = find_root(&mut parents, right);
+        if left_root != right_root {
+            parents.insert(right_root, left_root);
+        }
+        
+        copies.push((idx, left));
+    }
+    
+    let mut chains: HashMap<&str, Vec<usize>> = HashMap::new();
+    for (idx, variable) in &copies {
+        let root = find_root(&mut parents, variable);
+        chains.entry(root).or_default().push(*idx);
+    }
+    
+    let mut members: HashMap<&str, Vec<&str>> = HashMap::new();
+    let variables: Vec<&str> = parents.keys().copied().collect();
+    for variable in variables {
+        let root = find_root(&mut parents, variable);
+        members.entry(root).or_default().push(variable);
+    }
+    
+    let cost_of = |sites: &[usize]| -> usize {
+        sites.iter().map(|idx| ctx.opcode_costs.get(*idx).copied().unwrap_or(0)).sum()
+    };
+    
+    let mut lints = Vec::new();
+    let mut isolated: Vec<usize> = Vec::new();
+    
+    let mut chains: Vec<(&str, Vec<usize>)> = chains.into_iter().collect();
+    chains.sort_by_key(|(_, sites)| sites[0]);
+    
+    for (root, sites) in chains {
+        if sites.len() < 2 {
+            isolated.extend(sites);
+            continue;
+        }
+        
+        let mut chain = members.remove(root).unwrap_or_default();
+        chain.sort();
+        
+        lints.push(Lint {
+            rule: "copy-chains".to_string(),
+            severity: "low".to_string(),
+            message: format!("{} only copy each other ({} copy constraints); use one witness and let the backend's permutation argument handle the equality",
+                chain.join(" = "), sites.len()),
+            savings: cost_of(&sites),
+            sites,
+        });
+    }
+    
+    if !isolated.is_empty() {
+        let list: Vec<String> = isolated.iter().take(10).map(|idx| format!("#{}", idx)).collect();
+        
+        lints.push(Lint {
+            rule: "copy-chains".to_string(),
+            severity: "low".to_string(),
+            message: format!("{} AssertZero opcode(s) only copy one witness into another ({}{}); reuse the original witness instead",
+                isolated.len(), list.join(", "), if isolated.len() > list.len() { ", ..." } else { "" }),
+            savings: cost_of(&isolated),
+            sites: isolated,
+        });
+    }
+    
+    lints
+}
+
+fn fixed_base_msm(ctx: &LintContext) -> Vec<Lint> {
+    let fixed: Vec<usize> = curve_operations(ctx.opcodes, ctx.opcode_costs).iter()
+        .filter(|op| op.fixed_base())
+        .map(|op| op.site)
+        .collect();
+    
+    if fixed.len() < 2 {
+        return Vec::new();
+    }
+    
+    let site_list: Vec<String> = fixed.iter().map(|idx| format!("#{}", idx)).collect();
+    
+    vec![Lint {
+        rule: "fixed-base-msm".to_string(),
+        severity: "low".to_string(),
+        message: format!("{} multi_scalar_mul calls ({}) only use constant base points; pass all points and scalars to one multi_scalar_mul so the fixed-base tables are set up once",
+            fixed.len(), site_list.join(", ")),
+        savings: MSM_BASE_COST.saturating_mul(fixed.len() - 1),
+        sites: fixed,
+    }]
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn fixture(name: &str) -> Value {
+        serde_json::from_str(&std::fs::read_to_string(format!("examples/circuits/{}", name)).unwrap()).unwrap()
+    }
+    
+    fn lint(data: &Value, rule: LintRule) -> Vec<Lint> {
+        let opcodes = data["opcodes"].as_array().unwrap();
+        rule(&LintContext { data, opcodes, opcode_costs: &vec![1; opcodes.len()] })
+    }
+    
+    fn copy(left: &str, right: &str) -> Value {
+        json!({ "type": "AssertZero", "expression": { "constant": "0", "terms": [
+            { "coefficient": "1", "variable": left },
+            { "coefficient": "-1", "variable": right },
+        ]}})
+    }
+    
+    #[test]
+    fn drops_implied_and_repeated_range_checks() {
+        let lints = lint(&fixture("range_checks.json"), range_consolidation);
+        let sites: Vec<&[usize]> = lints.iter().map(|lint| lint.sites.as_slice()).collect();
+        
+        assert_eq!(sites, [&[3][..], &[0], &[5]]);
+        assert!(lints[0].message.contains("#2 bounds it to 33 bits"));
+        assert!(lints[1].message.contains("keep the 8-bit check at #4"));
+    }
+    
+    #[test]
+    fn groups_copy_chains() {
+        let data = json!({ "opcodes": [copy("x", "y"), copy("y", "z"), copy("p", "q")] });
+        let lints = lint(&data, copy_chains);
+        
+        assert_eq!(lints.len(), 2);
+        assert!(lints[0].message.starts_with("x = y = z only copy each other"));
+        assert_eq!((lints[0].sites.as_slice(), lints[0].savings), (&[0, 1][..], 2));
+        assert_eq!(lints[1].sites, [2]);
+    }
+    
+    #[test]
+    fn merges_fixed_base_multi_scalar_muls() {
+        let lints = lint(&fixture("fixed_base_msm.json"), fixed_base_msm);
+        
+        assert_eq!(lints.len(), 1);
+        assert_eq!(lints[0].savings, MSM_BASE_COST * (lints[0].sites.len() - 1));
+        assert!(lint(&fixture("range_checks.json"), fixed_base_msm).is_empty());
+    }
+    
+    #[test]
+    fn copy_chain_fixture_is_flagged() {
+        let lints = lint(&fixture("copy_chains.json"), copy_chains);
+        
+        assert_eq!(lints.iter().map(|lint| (lint.sites.as_slice(), lint.savings)).collect::<Vec<_>>(), [(&[1, 2, 3][..], 3), (&[5][..], 1)]);
+        assert!(lints[0].message.starts_with("w1 = w2 = w3 = w4 only copy each other"));
+    }
+}
@@ -0,0 +1,247 @@
+use crate::analyzer::Skipped;
+use crate::artifact::ArtifactError;
+use crate::core::CircuitAnalysis;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+use std::fs::{self, File};
+use std::io;
+use std::path::Path;
+
+pub const MANIFEST_FILE: &str = "manifest.json";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct BatchManifest {
+    pub generated: String,
+    pub directory: String,
+    pub artifacts: Vec<ManifestEntry>,
+    #[serde(default)]
+    pub failures: Vec<FailureEntry>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManifestEntry {
+    pub path: String,
+    pub sha256: Option<String>,
+    pub summary: Option<ManifestSummary>,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct FailureEntry {
+    pub path: String,
+    pub category: String,
+    pub reason: String,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ManifestSummary {
+    pub constraints: usize,
+    pub total_opcodes: usize,
+    pub public_inputs: usize,
+    pub private_inputs: usize,
+    pub return_values: usize,
+    pub black_box_calls: usize,
+    pub estimated_proving_time: f64,
+    pub exact: bool,
+}
+
+impl From<&CircuitAnalysis> for ManifestSummary {
+    fn from(analysis: &CircuitAnalysis) -> Self {
+        ManifestSummary {
+            constraints: analysis.constraints,
+            total_opcodes: analysis.total_opcodes,
+            public_inputs: analysis.public_inputs,
+            private_inputs: analysis.private_inputs,
+            return_values: analysis.return_values,
+            black_box_calls: analysis.black_box_functions.iter().map(|(_, count, _)| count).sum(),
+            estimated_proving_time: analysis.estimated_proving_time,
+            exact: analysis.exact,
+        }
+    }
+}
+
+pub fn file_sha256(path: &Path) -> Result<String> {
+    let mut file = File::open(path)
+        .with_context(|| format!("Failed to read artifact: {}", path.display()))?;
+    let mut hasher = Sha256::new();
+    io::copy(&mut file, &mut hasher)
+        .with_context(|| format!("Failed to read artifact: {}", path.display()))?;
+    
+    Ok(format!("{:x}", hasher.finalize()))
+}
+
+fn oversized(path: &Path, result: &Result<CircuitAnalysis>, max_file_size: Option<u64>) -> bool {
+    let rejected = matches!(result, Err(e) if matches!(e.downcast_ref::<Skipped>(), Some(Skipped::FileTooLarge { .. })));
+    rejected || max_file_size.is_some_and(|max| fs::metadata(path).map_or(true, |metadata| metadata.len() > max))
+}
+
+pub fn failure_category(error: &anyhow::Error) -> &'static str {
+    if let Some(artifact_error) = error.downcast_ref::<ArtifactError>() {
+        return match artifact_error {
+            ArtifactError::NotAnArtifact => "not-an-artifact",
+            ArtifactError::UnsupportedVersion { .. } => "unsupported-version",
+        };
+    }
+    
+    if let Some(skipped) = error.downcast_ref::<Skipped>() {
+        return match skipped {
+            Skipped::FileTooLarge { .. } | Skipped::TooManyOpcodes { .. } => "too-large",
+            Skipped::TimedOut { .. } => "timeout",
+        };
+    }
+    
+    if error.chain().any(|cause| cause.is::<serde_json::Error>()) {
+        "parse-error"
+    } else if error.chain().any(|cause| cause.is::<std::io::Error>()) {
+        "io-error"
+    } else {
+        "analysis-error"
+    }
+}
+
+pub fn build_manifest(dir: &Path, results: &[(String, Result<CircuitAnalysis>)], max_file_size: Option<u64>) -> BatchManifest {
+    let artifacts = results.iter()
+        .map(|(name, result)| {
+            let path = dir.join(name);
+            let sha256 = if oversized(&path, result, max_file_size) { None } else { file_sha256(&path).ok() };
+            
+            match result {
+                Ok(analysis) => ManifestEntry {
+                    path: name.clone(),
+                    sha256,
+                    summary: Some(ManifestSummary::from(analysis)),
+                    error: None,
+                },
+                Err(e) => ManifestEntry {
+                    path: name.clone(),
+                    sha256,
+                    summary: None,
+                    error: Some(format!("{:#}", e)),
+                }
+            }
+        })
+        .collect();
+    
+    let failures = results.iter()
+        .filter_map(|(name, result)| result.as_ref().err().map(|e| FailureEntry {
+            path: name.clone(),
+            category: failure_category(e).to_string(),
+            reason: format!("{:#}", e),
+        }))
+        .collect();
+    
+    BatchManifest {
+        generated: chrono::Local::now().to_rfc3339(),
+        directory: dir.display().to_string(),
+        artifacts,
+        failures,
+    }
+}
+
+pub fn load_manifest(path: &Path) -> Result<BatchManifest> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read manifest: {}", path.display()))?;
+    
+    serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse manifest: {}", path.display()))
+}
+
+pub fn merge_manifest(previous: BatchManifest, retried: BatchManifest) -> BatchManifest {
+    let retried_paths: Vec<String> = retried.artifacts.iter().map(|entry| entry.path.clone()).collect();
+    
+    let mut artifacts: Vec<ManifestEntry> = previous.artifacts.into_iter()
+        .filter(|entry| !retried_paths.contains(&entry.path))
+        .collect();
+    artifacts.extend(retried.artifacts);
+    artifacts.sort_by(|a, b| a.path.cmp(&b.path));
+    
+    let mut failures: Vec<FailureEntry> = previous.failures.into_iter()
+        .filter(|entry| !retried_paths.contains(&entry.path))
+        .collect();
+    failures.extend(retried.failures);
+    failures.sort_by(|a, b| a.path.cmp(&b.path));
+    
+    BatchManifest {
+        artifacts,
+        failures,
+        ..retried
+    }
+}
+
+pub fn write_manifest(manifest: &BatchManifest, path: &Path) -> Result<()> {
+    let content = serde_json::to_string_pretty(manifest)
+        .context("Failed to serialize manifest")?;
+    
+    fs::write(path, content)
+        .with_context(|| format!("Failed to write manifest: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anyhow::anyhow;
+    use std::time::Duration;
+    
+    fn entry(path: &str) -> ManifestEntry {
+        ManifestEntry { path: path.to_string(), sha256: None, summary: None, error: None }
+    }
+    
+    fn failure(path: &str) -> FailureEntry {
+        FailureEntry { path: path.to_string(), category: "timeout".to_string(), reason: "slow".to_string() }
+    }
+    
+    #[test]
+    fn failures_are_categorized() {
+        assert_eq!(failure_category(&ArtifactError::NotAnArtifact.into()), "not-an-artifact");
+        assert_eq!(failure_category(&Skipped::TooManyOpcodes { count: 10, limit: 5 }.into()), "too-large");
+        assert_eq!(failure_category(&Skipped::TimedOut { limit: Duration::from_secs(1) }.into()), "timeout");
+        
+        let parse: anyhow::Error = serde_json::from_str::<serde_json::Value>("{").unwrap_err().into();
+        assert_eq!(failure_category(&parse.context("Failed to parse")), "parse-error");
+        assert_eq!(failure_category(&anyhow!("no opcodes")), "analysis-error");
+    }
+    
+    #[test]
+    fn retried_entries_replace_previous_ones() {
+        let previous = BatchManifest {
+            generated: "before".to_string(),
+            directory: "circuits".to_string(),
+            artifacts: vec![entry("a.json"), entry("b.json"), entry("c.json")],
+            failures: vec![failure("b.json"), failure("c.json")],
+        };
+        let retried = BatchManifest {
+            generated: "after".to_string(),
+            directory: "circuits".to_string(),
+            artifacts: vec![ManifestEntry { error: Some("still slow".to_string()), ..entry("c.json") }, entry("b.json")],
+            failures: vec![failure("c.json")],
+        };
+        
+        let merged = merge_manifest(previous, retried);
+        
+        assert_eq!(merged.generated, "after");
+        assert_eq!(merged.artifacts.iter().map(|entry| entry.path.as_str()).collect::<Vec<_>>(), ["a.json", "b.json", "c.json"]);
+        assert_eq!(merged.artifacts[2].error.as_deref(), Some("still slow"));
+        assert_eq!(merged.failures.iter().map(|entry| entry.path.as_str()).collect::<Vec<_>>(), ["c.json"]);
+    }
+    
+    #[test]
+    fn manifests_record_failures_and_skip_oversized_hashes() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-manifest-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        fs::write(dir.join("ok.json"), "{}").unwrap();
+        fs::write(dir.join("big.json"), "{ \"opcodes\": [] }").unwrap();
+        
+        let results = vec![
+            ("big.json".to_string(), Err(Skipped::FileTooLarge { size: 17, limit: 10 }.into())),
+            ("ok.json".to_string(), Ok(CircuitAnalysis { constraints: 5, black_box_functions: vec![("sha256".into(), 2, 9)], ..Default::default() })),
+        ];
+        let manifest = build_manifest(&dir, &results, Some(10));
+        fs::remove_dir_all(&dir).unwrap();
+        
+        assert!(manifest.artifacts[0].sha256.is_none() && manifest.artifacts[0].error.is_some());
+        assert_eq!(manifest.artifacts[1].sha256.as_deref().map(str::len), Some(64));
+        assert_eq!(manifest.artifacts[1].summary.as_ref().map(|summary| summary.black_box_calls), Some(2));
+        assert_eq!(manifest.failures.iter().map(|failure| (failure.path.as_str(), failure.category.as_str())).collect::<Vec<_>>(), [("big.json", "too-large")]);
+    }
+}
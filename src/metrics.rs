@@ -0,0 +1,142 @@
+use crate::core::CircuitAnalysis;
+use anyhow::{Context, Result};
+use std::collections::BTreeMap;
+use std::fmt::Write as _;
+use std::io::{BufRead, BufReader, Write};
+use std::net::{SocketAddr, TcpListener, TcpStream};
+use std::path::Path;
+use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::{SystemTime, UNIX_EPOCH};
+
+type GaugeFn = fn(&CircuitMetrics) -> f64;
+
+struct CircuitMetrics {
+    constraints: usize,
+    aggregate_constraints: usize,
+    proving_time_ms: f64,
+    last_analysis: f64,
+}
+
+#[derive(Clone, Default)]
+pub struct MetricsRegistry {
+    circuits: Arc<Mutex<BTreeMap<String, CircuitMetrics>>>,
+}
+
+impl MetricsRegistry {
+    pub fn record(&self, path: &Path, analysis: &CircuitAnalysis) {
+        let last_analysis = SystemTime::now()
+            .duration_since(UNIX_EPOCH)
+            .map_or(0.0, |elapsed| elapsed.as_secs_f64());
+        
+        let metrics = CircuitMetrics {
+            constraints: analysis.constraints,
+            aggregate_constraints: analysis.aggregate_constraints,
+            proving_time_ms: analysis.estimated_proving_time,
+            last_analysis,
+        };
+        
+        if let Ok(mut circuits) = self.circuits.lock() {
+            circuits.insert(path.display().to_string(), metrics);
+        }
+    }
+    
+    pub fn render(&self) -> String {
+        let Ok(circuits) = self.circuits.lock() else {
+            return String::new();
+        };
+        
+        let gauges: [(&str, &str, GaugeFn); 4] = [
+            ("noir_circuit_constraints", "Constraints in main", |m| m.constraints as f64),
+            ("noir_circuit_aggregate_constraints", "Constraints with every call inlined", |m| m.aggregate_constraints as f64),
+            ("noir_circuit_proving_time_ms", "Estimated proving time in milliseconds", |m| m.proving_time_ms),
+            ("noir_circuit_last_analysis_timestamp_seconds", "Unix time of the last analysis", |m| m.last_analysis),
+        ];
+        
+        let mut output = String::new();
+        for (name, help, value) in gauges {
+            let _ = writeln!(output, "# HELP {} {}", name, help);
+            let _ = writeln!(output, "# TYPE {} gauge", name);
+            
+            for (circuit, metrics) in circuits.iter() {
+                let _ = writeln!(output, "{}{{circuit=\"{}\"}} {}", name, escape_label(circuit), value(metrics));
+            }
+        }
+        
+        output
+    }
+}
+
+fn escape_label(value: &str) -> String {
+    value.replace('\\', "\\\\").replace('"', "\\\"").replace('\n', "\\n")
+}
+
+fn respond(mut stream: TcpStream, registry: &MetricsRegistry) {
+    let mut request_line = String::new();
+    if BufReader::new(&stream).read_line(&mut request_line).is_err() {
+        return;
+    }
+    
+    let target = request_line.split_whitespace().nth(1).unwrap_or("");
+    let (status, content_type, body) = if target == "/metrics" || target.starts_with("/metrics?") {
+        ("200 OK", "text/plain; version=0.0.4", registry.render())
+    } else {
+        ("404 Not Found", "text/plain", "not found\n".to_string())
+    };
+    
+    let _ = write!(stream, "HTTP/1.1 {}\r\nContent-Type: {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                   status, content_type, body.len(), body);
+}
+
+pub fn serve_metrics(addr: SocketAddr, registry: MetricsRegistry) -> Result<SocketAddr> {
+    let listener = TcpListener::bind(addr)
+        .with_context(|| format!("Failed to bind metrics endpoint on {}", addr))?;
+    let local = listener.local_addr()?;
+    
+    thread::spawn(move || {
+        for stream in listener.incoming().flatten() {
+            respond(stream, &registry);
+        }
+    });
+    
+    Ok(local)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Read;
+    
+    fn registry() -> MetricsRegistry {
+        let registry = MetricsRegistry::default();
+        let analysis = CircuitAnalysis { constraints: 42, aggregate_constraints: 50, ..Default::default() };
+        registry.record(Path::new("dir/\"odd\".json"), &analysis);
+        registry
+    }
+    
+    fn get(addr: SocketAddr, target: &str) -> String {
+        let mut stream = TcpStream::connect(addr).unwrap();
+        write!(stream, "GET {} HTTP/1.1\r\n\r\n", target).unwrap();
+        let mut response = String::new();
+        stream.read_to_string(&mut response).unwrap();
+        response
+    }
+    
+    #[test]
+    fn renders_one_gauge_per_circuit() {
+        let rendered = registry().render();
+        
+        assert!(rendered.contains("# TYPE noir_circuit_constraints gauge\n"));
+        assert!(rendered.contains("noir_circuit_constraints{circuit=\"dir/\\\"odd\\\".json\"} 42\n"));
+        assert!(rendered.contains("noir_circuit_aggregate_constraints{circuit=\"dir/\\\"odd\\\".json\"} 50\n"));
+    }
+    
+    #[test]
+    fn serves_only_the_metrics_path() {
+        let addr = serve_metrics("127.0.0.1:0".parse().unwrap(), registry()).unwrap();
+        
+        assert!(get(addr, "/metrics").starts_with("HTTP/1.1 200 OK"));
+        assert!(get(addr, "/metrics").contains("noir_circuit_proving_time_ms"));
+        assert!(get(addr, "/").starts_with("HTTP/1.1 404 Not Found"));
+    }
+}
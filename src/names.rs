@@ -0,0 +1,106 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static RAW_NAMES: AtomicBool = AtomicBool::new(false);
+
+const DOCS: &str = "https://noir-lang.org/docs/noir";
+
+pub fn set_raw_names(raw: bool) {
+    RAW_NAMES.store(raw, Ordering::Relaxed);
+}
+
+pub fn raw_names() -> bool {
+    RAW_NAMES.load(Ordering::Relaxed)
+}
+
+#[derive(Debug)]
+pub struct BlackBoxName {
+    pub canonical: &'static str,
+    pub display: &'static str,
+    pub docs: &'static str,
+    aliases: &'static [&'static str],
+}
+
+static BLACK_BOX_NAMES: [BlackBoxName; 19] = [
+    BlackBoxName { canonical: "aes128_encrypt", display: "AES-128 encrypt", docs: "standard_library/cryptographic_primitives/ciphers", aliases: &["aes128encrypt", "aes128"] },
+    BlackBoxName { canonical: "and", display: "bitwise AND", docs: "concepts/data_types/integers", aliases: &["and"] },
+    BlackBoxName { canonical: "xor", display: "bitwise XOR", docs: "concepts/data_types/integers", aliases: &["xor"] },
+    BlackBoxName { canonical: "range", display: "range check", docs: "concepts/data_types/integers", aliases: &["range", "rangecheck"] },
+    BlackBoxName { canonical: "sha256", display: "SHA-256", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["sha256"] },
+    BlackBoxName { canonical: "sha256_compression", display: "SHA-256 compression", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["sha256compression"] },
+    BlackBoxName { canonical: "blake2s", display: "BLAKE2s", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["blake2s"] },
+    BlackBoxName { canonical: "blake3", display: "BLAKE3", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["blake3"] },
+    BlackBoxName { canonical: "keccak256", display: "Keccak-256", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["keccak256", "keccak"] },
+    BlackBoxName { canonical: "keccakf1600", display: "Keccak-f[1600] permutation", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["keccakf1600"] },
+    BlackBoxName { canonical: "pedersen_commitment", display: "Pedersen commitment", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["pedersencommitment", "pedersen"] },
+    BlackBoxName { canonical: "pedersen_hash", display: "Pedersen hash", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["pedersenhash"] },
+    BlackBoxName { canonical: "poseidon2_permutation", display: "Poseidon2 permutation", docs: "standard_library/cryptographic_primitives/hashes", aliases: &["poseidon2permutation", "poseidon2"] },
+    BlackBoxName { canonical: "schnorr_verify", display: "Schnorr signature check", docs: "standard_library/cryptographic_primitives/schnorr", aliases: &["schnorrverify", "schnorr"] },
+    BlackBoxName { canonical: "ecdsa_secp256k1", display: "ECDSA secp256k1 check", docs: "standard_library/cryptographic_primitives/ecdsa_sig_verification", aliases: &["ecdsasecp256k1", "ecdsasecp256k1verify"] },
+    BlackBoxName { canonical: "ecdsa_secp256r1", display: "ECDSA secp256r1 check", docs: "standard_library/cryptographic_primitives/ecdsa_sig_verification", aliases: &["ecdsasecp256r1", "ecdsasecp256r1verify"] },
+    BlackBoxName { canonical: "multi_scalar_mul", display: "multi-scalar multiplication", docs: "standard_library/cryptographic_primitives/embedded_curve_ops", aliases: &["multiscalarmul", "fixedbasescalarmul", "variablebasescalarmul"] },
+    BlackBoxName { canonical: "embedded_curve_add", display: "embedded curve addition", docs: "standard_library/cryptographic_primitives/embedded_curve_ops", aliases: &["embeddedcurveadd", "embeddedcurvedouble"] },
+    BlackBoxName { canonical: "recursive_aggregation", display: "recursive proof verification", docs: "standard_library/recursion", aliases: &["recursiveaggregation", "verifyproof"] },
+];
+
+pub fn known_names() -> &'static [BlackBoxName] {
+    &BLACK_BOX_NAMES
+}
+
+fn normalize(raw: &str) -> String {
+    raw.chars()
+        .filter(|c| c.is_ascii_alphanumeric())
+        .map(|c| c.to_ascii_lowercase())
+        .collect()
+}
+
+pub fn lookup(raw: &str) -> Option<&'static BlackBoxName> {
+    let key = normalize(raw);
+    BLACK_BOX_NAMES.iter().find(|name| name.aliases.contains(&key.as_str()))
+}
+
+pub fn canonical_name(raw: &str) -> String {
+    lookup(raw).map_or_else(|| raw.to_string(), |name| name.canonical.to_string())
+}
+
+pub fn display_name(raw: &str) -> String {
+    if raw_names() {
+        return raw.to_string();
+    }
+    
+    lookup(raw).map_or_else(|| raw.to_string(), |name| name.display.to_string())
+}
+
+pub fn docs_url(raw: &str) -> Option<String> {
+    lookup(raw).map(|name| format!("{}/{}", DOCS, name.docs))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn aliases_resolve_to_canonical_names() {
+        assert_eq!(canonical_name("Keccak"), "keccak256");
+        assert_eq!(canonical_name("fixed_base_scalar_mul"), "multi_scalar_mul");
+        assert_eq!(canonical_name("custom_gadget"), "custom_gadget");
+        assert_eq!(display_name("SHA256"), "SHA-256");
+    }
+    
+    #[test]
+    fn docs_links_only_known_functions() {
+        assert_eq!(docs_url("range").unwrap(), format!("{}/concepts/data_types/integers", DOCS));
+        assert!(docs_url("custom_gadget").is_none());
+    }
+    
+    #[test]
+    fn aliases_are_unambiguous() {
+        let aliases: Vec<&str> = BLACK_BOX_NAMES.iter().flat_map(|name| name.aliases.iter().copied()).collect();
+        
+        for (idx, alias) in aliases.iter().enumerate() {
+            assert!(!aliases[idx + 1..].contains(alias), "duplicate alias {}", alias);
+        }
+        for name in &BLACK_BOX_NAMES {
+            assert_eq!(canonical_name(name.canonical), name.canonical);
+        }
+    }
+}
@@ -0,0 +1,96 @@
+use crate::analyzer::{analyze_bytes_at, analyze_value_with_options, AnalysisOptions, Analyzer, Skipped};
+use crate::core::CircuitAnalysis;
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::path::Path;
+use tokio::task::spawn_blocking;
+
+pub async fn analyze_circuit_async(path: &Path) -> Result<CircuitAnalysis> {
+    analyze_circuit_with_options_async(path, &AnalysisOptions::default()).await
+}
+
+pub async fn analyze_circuit_with_options_async(path: &Path, options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    if let Some(limit) = options.limits.max_file_size {
+        let size = tokio::fs::metadata(path).await
+            .with_context(|| format!("Failed to read circuit file: {}", path.display()))?
+            .len();
+        
+        if size > limit {
+            return Err(Skipped::FileTooLarge { size, limit }.into());
+        }
+    }
+    
+    let bytes = tokio::fs::read(path).await
+        .with_context(|| format!("Failed to read circuit file: {}", path.display()))?;
+    
+    let path = path.to_path_buf();
+    let options = options.clone();
+    spawn_blocking(move || analyze_bytes_at(&path, &bytes, &options))
+        .await
+        .context("Analysis task failed")?
+}
+
+pub async fn analyze_bytes_async(bytes: Vec<u8>, options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    let options = options.clone();
+    spawn_blocking(move || analyze_bytes_at(Path::new(""), &bytes, &options))
+        .await
+        .context("Analysis task failed")?
+}
+
+pub async fn analyze_value_async(data: Value, options: &AnalysisOptions) -> Result<CircuitAnalysis> {
+    let options = options.clone();
+    spawn_blocking(move || analyze_value_with_options(&data, &options))
+        .await
+        .context("Analysis task failed")?
+}
+
+pub async fn compare_circuits_async(path1: &Path, path2: &Path, options: &AnalysisOptions) -> Result<(CircuitAnalysis, CircuitAnalysis)> {
+    tokio::try_join!(
+        analyze_circuit_with_options_async(path1, options),
+        analyze_circuit_with_options_async(path2, options),
+    )
+}
+
+impl Analyzer {
+    pub async fn analyze_async(&self, path: &Path) -> Result<CircuitAnalysis> {
+        analyze_circuit_with_options_async(path, self.options()).await
+    }
+    
+    pub async fn analyze_bytes_async(&self, bytes: Vec<u8>) -> Result<CircuitAnalysis> {
+        analyze_bytes_async(bytes, self.options()).await
+    }
+    
+    pub async fn analyze_value_async(&self, data: Value) -> Result<CircuitAnalysis> {
+        analyze_value_async(data, self.options()).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analyzer::{analyze_circuit_with_options, AnalysisLimits};
+    use crate::cost::CostModel;
+    use std::collections::HashMap;
+    
+    fn options() -> AnalysisOptions {
+        AnalysisOptions { cost_model: CostModel::Fixed(HashMap::new()), ..Default::default() }
+    }
+    
+    #[tokio::test]
+    async fn matches_the_blocking_analysis() {
+        let (path1, path2) = (Path::new("examples/circuits/circuit1.json"), Path::new("examples/circuits/circuit2.json"));
+        let (first, second) = compare_circuits_async(path1, path2, &options()).await.unwrap();
+        
+        assert_eq!(first.constraints, analyze_circuit_with_options(path1, &options()).unwrap().constraints);
+        assert_eq!(second.total_opcodes, analyze_circuit_with_options(path2, &options()).unwrap().total_opcodes);
+    }
+    
+    #[tokio::test]
+    async fn enforces_the_size_limit_before_reading() {
+        let limited = AnalysisOptions { limits: AnalysisLimits { max_file_size: Some(1), ..Default::default() }, ..options() };
+        let error = analyze_circuit_with_options_async(Path::new("examples/circuits/circuit1.json"), &limited).await.unwrap_err();
+        assert!(matches!(error.downcast_ref::<Skipped>(), Some(Skipped::FileTooLarge { limit: 1, .. })));
+        
+        assert!(analyze_bytes_async(b"not json".to_vec(), &options()).await.is_err());
+    }
+}
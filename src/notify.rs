@@ -0,0 +1,328 @@
+use crate::core::CircuitAnalysis;
+use crate::taxonomy::Category;
+use anyhow::{Context, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+#[derive(Debug, Deserialize)]
+pub struct NotifyConfig {
+    pub notify: NotifySection,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct NotifySection {
+    #[serde(default)]
+    pub webhook: Option<String>,
+    #[serde(default)]
+    pub fail_on_violation: bool,
+    #[serde(default)]
+    pub max_constraints: Option<usize>,
+    #[serde(default)]
+    pub max_proving_time: Option<f64>,
+    #[serde(default)]
+    pub max_regression_percent: Option<f64>,
+    #[serde(default)]
+    pub categories: BTreeMap<Category, CategoryBudget>,
+    #[serde(default)]
+    pub modules: BTreeMap<String, ModuleBudget>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct CategoryBudget {
+    #[serde(default)]
+    pub max_constraints: Option<usize>,
+    #[serde(default)]
+    pub max_percent: Option<f64>,
+}
+
+#[derive(Debug, Default, Deserialize)]
+pub struct ModuleBudget {
+    #[serde(default)]
+    pub max_constraints: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct ModuleUsage {
+    pub module: String,
+    pub constraints: usize,
+    pub circuits: usize,
+    pub limit: Option<usize>,
+}
+
+#[derive(Debug, Serialize)]
+pub struct Violation {
+    pub metric: String,
+    pub limit: f64,
+    pub actual: f64,
+    pub message: String,
+}
+
+#[derive(Debug, Serialize)]
+pub struct NotifyPayload<'a> {
+    pub text: String,
+    pub circuit: &'a str,
+    pub violations: &'a [Violation],
+}
+
+pub fn load_notify_config(path: &Path) -> Result<NotifyConfig> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read notify config: {}", path.display()))?;
+    
+    serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse notify config: {}", path.display()))
+}
+
+fn percent_change(old: f64, new: f64) -> f64 {
+    if old > 0.0 { (new - old) / old * 100.0 } else { 0.0 }
+}
+
+pub fn find_violations(old: &CircuitAnalysis, new: &CircuitAnalysis, config: &NotifySection) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    
+    if let Some(limit) = config.max_constraints {
+        if new.aggregate_constraints > limit {
+            violations.push(Violation {
+                metric: "constraints".to_string(),
+                limit: limit as f64,
+                actual: new.aggregate_constraints as f64,
+                message: format!("{} constraints exceeds the budget of {}", new.aggregate_constraints, limit),
+            });
+        }
+    }
+    
+    if let Some(limit) = config.max_proving_time {
+        if new.estimated_proving_time > limit {
+            violations.push(Violation {
+                metric: "proving_time".to_string(),
+                limit,
+                actual: new.estimated_proving_time,
+                message: format!("{:.2}ms proving time exceeds the budget of {:.2}ms", new.estimated_proving_time, limit),
+            });
+        }
+    }
+    
+    if let Some(limit) = config.max_regression_percent {
+        let change = percent_change(old.aggregate_constraints as f64, new.aggregate_constraints as f64);
+        if change > limit {
+            violations.push(Violation {
+                metric: "constraint_regression".to_string(),
+                limit,
+                actual: change,
+                message: format!("constraints grew {:.1}% ({} -> {}), above the {:.1}% limit",
+                                 change, old.aggregate_constraints, new.aggregate_constraints, limit),
+            });
+        }
+    }
+    
+    violations.extend(category_violations(new, &config.categories));
+    violations.extend(module_violations(&module_usage(&[new], &config.modules)));
+    violations
+}
+
+pub fn category_violations(analysis: &CircuitAnalysis, budgets: &BTreeMap<Category, CategoryBudget>) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    
+    for (category, budget) in budgets {
+        let constraints = analysis.categories.iter()
+            .find(|(other, _)| other == category)
+            .map_or(0, |(_, constraints)| *constraints);
+        
+        if let Some(limit) = budget.max_constraints {
+            if constraints > limit {
+                violations.push(Violation {
+                    metric: format!("{}_constraints", category),
+                    limit: limit as f64,
+                    actual: constraints as f64,
+                    message: format!("{} uses {} constraints, above its budget of {}", category, constraints, limit),
+                });
+            }
+        }
+        
+        if let Some(limit) = budget.max_percent {
+            let percent = if analysis.constraints > 0 {
+                constraints as f64 / analysis.constraints as f64 * 100.0
+            } else {
+                0.0
+            };
+            
+            if percent > limit {
+                violations.push(Violation {
+                    metric: format!("{}_percent", category),
+                    limit,
+                    actual: percent,
+                    message: format!("{} is {:.1}% of constraints, above its budget of {:.1}%", category, percent, limit),
+                });
+            }
+        }
+    }
+    
+    violations
+}
+
+pub fn in_module(file: &str, module: &str) -> bool {
+    let file = file.replace('\\', "/");
+    let module = module.trim_matches('/');
+    
+    if module.is_empty() {
+        return true;
+    }
+    
+    file == module
+        || file.starts_with(&format!("{}/", module))
+        || file.contains(&format!("/{}/", module))
+        || file.ends_with(&format!("/{}", module))
+}
+
+pub fn module_usage(analyses: &[&CircuitAnalysis], budgets: &BTreeMap<String, ModuleBudget>) -> Vec<ModuleUsage> {
+    budgets.iter()
+        .map(|(module, budget)| {
+            let per_circuit: Vec<usize> = analyses.iter()
+                .map(|analysis| analysis.source_files.iter()
+                    .filter(|(file, _)| in_module(file, module))
+                    .fold(0usize, |total, (_, constraints)| total.saturating_add(*constraints)))
+                .collect();
+            
+            ModuleUsage {
+                module: module.clone(),
+                constraints: per_circuit.iter().fold(0usize, |total, constraints| total.saturating_add(*constraints)),
+                circuits: per_circuit.iter().filter(|constraints| **constraints > 0).count(),
+                limit: budget.max_constraints,
+            }
+        })
+        .collect()
+}
+
+pub fn module_violations(usage: &[ModuleUsage]) -> Vec<Violation> {
+    usage.iter()
+        .filter_map(|module| {
+            let limit = module.limit.filter(|limit| module.constraints > *limit)?;
+            Some(Violation {
+                metric: format!("module:{}", module.module),
+                limit: limit as f64,
+                actual: module.constraints as f64,
+                message: format!("{} uses {} constraints across {} circuit(s), above its budget of {}",
+                                 module.module, module.constraints, module.circuits, limit),
+            })
+        })
+        .collect()
+}
+
+pub fn build_payload<'a>(circuit: &'a str, violations: &'a [Violation]) -> NotifyPayload<'a> {
+    let details: Vec<String> = violations.iter()
+        .map(|violation| format!("• {}", violation.message))
+        .collect();
+    
+    NotifyPayload {
+        text: format!("noir-circuit-profiler: {} threshold(s) violated for {}\n{}", violations.len(), circuit, details.join("\n")),
+        circuit,
+        violations,
+    }
+}
+
+#[cfg(not(feature = "remote"))]
+pub fn send_notification(webhook: &str, _payload: &NotifyPayload) -> Result<()> {
+    Err(anyhow::anyhow!("Cannot post to {}: rebuild with `--features remote` to send notifications", webhook))
+}
+
+#[cfg(feature = "remote")]
+pub fn send_notification(webhook: &str, payload: &NotifyPayload) -> Result<()> {
+    let body = serde_json::to_string(payload)?;
+    
+    reqwest::blocking::Client::new()
+        .post(webhook)
+        .header("Content-Type", "application/json")
+        .body(body)
+        .send()
+        .with_context(|| format!("Failed to post notification to {}", webhook))?
+        .error_for_status()
+        .with_context(|| format!("Webhook rejected notification: {}", webhook))?;
+    
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn section(yaml: &str) -> NotifySection {
+        serde_yaml::from_str::<NotifyConfig>(yaml).unwrap().notify
+    }
+    
+    fn analysis(constraints: usize) -> CircuitAnalysis {
+        CircuitAnalysis {
+            constraints,
+            aggregate_constraints: constraints,
+            categories: vec![(Category::Hashing, constraints / 2)],
+            source_files: vec![("src/crypto/hash.nr".to_string(), 300), ("src/main.nr".to_string(), 100)],
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn reports_every_exceeded_budget() {
+        let config = section("
+notify:
+  max_constraints: 900
+  max_regression_percent: 10
+  categories:
+    hashing: { max_percent: 40, max_constraints: 1000 }
+  modules:
+    crypto: { max_constraints: 250 }
+");
+        let metrics: Vec<String> = find_violations(&analysis(800), &analysis(1000), &config).into_iter().map(|v| v.metric).collect();
+        
+        assert_eq!(metrics, ["constraints", "constraint_regression", "hashing_percent", "module:crypto"]);
+        assert!(find_violations(&analysis(800), &analysis(800), &section("notify: { max_constraints: 900 }")).is_empty());
+    }
+    
+    #[test]
+    fn modules_match_whole_path_segments() {
+        assert!(in_module("src/crypto/hash.nr", "crypto"));
+        assert!(in_module("crypto\\hash.nr", "crypto/"));
+        assert!(in_module("lib/src/main.nr", "src/main.nr"));
+        assert!(!in_module("src/cryptography/hash.nr", "crypto"));
+    }
+    
+    #[test]
+    fn payload_lists_each_violation() {
+        let violations = find_violations(&analysis(10), &analysis(1000), &section("notify: { max_constraints: 900 }"));
+        let payload = build_payload("main.json", &violations);
+        
+        assert!(payload.text.starts_with("noir-circuit-profiler: 1 threshold(s) violated for main.json\n• 1000 constraints"));
+    }
+    
+    #[test]
+    fn category_budgets_check_counts_and_shares() {
+        let budgets = section("
+notify:
+  categories:
+    hashing: { max_constraints: 400, max_percent: 60 }
+    range: { max_constraints: 1 }
+").categories;
+        let violations = category_violations(&analysis(1000), &budgets);
+        
+        assert_eq!(violations.iter().map(|v| v.metric.as_str()).collect::<Vec<_>>(), ["hashing_constraints"]);
+        assert_eq!((violations[0].limit, violations[0].actual), (400.0, 500.0));
+        assert!(category_violations(&CircuitAnalysis::default(), &budgets).is_empty());
+    }
+    
+    #[test]
+    fn module_usage_sums_across_circuits() {
+        let budgets = BTreeMap::from([
+            ("src/crypto".to_string(), ModuleBudget { max_constraints: Some(500) }),
+            ("src/main.nr".to_string(), ModuleBudget { max_constraints: None }),
+            ("vendor".to_string(), ModuleBudget { max_constraints: Some(1) }),
+        ]);
+        let (a, b) = (analysis(1000), CircuitAnalysis { source_files: vec![("src/crypto/merkle.nr".to_string(), 250)], ..Default::default() });
+        let usage = module_usage(&[&a, &b], &budgets);
+        
+        let rows: Vec<(&str, usize, usize)> = usage.iter().map(|module| (module.module.as_str(), module.constraints, module.circuits)).collect();
+        assert_eq!(rows, [("src/crypto", 550, 2), ("src/main.nr", 100, 1), ("vendor", 0, 0)]);
+        
+        let violations = module_violations(&usage);
+        assert_eq!(violations.len(), 1);
+        assert_eq!(violations[0].message, "src/crypto uses 550 constraints across 2 circuit(s), above its budget of 500");
+    }
+}
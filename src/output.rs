@@ -0,0 +1,94 @@
+use anyhow::{Context, Result};
+use lazy_static::lazy_static;
+use std::fs::{File, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+lazy_static! {
+    static ref OUTPUT: Mutex<Option<File>> = Mutex::new(None);
+}
+
+static CONTINUING: AtomicBool = AtomicBool::new(false);
+
+#[cfg(test)]
+pub(crate) static CAPTURE_LOCK: Mutex<()> = Mutex::new(());
+
+pub fn redirect(path: &Path, append: bool) -> Result<()> {
+    let file = OpenOptions::new()
+        .create(true)
+        .write(true)
+        .append(append)
+        .truncate(!append)
+        .open(path)
+        .with_context(|| format!("Failed to open output file: {}", path.display()))?;
+    
+    let existing = file.metadata().map_or(0, |metadata| metadata.len());
+    CONTINUING.store(append && existing > 0, Ordering::Relaxed);
+    colored::control::set_override(false);
+    
+    *OUTPUT.lock().unwrap() = Some(file);
+    Ok(())
+}
+
+pub fn redirected() -> bool {
+    OUTPUT.lock().unwrap().is_some()
+}
+
+pub fn continuing() -> bool {
+    CONTINUING.load(Ordering::Relaxed)
+}
+
+pub fn write_line(line: &str) {
+    match OUTPUT.lock().unwrap().as_mut() {
+        Some(file) => {
+            let _ = writeln!(file, "{}", line);
+        },
+        None => std::println!("{}", line),
+    }
+}
+
+pub fn capture<T>(path: &Path, f: impl FnOnce() -> T) -> Result<T> {
+    let file = File::create(path)
+        .with_context(|| format!("Failed to open output file: {}", path.display()))?;
+    
+    let colorize = colored::control::SHOULD_COLORIZE.should_colorize();
+    colored::control::set_override(false);
+    let previous = OUTPUT.lock().unwrap().replace(file);
+    
+    let result = f();
+    
+    *OUTPUT.lock().unwrap() = previous;
+    colored::control::set_override(colorize);
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+    
+    #[test]
+    fn nested_captures_restore_the_outer_file() {
+        let _guard = CAPTURE_LOCK.lock().unwrap();
+        let dir = std::env::temp_dir();
+        let outer = dir.join(format!("noir-profiler-outer-{}.txt", std::process::id()));
+        let inner = dir.join(format!("noir-profiler-inner-{}.txt", std::process::id()));
+        
+        let result = capture(&outer, || {
+            write_line("before");
+            capture(&inner, || write_line("nested")).unwrap();
+            write_line("after");
+            redirected()
+        });
+        let (outer_text, inner_text) = (fs::read_to_string(&outer), fs::read_to_string(&inner));
+        fs::remove_file(&outer).ok();
+        fs::remove_file(&inner).ok();
+        
+        assert!(result.unwrap());
+        assert_eq!(outer_text.unwrap(), "before\nafter\n");
+        assert_eq!(inner_text.unwrap(), "nested\n");
+        assert!(capture(&dir.join("missing-dir").join("out.txt"), || ()).is_err());
+    }
+}
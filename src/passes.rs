@@ -0,0 +1,123 @@
+use anyhow::{bail, Result};
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pass {
+    Parse,
+    WitnessGraph,
+    Costs,
+    Bottlenecks,
+    Attribution,
+    Suggestions,
+}
+
+pub const PIPELINE: [Pass; 6] = [
+    Pass::Parse, Pass::WitnessGraph, Pass::Costs, Pass::Bottlenecks, Pass::Attribution, Pass::Suggestions,
+];
+
+pub const REQUIRED: [Pass; 2] = [Pass::Parse, Pass::Costs];
+
+pub const QUICK_SKIPPED: [Pass; 3] = [Pass::WitnessGraph, Pass::Attribution, Pass::Suggestions];
+
+impl Pass {
+    pub fn name(&self) -> &'static str {
+        match self {
+            Pass::Parse => "parse",
+            Pass::WitnessGraph => "witness-graph",
+            Pass::Costs => "costs",
+            Pass::Bottlenecks => "bottlenecks",
+            Pass::Attribution => "attribution",
+            Pass::Suggestions => "suggestions",
+        }
+    }
+}
+
+impl fmt::Display for Pass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Pass {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_lowercase().replace('_', "-");
+        PIPELINE.into_iter()
+            .find(|pass| pass.name() == name)
+            .ok_or_else(|| format!("unknown pass `{}`, expected one of: {}", s,
+                PIPELINE.iter().map(Pass::name).collect::<Vec<_>>().join(", ")))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PassSelection {
+    enabled: Vec<Pass>,
+}
+
+impl Default for PassSelection {
+    fn default() -> Self {
+        PassSelection { enabled: PIPELINE.to_vec() }
+    }
+}
+
+impl PassSelection {
+    pub fn new(only: &[Pass], skip: &[Pass]) -> Result<Self> {
+        if let Some(pass) = skip.iter().find(|pass| REQUIRED.contains(pass)) {
+            bail!("the {} pass cannot be skipped; every analysis needs it", pass);
+        }
+        
+        let enabled = PIPELINE.into_iter()
+            .filter(|pass| only.is_empty() || only.contains(pass) || REQUIRED.contains(pass))
+            .filter(|pass| !skip.contains(pass))
+            .collect();
+        
+        Ok(PassSelection { enabled })
+    }
+    
+    pub fn runs(&self, pass: Pass) -> bool {
+        self.enabled.contains(&pass)
+    }
+    
+    pub fn is_full(&self) -> bool {
+        self.enabled.len() == PIPELINE.len()
+    }
+    
+    pub fn enabled(&self) -> impl Iterator<Item = Pass> + '_ {
+        self.enabled.iter().copied()
+    }
+    
+    pub fn skipped(&self) -> impl Iterator<Item = Pass> + '_ {
+        PIPELINE.into_iter().filter(|pass| !self.runs(*pass))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn passes_parse_by_name() {
+        assert_eq!("Witness_Graph".parse::<Pass>(), Ok(Pass::WitnessGraph));
+        assert!("inline".parse::<Pass>().unwrap_err().contains("parse, witness-graph"));
+        assert!(PIPELINE.iter().all(|pass| pass.name().parse::<Pass>() == Ok(*pass)));
+    }
+    
+    #[test]
+    fn only_keeps_required_passes() {
+        let selection = PassSelection::new(&[Pass::Bottlenecks], &[]).unwrap();
+        assert_eq!(selection.enabled().collect::<Vec<_>>(), [Pass::Parse, Pass::Costs, Pass::Bottlenecks]);
+        assert!(!selection.is_full());
+        assert!(PassSelection::default().is_full());
+    }
+    
+    #[test]
+    fn required_passes_cannot_be_skipped() {
+        assert!(PassSelection::new(&[], &[Pass::Costs]).is_err());
+        
+        let selection = PassSelection::new(&[], &QUICK_SKIPPED).unwrap();
+        assert_eq!(selection.skipped().collect::<Vec<_>>(), QUICK_SKIPPED);
+        assert!(selection.runs(Pass::Bottlenecks));
+    }
+}
@@ -0,0 +1,243 @@
+use crate::focus::{site_location, suggestions, Substitution};
+use crate::progress::Deadline;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::hash::{Hash, Hasher};
+
+pub const MAX_PERIOD: usize = 1024;
+pub const MIN_REPEATS: usize = 3;
+pub const MAX_PATTERNS: usize = 5;
+pub const WORK_BUDGET: usize = 50_000_000;
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RepeatedPattern {
+    pub start: usize,
+    pub length: usize,
+    pub repeats: usize,
+    pub constraints: usize,
+    pub per_iteration: usize,
+    pub body: Vec<(String, usize)>,
+    pub location: Option<String>,
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Run {
+    start: usize,
+    period: usize,
+    repeats: usize,
+}
+
+impl Run {
+    fn len(&self) -> usize {
+        self.period * self.repeats
+    }
+    
+    fn overlaps(&self, other: &Run) -> bool {
+        self.start < other.start + other.len() && other.start < self.start + self.len()
+    }
+}
+
+fn opcode_name(op: &Value) -> String {
+    match op["type"].as_str().unwrap_or("Unknown") {
+        "BlackBoxFunction" => op["function"].as_str().unwrap_or("unknown").to_string(),
+        op_type => op_type.to_string(),
+    }
+}
+
+pub fn opcode_signature(op: &Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    
+    op["type"].as_str().unwrap_or("Unknown").hash(&mut hasher);
+    op["function"].as_str().hash(&mut hasher);
+    op["id"].as_u64().hash(&mut hasher);
+    op["expression"]["terms"].as_array().map(|terms| terms.len()).hash(&mut hasher);
+    op["inputs"].as_array().map(|inputs| inputs.len()).hash(&mut hasher);
+    op["outputs"].as_array().map(|outputs| outputs.len()).hash(&mut hasher);
+    
+    hasher.finish()
+}
+
+fn longest_run(signatures: &[u64], period: usize) -> Option<Run> {
+    let mut best: Option<Run> = None;
+    let mut run_start = 0;
+    let mut run = 0;
+    
+    for i in 0..=signatures.len() - period {
+        if i + period < signatures.len() && signatures[i] == signatures[i + period] {
+            if run == 0 {
+                run_start = i;
+            }
+            run += 1;
+            continue;
+        }
+        
+        let repeats = (run + period) / period;
+        if run > 0 && repeats >= MIN_REPEATS && best.is_none_or(|b| repeats > b.repeats) {
+            best = Some(Run { start: run_start, period, repeats });
+        }
+        run = 0;
+    }
+    
+    best
+}
+
+pub fn detect_patterns(opcodes: &[Value], opcode_costs: &[usize], deadline: &Deadline) -> Vec<RepeatedPattern> {
+    let signatures: Vec<u64> = opcodes.iter().map(opcode_signature).collect();
+    let max_period = MAX_PERIOD
+        .min(signatures.len() / MIN_REPEATS)
+        .min(WORK_BUDGET / signatures.len().max(1));
+    
+    let mut candidates: Vec<Run> = (1..=max_period)
+        .take_while(|_| !deadline.expired())
+        .filter_map(|period| longest_run(&signatures, period))
+        .collect();
+    
+    candidates.sort_by(|a, b| b.len().cmp(&a.len()).then(a.period.cmp(&b.period)));
+    
+    let mut selected: Vec<Run> = Vec::new();
+    for candidate in candidates {
+        if selected.len() == MAX_PATTERNS {
+            break;
+        }
+        
+        if !selected.iter().any(|run| run.overlaps(&candidate)) {
+            selected.push(candidate);
+        }
+    }
+    
+    selected.iter()
+        .map(|run| {
+            let constraints = opcode_costs.iter()
+                .skip(run.start)
+                .take(run.len())
+                .fold(0usize, |total, cost| total.saturating_add(*cost));
+            
+            let body = (run.start..run.start + run.period)
+                .map(|idx| (opcode_name(&opcodes[idx]), opcode_costs.get(idx).copied().unwrap_or(0)))
+                .collect();
+            
+            RepeatedPattern {
+                start: run.start,
+                length: run.period,
+                repeats: run.repeats,
+                constraints,
+                per_iteration: constraints / run.repeats,
+                body,
+                location: site_location(&opcodes[run.start]),
+            }
+        })
+        .collect()
+}
+
+pub fn compact_count(count: usize) -> String {
+    match count {
+        0..=999 => count.to_string(),
+        1_000..=999_999 => format!("{:.1}k", count as f64 / 1_000.0),
+        _ => format!("{:.1}M", count as f64 / 1_000_000.0),
+    }
+}
+
+pub fn iteration_projections(pattern: &RepeatedPattern) -> Vec<(usize, usize)> {
+    let mut iterations = vec![pattern.repeats, pattern.repeats / 2, pattern.repeats / 4];
+    iterations.retain(|count| *count > 0);
+    iterations.dedup();
+    
+    iterations.into_iter()
+        .map(|count| (count, pattern.per_iteration.saturating_mul(count)))
+        .collect()
+}
+
+pub fn loop_suggestions(pattern: &RepeatedPattern) -> Vec<Substitution> {
+    let projections: Vec<String> = iteration_projections(pattern).iter()
+        .map(|(count, constraints)| format!("{} → {}", count, compact_count(*constraints)))
+        .collect();
+    
+    let mut result = vec![Substitution {
+        alternative: None,
+        alternative_cost: None,
+        advice: format!("each iteration costs {} constraints; iterations {} (fewer iterations, e.g. a smaller tree depth or batch size, scale linearly)",
+            compact_count(pattern.per_iteration), projections.join(", ")),
+    }];
+    
+    let mut seen: Vec<&str> = Vec::new();
+    for (name, cost) in &pattern.body {
+        if seen.contains(&name.as_str()) {
+            continue;
+        }
+        seen.push(name);
+        
+        let per_body = pattern.body.iter().filter(|(other, _)| other == name).count();
+        
+        for suggestion in suggestions(name) {
+            let savings = suggestion.alternative_cost
+                .map(|alternative| cost.saturating_sub(alternative) * per_body * pattern.repeats);
+            
+            let advice = match savings {
+                Some(saved) if saved > 0 => format!("{} in the loop body: {}; saves ~{} constraints across {} iterations",
+                    name, suggestion.advice, compact_count(saved), pattern.repeats),
+                _ => format!("{} in the loop body: {}", name, suggestion.advice),
+            };
+            
+            result.push(Substitution { advice, ..suggestion });
+        }
+    }
+    
+    result
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn detects_an_unrolled_loop_body() {
+        let mut opcodes = vec![json!({ "type": "Setup" })];
+        for _ in 0..4 {
+            opcodes.push(json!({ "type": "BlackBoxFunction", "function": "sha256" }));
+            opcodes.push(json!({ "type": "AssertZero", "expression": { "terms": [{}, {}] } }));
+        }
+        opcodes.push(json!({ "type": "Teardown" }));
+        let costs: Vec<usize> = opcodes.iter().map(|op| if op["function"].is_string() { 2 } else { 1 }).collect();
+        
+        let patterns = detect_patterns(&opcodes, &costs, &Deadline::default());
+        
+        assert_eq!(patterns.len(), 1);
+        assert_eq!((patterns[0].start, patterns[0].length, patterns[0].repeats), (1, 2, 4));
+        assert_eq!((patterns[0].constraints, patterns[0].per_iteration), (12, 3));
+        assert_eq!(patterns[0].body, [("sha256".to_string(), 2), ("AssertZero".to_string(), 1)]);
+        assert_eq!(iteration_projections(&patterns[0]), [(4, 12), (2, 6), (1, 3)]);
+    }
+    
+    #[test]
+    fn short_repetitions_are_not_patterns() {
+        let opcodes = vec![json!({ "type": "AssertZero" }); 2];
+        assert!(detect_patterns(&opcodes, &[1, 1], &Deadline::default()).is_empty());
+    }
+    
+    #[test]
+    fn compacts_large_counts() {
+        assert_eq!(compact_count(999), "999");
+        assert_eq!(compact_count(12_345), "12.3k");
+        assert_eq!(compact_count(2_500_000), "2.5M");
+    }
+    
+    #[test]
+    fn loop_suggestions_project_fewer_iterations() {
+        let pattern = RepeatedPattern {
+            start: 0,
+            length: 2,
+            repeats: 8,
+            constraints: 16_000,
+            per_iteration: 2_000,
+            body: vec![("AssertZero".to_string(), 1), ("AssertZero".to_string(), 1)],
+            location: None,
+        };
+        let advice: Vec<String> = loop_suggestions(&pattern).into_iter().map(|suggestion| suggestion.advice).collect();
+        
+        assert_eq!(advice.len(), 2);
+        assert!(advice[0].starts_with("each iteration costs 2.0k constraints; iterations 8 → 16.0k, 4 → 8.0k, 2 → 4.0k"));
+        assert!(advice[1].starts_with("AssertZero in the loop body: merge linear combinations"));
+    }
+}
@@ -0,0 +1,277 @@
+use crate::analyzer::{analyze_circuit_with_options, AnalysisOptions};
+use crate::calls::AGGREGATION_OVERHEAD;
+use crate::core::PROVING_TIME_FACTOR;
+use crate::remote::resolve_artifact;
+use anyhow::{bail, Context, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+#[derive(Debug, Deserialize)]
+pub struct Pipeline {
+    #[serde(default)]
+    pub name: Option<String>,
+    pub stages: Vec<PipelineStage>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum StageMode {
+    Parallel,
+    Sequential,
+    Recursive,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PipelineStage {
+    pub name: String,
+    pub mode: StageMode,
+    #[serde(default)]
+    pub circuits: Vec<PipelineCircuit>,
+    #[serde(default)]
+    pub workers: Option<usize>,
+    #[serde(default)]
+    pub circuit: Option<String>,
+    #[serde(default)]
+    pub arity: Option<usize>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct PipelineCircuit {
+    pub path: String,
+    #[serde(default = "default_count")]
+    pub count: usize,
+}
+
+fn default_count() -> usize {
+    1
+}
+
+#[derive(Debug)]
+pub struct StageEstimate {
+    pub name: String,
+    pub mode: StageMode,
+    pub proofs: usize,
+    pub constraints: usize,
+    pub latency_ms: f64,
+    pub compute_ms: f64,
+}
+
+#[derive(Debug)]
+pub struct PipelineEstimate {
+    pub name: Option<String>,
+    pub stages: Vec<StageEstimate>,
+    pub latency_ms: f64,
+    pub compute_ms: f64,
+    pub constraints: usize,
+    pub final_proofs: usize,
+}
+
+pub fn load_pipeline(path: &Path) -> Result<Pipeline> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read pipeline: {}", path.display()))?;
+    
+    serde_yaml::from_str(&content)
+        .with_context(|| format!("Failed to parse pipeline: {}", path.display()))
+}
+
+struct CircuitCost {
+    constraints: usize,
+    proving_ms: f64,
+}
+
+struct CircuitCache<'a> {
+    base_dir: PathBuf,
+    options: &'a AnalysisOptions,
+    costs: HashMap<String, (usize, f64)>,
+}
+
+impl CircuitCache<'_> {
+    fn cost(&mut self, path: &str) -> Result<CircuitCost> {
+        if let Some((constraints, proving_ms)) = self.costs.get(path) {
+            return Ok(CircuitCost { constraints: *constraints, proving_ms: *proving_ms });
+        }
+        
+        let location = if path.contains("://") {
+            PathBuf::from(path)
+        } else {
+            self.base_dir.join(path)
+        };
+        
+        let local = resolve_artifact(&location)?;
+        let analysis = analyze_circuit_with_options(&local, self.options)
+            .with_context(|| format!("Failed to analyze pipeline circuit: {}", path))?;
+        
+        self.costs.insert(path.to_string(), (analysis.aggregate_constraints, analysis.estimated_proving_time));
+        Ok(CircuitCost {
+            constraints: analysis.aggregate_constraints,
+            proving_ms: analysis.estimated_proving_time,
+        })
+    }
+}
+
+fn estimate_stage(
+    stage: &PipelineStage,
+    incoming_proofs: usize,
+    cache: &mut CircuitCache
+) -> Result<StageEstimate> {
+    let mut estimate = StageEstimate {
+        name: stage.name.clone(),
+        mode: stage.mode,
+        proofs: 0,
+        constraints: 0,
+        latency_ms: 0.0,
+        compute_ms: 0.0,
+    };
+    
+    match stage.mode {
+        StageMode::Parallel | StageMode::Sequential => {
+            if stage.circuits.is_empty() {
+                bail!("Stage `{}` lists no circuits", stage.name);
+            }
+            
+            let mut slowest: f64 = 0.0;
+            for circuit in &stage.circuits {
+                let cost = cache.cost(&circuit.path)?;
+                
+                estimate.proofs = estimate.proofs.saturating_add(circuit.count);
+                estimate.constraints = estimate.constraints.saturating_add(cost.constraints.saturating_mul(circuit.count));
+                estimate.compute_ms += cost.proving_ms * circuit.count as f64;
+                slowest = slowest.max(cost.proving_ms);
+            }
+            
+            estimate.latency_ms = match stage.mode {
+                StageMode::Parallel => match stage.workers {
+                    Some(workers) if workers > 0 => slowest.max(estimate.compute_ms / workers as f64),
+                    _ => slowest,
+                },
+                _ => estimate.compute_ms,
+            };
+        },
+        StageMode::Recursive => {
+            let arity = stage.arity.unwrap_or(2);
+            if arity < 2 {
+                bail!("Stage `{}` needs an arity of at least 2", stage.name);
+            }
+            
+            if incoming_proofs == 0 {
+                bail!("Stage `{}` has no proofs to aggregate; add a stage before it", stage.name);
+            }
+            
+            let node = match &stage.circuit {
+                Some(path) => cache.cost(path)?,
+                None => {
+                    let constraints = arity.saturating_mul(AGGREGATION_OVERHEAD);
+                    CircuitCost {
+                        constraints,
+                        proving_ms: constraints as f64 * PROVING_TIME_FACTOR / 50.0,
+                    }
+                },
+            };
+            
+            let mut remaining = incoming_proofs;
+            while remaining > 1 {
+                let nodes = remaining.div_ceil(arity);
+                
+                estimate.proofs = estimate.proofs.saturating_add(nodes);
+                estimate.constraints = estimate.constraints.saturating_add(node.constraints.saturating_mul(nodes));
+                estimate.compute_ms += node.proving_ms * nodes as f64;
+                estimate.latency_ms += node.proving_ms;
+                remaining = nodes;
+            }
+        },
+    }
+    
+    Ok(estimate)
+}
+
+pub fn estimate_pipeline(pipeline: &Pipeline, base_dir: &Path, options: &AnalysisOptions) -> Result<PipelineEstimate> {
+    let mut cache = CircuitCache {
+        base_dir: base_dir.to_path_buf(),
+        options,
+        costs: HashMap::new(),
+    };
+    
+    let mut estimate = PipelineEstimate {
+        name: pipeline.name.clone(),
+        stages: Vec::new(),
+        latency_ms: 0.0,
+        compute_ms: 0.0,
+        constraints: 0,
+        final_proofs: 0,
+    };
+    
+    for stage in &pipeline.stages {
+        let stage_estimate = estimate_stage(stage, estimate.final_proofs, &mut cache)?;
+        
+        estimate.final_proofs = match stage.mode {
+            StageMode::Recursive => 1,
+            _ => stage_estimate.proofs,
+        };
+        estimate.latency_ms += stage_estimate.latency_ms;
+        estimate.compute_ms += stage_estimate.compute_ms;
+        estimate.constraints = estimate.constraints.saturating_add(stage_estimate.constraints);
+        estimate.stages.push(stage_estimate);
+    }
+    
+    Ok(estimate)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn cache(options: &AnalysisOptions) -> CircuitCache<'_> {
+        CircuitCache {
+            base_dir: PathBuf::new(),
+            options,
+            costs: HashMap::from([("leaf.json".to_string(), (100, 10.0)), ("root.json".to_string(), (500, 40.0))]),
+        }
+    }
+    
+    fn stage(yaml: &str) -> PipelineStage {
+        serde_yaml::from_str(yaml).unwrap()
+    }
+    
+    #[test]
+    fn parallel_stages_are_bounded_by_workers() {
+        let options = AnalysisOptions::default();
+        let stage = stage("{ name: txs, mode: parallel, workers: 2, circuits: [{ path: leaf.json, count: 8 }] }");
+        let estimate = estimate_stage(&stage, 0, &mut cache(&options)).unwrap();
+        
+        assert_eq!((estimate.proofs, estimate.constraints), (8, 800));
+        assert_eq!((estimate.compute_ms, estimate.latency_ms), (80.0, 40.0));
+    }
+    
+    #[test]
+    fn recursive_stages_fold_down_to_one_proof() {
+        let options = AnalysisOptions::default();
+        let stage = stage("{ name: agg, mode: recursive, arity: 3 }");
+        let estimate = estimate_stage(&stage, 8, &mut cache(&options)).unwrap();
+        let node_ms = (3 * AGGREGATION_OVERHEAD) as f64 * PROVING_TIME_FACTOR / 50.0;
+        
+        assert_eq!(estimate.proofs, 3 + 1);
+        assert_eq!(estimate.constraints, 4 * 3 * AGGREGATION_OVERHEAD);
+        assert!((estimate.latency_ms - 2.0 * node_ms).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn invalid_stages_are_rejected() {
+        let options = AnalysisOptions::default();
+        let mut cache = cache(&options);
+        
+        assert!(estimate_stage(&stage("{ name: agg, mode: recursive }"), 0, &mut cache).is_err());
+        assert!(estimate_stage(&stage("{ name: agg, mode: recursive, arity: 1 }"), 4, &mut cache).is_err());
+        assert!(estimate_stage(&stage("{ name: txs, mode: sequential }"), 0, &mut cache).is_err());
+    }
+    
+    #[test]
+    fn loads_the_example_pipeline() {
+        let pipeline = load_pipeline(Path::new("examples/circuits/rollup_pipeline.yaml")).unwrap();
+        let modes: Vec<StageMode> = pipeline.stages.iter().map(|stage| stage.mode).collect();
+        
+        assert_eq!(modes, [StageMode::Parallel, StageMode::Recursive, StageMode::Sequential]);
+        assert_eq!(pipeline.stages[2].circuits[0].count, 1);
+    }
+}
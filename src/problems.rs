@@ -0,0 +1,162 @@
+use crate::artifact::read_artifact;
+use crate::calls::{estimate_opcode_cost, function_opcodes};
+use crate::core::CircuitAnalysis;
+use crate::patterns::compact_count;
+use crate::suggest::{source_suggestions, BOTTLENECK_SHARE};
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::Path;
+
+pub const HOT_SPOT_SHARE: f64 = 0.10;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Warning,
+    Info,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Severity::Warning => write!(f, "warning"),
+            Severity::Info => write!(f, "info"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub file: String,
+    pub line: u64,
+    pub severity: Severity,
+    pub message: String,
+    pub cost: usize,
+}
+
+impl fmt::Display for Problem {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}:{}: {}: {} [{} constraints]", self.file, self.line, self.severity, self.message, self.cost)
+    }
+}
+
+#[derive(Default)]
+struct HotSpot {
+    constraints: usize,
+    operations: Vec<(String, usize)>,
+}
+
+fn split_location(location: &str) -> (String, u64) {
+    match location.rsplit_once(':').and_then(|(file, line)| Some((file, line.parse().ok()?))) {
+        Some((file, line)) => (file.to_string(), line),
+        None => (location.to_string(), 1),
+    }
+}
+
+pub fn problems(path: &Path, analysis: &CircuitAnalysis, max_file_size: Option<u64>) -> Result<Vec<Problem>> {
+    let json = read_artifact(path, max_file_size)?;
+    let data: Value = serde_json::from_slice(&json)
+        .with_context(|| format!("Failed to parse circuit JSON: {}", path.display()))?;
+    
+    let mut spots: BTreeMap<(String, u64), HotSpot> = BTreeMap::new();
+    for op in function_opcodes(&data, 0) {
+        let Some(file) = op["location"]["file"].as_str() else {
+            continue;
+        };
+        
+        let spot = spots.entry((file.to_string(), op["location"]["line"].as_u64().unwrap_or(1))).or_default();
+        let name = match op["type"].as_str().unwrap_or("Unknown") {
+            "BlackBoxFunction" => op["function"].as_str().unwrap_or("unknown"),
+            op_type => op_type,
+        };
+        
+        spot.constraints = spot.constraints.saturating_add(estimate_opcode_cost(op, analysis.expression_width));
+        match spot.operations.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, count)) => *count += 1,
+            None => spot.operations.push((name.to_string(), 1)),
+        }
+    }
+    
+    let total = analysis.constraints.max(1) as f64;
+    let mut problems: Vec<Problem> = spots.into_iter()
+        .filter(|(_, spot)| spot.constraints as f64 / total >= BOTTLENECK_SHARE)
+        .map(|((file, line), mut spot)| {
+            let share = spot.constraints as f64 / total;
+            spot.operations.sort_by(|a, b| b.1.cmp(&a.1).then(a.0.cmp(&b.0)));
+            let operations: Vec<String> = spot.operations.iter()
+                .map(|(name, count)| format!("{}× {}", count, name))
+                .collect();
+            
+            Problem {
+                file,
+                line,
+                severity: if share >= HOT_SPOT_SHARE { Severity::Warning } else { Severity::Info },
+                message: format!("constraint hot spot: {} constraints ({:.1}% of circuit) from {}",
+                    compact_count(spot.constraints), share * 100.0, operations.join(", ")),
+                cost: spot.constraints,
+            }
+        })
+        .collect();
+    
+    for entry in source_suggestions(path, analysis, max_file_size)? {
+        let (file, line) = split_location(&entry.location);
+        problems.extend(entry.advice.into_iter().map(|advice| Problem {
+            file: file.clone(),
+            line,
+            severity: Severity::Info,
+            message: format!("suggestion: {}", advice),
+            cost: entry.constraints,
+        }));
+    }
+    
+    problems.sort_by(|a, b| a.severity.cmp(&b.severity)
+        .then(b.cost.cmp(&a.cost))
+        .then(a.file.cmp(&b.file))
+        .then(a.line.cmp(&b.line)));
+    Ok(problems)
+}
+
+pub fn render_problems(problems: &[Problem]) -> String {
+    problems.iter().map(|problem| format!("{}\n", problem)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::Lint;
+    use serde_json::json;
+    
+    fn assert_zero(terms: usize, line: u64) -> Value {
+        let terms: Vec<Value> = (0..terms).map(|_| json!({ "variable": "w" })).collect();
+        json!({ "type": "AssertZero", "expression": { "terms": terms }, "location": { "file": "main.nr", "line": line } })
+    }
+    
+    #[test]
+    fn splits_locations() {
+        assert_eq!(split_location("src/main.nr:12"), ("src/main.nr".to_string(), 12));
+        assert_eq!(split_location("src/main.nr"), ("src/main.nr".to_string(), 1));
+        assert_eq!(split_location("C:main"), ("C:main".to_string(), 1));
+    }
+    
+    #[test]
+    fn reports_hot_spots_before_suggestions() {
+        let path = std::env::temp_dir().join(format!("noir-profiler-problems-{}.json", std::process::id()));
+        let data = json!({ "opcodes": [assert_zero(8, 3), assert_zero(4, 3), assert_zero(1, 7)] });
+        std::fs::write(&path, data.to_string()).unwrap();
+        
+        let analysis = CircuitAnalysis {
+            constraints: 20,
+            lints: vec![Lint { rule: "copy-chains".to_string(), severity: "low".to_string(), message: "reuse w".to_string(), sites: vec![2], savings: 1 }],
+            ..Default::default()
+        };
+        let found = problems(&path, &analysis, None);
+        std::fs::remove_file(&path).ok();
+        let found = found.unwrap();
+        
+        assert_eq!(found[0].to_string(), "main.nr:3: warning: constraint hot spot: 3 constraints (15.0% of circuit) from 2× AssertZero [3 constraints]");
+        assert!(found[1..].iter().all(|problem| problem.severity == Severity::Info));
+        assert!(found.iter().any(|problem| problem.line == 7 && problem.message.starts_with("suggestion: reuse w")));
+        assert_eq!(render_problems(&found).lines().count(), found.len());
+    }
+}
@@ -0,0 +1,107 @@
+use crate::analyzer::{analyze_circuit_data, AnalysisOptions};
+use crate::artifact::{check_artifact, read_artifact};
+use crate::core::CircuitAnalysis;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::path::Path;
+
+pub const MEMBER_KEYS: [&str; 3] = ["circuits", "programs", "members"];
+
+#[derive(Debug, Serialize)]
+pub struct ProgramMember {
+    pub name: String,
+    pub analysis: CircuitAnalysis,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct ProgramReport {
+    pub members: Vec<ProgramMember>,
+    pub constraints: usize,
+    pub aggregate_constraints: usize,
+    pub total_opcodes: usize,
+    pub estimated_proving_time: f64,
+}
+
+fn members_of(value: Value) -> Option<Vec<(Option<String>, Value)>> {
+    match value {
+        Value::Array(members) => Some(members.into_iter().map(|member| (None, member)).collect()),
+        Value::Object(members) => Some(members.into_iter().map(|(name, member)| (Some(name), member)).collect()),
+        _ => None,
+    }
+}
+
+fn embedded_circuits(json: &[u8]) -> Option<Vec<(Option<String>, Value)>> {
+    let mut values = serde_json::Deserializer::from_slice(json)
+        .into_iter::<Value>()
+        .collect::<Result<Vec<_>, _>>()
+        .ok()?;
+    
+    if values.len() > 1 {
+        return Some(values.into_iter().map(|value| (None, value)).collect());
+    }
+    
+    match values.pop()? {
+        Value::Array(members) => Some(members.into_iter().map(|member| (None, member)).collect()),
+        mut value => MEMBER_KEYS.iter().find_map(|key| members_of(value[*key].take())),
+    }
+}
+
+pub fn analyze_program(path: &Path, options: &AnalysisOptions) -> Result<Option<ProgramReport>> {
+    let json = read_artifact(path, options.limits.max_file_size)?;
+    
+    let Some(members) = embedded_circuits(&json) else {
+        return Ok(None);
+    };
+    
+    if !members.iter().any(|(_, member)| check_artifact(member).is_ok()) {
+        return Ok(None);
+    }
+    
+    let mut report = ProgramReport::default();
+    
+    for (idx, (key, member)) in members.into_iter().enumerate() {
+        let label = key.or_else(|| member["name"].as_str().map(str::to_string))
+            .unwrap_or_else(|| format!("circuit {}", idx + 1));
+        
+        let analysis = analyze_circuit_data(path, &member, options)
+            .with_context(|| format!("Failed to analyze bundle member {}", label))?;
+        
+        report.constraints = report.constraints.saturating_add(analysis.constraints);
+        report.aggregate_constraints = report.aggregate_constraints.saturating_add(analysis.aggregate_constraints);
+        report.total_opcodes += analysis.total_opcodes;
+        report.estimated_proving_time += analysis.estimated_proving_time;
+        report.members.push(ProgramMember { name: label, analysis });
+    }
+    
+    Ok(Some(report))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::cost::CostModel;
+    use std::collections::HashMap;
+    
+    fn labels(json: &str) -> Option<Vec<Option<String>>> {
+        embedded_circuits(json.as_bytes()).map(|members| members.into_iter().map(|(name, _)| name).collect())
+    }
+    
+    #[test]
+    fn finds_members_in_every_layout() {
+        assert_eq!(labels(r#"{ "programs": { "a": {}, "b": {} } }"#), Some(vec![Some("a".to_string()), Some("b".to_string())]));
+        assert_eq!(labels(r#"[{}, {}]"#), Some(vec![None, None]));
+        assert_eq!(labels("{} {} {}").map(|members| members.len()), Some(3));
+        assert_eq!(labels(r#"{ "opcodes": [] }"#), None);
+    }
+    
+    #[test]
+    fn sums_member_analyses() {
+        let options = AnalysisOptions { cost_model: CostModel::Fixed(HashMap::new()), ..Default::default() };
+        let report = analyze_program(Path::new("examples/circuits/program_bundle.json"), &options).unwrap().unwrap();
+        
+        assert_eq!(report.members.len(), 2);
+        assert_eq!(report.constraints, report.members.iter().map(|member| member.analysis.constraints).sum::<usize>());
+        assert!(analyze_program(Path::new("examples/circuits/simple_hash.json"), &options).unwrap().is_none());
+    }
+}
@@ -0,0 +1,108 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+use std::time::Instant;
+
+pub const PROGRESS_INTERVAL: usize = 4096;
+
+#[derive(Debug, Clone, Default)]
+pub struct CancellationToken(Arc<AtomicBool>);
+
+impl CancellationToken {
+    pub fn new() -> Self {
+        CancellationToken::default()
+    }
+    
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+    
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+#[derive(Debug, Clone, Default)]
+pub struct Deadline {
+    at: Option<Instant>,
+    cancel: Option<CancellationToken>,
+}
+
+impl Deadline {
+    pub fn new(at: Option<Instant>, cancel: Option<CancellationToken>) -> Self {
+        Deadline { at, cancel }
+    }
+    
+    pub fn expired(&self) -> bool {
+        self.cancel.as_ref().is_some_and(CancellationToken::is_cancelled)
+            || self.at.is_some_and(|at| Instant::now() > at)
+    }
+    
+    pub fn expired_at(&self, idx: usize) -> bool {
+        idx.is_multiple_of(PROGRESS_INTERVAL) && self.expired()
+    }
+}
+
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl fmt::Display for Cancelled {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "analysis cancelled")
+    }
+}
+
+impl std::error::Error for Cancelled {}
+
+type ProgressFn = dyn Fn(&str, f64) + Send + Sync;
+
+#[derive(Clone)]
+pub struct ProgressCallback(Arc<ProgressFn>);
+
+impl ProgressCallback {
+    pub fn new(callback: impl Fn(&str, f64) + Send + Sync + 'static) -> Self {
+        ProgressCallback(Arc::new(callback))
+    }
+    
+    pub fn report(&self, phase: &str, percent: f64) {
+        (self.0)(phase, percent.clamp(0.0, 100.0));
+    }
+}
+
+impl fmt::Debug for ProgressCallback {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ProgressCallback")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::sync::Mutex;
+    use std::time::Duration;
+    
+    #[test]
+    fn deadline_expires_on_cancel_or_time() {
+        let token = CancellationToken::new();
+        let deadline = Deadline::new(None, Some(token.clone()));
+        assert!(!deadline.expired());
+        token.cancel();
+        assert!(deadline.expired());
+        assert!(deadline.expired_at(PROGRESS_INTERVAL) && !deadline.expired_at(1));
+        
+        let past = Deadline::new(Instant::now().checked_sub(Duration::from_secs(1)), None);
+        assert!(past.expired());
+        assert!(!Deadline::default().expired());
+    }
+    
+    #[test]
+    fn progress_reports_are_clamped() {
+        let seen = Arc::new(Mutex::new(Vec::new()));
+        let sink = seen.clone();
+        let callback = ProgressCallback::new(move |phase, percent| sink.lock().unwrap().push((phase.to_string(), percent)));
+        
+        callback.report("parse", -5.0);
+        callback.report("costs", 250.0);
+        assert_eq!(*seen.lock().unwrap(), [("parse".to_string(), 0.0), ("costs".to_string(), 100.0)]);
+    }
+}
@@ -0,0 +1,166 @@
+use crate::cloud::estimate_memory_gb;
+use crate::core::CircuitAnalysis;
+use std::fmt;
+use std::str::FromStr;
+
+pub const DEFAULT_WEIGHTS: &str = "constraints=0.6,proving_time=0.3,memory=0.1";
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Objective {
+    Constraints,
+    ProvingTime,
+    Memory,
+}
+
+impl Objective {
+    fn value(&self, analysis: &CircuitAnalysis) -> f64 {
+        match self {
+            Objective::Constraints => analysis.aggregate_constraints as f64,
+            Objective::ProvingTime => analysis.estimated_proving_time,
+            Objective::Memory => estimate_memory_gb(analysis.aggregate_constraints),
+        }
+    }
+}
+
+impl fmt::Display for Objective {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Objective::Constraints => write!(f, "constraints"),
+            Objective::ProvingTime => write!(f, "proving_time"),
+            Objective::Memory => write!(f, "memory"),
+        }
+    }
+}
+
+impl FromStr for Objective {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().replace('-', "_").as_str() {
+            "constraints" => Ok(Objective::Constraints),
+            "proving_time" | "time" => Ok(Objective::ProvingTime),
+            "memory" => Ok(Objective::Memory),
+            _ => Err(format!("invalid objective `{}`, expected `constraints`, `proving_time` or `memory`", s)),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Weights(pub Vec<(Objective, f64)>);
+
+impl FromStr for Weights {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let mut weights: Vec<(Objective, f64)> = Vec::new();
+        
+        for entry in s.split(',').filter(|entry| !entry.trim().is_empty()) {
+            let (objective, weight) = entry.split_once('=')
+                .ok_or_else(|| format!("invalid weight `{}`, expected objective=weight", entry))?;
+            
+            let objective: Objective = objective.parse()?;
+            let weight: f64 = weight.trim().parse()
+                .map_err(|_| format!("invalid weight `{}` for {}", weight.trim(), objective))?;
+            
+            if !weight.is_finite() || weight < 0.0 {
+                return Err(format!("weight for {} must be a non-negative number, got {}", objective, weight));
+            }
+            
+            if weights.iter().any(|(existing, _)| *existing == objective) {
+                return Err(format!("{} is weighted twice", objective));
+            }
+            weights.push((objective, weight));
+        }
+        
+        let total: f64 = weights.iter().map(|(_, weight)| weight).sum();
+        if total <= 0.0 {
+            return Err("weights must add up to more than zero".to_string());
+        }
+        
+        Ok(Weights(weights.into_iter().map(|(objective, weight)| (objective, weight / total)).collect()))
+    }
+}
+
+impl fmt::Display for Weights {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let entries: Vec<String> = self.0.iter()
+            .map(|(objective, weight)| format!("{}={:.2}", objective, weight))
+            .collect();
+        write!(f, "{}", entries.join(","))
+    }
+}
+
+#[derive(Debug)]
+pub struct RankedCandidate {
+    pub name: String,
+    pub constraints: usize,
+    pub proving_time: f64,
+    pub memory_gb: f64,
+    pub score: f64,
+}
+
+pub fn rank_candidates(candidates: &[(String, CircuitAnalysis)], weights: &Weights) -> Vec<RankedCandidate> {
+    let best = |objective: Objective| -> f64 {
+        candidates.iter()
+            .map(|(_, analysis)| objective.value(analysis))
+            .fold(f64::INFINITY, f64::min)
+    };
+    
+    let baselines: Vec<(Objective, f64, f64)> = weights.0.iter()
+        .map(|(objective, weight)| (*objective, *weight, best(*objective)))
+        .collect();
+    
+    let mut ranked: Vec<RankedCandidate> = candidates.iter()
+        .map(|(name, analysis)| {
+            let score = baselines.iter()
+                .map(|(objective, weight, best)| {
+                    let value = objective.value(analysis);
+                    let relative = if *best > 0.0 { value / best } else if value > 0.0 { 2.0 } else { 1.0 };
+                    weight * relative
+                })
+                .sum();
+            
+            RankedCandidate {
+                name: name.clone(),
+                constraints: analysis.aggregate_constraints,
+                proving_time: analysis.estimated_proving_time,
+                memory_gb: estimate_memory_gb(analysis.aggregate_constraints),
+                score,
+            }
+        })
+        .collect();
+    
+    ranked.sort_by(|a, b| a.score.partial_cmp(&b.score).unwrap_or(std::cmp::Ordering::Equal).then(a.name.cmp(&b.name)));
+    ranked
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn candidate(name: &str, constraints: usize, time: f64) -> (String, CircuitAnalysis) {
+        (name.to_string(), CircuitAnalysis { aggregate_constraints: constraints, estimated_proving_time: time, ..Default::default() })
+    }
+    
+    #[test]
+    fn weights_are_validated_and_normalized() {
+        let weights: Weights = "constraints=3, time=1".parse().unwrap();
+        assert_eq!(weights.to_string(), "constraints=0.75,proving_time=0.25");
+        assert_eq!(DEFAULT_WEIGHTS.parse::<Weights>().unwrap().0.len(), 3);
+        
+        assert!("constraints=1,constraints=2".parse::<Weights>().unwrap_err().contains("twice"));
+        assert!("memory=-1".parse::<Weights>().is_err());
+        assert!("memory=0".parse::<Weights>().is_err());
+        assert!("gas=1".parse::<Weights>().is_err());
+    }
+    
+    #[test]
+    fn ranks_relative_to_the_best_candidate() {
+        let weights: Weights = "constraints=1,proving_time=1".parse().unwrap();
+        let ranked = rank_candidates(&[candidate("slow", 1000, 4.0), candidate("small", 500, 2.0), candidate("fast", 2000, 1.0)], &weights);
+        
+        assert_eq!(ranked.iter().map(|candidate| candidate.name.as_str()).collect::<Vec<_>>(), ["small", "fast", "slow"]);
+        assert_eq!(ranked[0].score, 1.5);
+        assert_eq!(ranked[1].score, 2.5);
+    }
+}
@@ -0,0 +1,156 @@
+use anyhow::{bail, Result};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+pub const NARGO_ENV: &str = "NARGO";
+
+pub fn nargo_binary() -> String {
+    std::env::var(NARGO_ENV).unwrap_or_else(|_| "nargo".to_string())
+}
+
+#[cfg(feature = "nargo")]
+fn find_artifacts(target_dir: &Path) -> Result<Vec<PathBuf>> {
+    use anyhow::Context;
+    
+    let mut artifacts: Vec<PathBuf> = fs::read_dir(target_dir)
+        .with_context(|| format!("Failed to read target directory: {}", target_dir.display()))?
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    
+    if artifacts.is_empty() {
+        bail!("nargo produced no artifact in {}", target_dir.display());
+    }
+    
+    artifacts.sort();
+    Ok(artifacts)
+}
+
+#[cfg(not(feature = "nargo"))]
+pub fn compile_all(project: &Path, _flags: &str, _target_dir: &Path) -> Result<Vec<PathBuf>> {
+    bail!("Cannot compile {}: rebuild with `--features nargo` to invoke nargo", project.display())
+}
+
+#[cfg(feature = "nargo")]
+pub fn compile_all(project: &Path, flags: &str, target_dir: &Path) -> Result<Vec<PathBuf>> {
+    use anyhow::Context;
+    use std::process::Command;
+    
+    if !project.join("Nargo.toml").is_file() {
+        bail!("{} is not a Noir project (no Nargo.toml)", project.display());
+    }
+    
+    if target_dir.exists() {
+        fs::remove_dir_all(target_dir)
+            .with_context(|| format!("Failed to clear {}", target_dir.display()))?;
+    }
+    
+    let nargo = nargo_binary();
+    let output = Command::new(&nargo)
+        .arg("compile")
+        .arg("--program-dir")
+        .arg(project)
+        .arg("--target-dir")
+        .arg(target_dir)
+        .args(flags.split_whitespace())
+        .output()
+        .with_context(|| format!("Failed to run `{}`; install nargo or set {}", nargo, NARGO_ENV))?;
+    
+    if !output.status.success() {
+        bail!("`{} compile {}` failed:\n{}", nargo, flags, String::from_utf8_lossy(&output.stderr).trim());
+    }
+    
+    find_artifacts(target_dir)
+}
+
+pub fn compile_with_flags(project: &Path, flags: &str, target_dir: &Path) -> Result<PathBuf> {
+    let mut artifacts = compile_all(project, flags, target_dir)?;
+    if artifacts.len() > 1 {
+        bail!("nargo produced several artifacts in {}; point --recompile at a single package", target_dir.display());
+    }
+    
+    Ok(artifacts.remove(0))
+}
+
+pub fn recompile_pair(project: &Path, flags_a: &str, flags_b: &str) -> Result<(PathBuf, PathBuf)> {
+    let work_dir = std::env::temp_dir().join("noir-profiler-recompile");
+    
+    let artifact_a = compile_with_flags(project, flags_a, &work_dir.join("a"))?;
+    let artifact_b = compile_with_flags(project, flags_b, &work_dir.join("b"))?;
+    
+    Ok((artifact_a, artifact_b))
+}
+
+pub struct CompiledProject {
+    target_dir: PathBuf,
+    pub artifact: PathBuf,
+}
+
+impl Drop for CompiledProject {
+    fn drop(&mut self) {
+        fs::remove_dir_all(&self.target_dir).ok();
+    }
+}
+
+pub fn compile_project(project: &Path) -> Result<CompiledProject> {
+    let target_dir = std::env::temp_dir().join(format!("noir-profiler-src-{}", std::process::id()));
+    
+    match compile_with_flags(project, "--silence-warnings", &target_dir) {
+        Ok(artifact) => Ok(CompiledProject { target_dir, artifact }),
+        Err(e) => {
+            fs::remove_dir_all(&target_dir).ok();
+            Err(e)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[cfg(not(feature = "nargo"))]
+    #[test]
+    fn compiling_needs_the_feature() {
+        let error = compile_all(Path::new("circuits/demo"), "", Path::new("target")).unwrap_err();
+        assert!(error.to_string().contains("--features nargo"));
+    }
+    
+    #[cfg(feature = "nargo")]
+    #[test]
+    fn projects_need_a_manifest() {
+        let error = compile_all(Path::new("examples/circuits"), "", Path::new("target/unused")).unwrap_err();
+        assert!(error.to_string().contains("Nargo.toml"));
+    }
+    
+    #[cfg(feature = "nargo")]
+    #[test]
+    fn finds_sorted_json_artifacts() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-artifacts-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        assert!(find_artifacts(&dir).is_err());
+        
+        for name in ["b.json", "a.json", "a.gz"] {
+            fs::write(dir.join(name), "{}").unwrap();
+        }
+        let found = find_artifacts(&dir);
+        fs::remove_dir_all(&dir).ok();
+        
+        assert_eq!(found.unwrap(), [dir.join("a.json"), dir.join("b.json")]);
+    }
+    
+    #[test]
+    fn compiled_projects_clean_up_their_target_dir() {
+        let target_dir = std::env::temp_dir().join(format!("noir-profiler-src-test-{}", std::process::id()));
+        fs::create_dir_all(&target_dir).unwrap();
+        fs::write(target_dir.join("main.json"), "{}").unwrap();
+        
+        drop(CompiledProject { artifact: target_dir.join("main.json"), target_dir: target_dir.clone() });
+        assert!(!target_dir.exists());
+    }
+    
+    #[test]
+    fn failed_compiles_leave_no_target_dir() {
+        assert!(compile_project(Path::new("examples/circuits")).is_err());
+        assert!(!std::env::temp_dir().join(format!("noir-profiler-src-{}", std::process::id())).exists());
+    }
+}
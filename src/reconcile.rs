@@ -0,0 +1,191 @@
+use crate::core::{gate_factor, tune_gate_factor, CircuitAnalysis};
+use crate::taxonomy::{opcode_category, Category};
+use anyhow::{bail, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::BTreeMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GapSource {
+    LookupExpansion,
+    RangeDecomposition,
+    Arithmetization,
+    CopyConstraints,
+}
+
+impl GapSource {
+    pub const ALL: [GapSource; 4] = [
+        GapSource::LookupExpansion,
+        GapSource::RangeDecomposition,
+        GapSource::Arithmetization,
+        GapSource::CopyConstraints,
+    ];
+    
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            GapSource::LookupExpansion => "reconcile.lookup_expansion",
+            GapSource::RangeDecomposition => "reconcile.range_decomposition",
+            GapSource::Arithmetization => "reconcile.arithmetization",
+            GapSource::CopyConstraints => "reconcile.copy_constraints",
+        }
+    }
+    
+    fn of(category: Category) -> GapSource {
+        match category {
+            Category::Hashing | Category::Signatures => GapSource::LookupExpansion,
+            Category::Range => GapSource::RangeDecomposition,
+            Category::Arithmetic | Category::Memory | Category::Control => GapSource::Arithmetization,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct GapRow {
+    pub source: GapSource,
+    pub estimated: usize,
+    pub actual: Option<usize>,
+}
+
+impl GapRow {
+    pub fn difference(&self) -> Option<i64> {
+        self.actual.map(|actual| actual as i64 - self.estimated as i64)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct Reconciliation {
+    pub backend: String,
+    pub estimated: usize,
+    pub actual: usize,
+    pub rows: Vec<GapRow>,
+    pub per_opcode: bool,
+    pub factor_before: f64,
+    pub factor_after: f64,
+}
+
+impl Reconciliation {
+    pub fn tune(&mut self, artifact_sha256: &str) -> Result<bool> {
+        if self.estimated == 0 {
+            return Ok(false);
+        }
+        
+        match tune_gate_factor(&self.backend, artifact_sha256, self.ratio())? {
+            Some(factor) => {
+                self.factor_after = factor;
+                Ok(true)
+            },
+            None => Ok(false),
+        }
+    }
+    
+    pub fn residual(&self) -> i64 {
+        self.actual as i64 - self.estimated as i64
+    }
+    
+    pub fn ratio(&self) -> f64 {
+        self.actual as f64 / self.estimated.max(1) as f64
+    }
+}
+
+fn gate_report(report: &Value) -> Result<(usize, Option<Vec<usize>>)> {
+    let main = report["functions"].as_array()
+        .and_then(|functions| functions.first())
+        .unwrap_or(report);
+    
+    let Some(circuit_size) = main["circuit_size"].as_u64() else {
+        bail!("Gate report has no `circuit_size`; expected the JSON output of `bb gates`");
+    };
+    
+    let per_opcode = main["gates_per_opcode"].as_array()
+        .map(|gates| gates.iter().map(|gate| gate.as_u64().unwrap_or(0) as usize).collect());
+    
+    Ok((circuit_size as usize, per_opcode))
+}
+
+pub fn reconcile(analysis: &CircuitAnalysis, artifact: &Value, report: &Value, backend: &str) -> Result<Reconciliation> {
+    let (circuit_size, per_opcode) = gate_report(report)?;
+    let opcodes = artifact["opcodes"].as_array().map_or(&[][..], Vec::as_slice);
+    let per_opcode = per_opcode.filter(|gates| gates.len() == opcodes.len());
+    
+    let mut estimated: BTreeMap<GapSource, usize> = BTreeMap::new();
+    for (category, constraints) in &analysis.categories {
+        let entry = estimated.entry(GapSource::of(*category)).or_insert(0);
+        *entry = entry.saturating_add(*constraints);
+    }
+    
+    let actual: Option<BTreeMap<GapSource, usize>> = per_opcode.as_ref().map(|gates| {
+        let mut actual = BTreeMap::new();
+        for (op, gates) in opcodes.iter().zip(gates) {
+            *actual.entry(GapSource::of(opcode_category(op))).or_insert(0) += gates;
+        }
+        let attributed: usize = gates.iter().sum();
+        actual.insert(GapSource::CopyConstraints, circuit_size.saturating_sub(attributed));
+        actual
+    });
+    
+    let rows = GapSource::ALL.iter()
+        .map(|source| GapRow {
+            source: *source,
+            estimated: estimated.get(source).copied().unwrap_or(0),
+            actual: actual.as_ref().map(|actual| actual.get(source).copied().unwrap_or(0)),
+        })
+        .collect();
+    
+    Ok(Reconciliation {
+        backend: backend.to_string(),
+        estimated: analysis.constraints,
+        actual: circuit_size,
+        rows,
+        per_opcode: per_opcode.is_some(),
+        factor_before: gate_factor(backend),
+        factor_after: gate_factor(backend),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn analysis() -> CircuitAnalysis {
+        CircuitAnalysis {
+            constraints: 100,
+            categories: vec![(Category::Hashing, 60), (Category::Arithmetic, 30), (Category::Memory, 10)],
+            ..Default::default()
+        }
+    }
+    
+    #[test]
+    fn attributes_gates_per_source() {
+        let artifact = json!({ "opcodes": [
+            { "type": "BlackBoxFunction", "function": "sha256" },
+            { "type": "AssertZero" },
+            { "type": "MemoryInit" },
+        ] });
+        let report = json!({ "functions": [{ "circuit_size": 150, "gates_per_opcode": [80, 30, 20] }] });
+        let reconciliation = reconcile(&analysis(), &artifact, &report, "ultra_honk").unwrap();
+        
+        assert!(reconciliation.per_opcode);
+        assert_eq!((reconciliation.residual(), reconciliation.ratio()), (50, 1.5));
+        let rows: Vec<(GapSource, usize, Option<i64>)> = reconciliation.rows.iter()
+            .map(|row| (row.source, row.estimated, row.difference()))
+            .collect();
+        assert_eq!(rows, [
+            (GapSource::LookupExpansion, 60, Some(20)),
+            (GapSource::RangeDecomposition, 0, Some(0)),
+            (GapSource::Arithmetization, 40, Some(10)),
+            (GapSource::CopyConstraints, 0, Some(20)),
+        ]);
+    }
+    
+    #[test]
+    fn mismatched_gate_lists_fall_back_to_totals() {
+        let artifact = json!({ "opcodes": [{ "type": "AssertZero" }] });
+        let reconciliation = reconcile(&analysis(), &artifact, &json!({ "circuit_size": 120, "gates_per_opcode": [1, 2] }), "ultra_honk").unwrap();
+        
+        assert!(!reconciliation.per_opcode);
+        assert!(reconciliation.rows.iter().all(|row| row.actual.is_none()));
+        assert!(reconcile(&analysis(), &artifact, &json!({}), "ultra_honk").is_err());
+    }
+}
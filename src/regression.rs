@@ -0,0 +1,280 @@
+use serde::Serialize;
+use std::fmt;
+use std::str::FromStr;
+
+pub const MIN_SEGMENT: usize = 2;
+pub const HARDWARE_VARIATION: f64 = 0.15;
+pub const Z_95: f64 = 1.96;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct ChangePoint {
+    pub index: usize,
+    pub before_mean: f64,
+    pub after_mean: f64,
+    pub shift_percent: f64,
+    pub p_value: f64,
+    pub confidence: f64,
+}
+
+impl ChangePoint {
+    pub fn is_regression(&self, min_confidence: f64) -> bool {
+        self.after_mean > self.before_mean && self.confidence >= min_confidence
+    }
+}
+
+fn mean(values: &[f64]) -> f64 {
+    if values.is_empty() {
+        return 0.0;
+    }
+    values.iter().sum::<f64>() / values.len() as f64
+}
+
+pub fn cusum_change_point(values: &[f64]) -> Option<usize> {
+    if values.len() < MIN_SEGMENT * 2 {
+        return None;
+    }
+    
+    let average = mean(values);
+    let mut sum = 0.0;
+    let mut best: Option<(usize, f64)> = None;
+    
+    for (idx, value) in values.iter().enumerate().take(values.len() - MIN_SEGMENT) {
+        sum += value - average;
+        let split = idx + 1;
+        
+        if split >= MIN_SEGMENT && best.is_none_or(|(_, peak)| sum.abs() > peak) {
+            best = Some((split, sum.abs()));
+        }
+    }
+    
+    best.filter(|(_, peak)| *peak > 0.0).map(|(split, _)| split)
+}
+
+fn normal_cdf(z: f64) -> f64 {
+    let t = 1.0 / (1.0 + 0.3275911 * z.abs() / std::f64::consts::SQRT_2);
+    let poly = t * (0.254829592 + t * (-0.284496736 + t * (1.421413741 + t * (-1.453152027 + t * 1.061405429))));
+    let erf = 1.0 - poly * (-(z * z) / 2.0).exp();
+    
+    if z >= 0.0 { 0.5 * (1.0 + erf) } else { 0.5 * (1.0 - erf) }
+}
+
+pub fn mann_whitney_greater(before: &[f64], after: &[f64]) -> f64 {
+    let (n1, n2) = (before.len() as f64, after.len() as f64);
+    if before.is_empty() || after.is_empty() {
+        return 1.0;
+    }
+    
+    let mut pooled: Vec<(f64, bool)> = before.iter().map(|v| (*v, false))
+        .chain(after.iter().map(|v| (*v, true)))
+        .collect();
+    pooled.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap_or(std::cmp::Ordering::Equal));
+    
+    let mut after_ranks = 0.0;
+    let mut tie_term = 0.0;
+    let mut start = 0;
+    while start < pooled.len() {
+        let mut end = start;
+        while end + 1 < pooled.len() && pooled[end + 1].0 == pooled[start].0 {
+            end += 1;
+        }
+        
+        let rank = (start + end) as f64 / 2.0 + 1.0;
+        let ties = (end - start + 1) as f64;
+        tie_term += ties * ties * ties - ties;
+        after_ranks += pooled[start..=end].iter().filter(|(_, is_after)| *is_after).count() as f64 * rank;
+        start = end + 1;
+    }
+    
+    let u = after_ranks - n2 * (n2 + 1.0) / 2.0;
+    let n = n1 + n2;
+    let variance = n1 * n2 / 12.0 * ((n + 1.0) - tie_term / (n * (n - 1.0)));
+    if variance <= 0.0 {
+        return 1.0;
+    }
+    
+    let z = (u - n1 * n2 / 2.0 - 0.5) / variance.sqrt();
+    1.0 - normal_cdf(z)
+}
+
+pub fn detect_change(values: &[f64]) -> Option<ChangePoint> {
+    let index = cusum_change_point(values)?;
+    let (before, after) = values.split_at(index);
+    
+    let before_mean = mean(before);
+    let after_mean = mean(after);
+    let p_value = if after_mean >= before_mean {
+        mann_whitney_greater(before, after)
+    } else {
+        mann_whitney_greater(after, before)
+    };
+    
+    Some(ChangePoint {
+        index,
+        before_mean,
+        after_mean,
+        shift_percent: if before_mean > 0.0 { (after_mean - before_mean) / before_mean * 100.0 } else { 0.0 },
+        p_value,
+        confidence: 1.0 - p_value,
+    })
+}
+
+#[derive(Debug, Clone, Copy, Serialize)]
+pub struct DeltaInterval {
+    pub delta: f64,
+    pub low: f64,
+    pub high: f64,
+}
+
+impl DeltaInterval {
+    pub fn verdict(&self) -> &'static str {
+        if self.low > 0.0 {
+            "likely regression"
+        } else if self.high < 0.0 {
+            "likely improvement"
+        } else {
+            "not statistically distinguishable"
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize)]
+pub struct NoiseFloor(pub f64);
+
+impl FromStr for NoiseFloor {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let percent = s.trim().trim_end_matches('%').trim().parse::<f64>()
+            .map_err(|_| format!("invalid noise floor `{}`, expected a percentage like 0.5%", s))?;
+        
+        if !(percent >= 0.0 && percent.is_finite()) {
+            return Err(format!("noise floor must be a non-negative percentage, got {}", s));
+        }
+        
+        Ok(NoiseFloor(percent))
+    }
+}
+
+impl fmt::Display for NoiseFloor {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}%", self.0)
+    }
+}
+
+impl NoiseFloor {
+    pub fn within(&self, before: f64, after: f64) -> bool {
+        before != after && before != 0.0 && ((after - before) / before).abs() * 100.0 < self.0
+    }
+}
+
+pub fn relative_spread(confidence: f32) -> f64 {
+    let model = (1.0 - confidence as f64).max(0.0) / Z_95;
+    let hardware = 2.0 * HARDWARE_VARIATION / 12f64.sqrt();
+    (model * model + hardware * hardware).sqrt()
+}
+
+pub fn delta_interval(before: f64, before_spread: f64, after: f64, after_spread: f64) -> DeltaInterval {
+    let delta = after - before;
+    let deviation = ((before * before_spread).powi(2) + (after * after_spread).powi(2)).sqrt();
+    
+    DeltaInterval {
+        delta,
+        low: delta - Z_95 * deviation,
+        high: delta + Z_95 * deviation,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    const STEP: [f64; 10] = [10.0, 11.0, 10.0, 11.0, 10.0, 20.0, 21.0, 20.0, 21.0, 20.0];
+    
+    #[test]
+    fn cusum_needs_two_segments() {
+        assert_eq!(cusum_change_point(&[1.0, 2.0, 3.0]), None);
+        assert_eq!(cusum_change_point(&[5.0; 6]), None);
+    }
+    
+    #[test]
+    fn cusum_finds_step() {
+        assert_eq!(cusum_change_point(&STEP), Some(5));
+        assert_eq!(cusum_change_point(&[10.0, 10.0, 10.0, 20.0, 20.0, 20.0]), Some(3));
+    }
+    
+    #[test]
+    fn cusum_keeps_minimum_segment() {
+        let late = [10.0, 10.0, 10.0, 10.0, 10.0, 30.0];
+        let split = cusum_change_point(&late).unwrap();
+        assert!(split >= MIN_SEGMENT && late.len() - split >= MIN_SEGMENT);
+    }
+    
+    #[test]
+    fn mann_whitney_separated_samples() {
+        let low = [1.0, 2.0, 3.0, 4.0, 5.0];
+        let high = [6.0, 7.0, 8.0, 9.0, 10.0];
+        
+        assert!(mann_whitney_greater(&low, &high) < 0.01);
+        assert!(mann_whitney_greater(&high, &low) > 0.99);
+    }
+    
+    #[test]
+    fn mann_whitney_small_samples_stay_below_95_percent() {
+        let p = mann_whitney_greater(&[1.0, 2.0], &[3.0, 4.0]);
+        assert!(p > 0.05 && p < 0.2);
+    }
+    
+    #[test]
+    fn mann_whitney_degenerate_inputs() {
+        assert_eq!(mann_whitney_greater(&[], &[1.0]), 1.0);
+        assert_eq!(mann_whitney_greater(&[5.0; 5], &[5.0; 5]), 1.0);
+    }
+    
+    #[test]
+    fn detect_change_reports_regression() {
+        let change = detect_change(&STEP).unwrap();
+        
+        assert_eq!(change.index, 5);
+        assert!((change.before_mean - 10.4).abs() < 1e-9);
+        assert!((change.after_mean - 20.4).abs() < 1e-9);
+        assert!(change.p_value < 0.01);
+        assert!(change.is_regression(0.95));
+    }
+    
+    #[test]
+    fn detect_change_improvement_is_not_regression() {
+        let mut improving = STEP;
+        improving.reverse();
+        let change = detect_change(&improving).unwrap();
+        
+        assert!(change.after_mean < change.before_mean);
+        assert!(change.confidence > 0.99);
+        assert!(!change.is_regression(0.95));
+    }
+    
+    #[test]
+    fn delta_intervals_pick_a_verdict() {
+        assert!(relative_spread(0.5) > relative_spread(1.0));
+        
+        let regression = delta_interval(100.0, 0.01, 150.0, 0.01);
+        assert_eq!(regression.delta, 50.0);
+        assert!(regression.low < 50.0 && regression.high > 50.0);
+        assert_eq!(regression.verdict(), "likely regression");
+        
+        assert_eq!(delta_interval(150.0, 0.01, 100.0, 0.01).verdict(), "likely improvement");
+        assert_eq!(delta_interval(100.0, 0.2, 105.0, 0.2).verdict(), "not statistically distinguishable");
+    }
+    
+    #[test]
+    fn noise_floors_parse_percentages() {
+        assert_eq!("0.5%".parse::<NoiseFloor>(), Ok(NoiseFloor(0.5)));
+        assert_eq!(" 2 ".parse::<NoiseFloor>(), Ok(NoiseFloor(2.0)));
+        assert!("-1%".parse::<NoiseFloor>().unwrap_err().contains("non-negative"));
+        assert!("lots".parse::<NoiseFloor>().unwrap_err().contains("invalid noise floor"));
+        assert_eq!(NoiseFloor(0.5).to_string(), "0.5%");
+        
+        assert!(NoiseFloor(1.0).within(1000.0, 1005.0));
+        assert!(!NoiseFloor(1.0).within(1000.0, 1020.0));
+        assert!(!NoiseFloor(1.0).within(1000.0, 1000.0) && !NoiseFloor(1.0).within(0.0, 0.001));
+    }
+}
@@ -0,0 +1,156 @@
+use anyhow::Result;
+use std::path::{Path, PathBuf};
+
+#[cfg(feature = "remote")]
+const CACHE_DIR: &str = "noir-profiler-cache";
+
+pub fn is_remote(location: &Path) -> bool {
+    let location = location.to_string_lossy();
+    location.starts_with("http://") || location.starts_with("https://") || location.starts_with("s3://")
+}
+
+pub fn resolve_artifact(location: &Path) -> Result<PathBuf> {
+    if !is_remote(location) {
+        return Ok(location.to_path_buf());
+    }
+    
+    fetch(&location.to_string_lossy())
+}
+
+#[cfg(not(feature = "remote"))]
+fn fetch(url: &str) -> Result<PathBuf> {
+    Err(anyhow::anyhow!("Cannot fetch {}: rebuild with `--features remote` to analyze remote artifacts", url))
+}
+
+#[cfg(feature = "remote")]
+fn fetch(url: &str) -> Result<PathBuf> {
+    use anyhow::Context;
+    use reqwest::header::{ETAG, IF_MODIFIED_SINCE, IF_NONE_MATCH, LAST_MODIFIED};
+    use reqwest::StatusCode;
+    
+    let s3 = url.starts_with("s3://");
+    let http_url = match url.strip_prefix("s3://") {
+        Some(rest) => s3_to_https(rest)?,
+        None => url.to_string(),
+    };
+    
+    let cache_dir = std::env::temp_dir().join(CACHE_DIR);
+    std::fs::create_dir_all(&cache_dir)
+        .with_context(|| format!("Failed to create cache directory: {}", cache_dir.display()))?;
+    
+    let (key, suffix) = cache_key(url);
+    let cached = cache_dir.join(format!("{}{}", key, suffix));
+    let validators = cache_dir.join(format!("{}.validators", key));
+    
+    let mut request = reqwest::blocking::Client::new().get(&http_url);
+    if cached.is_file() {
+        let saved = std::fs::read_to_string(&validators).unwrap_or_default();
+        for line in saved.lines() {
+            match line.split_once(": ") {
+                Some(("etag", value)) => request = request.header(IF_NONE_MATCH, value),
+                Some(("last-modified", value)) => request = request.header(IF_MODIFIED_SINCE, value),
+                _ => {},
+            }
+        }
+    }
+    
+    let response = request.send()
+        .with_context(|| format!("Failed to download {}", url))?;
+    
+    if response.status() == StatusCode::NOT_MODIFIED && cached.is_file() {
+        return Ok(cached);
+    }
+    if s3 && matches!(response.status(), StatusCode::FORBIDDEN | StatusCode::UNAUTHORIZED) {
+        anyhow::bail!("Failed to download {}: access denied ({}). s3:// locations are fetched unsigned, \
+            so only public buckets work; pass a presigned https URL for private objects", url, response.status());
+    }
+    
+    let response = response.error_for_status()
+        .with_context(|| format!("Failed to download {}", url))?;
+    
+    let saved: String = [("etag", ETAG), ("last-modified", LAST_MODIFIED)].iter()
+        .filter_map(|(name, header)| response.headers().get(header)
+            .and_then(|value| value.to_str().ok())
+            .map(|value| format!("{}: {}\n", name, value)))
+        .collect();
+    
+    let bytes = response.bytes()
+        .with_context(|| format!("Failed to read response body from {}", url))?;
+    
+    std::fs::write(&cached, &bytes)
+        .with_context(|| format!("Failed to write cached artifact: {}", cached.display()))?;
+    std::fs::write(&validators, saved)
+        .with_context(|| format!("Failed to write cache validators: {}", validators.display()))?;
+    
+    Ok(cached)
+}
+
+#[cfg(feature = "remote")]
+fn cache_key(url: &str) -> (String, &'static str) {
+    use sha2::{Digest, Sha256};
+    
+    let path = url.split(['?', '#']).next().unwrap_or(url);
+    let suffix = crate::artifact::ARTIFACT_SUFFIXES.iter()
+        .find(|suffix| path.ends_with(*suffix))
+        .unwrap_or(&".json");
+    
+    (format!("{:x}", Sha256::digest(url.as_bytes())), suffix)
+}
+
+#[cfg(feature = "remote")]
+fn s3_to_https(rest: &str) -> Result<String> {
+    let (bucket, key) = rest.split_once('/')
+        .filter(|(bucket, key)| !bucket.is_empty() && !key.is_empty())
+        .ok_or_else(|| anyhow::anyhow!("Invalid S3 location, expected s3://bucket/key: s3://{}", rest))?;
+    
+    if let Ok(endpoint) = std::env::var("AWS_ENDPOINT_URL") {
+        return Ok(format!("{}/{}/{}", endpoint.trim_end_matches('/'), bucket, key));
+    }
+    
+    match std::env::var("AWS_REGION") {
+        Ok(region) => Ok(format!("https://{}.s3.{}.amazonaws.com/{}", bucket, region, key)),
+        Err(_) => Ok(format!("https://{}.s3.amazonaws.com/{}", bucket, key)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn only_urls_are_remote() {
+        assert!(is_remote(Path::new("https://example.com/main.json")));
+        assert!(is_remote(Path::new("s3://bucket/main.json")));
+        assert!(!is_remote(Path::new("target/main.json")));
+        assert!(!is_remote(Path::new("https:/main.json")));
+        
+        assert_eq!(resolve_artifact(Path::new("target/main.json")).unwrap(), PathBuf::from("target/main.json"));
+    }
+    
+    #[cfg(not(feature = "remote"))]
+    #[test]
+    fn remote_locations_need_the_feature() {
+        let error = resolve_artifact(Path::new("https://example.com/main.json")).unwrap_err();
+        assert!(error.to_string().contains("--features remote"));
+    }
+    
+    #[cfg(feature = "remote")]
+    #[test]
+    fn cache_key_covers_the_full_url() {
+        let (plain, suffix) = cache_key("https://example.com/main.json.gz");
+        let (signed, signed_suffix) = cache_key("https://example.com/main.json.gz?X-Amz-Signature=abc");
+        
+        assert_ne!(plain, signed);
+        assert_eq!((suffix, signed_suffix), (".json.gz", ".json.gz"));
+        assert_eq!(cache_key("https://example.com/artifact").1, ".json");
+        assert_eq!(cache_key("s3://bucket/main.json.zst").1, ".json.zst");
+    }
+    
+    #[cfg(feature = "remote")]
+    #[test]
+    fn s3_locations_need_bucket_and_key() {
+        assert!(s3_to_https("bucket").is_err());
+        assert!(s3_to_https("/main.json").is_err());
+        assert!(s3_to_https("bucket/").is_err());
+    }
+}
@@ -0,0 +1,413 @@
+use crate::core::{CircuitAnalysis, FALLBACK_COST};
+use crate::i18n::t;
+use crate::names::display_name;
+use anyhow::{bail, Context, Result};
+use minijinja::Environment;
+use serde::{Deserialize, Serialize};
+use serde_json::{json, Value};
+use std::fmt::{self, Write};
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EmitFormat {
+    Text,
+    Json,
+    Markdown,
+    Problems,
+}
+
+impl fmt::Display for EmitFormat {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            EmitFormat::Text => write!(f, "text"),
+            EmitFormat::Json => write!(f, "json"),
+            EmitFormat::Markdown => write!(f, "markdown"),
+            EmitFormat::Problems => write!(f, "problems"),
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct EmitSink {
+    pub format: EmitFormat,
+    pub path: Option<PathBuf>,
+}
+
+impl FromStr for EmitSink {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (format, path) = match s.split_once('=') {
+            Some((format, path)) if !path.trim().is_empty() => (format, Some(PathBuf::from(path.trim()))),
+            Some((format, _)) => return Err(format!("missing path after `{}=`", format)),
+            None => (s, None),
+        };
+        
+        let format = match format.trim().to_ascii_lowercase().as_str() {
+            "text" => EmitFormat::Text,
+            "json" => EmitFormat::Json,
+            "markdown" | "md" => EmitFormat::Markdown,
+            "problems" => EmitFormat::Problems,
+            other => return Err(format!("unknown format `{}`, expected `text`, `json`, `markdown` or `problems`", other)),
+        };
+        
+        Ok(EmitSink { format, path })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MetricRow {
+    pub circuit: String,
+    pub metric: String,
+    pub value: f64,
+}
+
+pub fn long_rows(analysis: &CircuitAnalysis, circuit: &str) -> Vec<MetricRow> {
+    let mut metrics: Vec<(String, f64)> = vec![
+        ("constraints".to_string(), analysis.constraints as f64),
+        ("aggregate_constraints".to_string(), analysis.aggregate_constraints as f64),
+        ("opcodes".to_string(), analysis.total_opcodes as f64),
+        ("public_inputs".to_string(), analysis.public_inputs as f64),
+        ("private_inputs".to_string(), analysis.private_inputs as f64),
+        ("return_values".to_string(), analysis.return_values as f64),
+        ("proof_count".to_string(), analysis.proof_count as f64),
+        ("estimated_proving_time_ms".to_string(), analysis.estimated_proving_time),
+        ("confidence".to_string(), analysis.confidence as f64),
+    ];
+    
+    for (category, constraints) in &analysis.categories {
+        metrics.push((format!("category.{}.constraints", category), *constraints as f64));
+    }
+    
+    for (op, count) in &analysis.operation_counts {
+        metrics.push((format!("operation.{}.count", op), *count as f64));
+    }
+    
+    for (name, calls, cost) in &analysis.black_box_functions {
+        metrics.push((format!("black_box.{}.calls", name), *calls as f64));
+        metrics.push((format!("black_box.{}.constraints", name), calls.saturating_mul(*cost) as f64));
+    }
+    
+    metrics.into_iter()
+        .map(|(metric, value)| MetricRow { circuit: circuit.to_string(), metric, value })
+        .collect()
+}
+
+pub fn write_report(analysis: &CircuitAnalysis, path: &Path) -> Result<()> {
+    let content = serde_json::to_string_pretty(analysis)
+        .context("Failed to serialize analysis")?;
+    
+    fs::write(path, content)
+        .with_context(|| format!("Failed to write report: {}", path.display()))
+}
+
+pub fn render_template(analysis: &CircuitAnalysis, artifact: &Path, template: &Path) -> Result<String> {
+    let source = fs::read_to_string(template)
+        .with_context(|| format!("Failed to read template: {}", template.display()))?;
+    
+    let name = template.file_name()
+        .map(|name| name.to_string_lossy().to_string())
+        .unwrap_or_else(|| "report".to_string());
+    
+    let mut env = Environment::new();
+    env.add_template(&name, &source)
+        .with_context(|| format!("Failed to parse template: {}", template.display()))?;
+    
+    let mut context = serde_json::to_value(analysis)
+        .context("Failed to serialize analysis")?;
+    context["artifact"] = json!(artifact.display().to_string());
+    context["generated"] = json!(chrono::Local::now().to_rfc3339());
+    
+    env.get_template(&name)?
+        .render(context)
+        .with_context(|| format!("Failed to render template: {}", template.display()))
+}
+
+pub fn render_markdown(analysis: &CircuitAnalysis, artifact: &Path) -> String {
+    let mut markdown = String::new();
+    
+    let _ = writeln!(markdown, "# Circuit report: `{}`\n", artifact.display());
+    let _ = writeln!(markdown, "| Metric | Value |\n|--------|-------|");
+    for (key, value) in analysis.metadata.header_rows() {
+        let _ = writeln!(markdown, "| {} | {} |", t(key), value);
+    }
+    let _ = writeln!(markdown, "| {} | {} ({}) |", t("metrics.constraints"), analysis.constraints, analysis.count_label());
+    if let Some(sampling) = &analysis.sampling {
+        let _ = writeln!(markdown, "| 95% Interval | {} – {} (from {} of {} opcodes) |",
+            sampling.constraints_low, sampling.constraints_high, sampling.sampled_opcodes, sampling.total_opcodes);
+    }
+    if analysis.aggregate_constraints != analysis.constraints {
+        let _ = writeln!(markdown, "| Aggregate Constraints | {} |", analysis.aggregate_constraints);
+    }
+    let _ = writeln!(markdown, "| Opcodes | {} |", analysis.total_opcodes);
+    let _ = writeln!(markdown, "| Public Inputs | {} |", analysis.public_inputs);
+    let _ = writeln!(markdown, "| Private Inputs | {} |", analysis.private_inputs);
+    let _ = writeln!(markdown, "| Estimated Proving Time | {:.2}ms |", analysis.estimated_proving_time);
+    
+    for missing in analysis.unknown_sections() {
+        let _ = writeln!(markdown, "\n> **Warning:** {}", missing);
+    }
+    for missing in analysis.estimated_sections() {
+        let _ = writeln!(markdown, "\n> **Note:** {}", missing);
+    }
+    
+    if let Some(warning) = analysis.uncalibrated_warning() {
+        let _ = writeln!(markdown, "\n> **Warning:** {}\n", warning);
+        let _ = writeln!(markdown, "| Operation | Occurrences | Assumed Cost |\n|-----------|-------------|--------------|");
+        for (name, count) in &analysis.uncalibrated_operations {
+            let _ = writeln!(markdown, "| {} | {} | {} |", name, count, FALLBACK_COST);
+        }
+    }
+    
+    if !analysis.black_box_functions.is_empty() {
+        let _ = writeln!(markdown, "\n## Black-box functions\n");
+        let _ = writeln!(markdown, "| Function | Calls | Constraints |\n|----------|-------|-------------|");
+        for (name, count, cost) in &analysis.black_box_functions {
+            let _ = writeln!(markdown, "| {} | {} | {} |", display_name(name), count, count.saturating_mul(*cost));
+        }
+    }
+    
+    if !analysis.categories.is_empty() {
+        let _ = writeln!(markdown, "\n## Constraint categories\n");
+        let _ = writeln!(markdown, "| Category | Constraints | Share |\n|----------|-------------|-------|");
+        for (category, constraints) in &analysis.categories {
+            let share = if analysis.constraints > 0 {
+                *constraints as f64 / analysis.constraints as f64 * 100.0
+            } else {
+                0.0
+            };
+            let _ = writeln!(markdown, "| {} | {} | {:.1}% |", t(category.i18n_key()), constraints, share);
+        }
+    }
+    
+    if !analysis.lints.is_empty() {
+        let _ = writeln!(markdown, "\n## Suggestions\n");
+        for lint in analysis.lints.iter().take(10) {
+            let _ = writeln!(markdown, "- **{}**: {} (saves ~{} constraints)", lint.rule, lint.message, lint.savings);
+        }
+    }
+    
+    markdown
+}
+
+pub fn load_report(path: &Path) -> Result<CircuitAnalysis> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read report: {}", path.display()))?;
+    
+    let mut value: Value = serde_json::from_str(&content)
+        .with_context(|| format!("Failed to parse report: {}", path.display()))?;
+    
+    if value.get("signature").is_some() {
+        value = value["report"].take();
+    }
+    
+    serde_json::from_value(value)
+        .with_context(|| format!("Not an analysis report: {}", path.display()))
+}
+
+fn to_pointer(selector: &str) -> Result<String> {
+    if selector.starts_with('/') || selector.is_empty() {
+        return Ok(selector.to_string());
+    }
+    
+    let Some(path) = selector.strip_prefix('.') else {
+        bail!("Invalid selector `{}`: use `.field.sub[0]` or a JSON pointer like `/field/sub/0`", selector);
+    };
+    
+    let mut pointer = String::new();
+    for segment in path.split('.').filter(|segment| !segment.is_empty()) {
+        let (key, indices) = match segment.find('[') {
+            Some(start) => segment.split_at(start),
+            None => (segment, ""),
+        };
+        
+        if !key.is_empty() {
+            pointer.push('/');
+            pointer.push_str(&key.replace('~', "~0").replace('/', "~1"));
+        }
+        
+        for index in indices.split('[').filter(|index| !index.is_empty()) {
+            let Some(index) = index.strip_suffix(']').filter(|index| index.parse::<usize>().is_ok()) else {
+                bail!("Invalid index in selector `{}`", selector);
+            };
+            pointer.push('/');
+            pointer.push_str(index);
+        }
+    }
+    
+    Ok(pointer)
+}
+
+pub fn select_fields(report: &Value, selectors: &str) -> Result<Value> {
+    let selectors: Vec<&str> = selectors.split(',').map(str::trim).filter(|selector| !selector.is_empty()).collect();
+    
+    let mut selected = serde_json::Map::new();
+    for selector in &selectors {
+        let pointer = to_pointer(selector)?;
+        let value = report.pointer(&pointer)
+            .with_context(|| format!("Selector `{}` does not match anything in the report", selector))?;
+        
+        if selectors.len() == 1 {
+            return Ok(value.clone());
+        }
+        
+        selected.insert(selector.trim_start_matches(['.', '/']).to_string(), value.clone());
+    }
+    
+    Ok(Value::Object(selected))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn pointers_pass_through() {
+        assert_eq!(to_pointer("").unwrap(), "");
+        assert_eq!(to_pointer("/constraints").unwrap(), "/constraints");
+        assert_eq!(to_pointer("/operation_counts/0/1").unwrap(), "/operation_counts/0/1");
+    }
+    
+    #[test]
+    fn dotted_selectors() {
+        assert_eq!(to_pointer(".").unwrap(), "");
+        assert_eq!(to_pointer(".constraints").unwrap(), "/constraints");
+        assert_eq!(to_pointer(".sampling.rate").unwrap(), "/sampling/rate");
+        assert_eq!(to_pointer("..sampling..rate.").unwrap(), "/sampling/rate");
+    }
+    
+    #[test]
+    fn indexed_selectors() {
+        assert_eq!(to_pointer(".operation_counts[0]").unwrap(), "/operation_counts/0");
+        assert_eq!(to_pointer(".operation_counts[0][1]").unwrap(), "/operation_counts/0/1");
+        assert_eq!(to_pointer(".[2]").unwrap(), "/2");
+        assert_eq!(to_pointer(".bottlenecks[10].cost").unwrap(), "/bottlenecks/10/cost");
+    }
+    
+    #[test]
+    fn selector_keys_are_escaped() {
+        assert_eq!(to_pointer(".a/b").unwrap(), "/a~1b");
+        assert_eq!(to_pointer(".a~b").unwrap(), "/a~0b");
+    }
+    
+    #[test]
+    fn invalid_selectors() {
+        assert!(to_pointer("constraints").is_err());
+        assert!(to_pointer(".operation_counts[x]").is_err());
+        assert!(to_pointer(".operation_counts[-1]").is_err());
+        assert!(to_pointer(".operation_counts[0").is_err());
+    }
+    
+    #[test]
+    fn select_fields_shapes() {
+        let report = json!({ "constraints": 42, "sampling": { "rate": 0.5 }, "operation_counts": [["Constraint", 3]] });
+        
+        assert_eq!(select_fields(&report, ".constraints").unwrap(), json!(42));
+        assert_eq!(select_fields(&report, ".constraints, .sampling.rate").unwrap(),
+            json!({ "constraints": 42, "sampling.rate": 0.5 }));
+        assert_eq!(select_fields(&report, "/operation_counts/0/1").unwrap(), json!(3));
+        assert!(select_fields(&report, ".missing").is_err());
+    }
+    
+    #[test]
+    fn reports_round_trip_and_unwrap_signatures() {
+        let dir = std::env::temp_dir().join(format!("noir-profiler-report-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let analysis = CircuitAnalysis { constraints: 42, total_opcodes: 7, ..Default::default() };
+        write_report(&analysis, &dir.join("plain.json")).unwrap();
+        fs::write(dir.join("signed.json"), json!({ "report": { "constraints": 9 }, "signature": "abc" }).to_string()).unwrap();
+        fs::write(dir.join("other.json"), "[1, 2]").unwrap();
+        
+        let plain = load_report(&dir.join("plain.json"));
+        let signed = load_report(&dir.join("signed.json"));
+        let other = load_report(&dir.join("other.json"));
+        fs::remove_dir_all(&dir).unwrap();
+        
+        let plain = plain.unwrap();
+        assert_eq!((plain.constraints, plain.total_opcodes), (42, 7));
+        assert_eq!(signed.unwrap().constraints, 9);
+        assert!(other.unwrap_err().to_string().starts_with("Not an analysis report"));
+    }
+    
+    #[test]
+    fn renders_user_templates() {
+        let analysis = CircuitAnalysis {
+            constraints: 1234,
+            exact: true,
+            black_box_functions: vec![("sha256".to_string(), 2, 100)],
+            ..Default::default()
+        };
+        let rendered = render_template(&analysis, Path::new("main.json"), Path::new("examples/templates/summary.md.j2")).unwrap();
+        
+        assert!(rendered.starts_with("# Circuit report: main.json"));
+        assert!(rendered.contains("| constraints (exact) | 1234 |"));
+        assert!(rendered.contains("| sha256 | 2 | 100 |"));
+        assert!(!rendered.contains("## suggestions"));
+        
+        let broken = std::env::temp_dir().join(format!("noir-profiler-template-{}.j2", std::process::id()));
+        fs::write(&broken, "{% if constraints %}").unwrap();
+        let error = render_template(&analysis, Path::new("main.json"), &broken);
+        fs::remove_file(&broken).unwrap();
+        assert!(error.unwrap_err().to_string().starts_with("Failed to parse template"));
+    }
+    
+    #[test]
+    fn parses_emit_sinks() {
+        let sink: EmitSink = "md=out/report.md".parse().unwrap();
+        assert_eq!((sink.format, sink.path), (EmitFormat::Markdown, Some(PathBuf::from("out/report.md"))));
+        
+        let sink: EmitSink = "JSON".parse().unwrap();
+        assert_eq!((sink.format, sink.path), (EmitFormat::Json, None));
+        
+        assert!("json=".parse::<EmitSink>().unwrap_err().contains("missing path"));
+        assert!("html=out.html".parse::<EmitSink>().unwrap_err().contains("unknown format `html`"));
+    }
+    
+    #[test]
+    fn renders_markdown_sections() {
+        let analysis = CircuitAnalysis {
+            constraints: 500,
+            aggregate_constraints: 800,
+            black_box_functions: vec![("sha256".to_string(), 2, 100)],
+            ..Default::default()
+        };
+        let markdown = render_markdown(&analysis, Path::new("main.json"));
+        
+        assert!(markdown.starts_with("# Circuit report: `main.json`"));
+        assert!(markdown.contains("| Aggregate Constraints | 800 |"));
+        assert!(markdown.contains("## Black-box functions"));
+        assert!(markdown.contains("| 2 | 200 |"));
+        assert!(!markdown.contains("## Suggestions"));
+    }
+    
+    #[test]
+    fn markdown_warns_about_uncalibrated_operations() {
+        let analysis = CircuitAnalysis { uncalibrated_operations: vec![("mystery_gadget".to_string(), 3)], ..Default::default() };
+        let markdown = render_markdown(&analysis, Path::new("main.json"));
+        
+        assert!(markdown.contains("> **Warning:** 1 operation used the uncalibrated default cost"));
+        assert!(markdown.contains(&format!("| mystery_gadget | 3 | {} |", FALLBACK_COST)));
+        assert!(!render_markdown(&CircuitAnalysis::default(), Path::new("main.json")).contains("uncalibrated"));
+    }
+    
+    #[test]
+    fn long_rows_flatten_metrics() {
+        let analysis = CircuitAnalysis {
+            constraints: 400,
+            categories: vec![(crate::taxonomy::Category::Hashing, 300)],
+            operation_counts: vec![("AssertZero".to_string(), 5)],
+            black_box_functions: vec![("sha256".to_string(), 2, 100)],
+            ..Default::default()
+        };
+        let rows = long_rows(&analysis, "main");
+        let value = |metric: &str| rows.iter().find(|row| row.metric == metric).map(|row| row.value);
+        
+        assert!(rows.iter().all(|row| row.circuit == "main"));
+        assert_eq!(value("constraints"), Some(400.0));
+        assert_eq!(value("category.hashing.constraints"), Some(300.0));
+        assert_eq!(value("operation.AssertZero.count"), Some(5.0));
+        assert_eq!((value("black_box.sha256.calls"), value("black_box.sha256.constraints")), (Some(2.0), Some(200.0)));
+    }
+}
@@ -0,0 +1,132 @@
+use serde::{Deserialize, Serialize};
+use std::str::FromStr;
+
+pub const Z_95: f64 = 1.96;
+
+const SEED: u64 = 0x9e37_79b9_7f4a_7c15;
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct SampleRate(pub f64);
+
+impl FromStr for SampleRate {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let trimmed = s.trim();
+        let rate = match trimmed.strip_suffix('%') {
+            Some(percent) => percent.trim().parse::<f64>().map(|percent| percent / 100.0),
+            None => trimmed.parse::<f64>(),
+        }.map_err(|_| format!("invalid sample rate `{}`, expected a percentage like 10% or a fraction like 0.1", s))?;
+        
+        if !(rate > 0.0 && rate <= 1.0) {
+            return Err(format!("sample rate must be above 0% and at most 100%, got {}", s));
+        }
+        
+        Ok(SampleRate(rate))
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Sampling {
+    pub rate: f64,
+    pub sampled_opcodes: usize,
+    pub total_opcodes: usize,
+    pub constraints_low: usize,
+    pub constraints_high: usize,
+}
+
+pub fn sample_mask(total: usize, rate: f64) -> Vec<bool> {
+    let mut state = SEED ^ total as u64;
+    let mut mask: Vec<bool> = (0..total)
+        .map(|_| {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            ((state >> 11) as f64 / (1u64 << 53) as f64) < rate
+        })
+        .collect();
+    
+    if total > 0 && !mask.contains(&true) {
+        mask[0] = true;
+    }
+    
+    mask
+}
+
+pub fn scale_count(count: usize, factor: f64) -> usize {
+    (count as f64 * factor).round() as usize
+}
+
+pub fn extrapolate(sampled_costs: &[usize], total: usize, rate: f64) -> (usize, Sampling) {
+    let n = sampled_costs.len();
+    if n == 0 {
+        return (0, Sampling { rate, total_opcodes: total, ..Default::default() });
+    }
+    
+    let mean = sampled_costs.iter().map(|cost| *cost as f64).sum::<f64>() / n as f64;
+    let variance = if n > 1 {
+        sampled_costs.iter().map(|cost| (*cost as f64 - mean).powi(2)).sum::<f64>() / (n - 1) as f64
+    } else {
+        0.0
+    };
+    
+    let population_correction = if total > 1 {
+        ((total - n) as f64 / (total - 1) as f64).sqrt()
+    } else {
+        0.0
+    };
+    
+    let estimate = mean * total as f64;
+    let margin = Z_95 * total as f64 * variance.sqrt() / (n as f64).sqrt() * population_correction;
+    let observed: usize = sampled_costs.iter().sum();
+    
+    (estimate.round() as usize, Sampling {
+        rate,
+        sampled_opcodes: n,
+        total_opcodes: total,
+        constraints_low: ((estimate - margin).round().max(0.0) as usize).max(observed),
+        constraints_high: (estimate + margin).round() as usize,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_percentages_and_fractions() {
+        assert_eq!("10%".parse(), Ok(SampleRate(0.1)));
+        assert_eq!("0.25".parse(), Ok(SampleRate(0.25)));
+        assert!("0%".parse::<SampleRate>().is_err());
+        assert!("150%".parse::<SampleRate>().is_err());
+        assert!("some".parse::<SampleRate>().is_err());
+    }
+    
+    #[test]
+    fn masks_are_deterministic_and_close_to_the_rate() {
+        let mask = sample_mask(10_000, 0.1);
+        let sampled = mask.iter().filter(|picked| **picked).count();
+        
+        assert_eq!(mask, sample_mask(10_000, 0.1));
+        assert!((800..1200).contains(&sampled), "{}", sampled);
+        assert_eq!(sample_mask(5, 1e-9).iter().filter(|picked| **picked).count(), 1);
+    }
+    
+    #[test]
+    fn uniform_costs_extrapolate_exactly() {
+        let (estimate, sampling) = extrapolate(&[2; 10], 100, 0.1);
+        
+        assert_eq!(estimate, 200);
+        assert_eq!((sampling.constraints_low, sampling.constraints_high), (200, 200));
+    }
+    
+    #[test]
+    fn the_interval_never_drops_below_what_was_seen() {
+        let (estimate, sampling) = extrapolate(&[1, 1, 1, 500], 5, 0.8);
+        
+        assert!(sampling.constraints_low >= 503);
+        assert!(sampling.constraints_low <= estimate && estimate <= sampling.constraints_high);
+        assert_eq!(extrapolate(&[], 10, 0.1).0, 0);
+    }
+}
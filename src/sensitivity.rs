@@ -0,0 +1,84 @@
+use crate::core::{get_operation_details, is_fallback_cost, CircuitAnalysis};
+use serde::Serialize;
+
+pub const COST_ERROR: f64 = 0.20;
+pub const SENSITIVE_SHARE: f64 = 10.0;
+pub const CALIBRATED_CONFIDENCE: f32 = 0.9;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct CostSensitivity {
+    pub operation: String,
+    pub calls: usize,
+    pub cost: usize,
+    pub share: f64,
+    pub constraints_delta: f64,
+    pub proving_time_delta: f64,
+    pub confidence: f32,
+    pub fallback: bool,
+}
+
+impl CostSensitivity {
+    pub fn worth_calibrating(&self) -> bool {
+        self.share >= SENSITIVE_SHARE && (self.fallback || self.confidence < CALIBRATED_CONFIDENCE)
+    }
+}
+
+pub fn cost_sensitivity(analysis: &CircuitAnalysis, error: f64) -> Vec<CostSensitivity> {
+    let total = analysis.aggregate_constraints.max(analysis.constraints).max(1) as f64;
+    let time_per_constraint = analysis.estimated_proving_time / total;
+    
+    let mut entries: Vec<CostSensitivity> = analysis.black_box_functions.iter()
+        .map(|(operation, calls, cost)| {
+            let contribution = calls.saturating_mul(*cost) as f64;
+            let constraints_delta = contribution * error;
+            
+            CostSensitivity {
+                operation: operation.clone(),
+                calls: *calls,
+                cost: *cost,
+                share: contribution / total * 100.0,
+                constraints_delta,
+                proving_time_delta: constraints_delta * time_per_constraint,
+                confidence: get_operation_details(operation).1,
+                fallback: is_fallback_cost(operation),
+            }
+        })
+        .collect();
+    
+    entries.sort_by(|a, b| b.constraints_delta.total_cmp(&a.constraints_delta)
+        .then_with(|| a.operation.cmp(&b.operation)));
+    entries
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn sensitivity(share: f64, confidence: f32, fallback: bool) -> CostSensitivity {
+        CostSensitivity { operation: "sha256".into(), calls: 1, cost: 1, share, constraints_delta: 0.0, proving_time_delta: 0.0, confidence, fallback }
+    }
+    
+    #[test]
+    fn ranks_operations_by_constraint_delta() {
+        let analysis = CircuitAnalysis {
+            constraints: 10_000,
+            estimated_proving_time: 2.0,
+            black_box_functions: vec![("sha256".into(), 2, 1000), ("keccak256".into(), 1, 5000)],
+            ..Default::default()
+        };
+        let entries = cost_sensitivity(&analysis, COST_ERROR);
+        
+        assert_eq!(entries.iter().map(|entry| entry.operation.as_str()).collect::<Vec<_>>(), ["keccak256", "sha256"]);
+        assert_eq!((entries[0].share, entries[0].constraints_delta), (50.0, 1000.0));
+        assert!((entries[0].proving_time_delta - 0.2).abs() < 1e-9);
+        assert_eq!(entries[1].share, 20.0);
+    }
+    
+    #[test]
+    fn only_large_uncertain_costs_are_worth_calibrating() {
+        assert!(sensitivity(SENSITIVE_SHARE, 1.0, true).worth_calibrating());
+        assert!(sensitivity(50.0, 0.5, false).worth_calibrating());
+        assert!(!sensitivity(50.0, CALIBRATED_CONFIDENCE, false).worth_calibrating());
+        assert!(!sensitivity(1.0, 0.5, true).worth_calibrating());
+    }
+}
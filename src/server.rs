@@ -0,0 +1,187 @@
+use crate::analyzer::{analyze_circuit_data, AnalysisLimits, AnalysisOptions, Skipped};
+use crate::artifact::read_artifact;
+use crate::daemon::{DaemonRequest, DaemonResponse};
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+struct CachedArtifact {
+    modified: Option<SystemTime>,
+    len: u64,
+    decoded_len: u64,
+    data: Value,
+}
+
+#[derive(Default)]
+pub struct ArtifactCache {
+    entries: HashMap<PathBuf, CachedArtifact>,
+}
+
+impl ArtifactCache {
+    pub fn get(&mut self, path: &Path, limits: &AnalysisLimits) -> Result<(&Value, bool)> {
+        let metadata = fs::metadata(path)
+            .with_context(|| format!("Failed to read circuit file: {}", path.display()))?;
+        let modified = metadata.modified().ok();
+        
+        if let Some(limit) = limits.max_file_size.filter(|limit| metadata.len() > *limit) {
+            return Err(Skipped::FileTooLarge { size: metadata.len(), limit }.into());
+        }
+        
+        let fresh = self.entries.get(path)
+            .is_some_and(|entry| entry.modified == modified && entry.len == metadata.len());
+        
+        if !fresh {
+            let json = read_artifact(path, limits.max_file_size)?;
+            if let Some(limit) = limits.max_file_size.filter(|limit| json.len() as u64 > *limit) {
+                return Err(Skipped::FileTooLarge { size: json.len() as u64, limit }.into());
+            }
+            
+            let data: Value = serde_json::from_slice(&json)
+                .context("Failed to parse JSON")?;
+            
+            self.entries.insert(path.to_path_buf(), CachedArtifact { modified, len: metadata.len(), decoded_len: json.len() as u64, data });
+        }
+        
+        let entry = &self.entries[path];
+        if let Some(limit) = limits.max_file_size.filter(|limit| entry.decoded_len > *limit) {
+            return Err(Skipped::FileTooLarge { size: entry.decoded_len, limit }.into());
+        }
+        
+        Ok((&entry.data, fresh))
+    }
+}
+
+pub fn handle_request(cache: &mut ArtifactCache, request: DaemonRequest) -> DaemonResponse {
+    match request {
+        DaemonRequest::Analyze { path, expression_width, proving_strategy, limits } => {
+            let options = AnalysisOptions {
+                expression_width,
+                proving_strategy,
+                limits,
+                ..Default::default()
+            };
+            
+            let result = cache.get(&path, &options.limits)
+                .and_then(|(data, cached)| Ok((analyze_circuit_data(&path, data, &options)?, cached)));
+            
+            match result {
+                Ok((analysis, cached)) => DaemonResponse { analysis: Some(analysis), error: None, cached },
+                Err(e) => DaemonResponse { error: Some(format!("{:#}", e)), ..Default::default() },
+            }
+        },
+        DaemonRequest::Shutdown => DaemonResponse::default(),
+    }
+}
+
+#[cfg(unix)]
+pub fn serve(socket: &Path, mut on_request: impl FnMut(&Path, &DaemonResponse)) -> Result<()> {
+    use std::io::{BufRead, BufReader, Write};
+    use std::os::unix::net::{UnixListener, UnixStream};
+    
+    if socket.exists() {
+        if UnixStream::connect(socket).is_ok() {
+            anyhow::bail!("A daemon is already listening on {}", socket.display());
+        }
+        fs::remove_file(socket)
+            .with_context(|| format!("Failed to remove stale socket: {}", socket.display()))?;
+    }
+    
+    let listener = UnixListener::bind(socket)
+        .with_context(|| format!("Failed to bind {}", socket.display()))?;
+    let mut cache = ArtifactCache::default();
+    
+    for stream in listener.incoming() {
+        let Ok(mut stream) = stream else {
+            continue;
+        };
+        
+        let mut line = String::new();
+        if BufReader::new(&stream).read_line(&mut line).is_err() {
+            continue;
+        }
+        
+        let request = match serde_json::from_str::<DaemonRequest>(&line) {
+            Ok(request) => request,
+            Err(e) => {
+                let response = DaemonResponse { error: Some(format!("Invalid request: {}", e)), ..Default::default() };
+                if let Ok(content) = serde_json::to_string(&response) {
+                    let _ = writeln!(stream, "{}", content);
+                }
+                continue;
+            }
+        };
+        
+        let path = match &request {
+            DaemonRequest::Analyze { path, .. } => path.clone(),
+            DaemonRequest::Shutdown => {
+                let _ = writeln!(stream, "{{}}");
+                break;
+            }
+        };
+        
+        let response = handle_request(&mut cache, request);
+        if let Ok(content) = serde_json::to_string(&response) {
+            let _ = writeln!(stream, "{}", content);
+        }
+        
+        on_request(&path, &response);
+    }
+    
+    fs::remove_file(socket).ok();
+    Ok(())
+}
+
+#[cfg(not(unix))]
+pub fn serve(socket: &Path, _on_request: impl FnMut(&Path, &DaemonResponse)) -> Result<()> {
+    anyhow::bail!("Cannot listen on {}: daemon mode needs unix domain sockets", socket.display())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn cache_reuses_unchanged_artifacts() {
+        let path = Path::new("examples/circuits/simple_arithmetic.json");
+        let mut cache = ArtifactCache::default();
+        
+        assert!(!cache.get(path, &AnalysisLimits::default()).unwrap().1);
+        assert!(cache.get(path, &AnalysisLimits::default()).unwrap().1);
+        
+        let tight = AnalysisLimits { max_file_size: Some(8), ..Default::default() };
+        assert!(cache.get(path, &tight).unwrap_err().downcast_ref::<Skipped>().is_some());
+    }
+    
+    #[cfg(unix)]
+    #[test]
+    fn serves_errors_and_shuts_down() {
+        use crate::daemon::request_shutdown;
+        use std::io::{BufRead, BufReader, Write};
+        use std::os::unix::net::UnixStream;
+        
+        let socket = std::env::temp_dir().join(format!("noir-profiler-serve-{}.sock", std::process::id()));
+        let server = {
+            let socket = socket.clone();
+            std::thread::spawn(move || serve(&socket, |_, _| {}))
+        };
+        while UnixStream::connect(&socket).is_err() {
+            std::thread::sleep(std::time::Duration::from_millis(10));
+        }
+        
+        let mut stream = UnixStream::connect(&socket).unwrap();
+        writeln!(stream, r#"{{"command":"analyze","path":"missing.json","expression_width":"unbounded","proving_strategy":"fold"}}"#).unwrap();
+        let mut line = String::new();
+        BufReader::new(&stream).read_line(&mut line).unwrap();
+        let response: DaemonResponse = serde_json::from_str(&line).unwrap();
+        
+        assert!(response.analysis.is_none());
+        assert!(response.error.unwrap().contains("missing.json"));
+        
+        request_shutdown(&socket).unwrap();
+        server.join().unwrap().unwrap();
+        assert!(!socket.exists());
+    }
+}
@@ -0,0 +1,277 @@
+use crate::core::{gate_factor, CircuitAnalysis};
+use crate::names::canonical_name;
+use anyhow::{bail, Result};
+use std::fmt;
+
+pub const KEYGEN_POINTS_PER_SEC: f64 = 2.0e6;
+pub const KEY_LOAD_BYTES_PER_SEC: f64 = 1.0e9;
+
+#[derive(Debug, Clone, Copy)]
+pub struct BackendProfile {
+    pub name: &'static str,
+    pub key_bytes_per_gate: f64,
+    pub keygen_points_per_gate: f64,
+    pub memory_factor: f64,
+    pub proving_time_factor: f64,
+}
+
+pub static BACKEND_PROFILES: [BackendProfile; 3] = [
+    BackendProfile { name: "ultra-honk", key_bytes_per_gate: 960.0, keygen_points_per_gate: 28.0, memory_factor: 2.0, proving_time_factor: 1.0 },
+    BackendProfile { name: "ultra-plonk", key_bytes_per_gate: 3136.0, keygen_points_per_gate: 24.0, memory_factor: 1.5, proving_time_factor: 1.6 },
+    BackendProfile { name: "groth16", key_bytes_per_gate: 384.0, keygen_points_per_gate: 6.0, memory_factor: 2.0, proving_time_factor: 1.3 },
+];
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Support {
+    Native,
+    Emulated { low: f64, high: f64 },
+    Unsupported,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub struct Capability {
+    pub backend: &'static str,
+    pub operation: &'static str,
+    pub support: Support,
+}
+
+const fn emulated(backend: &'static str, operation: &'static str, low: f64, high: f64) -> Capability {
+    Capability { backend, operation, support: Support::Emulated { low, high } }
+}
+
+pub static BACKEND_CAPABILITIES: [Capability; 14] = [
+    emulated("ultra-plonk", "poseidon2_permutation", 1.5, 2.0),
+    emulated("groth16", "and", 2.0, 4.0),
+    emulated("groth16", "xor", 2.0, 4.0),
+    emulated("groth16", "range", 2.0, 4.0),
+    emulated("groth16", "sha256", 3.0, 5.0),
+    emulated("groth16", "sha256_compression", 3.0, 5.0),
+    emulated("groth16", "blake2s", 3.0, 5.0),
+    emulated("groth16", "blake3", 3.0, 5.0),
+    emulated("groth16", "keccak256", 3.0, 5.0),
+    emulated("groth16", "keccakf1600", 3.0, 5.0),
+    emulated("groth16", "aes128_encrypt", 3.0, 5.0),
+    emulated("groth16", "ecdsa_secp256k1", 2.0, 3.0),
+    emulated("groth16", "ecdsa_secp256r1", 2.0, 3.0),
+    Capability { backend: "groth16", operation: "recursive_aggregation", support: Support::Unsupported },
+];
+
+#[derive(Debug, Clone)]
+pub struct CapabilityWarning {
+    pub backend: &'static str,
+    pub operation: String,
+    pub calls: usize,
+    pub support: Support,
+}
+
+impl fmt::Display for CapabilityWarning {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.support {
+            Support::Emulated { low, high } => write!(f, "{} on {} is emulated: expect {}–{}x listed cost", self.operation, self.backend, low, high),
+            Support::Unsupported => write!(f, "{} is not supported by {}", self.operation, self.backend),
+            Support::Native => write!(f, "{} is native on {}", self.operation, self.backend),
+        }
+    }
+}
+
+pub fn backend_support(backend: &str, operation: &str) -> Support {
+    let operation = canonical_name(operation);
+    BACKEND_CAPABILITIES.iter()
+        .find(|capability| capability.backend == backend && capability.operation == operation)
+        .map_or(Support::Native, |capability| capability.support)
+}
+
+pub fn capability_warnings(analysis: &CircuitAnalysis, profile: BackendProfile) -> Vec<CapabilityWarning> {
+    let mut warnings: Vec<CapabilityWarning> = Vec::new();
+    
+    for (name, calls, _) in &analysis.black_box_functions {
+        let support = backend_support(profile.name, name);
+        if support == Support::Native {
+            continue;
+        }
+        
+        let operation = canonical_name(name);
+        match warnings.iter_mut().find(|warning| warning.operation == operation) {
+            Some(warning) => warning.calls += calls,
+            None => warnings.push(CapabilityWarning { backend: profile.name, operation, calls: *calls, support }),
+        }
+    }
+    
+    warnings.sort_by(|a, b| (b.support == Support::Unsupported).cmp(&(a.support == Support::Unsupported))
+        .then_with(|| a.operation.cmp(&b.operation)));
+    warnings
+}
+
+#[derive(Debug)]
+pub struct SetupEstimate {
+    pub profile: BackendProfile,
+    pub circuit_size: usize,
+    pub proving_key_bytes: f64,
+    pub keygen_ms: f64,
+    pub load_ms: f64,
+    pub memory_gb: f64,
+}
+
+fn normalize(name: &str) -> String {
+    name.chars().filter(|c| c.is_ascii_alphanumeric()).map(|c| c.to_ascii_lowercase()).collect()
+}
+
+pub fn find_backend_profile(name: &str) -> Result<BackendProfile> {
+    match BACKEND_PROFILES.iter().find(|profile| normalize(profile.name) == normalize(name)) {
+        Some(profile) => Ok(*profile),
+        None => {
+            let known: Vec<_> = BACKEND_PROFILES.iter().map(|profile| profile.name).collect();
+            bail!("Unknown backend profile `{}` (known: {})", name, known.join(", "))
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct BackendEstimate {
+    pub profile: BackendProfile,
+    pub gates: usize,
+    pub circuit_size: usize,
+    pub proving_time_ms: f64,
+    pub memory_gb: f64,
+    pub emulated: usize,
+    pub unsupported: Vec<String>,
+}
+
+pub fn estimate_backend(analysis: &CircuitAnalysis, profile: BackendProfile) -> BackendEstimate {
+    let mut extra = 0.0;
+    let mut emulated = 0;
+    let mut unsupported = Vec::new();
+    
+    for warning in capability_warnings(analysis, profile) {
+        match warning.support {
+            Support::Emulated { low, high } => {
+                let listed: usize = analysis.black_box_functions.iter()
+                    .filter(|(name, _, _)| canonical_name(name) == warning.operation)
+                    .map(|(_, calls, cost)| calls.saturating_mul(*cost))
+                    .sum();
+                extra += listed as f64 * ((low + high) / 2.0 - 1.0);
+                emulated += 1;
+            },
+            Support::Unsupported => unsupported.push(warning.operation),
+            Support::Native => {},
+        }
+    }
+    
+    let base = analysis.aggregate_constraints.max(1) as f64;
+    let gates = ((analysis.aggregate_constraints as f64 + extra) * gate_factor(profile.name)).round() as usize;
+    let setup = estimate_setup(gates, profile);
+    
+    BackendEstimate {
+        profile,
+        gates,
+        circuit_size: setup.circuit_size,
+        proving_time_ms: analysis.estimated_proving_time * gates as f64 / base * profile.proving_time_factor,
+        memory_gb: setup.memory_gb,
+        emulated,
+        unsupported,
+    }
+}
+
+pub fn estimate_setup(constraints: usize, profile: BackendProfile) -> SetupEstimate {
+    let circuit_size = constraints.max(2).next_power_of_two();
+    let size = circuit_size as f64;
+    let proving_key_bytes = size * profile.key_bytes_per_gate;
+    
+    SetupEstimate {
+        profile,
+        circuit_size,
+        proving_key_bytes,
+        keygen_ms: profile.keygen_points_per_gate * size / KEYGEN_POINTS_PER_SEC * 1000.0,
+        load_ms: proving_key_bytes / KEY_LOAD_BYTES_PER_SEC * 1000.0,
+        memory_gb: proving_key_bytes * profile.memory_factor / (1024.0 * 1024.0 * 1024.0),
+    }
+}
+
+pub fn format_bytes(bytes: f64) -> String {
+    const UNITS: [&str; 5] = ["B", "KiB", "MiB", "GiB", "TiB"];
+    
+    let mut value = bytes;
+    let mut unit = 0;
+    while value >= 1024.0 && unit < UNITS.len() - 1 {
+        value /= 1024.0;
+        unit += 1;
+    }
+    
+    if unit == 0 {
+        format!("{:.0} {}", value, UNITS[unit])
+    } else {
+        format!("{:.1} {}", value, UNITS[unit])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn setup_scales_with_the_padded_circuit() {
+        let honk = find_backend_profile("UltraHonk").unwrap();
+        let estimate = estimate_setup(1000, honk);
+        
+        assert_eq!(estimate.circuit_size, 1024);
+        assert_eq!(estimate.proving_key_bytes, 1024.0 * 960.0);
+        assert!(estimate_setup(1025, honk).keygen_ms > estimate.keygen_ms);
+        assert!(find_backend_profile("plonky3").is_err());
+    }
+    
+    #[test]
+    fn groth16_flags_emulated_and_unsupported_operations() {
+        let analysis = CircuitAnalysis {
+            black_box_functions: vec![
+                ("sha256".to_string(), 2, 100),
+                ("SHA256".to_string(), 1, 100),
+                ("recursive_aggregation".to_string(), 1, 1000),
+                ("poseidon2_permutation".to_string(), 4, 50),
+            ],
+            ..Default::default()
+        };
+        let warnings = capability_warnings(&analysis, find_backend_profile("groth16").unwrap());
+        let summary: Vec<(&str, usize)> = warnings.iter().map(|warning| (warning.operation.as_str(), warning.calls)).collect();
+        
+        assert_eq!(summary, [("recursive_aggregation", 1), ("sha256", 3)]);
+        assert_eq!(warnings[0].to_string(), "recursive_aggregation is not supported by groth16");
+        assert!(capability_warnings(&analysis, find_backend_profile("ultra-honk").unwrap()).is_empty());
+    }
+    
+    #[test]
+    fn formats_binary_units() {
+        assert_eq!(format_bytes(512.0), "512 B");
+        assert_eq!(format_bytes(1536.0), "1.5 KiB");
+        assert_eq!(format_bytes(3.0 * 1024.0 * 1024.0 * 1024.0), "3.0 GiB");
+    }
+    
+    #[test]
+    fn backend_estimates_add_emulation_overhead() {
+        let analysis = CircuitAnalysis {
+            aggregate_constraints: 1000,
+            estimated_proving_time: 10.0,
+            black_box_functions: vec![("sha256".to_string(), 2, 100), ("recursive_aggregation".to_string(), 1, 0)],
+            ..Default::default()
+        };
+        let estimate = |name: &str| estimate_backend(&analysis, find_backend_profile(name).unwrap());
+        
+        let honk = estimate("ultra-honk");
+        assert_eq!((honk.gates, honk.circuit_size, honk.proving_time_ms), (1000, 1024, 10.0));
+        assert_eq!(estimate("UltraPlonk").proving_time_ms, 16.0);
+        
+        let groth16 = estimate("groth16");
+        assert_eq!((groth16.gates, groth16.emulated), (1600, 1));
+        assert_eq!(groth16.unsupported, ["recursive_aggregation"]);
+        assert!((groth16.proving_time_ms - 10.0 * 1.6 * 1.3).abs() < 1e-9);
+    }
+    
+    #[test]
+    fn emulated_warnings_quote_the_overhead() {
+        let analysis = CircuitAnalysis { black_box_functions: vec![("poseidon2_permutation".to_string(), 4, 50)], ..Default::default() };
+        let warnings = capability_warnings(&analysis, find_backend_profile("ultra-plonk").unwrap());
+        
+        assert_eq!(warnings.len(), 1);
+        assert_eq!(warnings[0].support, Support::Emulated { low: 1.5, high: 2.0 });
+        assert_eq!(warnings[0].to_string(), "poseidon2_permutation on ultra-plonk is emulated: expect 1.5–2x listed cost");
+    }
+}
@@ -0,0 +1,189 @@
+use crate::analyzer::AnalysisOptions;
+use crate::artifact::read_artifact;
+use crate::calls::{entry_index, estimate_opcode_cost, function_name, function_opcodes};
+use crate::core::CircuitAnalysis;
+use crate::patterns::opcode_signature;
+use anyhow::{Context, Result};
+use serde::Serialize;
+use serde_json::Value;
+use std::collections::hash_map::DefaultHasher;
+use std::collections::HashMap;
+use std::hash::{Hash, Hasher};
+use std::path::Path;
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedUse {
+    pub circuit: String,
+    pub occurrences: usize,
+    pub constraints: usize,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct SharedComponent {
+    pub kind: &'static str,
+    pub name: String,
+    pub opcodes: usize,
+    pub constraints_each: usize,
+    pub uses: Vec<SharedUse>,
+}
+
+impl SharedComponent {
+    pub fn total_constraints(&self) -> usize {
+        self.uses.iter().fold(0usize, |total, usage| total.saturating_add(usage.constraints))
+    }
+}
+
+fn body_fingerprint(opcodes: &[Value]) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    
+    opcodes.len().hash(&mut hasher);
+    for op in opcodes {
+        opcode_signature(op).hash(&mut hasher);
+    }
+    
+    hasher.finish()
+}
+
+fn record(
+    candidates: &mut HashMap<(&'static str, u64), SharedComponent>,
+    kind: &'static str,
+    name: String,
+    body: &[Value],
+    constraints_each: usize,
+    usage: SharedUse
+) {
+    let candidate = candidates.entry((kind, body_fingerprint(body))).or_insert_with(|| SharedComponent {
+        kind,
+        name,
+        opcodes: body.len(),
+        constraints_each,
+        uses: Vec::new(),
+    });
+    
+    match candidate.uses.iter_mut().find(|existing| existing.circuit == usage.circuit) {
+        Some(existing) => {
+            existing.occurrences += usage.occurrences;
+            existing.constraints = existing.constraints.saturating_add(usage.constraints);
+        },
+        None => candidate.uses.push(usage),
+    }
+}
+
+fn collect_components(
+    candidates: &mut HashMap<(&'static str, u64), SharedComponent>,
+    circuit: &str,
+    data: &Value,
+    analysis: &CircuitAnalysis,
+    options: &AnalysisOptions
+) {
+    let function_count = data["functions"].as_array().map_or(0, |functions| functions.len());
+    let entry = options.entry.as_deref().and_then(|entry| entry_index(data, entry)).unwrap_or(0);
+    
+    for id in (0..function_count).filter(|id| *id != entry) {
+        let body = function_opcodes(data, id);
+        if body.is_empty() {
+            continue;
+        }
+        
+        let name = function_name(data, id);
+        let invocations = analysis.callees.iter()
+            .find(|(callee, _, _)| *callee == name)
+            .map_or(1, |(_, count, _)| (*count).max(1));
+        
+        let constraints_each = body.iter()
+            .map(|op| estimate_opcode_cost(op, options.expression_width))
+            .fold(0usize, |total, cost| total.saturating_add(cost));
+        
+        record(candidates, "function", name, body, constraints_each, SharedUse {
+            circuit: circuit.to_string(),
+            occurrences: invocations,
+            constraints: constraints_each.saturating_mul(invocations),
+        });
+    }
+    
+    let opcodes = function_opcodes(data, entry);
+    for pattern in &analysis.repeated_patterns {
+        let Some(body) = opcodes.get(pattern.start..pattern.start + pattern.length) else {
+            continue;
+        };
+        
+        let name = match &pattern.location {
+            Some(location) => format!("loop body at {}", location),
+            None => format!("{}-opcode loop body", pattern.length),
+        };
+        
+        record(candidates, "pattern", name, body, pattern.per_iteration, SharedUse {
+            circuit: circuit.to_string(),
+            occurrences: pattern.repeats,
+            constraints: pattern.constraints,
+        });
+    }
+}
+
+pub fn find_shared_components(
+    dir: &Path,
+    results: &[(String, Result<CircuitAnalysis>)],
+    options: &AnalysisOptions
+) -> (Vec<SharedComponent>, Vec<String>) {
+    let mut candidates: HashMap<(&'static str, u64), SharedComponent> = HashMap::new();
+    let mut skipped = Vec::new();
+    
+    for (name, result) in results {
+        let Ok(analysis) = result else {
+            continue;
+        };
+        
+        let path = dir.join(name);
+        let data = read_artifact(&path, options.limits.max_file_size).and_then(|json| serde_json::from_slice::<Value>(&json)
+            .with_context(|| format!("Failed to parse circuit JSON: {}", path.display())));
+        
+        match data {
+            Ok(data) => collect_components(&mut candidates, name, &data, analysis, options),
+            Err(error) => skipped.push(format!("{}: {:#}", name, error)),
+        }
+    }
+    
+    let mut shared: Vec<SharedComponent> = candidates.into_values()
+        .filter(|component| component.uses.len() > 1)
+        .collect();
+    
+    for component in &mut shared {
+        component.uses.sort_by(|a, b| b.constraints.cmp(&a.constraints).then(a.circuit.cmp(&b.circuit)));
+    }
+    
+    shared.sort_by(|a, b| b.total_constraints().cmp(&a.total_constraints()).then(a.name.cmp(&b.name)));
+    (shared, skipped)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    fn circuit(helper: &str, terms: usize) -> Value {
+        let terms: Vec<Value> = (0..terms).map(|_| json!({ "variable": "w" })).collect();
+        json!({
+            "names": ["main", helper],
+            "functions": [
+                { "opcodes": [{ "type": "Call", "id": 1 }] },
+                { "opcodes": [{ "type": "AssertZero", "expression": { "terms": terms } }] },
+            ],
+        })
+    }
+    
+    #[test]
+    fn identical_bodies_are_shared_across_circuits() {
+        let options = AnalysisOptions::default();
+        let mut candidates = HashMap::new();
+        let analysis = CircuitAnalysis { callees: vec![("hash".to_string(), 3, 2)], ..Default::default() };
+        
+        collect_components(&mut candidates, "a.json", &circuit("hash", 6), &analysis, &options);
+        collect_components(&mut candidates, "b.json", &circuit("digest", 6), &CircuitAnalysis::default(), &options);
+        collect_components(&mut candidates, "c.json", &circuit("other", 1), &CircuitAnalysis::default(), &options);
+        
+        let shared: Vec<&SharedComponent> = candidates.values().filter(|component| component.uses.len() > 1).collect();
+        assert_eq!(shared.len(), 1);
+        assert_eq!((shared[0].name.as_str(), shared[0].constraints_each), ("hash", 2));
+        assert_eq!(shared[0].total_constraints(), 3 * 2 + 2);
+    }
+}
@@ -0,0 +1,136 @@
+use crate::core::CircuitAnalysis;
+use anyhow::{Context, Result};
+use ed25519_dalek::{Signature, Signer, SigningKey, Verifier, VerifyingKey};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fs;
+use std::path::Path;
+
+pub const SIGNATURE_ALGORITHM: &str = "ed25519";
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct SignedReport {
+    pub report: Value,
+    pub signature: ReportSignature,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+pub struct ReportSignature {
+    pub algorithm: String,
+    pub public_key: String,
+    pub value: String,
+}
+
+pub fn load_signing_key(path: &Path) -> Result<SigningKey> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read signing key: {}", path.display()))?;
+    
+    let seed: [u8; 32] = hex::decode(content.trim())
+        .context("Signing key must be hex encoded")?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Signing key must be 32 bytes (64 hex characters)"))?;
+    
+    Ok(SigningKey::from_bytes(&seed))
+}
+
+pub fn parse_public_key(key: &str) -> Result<VerifyingKey> {
+    let bytes: [u8; 32] = hex::decode(key.trim())
+        .context("Public key must be hex encoded")?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Public key must be 32 bytes (64 hex characters)"))?;
+    
+    VerifyingKey::from_bytes(&bytes).context("Invalid ed25519 public key")
+}
+
+fn canonical_bytes(report: &Value) -> Result<Vec<u8>> {
+    serde_json::to_vec(report).context("Failed to serialize report")
+}
+
+pub fn sign_analysis(analysis: &CircuitAnalysis, key: &SigningKey) -> Result<SignedReport> {
+    let report = serde_json::to_value(analysis).context("Failed to serialize analysis")?;
+    let signature = key.sign(&canonical_bytes(&report)?);
+    
+    Ok(SignedReport {
+        report,
+        signature: ReportSignature {
+            algorithm: SIGNATURE_ALGORITHM.to_string(),
+            public_key: hex::encode(key.verifying_key().to_bytes()),
+            value: hex::encode(signature.to_bytes()),
+        },
+    })
+}
+
+pub fn verify_report(signed: &SignedReport, trusted_key: Option<&VerifyingKey>) -> Result<VerifyingKey> {
+    if signed.signature.algorithm != SIGNATURE_ALGORITHM {
+        return Err(anyhow::anyhow!("Unsupported signature algorithm: {}", signed.signature.algorithm));
+    }
+    
+    let embedded_key = parse_public_key(&signed.signature.public_key)?;
+    
+    if let Some(trusted) = trusted_key {
+        if trusted != &embedded_key {
+            return Err(anyhow::anyhow!("Report was signed by {}, not the trusted key", signed.signature.public_key));
+        }
+    }
+    
+    let signature_bytes: [u8; 64] = hex::decode(&signed.signature.value)
+        .context("Signature must be hex encoded")?
+        .try_into()
+        .map_err(|_| anyhow::anyhow!("Signature must be 64 bytes"))?;
+    
+    embedded_key.verify(&canonical_bytes(&signed.report)?, &Signature::from_bytes(&signature_bytes))
+        .context("Signature does not match report contents")?;
+    
+    Ok(embedded_key)
+}
+
+pub fn load_signed_report(path: &Path) -> Result<SignedReport> {
+    let content = fs::read_to_string(path)
+        .with_context(|| format!("Failed to read report: {}", path.display()))?;
+    
+    serde_json::from_str(&content)
+        .with_context(|| format!("Not a signed report: {}", path.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn signed() -> (SignedReport, SigningKey) {
+        let key = SigningKey::from_bytes(&[7; 32]);
+        let analysis = CircuitAnalysis { constraints: 42, total_opcodes: 3, ..Default::default() };
+        (sign_analysis(&analysis, &key).unwrap(), key)
+    }
+    
+    #[test]
+    fn signed_reports_verify_and_survive_a_round_trip() {
+        let (report, key) = signed();
+        let round_tripped: SignedReport = serde_json::from_str(&serde_json::to_string_pretty(&report).unwrap()).unwrap();
+        
+        assert_eq!(verify_report(&round_tripped, None).unwrap(), key.verifying_key());
+        assert!(verify_report(&round_tripped, Some(&key.verifying_key())).is_ok());
+    }
+    
+    #[test]
+    fn tampered_reports_fail() {
+        let (mut report, _) = signed();
+        report.report["constraints"] = 41.into();
+        
+        assert!(verify_report(&report, None).is_err());
+    }
+    
+    #[test]
+    fn untrusted_keys_fail() {
+        let (report, _) = signed();
+        let other = SigningKey::from_bytes(&[8; 32]).verifying_key();
+        
+        assert!(verify_report(&report, Some(&other)).is_err());
+    }
+    
+    #[test]
+    fn malformed_keys_are_rejected() {
+        assert!(parse_public_key("abcd").is_err());
+        assert!(parse_public_key("not hex").is_err());
+        assert!(parse_public_key(&hex::encode(SigningKey::from_bytes(&[7; 32]).verifying_key().to_bytes())).is_ok());
+    }
+}
@@ -0,0 +1,182 @@
+use crate::artifact::read_artifact;
+use crate::calls::{estimate_opcode_cost, function_opcodes};
+use crate::core::CircuitAnalysis;
+use crate::focus::{site_location, suggestions};
+use crate::patterns::{compact_count, loop_suggestions};
+use anyhow::{Context, Result};
+use serde_json::Value;
+use std::collections::BTreeMap;
+use std::fmt::Write;
+use std::path::Path;
+
+pub const SUGGESTIONS_FILE: &str = "suggestions.patch.md";
+
+pub const BOTTLENECK_SHARE: f64 = 0.01;
+
+#[derive(Debug, Default)]
+pub struct SourceSuggestion {
+    pub location: String,
+    pub operations: Vec<(String, usize)>,
+    pub sites: Vec<usize>,
+    pub constraints: usize,
+    pub savings: usize,
+    pub advice: Vec<String>,
+}
+
+fn operation_name(op: &Value) -> &str {
+    match op["type"].as_str().unwrap_or("Unknown") {
+        "BlackBoxFunction" => op["function"].as_str().unwrap_or("unknown"),
+        op_type => op_type,
+    }
+}
+
+pub fn source_suggestions(path: &Path, analysis: &CircuitAnalysis, max_file_size: Option<u64>) -> Result<Vec<SourceSuggestion>> {
+    let json = read_artifact(path, max_file_size)?;
+    let data: Value = serde_json::from_slice(&json)
+        .with_context(|| format!("Failed to parse circuit JSON: {}", path.display()))?;
+    
+    let opcodes = function_opcodes(&data, 0);
+    let cost_of = |idx: usize| opcodes.get(idx)
+        .map_or(0, |op| estimate_opcode_cost(op, analysis.expression_width));
+    
+    let mut entries: BTreeMap<String, SourceSuggestion> = BTreeMap::new();
+    
+    for (idx, op) in opcodes.iter().enumerate() {
+        let Some(location) = site_location(op) else {
+            continue;
+        };
+        
+        let entry = entries.entry(location.clone()).or_insert_with(|| SourceSuggestion { location, ..Default::default() });
+        let name = operation_name(op);
+        let cost = cost_of(idx);
+        
+        entry.sites.push(idx);
+        entry.constraints = entry.constraints.saturating_add(cost);
+        match entry.operations.iter_mut().find(|(existing, _)| existing == name) {
+            Some((_, count)) => *count += 1,
+            None => entry.operations.push((name.to_string(), 1)),
+        }
+    }
+    
+    if entries.is_empty() {
+        return Ok(Vec::new());
+    }
+    
+    let threshold = (analysis.constraints as f64 * BOTTLENECK_SHARE) as usize;
+    
+    for entry in entries.values_mut().filter(|entry| entry.constraints >= threshold) {
+        for (name, count) in &entry.operations {
+            let cost_each = entry.sites.iter()
+                .filter(|idx| opcodes.get(**idx).is_some_and(|op| operation_name(op) == name))
+                .map(|idx| cost_of(*idx))
+                .max()
+                .unwrap_or(0);
+            
+            for suggestion in suggestions(name) {
+                let saved = suggestion.alternative_cost
+                    .map_or(0, |alternative| cost_each.saturating_sub(alternative).saturating_mul(*count));
+                
+                if saved > 0 {
+                    entry.savings = entry.savings.saturating_add(saved);
+                    entry.advice.push(format!("{}× {}: {} (saves ~{} constraints)", count, name, suggestion.advice, compact_count(saved)));
+                } else {
+                    entry.advice.push(format!("{}× {}: {}", count, name, suggestion.advice));
+                }
+            }
+        }
+    }
+    
+    for lint in &analysis.lints {
+        let location = lint.sites.iter().find_map(|idx| opcodes.get(*idx).and_then(site_location));
+        if let Some(entry) = location.and_then(|location| entries.get_mut(&location)) {
+            entry.savings = entry.savings.saturating_add(lint.savings);
+            entry.advice.push(format!("{} (`{}`, saves ~{} constraints)", lint.message, lint.rule, compact_count(lint.savings)));
+        }
+    }
+    
+    for pattern in &analysis.repeated_patterns {
+        if let Some(entry) = pattern.location.as_ref().and_then(|location| entries.get_mut(location)) {
+            entry.advice.extend(loop_suggestions(pattern).into_iter().map(|suggestion| suggestion.advice));
+        }
+    }
+    
+    let mut result: Vec<SourceSuggestion> = entries.into_values()
+        .filter(|entry| !entry.advice.is_empty())
+        .collect();
+    
+    result.sort_by(|a, b| b.savings.cmp(&a.savings).then(b.constraints.cmp(&a.constraints)).then(a.location.cmp(&b.location)));
+    Ok(result)
+}
+
+pub fn render_suggestions(artifact: &Path, entries: &[SourceSuggestion]) -> String {
+    let mut markdown = String::new();
+    let total: usize = entries.iter().fold(0usize, |total, entry| total.saturating_add(entry.savings));
+    
+    let _ = writeln!(markdown, "# Constraint suggestions for `{}`\n", artifact.display());
+    let _ = writeln!(markdown, "{} source locations, ~{} constraints of estimated savings. Each section is advice for one line; nothing here rewrites code.\n",
+        entries.len(), compact_count(total));
+    
+    for entry in entries {
+        let operations: Vec<String> = entry.operations.iter()
+            .map(|(name, count)| format!("{}× {}", count, name))
+            .collect();
+        let sites: Vec<String> = entry.sites.iter().take(8).map(|idx| format!("#{}", idx)).collect();
+        let more = if entry.sites.len() > 8 { format!(" (+{})", entry.sites.len() - 8) } else { String::new() };
+        
+        let _ = writeln!(markdown, "## `{}`\n", entry.location);
+        let _ = writeln!(markdown, "{} constraints from {} (opcodes {}{}).", compact_count(entry.constraints), operations.join(", "), sites.join(", "), more);
+        if entry.savings > 0 {
+            let _ = writeln!(markdown, "Estimated savings: ~{} constraints.", compact_count(entry.savings));
+        }
+        let _ = writeln!(markdown);
+        
+        for advice in &entry.advice {
+            let _ = writeln!(markdown, "- {}", advice);
+        }
+        let _ = writeln!(markdown);
+    }
+    
+    markdown
+}
+
+pub fn write_suggestions(out: &Path, artifact: &Path, entries: &[SourceSuggestion]) -> Result<()> {
+    std::fs::write(out, render_suggestions(artifact, entries))
+        .with_context(|| format!("Failed to write suggestions: {}", out.display()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lint::Lint;
+    use serde_json::json;
+    
+    fn assert_zero(terms: usize, line: u64) -> Value {
+        let terms: Vec<Value> = (0..terms).map(|_| json!({ "variable": "w" })).collect();
+        json!({ "type": "AssertZero", "expression": { "terms": terms }, "location": { "file": "main.nr", "line": line } })
+    }
+    
+    #[test]
+    fn groups_opcodes_by_line_and_attaches_lints() {
+        let path = std::env::temp_dir().join(format!("noir-profiler-suggest-{}.json", std::process::id()));
+        let data = json!({ "opcodes": [assert_zero(8, 3), assert_zero(4, 3), assert_zero(1, 7), { "type": "AssertZero" }] });
+        std::fs::write(&path, data.to_string()).unwrap();
+        
+        let analysis = CircuitAnalysis {
+            constraints: 5,
+            lints: vec![Lint { rule: "copy-chains".to_string(), severity: "low".to_string(), message: "reuse w".to_string(), sites: vec![2], savings: 1 }],
+            ..Default::default()
+        };
+        let entries = source_suggestions(&path, &analysis, None);
+        std::fs::remove_file(&path).ok();
+        let entries = entries.unwrap();
+        
+        assert_eq!(entries.iter().map(|entry| entry.location.as_str()).collect::<Vec<_>>(), ["main.nr:7", "main.nr:3"]);
+        assert_eq!((entries[0].savings, entries[1].constraints), (1, 3));
+        assert_eq!(entries[1].operations, [("AssertZero".to_string(), 2)]);
+        assert!(entries[0].advice.last().unwrap().starts_with("reuse w (`copy-chains`"));
+        
+        let markdown = render_suggestions(&path, &entries);
+        assert!(markdown.contains("2 source locations, ~1 constraints of estimated savings"));
+        assert!(markdown.contains("## `main.nr:3`\n\n3 constraints from 2× AssertZero (opcodes #0, #1)."));
+    }
+}
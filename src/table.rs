@@ -0,0 +1,103 @@
+use std::fmt;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static PLAIN: AtomicBool = AtomicBool::new(false);
+
+pub fn set_plain(plain: bool) {
+    PLAIN.store(plain, Ordering::Relaxed);
+}
+
+pub fn plain() -> bool {
+    PLAIN.load(Ordering::Relaxed)
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Row {
+    cells: Vec<String>,
+}
+
+impl Row {
+    pub fn new() -> Self {
+        Row::default()
+    }
+    
+    pub fn with_cell<T: fmt::Display>(mut self, cell: T) -> Self {
+        self.cells.push(cell.to_string());
+        self
+    }
+    
+    fn is_separator(&self) -> bool {
+        !self.cells.is_empty() && self.cells.iter().all(|cell| !cell.is_empty() && cell.chars().all(|c| c == '─'))
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct Table {
+    spec: String,
+    rows: Vec<Row>,
+}
+
+impl Table {
+    pub fn new(spec: &str) -> Self {
+        Table { spec: spec.to_string(), rows: Vec::new() }
+    }
+    
+    pub fn add_row(&mut self, row: Row) -> &mut Self {
+        self.rows.push(row);
+        self
+    }
+    
+    pub fn to_tsv(&self) -> String {
+        self.rows.iter()
+            .filter(|row| !row.is_separator())
+            .map(|row| row.cells.iter()
+                .map(|cell| cell.replace(['\t', '\n'], " "))
+                .collect::<Vec<_>>()
+                .join("\t"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut table = tabular::Table::new(&self.spec);
+        
+        for row in &self.rows {
+            let mut tabular_row = tabular::Row::new();
+            for cell in &row.cells {
+                tabular_row = tabular_row.with_cell(cell);
+            }
+            table.add_row(tabular_row);
+        }
+        
+        write!(f, "{}", table)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn sample() -> Table {
+        let mut table = Table::new("{:<} {:>}");
+        table.add_row(Row::new().with_cell("name").with_cell("cost"))
+            .add_row(Row::new().with_cell("───").with_cell("──"))
+            .add_row(Row::new().with_cell("sha\t256").with_cell(42));
+        table
+    }
+    
+    #[test]
+    fn tsv_skips_separators_and_escapes_tabs() {
+        assert_eq!(sample().to_tsv(), "name\tcost\nsha 256\t42");
+    }
+    
+    #[test]
+    fn display_aligns_columns() {
+        let rendered = sample().to_string();
+        let lines: Vec<&str> = rendered.lines().collect();
+        
+        assert_eq!(lines.len(), 3);
+        assert!(lines[0].ends_with("cost") && lines[2].ends_with("  42"));
+    }
+}
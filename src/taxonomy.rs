@@ -0,0 +1,134 @@
+use crate::names::lookup;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::str::FromStr;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, PartialOrd, Ord, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Category {
+    Hashing,
+    Signatures,
+    Arithmetic,
+    Memory,
+    Range,
+    Control,
+}
+
+impl Category {
+    pub const ALL: [Category; 6] = [
+        Category::Hashing,
+        Category::Signatures,
+        Category::Arithmetic,
+        Category::Memory,
+        Category::Range,
+        Category::Control,
+    ];
+    
+    pub fn i18n_key(&self) -> &'static str {
+        match self {
+            Category::Hashing => "details.hashing",
+            Category::Signatures => "details.signatures",
+            Category::Arithmetic => "details.arithmetic",
+            Category::Memory => "details.memory",
+            Category::Range => "details.range",
+            Category::Control => "details.control",
+        }
+    }
+}
+
+impl fmt::Display for Category {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Category::Hashing => write!(f, "hashing"),
+            Category::Signatures => write!(f, "signatures"),
+            Category::Arithmetic => write!(f, "arithmetic"),
+            Category::Memory => write!(f, "memory"),
+            Category::Range => write!(f, "range"),
+            Category::Control => write!(f, "control"),
+        }
+    }
+}
+
+impl FromStr for Category {
+    type Err = String;
+    
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Category::ALL.iter()
+            .copied()
+            .find(|category| category.to_string().eq_ignore_ascii_case(s.trim()))
+            .ok_or_else(|| format!("unknown category `{}`, expected one of: hashing, signatures, arithmetic, memory, range, control", s))
+    }
+}
+
+const HASHING: [&str; 10] = [
+    "sha256", "sha256_compression", "blake2s", "blake3", "keccak256", "keccakf1600",
+    "pedersen_commitment", "pedersen_hash", "poseidon2_permutation", "aes128_encrypt",
+];
+
+const SIGNATURES: [&str; 4] = ["ecdsa_secp256k1", "ecdsa_secp256r1", "schnorr_verify", "recursive_aggregation"];
+
+const RANGE: [&str; 3] = ["range", "and", "xor"];
+
+pub fn black_box_category(name: &str) -> Category {
+    let canonical = lookup(name).map_or_else(|| name.to_ascii_lowercase(), |known| known.canonical.to_string());
+    
+    if HASHING.contains(&canonical.as_str()) || canonical.contains("hash") {
+        Category::Hashing
+    } else if SIGNATURES.contains(&canonical.as_str()) {
+        Category::Signatures
+    } else if RANGE.contains(&canonical.as_str()) {
+        Category::Range
+    } else {
+        Category::Arithmetic
+    }
+}
+
+pub fn operation_category(op_type: &str) -> Category {
+    match op_type {
+        "AssertZero" | "Constraint" | "Arithmetic" => Category::Arithmetic,
+        "MemoryOp" | "MemoryInit" => Category::Memory,
+        "BrilligCall" | "Brillig" | "Call" | "Directive" => Category::Control,
+        _ if op_type.contains("Memory") || op_type.contains("Array") => Category::Memory,
+        _ => Category::Control,
+    }
+}
+
+pub fn opcode_category(op: &Value) -> Category {
+    match op["type"].as_str().unwrap_or("Unknown") {
+        "BlackBoxFunction" => black_box_category(op["function"].as_str().unwrap_or("unknown")),
+        op_type => operation_category(op_type),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    
+    #[test]
+    fn black_boxes_fall_into_categories() {
+        assert_eq!(black_box_category("Keccak"), Category::Hashing);
+        assert_eq!(black_box_category("my_hash_gadget"), Category::Hashing);
+        assert_eq!(black_box_category("verify_proof"), Category::Signatures);
+        assert_eq!(black_box_category("xor"), Category::Range);
+        assert_eq!(black_box_category("multi_scalar_mul"), Category::Arithmetic);
+    }
+    
+    #[test]
+    fn opcodes_fall_into_categories() {
+        assert_eq!(opcode_category(&json!({ "type": "BlackBoxFunction", "function": "sha256" })), Category::Hashing);
+        assert_eq!(opcode_category(&json!({ "type": "MemoryInit" })), Category::Memory);
+        assert_eq!(opcode_category(&json!({ "type": "ArrayGet" })), Category::Memory);
+        assert_eq!(opcode_category(&json!({ "type": "BrilligCall" })), Category::Control);
+        assert_eq!(opcode_category(&json!({ "type": "AssertZero" })), Category::Arithmetic);
+    }
+    
+    #[test]
+    fn categories_round_trip_through_text() {
+        for category in Category::ALL {
+            assert_eq!(category.to_string().to_uppercase().parse(), Ok(category));
+        }
+        assert!("crypto".parse::<Category>().is_err());
+    }
+}
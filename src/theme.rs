@@ -0,0 +1,137 @@
+use anyhow::Result;
+use std::borrow::Cow;
+use std::fmt;
+use std::io::IsTerminal;
+use std::str::FromStr;
+use std::sync::atomic::{AtomicBool, Ordering};
+
+pub const THEME_ENV: &str = "NOIR_PROFILER_THEME";
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum ColorChoice {
+    #[default]
+    Auto,
+    Always,
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = String;
+    
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "auto" => Ok(ColorChoice::Auto),
+            "always" => Ok(ColorChoice::Always),
+            "never" => Ok(ColorChoice::Never),
+            _ => Err(format!("invalid color choice `{}`, expected auto, always or never", s)),
+        }
+    }
+}
+
+impl fmt::Display for ColorChoice {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ColorChoice::Auto => write!(f, "auto"),
+            ColorChoice::Always => write!(f, "always"),
+            ColorChoice::Never => write!(f, "never"),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Theme {
+    #[default]
+    Unicode,
+    Ascii,
+}
+
+impl FromStr for Theme {
+    type Err = String;
+    
+    fn from_str(s: &str) -> std::result::Result<Self, Self::Err> {
+        match s.to_ascii_lowercase().as_str() {
+            "unicode" => Ok(Theme::Unicode),
+            "ascii" => Ok(Theme::Ascii),
+            _ => Err(format!("invalid theme `{}`, expected unicode or ascii", s)),
+        }
+    }
+}
+
+static ASCII_THEME: AtomicBool = AtomicBool::new(false);
+
+static ASCII_MAP: [(char, &str); 31] = [
+    ('─', "-"), ('│', "|"), ('╭', "+"), ('╮', "+"), ('╰', "+"), ('╯', "+"),
+    ('█', "#"), ('═', "="), ('║', "|"), ('╔', "+"), ('╗', "+"), ('╚', "+"), ('╝', "+"),
+    ('✓', "OK"), ('✗', "FAIL"), ('•', "*"), ('→', "->"), ('–', "-"), ('—', "--"),
+    ('Δ', "delta"), ('×', "x"), ('μ', "u"), ('µ', "u"), ('±', "+/-"),
+    ('▁', "_"), ('▂', "."), ('▃', ":"), ('▄', "-"), ('▅', "="), ('▆', "+"), ('▇', "#"),
+];
+
+pub fn colors_enabled(choice: ColorChoice) -> bool {
+    match choice {
+        ColorChoice::Always => true,
+        ColorChoice::Never => false,
+        ColorChoice::Auto => {
+            let no_color = std::env::var_os("NO_COLOR").is_some_and(|value| !value.is_empty());
+            !no_color && std::io::stdout().is_terminal()
+        }
+    }
+}
+
+pub fn apply(choice: ColorChoice, theme: Option<Theme>) -> Result<()> {
+    colored::control::set_override(colors_enabled(choice));
+    
+    let theme = match theme {
+        Some(theme) => theme,
+        None => match std::env::var(THEME_ENV) {
+            Ok(value) if !value.is_empty() => value.parse()
+                .map_err(|e| anyhow::anyhow!("{}: {}", THEME_ENV, e))?,
+            _ => Theme::default(),
+        },
+    };
+    
+    ASCII_THEME.store(theme == Theme::Ascii, Ordering::Relaxed);
+    Ok(())
+}
+
+pub fn render(text: &str) -> Cow<'_, str> {
+    if !ASCII_THEME.load(Ordering::Relaxed) || text.is_ascii() {
+        return Cow::Borrowed(text);
+    }
+    
+    let mut rendered = String::with_capacity(text.len());
+    for c in text.chars() {
+        match ASCII_MAP.iter().find(|(from, _)| *from == c) {
+            Some((_, to)) => rendered.push_str(to),
+            None => rendered.push(c),
+        }
+    }
+    
+    Cow::Owned(rendered)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn parses_choices_case_insensitively() {
+        assert_eq!("ALWAYS".parse(), Ok(ColorChoice::Always));
+        assert_eq!("Ascii".parse(), Ok(Theme::Ascii));
+        assert!("sometimes".parse::<ColorChoice>().is_err());
+        assert!("emoji".parse::<Theme>().is_err());
+    }
+    
+    #[test]
+    fn explicit_choices_ignore_the_terminal() {
+        assert!(colors_enabled(ColorChoice::Always));
+        assert!(!colors_enabled(ColorChoice::Never));
+    }
+    
+    #[test]
+    fn ascii_replacements_are_ascii() {
+        for (from, to) in &ASCII_MAP {
+            assert!(!from.is_ascii() && to.is_ascii(), "{} -> {}", from, to);
+        }
+    }
+}
@@ -0,0 +1,77 @@
+use lazy_static::lazy_static;
+use std::sync::Mutex;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::time::{Duration, Instant};
+
+static ENABLED: AtomicBool = AtomicBool::new(false);
+
+lazy_static! {
+    static ref PHASES: Mutex<Vec<(&'static str, Duration, usize)>> = Mutex::new(Vec::new());
+}
+
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+pub fn record(phase: &'static str, elapsed: Duration) {
+    if !enabled() {
+        return;
+    }
+    
+    let mut phases = PHASES.lock().unwrap();
+    match phases.iter_mut().find(|(name, _, _)| *name == phase) {
+        Some((_, total, runs)) => {
+            *total += elapsed;
+            *runs += 1;
+        },
+        None => phases.push((phase, elapsed, 1)),
+    }
+}
+
+pub fn lap(phase: &'static str, since: &mut Instant) {
+    let now = Instant::now();
+    record(phase, now - *since);
+    *since = now;
+}
+
+pub fn phases() -> Vec<(&'static str, Duration, usize)> {
+    PHASES.lock().unwrap().clone()
+}
+
+#[cfg(target_os = "linux")]
+pub fn peak_rss_kb() -> Option<u64> {
+    std::fs::read_to_string("/proc/self/status").ok()?
+        .lines()
+        .find_map(|line| line.strip_prefix("VmHWM:"))
+        .and_then(|value| value.trim().trim_end_matches("kB").trim().parse().ok())
+}
+
+#[cfg(not(target_os = "linux"))]
+pub fn peak_rss_kb() -> Option<u64> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    #[test]
+    fn records_accumulate_per_phase() {
+        set_enabled(true);
+        record("timings-test", Duration::from_millis(3));
+        record("timings-test", Duration::from_millis(4));
+        
+        let phase = phases().into_iter().find(|(name, _, _)| *name == "timings-test").unwrap();
+        assert_eq!((phase.1, phase.2), (Duration::from_millis(7), 2));
+    }
+    
+    #[cfg(target_os = "linux")]
+    #[test]
+    fn reads_peak_memory() {
+        assert!(peak_rss_kb().is_some_and(|kb| kb > 0));
+    }
+}
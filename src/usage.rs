@@ -0,0 +1,249 @@
+use crate::core::CircuitAnalysis;
+use crate::names::canonical_name;
+use anyhow::{Context, Result};
+use chrono::{DateTime, NaiveDate};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::fs::{self, OpenOptions};
+use std::io::Write;
+use std::path::Path;
+
+pub const USAGE_LOG_PATH: &str = "circuit_stats/usage.jsonl";
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct UsageEntry {
+    pub timestamp: String,
+    pub circuit: String,
+    pub constraints: usize,
+    pub total_opcodes: usize,
+    #[serde(default)]
+    pub black_box_functions: Vec<(String, usize)>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct BlackBoxUsage {
+    pub name: String,
+    pub circuits: usize,
+    pub calls: usize,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+pub struct UsageSummary {
+    pub runs: usize,
+    pub circuits: usize,
+    pub first: Option<String>,
+    pub last: Option<String>,
+    pub average_constraints: f64,
+    pub average_opcodes: f64,
+    pub largest: Option<(String, usize)>,
+    pub black_boxes: Vec<BlackBoxUsage>,
+    pub unreadable: usize,
+}
+
+impl UsageEntry {
+    pub fn new(circuit: &Path, analysis: &CircuitAnalysis) -> Self {
+        let mut black_box_functions: BTreeMap<String, usize> = BTreeMap::new();
+        for (name, count, _) in &analysis.black_box_functions {
+            *black_box_functions.entry(canonical_name(name)).or_insert(0) += count;
+        }
+        
+        UsageEntry {
+            timestamp: chrono::Local::now().to_rfc3339(),
+            circuit: fs::canonicalize(circuit).unwrap_or_else(|_| circuit.to_path_buf()).display().to_string(),
+            constraints: analysis.constraints,
+            total_opcodes: analysis.total_opcodes,
+            black_box_functions: black_box_functions.into_iter().collect(),
+        }
+    }
+    
+    pub fn date(&self) -> Option<NaiveDate> {
+        DateTime::parse_from_rfc3339(&self.timestamp).ok().map(|timestamp| timestamp.date_naive())
+    }
+}
+
+pub fn record_usage(log: &Path, entry: &UsageEntry) -> Result<()> {
+    if let Some(parent) = log.parent().filter(|parent| !parent.as_os_str().is_empty()) {
+        fs::create_dir_all(parent)
+            .with_context(|| format!("Failed to create {}", parent.display()))?;
+    }
+    
+    let mut file = OpenOptions::new().create(true).append(true).open(log)
+        .with_context(|| format!("Failed to open usage log: {}", log.display()))?;
+    writeln!(file, "{}", serde_json::to_string(entry)?)
+        .with_context(|| format!("Failed to write usage log: {}", log.display()))
+}
+
+#[derive(Debug, Clone, Serialize)]
+pub struct UsageChange {
+    pub circuit: String,
+    pub timestamp: String,
+    pub before: usize,
+    pub after: usize,
+}
+
+impl UsageChange {
+    pub fn percent(&self) -> f64 {
+        if self.before == 0 {
+            return 0.0;
+        }
+        (self.after as f64 - self.before as f64) / self.before as f64 * 100.0
+    }
+}
+
+pub fn load_usage(log: &Path) -> Result<(Vec<UsageEntry>, usize)> {
+    if !log.exists() {
+        return Ok((Vec::new(), 0));
+    }
+    
+    let content = fs::read_to_string(log)
+        .with_context(|| format!("Failed to read usage log: {}", log.display()))?;
+    
+    let mut entries = Vec::new();
+    let mut unreadable = 0;
+    for line in content.lines().filter(|line| !line.trim().is_empty()) {
+        match serde_json::from_str::<UsageEntry>(line) {
+            Ok(entry) => entries.push(entry),
+            Err(_) => unreadable += 1,
+        }
+    }
+    
+    Ok((entries, unreadable))
+}
+
+fn is_before(entry: &UsageEntry, since: Option<NaiveDate>) -> bool {
+    since.is_some_and(|since| entry.date().is_none_or(|date| date < since))
+}
+
+pub fn biggest_regression(entries: &[UsageEntry], since: Option<NaiveDate>) -> Option<UsageChange> {
+    let mut previous: BTreeMap<&str, usize> = BTreeMap::new();
+    let mut biggest: Option<UsageChange> = None;
+    
+    for entry in entries {
+        let before = previous.insert(&entry.circuit, entry.constraints);
+        let Some(before) = before.filter(|before| entry.constraints > *before) else {
+            continue;
+        };
+        if is_before(entry, since) {
+            continue;
+        }
+        
+        let change = UsageChange {
+            circuit: entry.circuit.clone(),
+            timestamp: entry.timestamp.clone(),
+            before,
+            after: entry.constraints,
+        };
+        if biggest.as_ref().is_none_or(|biggest| change.percent() > biggest.percent()) {
+            biggest = Some(change);
+        }
+    }
+    
+    biggest
+}
+
+pub fn summarize_usage(log: &Path, since: Option<NaiveDate>) -> Result<UsageSummary> {
+    let (entries, unreadable) = load_usage(log)?;
+    let mut summary = UsageSummary { unreadable, ..Default::default() };
+    
+    let mut latest: BTreeMap<String, UsageEntry> = BTreeMap::new();
+    for entry in entries {
+        if is_before(&entry, since) {
+            continue;
+        }
+        
+        summary.runs += 1;
+        if summary.first.is_none() {
+            summary.first = Some(entry.timestamp.clone());
+        }
+        summary.last = Some(entry.timestamp.clone());
+        latest.insert(entry.circuit.clone(), entry);
+    }
+    
+    summary.circuits = latest.len();
+    if latest.is_empty() {
+        return Ok(summary);
+    }
+    
+    let circuits = latest.len() as f64;
+    summary.average_constraints = latest.values().map(|entry| entry.constraints as f64).sum::<f64>() / circuits;
+    summary.average_opcodes = latest.values().map(|entry| entry.total_opcodes as f64).sum::<f64>() / circuits;
+    summary.largest = latest.values()
+        .max_by(|a, b| a.constraints.cmp(&b.constraints).then_with(|| b.circuit.cmp(&a.circuit)))
+        .map(|entry| (entry.circuit.clone(), entry.constraints));
+    
+    let mut black_boxes: BTreeMap<&str, (usize, usize)> = BTreeMap::new();
+    for entry in latest.values() {
+        for (name, calls) in &entry.black_box_functions {
+            let usage = black_boxes.entry(name.as_str()).or_insert((0, 0));
+            usage.0 += 1;
+            usage.1 = usage.1.saturating_add(*calls);
+        }
+    }
+    
+    summary.black_boxes = black_boxes.into_iter()
+        .map(|(name, (circuits, calls))| BlackBoxUsage { name: name.to_string(), circuits, calls })
+        .collect();
+    summary.black_boxes.sort_by(|a, b| b.circuits.cmp(&a.circuits)
+        .then_with(|| b.calls.cmp(&a.calls))
+        .then_with(|| a.name.cmp(&b.name)));
+    
+    Ok(summary)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    
+    fn entry(circuit: &str, timestamp: &str, constraints: usize, black_boxes: &[(&str, usize)]) -> UsageEntry {
+        UsageEntry {
+            timestamp: timestamp.to_string(),
+            circuit: circuit.to_string(),
+            constraints,
+            total_opcodes: constraints / 10,
+            black_box_functions: black_boxes.iter().map(|(name, calls)| (name.to_string(), *calls)).collect(),
+        }
+    }
+    
+    #[test]
+    fn finds_the_biggest_regression() {
+        let entries = [
+            entry("a", "2026-01-01T00:00:00Z", 100, &[]),
+            entry("b", "2026-01-01T00:00:00Z", 1000, &[]),
+            entry("a", "2026-01-02T00:00:00Z", 150, &[]),
+            entry("b", "2026-03-01T00:00:00Z", 2000, &[]),
+        ];
+        
+        let change = biggest_regression(&entries, None).unwrap();
+        assert_eq!((change.circuit.as_str(), change.percent()), ("b", 100.0));
+        
+        let since = NaiveDate::from_ymd_opt(2026, 4, 1);
+        assert!(biggest_regression(&entries, since).is_none());
+    }
+    
+    #[test]
+    fn summarizes_the_latest_run_per_circuit() {
+        let log = std::env::temp_dir().join(format!("noir-profiler-usage-{}.jsonl", std::process::id()));
+        record_usage(&log, &entry("a", "2026-01-01T00:00:00Z", 100, &[("sha256", 2)])).unwrap();
+        record_usage(&log, &entry("a", "2026-01-02T00:00:00Z", 300, &[("sha256", 4)])).unwrap();
+        record_usage(&log, &entry("b", "2026-01-03T00:00:00Z", 100, &[("sha256", 1), ("keccak256", 1)])).unwrap();
+        OpenOptions::new().append(true).open(&log).unwrap().write_all(b"not json\n").unwrap();
+        
+        let summary = summarize_usage(&log, None);
+        fs::remove_file(&log).unwrap();
+        let summary = summary.unwrap();
+        
+        assert_eq!((summary.runs, summary.circuits, summary.unreadable), (3, 2, 1));
+        assert_eq!(summary.average_constraints, 200.0);
+        assert_eq!(summary.largest, Some(("a".to_string(), 300)));
+        assert_eq!((summary.black_boxes[0].name.as_str(), summary.black_boxes[0].circuits, summary.black_boxes[0].calls), ("sha256", 2, 5));
+    }
+    
+    #[test]
+    fn entries_merge_black_box_aliases() {
+        let analysis = CircuitAnalysis { black_box_functions: vec![("Keccak".into(), 2, 10), ("keccak256".into(), 1, 10)], ..Default::default() };
+        let entry = UsageEntry::new(Path::new("missing.json"), &analysis);
+        
+        assert_eq!(entry.black_box_functions, [("keccak256".to_string(), 3)]);
+        assert!(entry.date().is_some());
+    }
+}